@@ -13,32 +13,39 @@
 // limitations under the License.
 
 use anyhow::{Result, anyhow};
-use aurora_config::PortfolioConfig;
-use aurora_core::{Kline, MarketEvent, Signal, Strategy};
-use aurora_portfolio::{BasePortfolio, Portfolio, PortfolioAnalytics};
-use aurora_strategy::{BuyAndHoldStrategy, MACrossoverStrategy};
+use aurora_config::{PortfolioConfig, StrategyConfig};
+use aurora_core::{
+    Clock, DataSource, Kline, MarketEvent, OrderRejection, RunMode, Signal, SignalEvent, SimulatedClock,
+    Strategy, StrategyContext, TimerSchedule,
+};
+use aurora_portfolio::{BasePortfolio, ExecutionPrices, IntentExecutor, Portfolio, PortfolioAnalytics, Trade};
+use aurora_strategy::{BuyAndHoldStrategy, StrategyRegistry};
 use std::path::Path;
+use std::sync::Arc;
 use tracing::{debug, error, info};
 
+mod builder;
+
+pub use builder::BacktestEngineBuilder;
+
 // 在库内部使用相对路径
 use crate::pricing_mode::PricingMode;
 use crate::result::BacktestResult;
 use crate::time_utils::{parse_date_to_timestamp_with_tz, validate_time_range, format_timestamp_with_tz, TimeRangeValidation};
 
 /// 运行回测
+///
+/// 策略由 [`StrategyRegistry`] 按 `strategy_config` 的类型和参数创建，
+/// 需要进度回调、时间范围或基准对比时使用 [`run_backtest_with_progress`]。
 pub async fn run_backtest(
     data_path: &str,
-    strategy_name: &str,
-    short_period: usize,
-    long_period: usize,
+    strategy_config: &StrategyConfig,
     portfolio_config: &PortfolioConfig,
     pricing_mode_config: Option<&aurora_config::PricingModeConfig>,
 ) -> Result<BacktestResult> {
     run_backtest_with_progress::<fn(u8)>(
         data_path,
-        strategy_name,
-        short_period,
-        long_period,
+        strategy_config,
         portfolio_config,
         pricing_mode_config,
         None,
@@ -51,11 +58,13 @@ pub async fn run_backtest(
 }
 
 /// 运行回测（支持进度回调和时间范围）
+///
+/// 策略由 [`StrategyRegistry`] 按 `strategy_config` 的类型和参数创建，
+/// 策略类型未注册或参数非法时返回错误。
+/// `portfolio_config` 中配置了交易品种规则时，策略和基准的订单都按该规则取整。
 pub async fn run_backtest_with_progress<F>(
     data_path: &str,
-    strategy_name: &str,
-    short_period: usize,
-    long_period: usize,
+    strategy_config: &StrategyConfig,
     portfolio_config: &PortfolioConfig,
     pricing_mode_config: Option<&aurora_config::PricingModeConfig>,
     progress_callback: Option<F>,
@@ -67,6 +76,11 @@ pub async fn run_backtest_with_progress<F>(
 where
     F: Fn(u8) + Send + Sync,
 {
+    // 先创建策略，参数错误时无需加载数据
+    let strategy = StrategyRegistry::new()
+        .from_config(strategy_config)
+        .map_err(|e| anyhow!("创建策略 {} 失败: {}", strategy_config.name, e))?;
+
     // 验证数据文件是否存在
     if !Path::new(data_path).exists() {
         return Err(anyhow!("数据文件不存在: {}", data_path));
@@ -105,18 +119,16 @@ where
         return Err(anyhow!("没有有效的K线数据"));
     }
 
-    // 创建策略
-    let strategy = match strategy_name {
-        "ma-crossover" => MACrossoverStrategy::new(short_period, long_period),
-        _ => return Err(anyhow!("不支持的策略: {}", strategy_name)),
-    };
-
     // 从配置创建定价模式
     let pricing_mode = PricingMode::from_config(pricing_mode_config);
 
     info!(
-        "初始化回测引擎，策略: {}, 参数: {}:{}, 初始资金: {:.2}, 定价模式: {:?}",
-        strategy_name, short_period, long_period, portfolio_config.initial_cash, pricing_mode
+        "初始化回测引擎，策略: {} ({}), 参数: {:?}, 初始资金: {:.2}, 定价模式: {:?}",
+        strategy_config.name,
+        strategy_config.strategy_type,
+        strategy_config.parameters,
+        portfolio_config.initial_cash,
+        pricing_mode
     );
 
     // 创建回测引擎并运行
@@ -238,22 +250,31 @@ fn load_klines_from_csv_with_filter(
 }
 
 /// 回测引擎
-pub struct BacktestEngine {
-    strategy: MACrossoverStrategy,
-    portfolio: BasePortfolio,
+///
+/// 引擎持有一个由事件时间戳驱动的 [`SimulatedClock`]，
+/// 风险管理器的单日统计和策略定时器都按这个时钟计时。
+///
+/// 策略类型默认为 `Box<dyn Strategy>`，用于运行 [`StrategyRegistry`] 按配置创建的策略；
+/// 直接传入具体策略时引擎按该类型单态化。投资组合类型默认为 [`BasePortfolio`]，
+/// 需要自定义投资组合实现或风险规则时使用 [`BacktestEngine::builder`]。
+pub struct BacktestEngine<S: Strategy = Box<dyn Strategy>, P: Portfolio = BasePortfolio> {
+    strategy: S,
+    portfolio: P,
     pricing_mode: PricingMode,
+    executor: IntentExecutor,
+    clock: Arc<SimulatedClock>,
     stop_loss_pct: Option<f64>,
     take_profit_pct: Option<f64>,
 }
 
-impl BacktestEngine {
+impl<S: Strategy> BacktestEngine<S> {
     /// 创建新的回测引擎
     ///
     /// # 参数
     ///
-    /// * `strategy` - 交易策略
+    /// * `strategy` - 交易策略，可以是具体策略，也可以是注册表创建的 `Box<dyn Strategy>`
     /// * `portfolio_config` - 投资组合配置（包含风险管理和仓位管理规则）
-    pub fn new(strategy: MACrossoverStrategy, portfolio_config: &PortfolioConfig) -> Result<Self> {
+    pub fn new(strategy: S, portfolio_config: &PortfolioConfig) -> Result<Self> {
         Self::with_pricing_mode(strategy, portfolio_config, PricingMode::default())
     }
 
@@ -265,63 +286,43 @@ impl BacktestEngine {
     /// * `portfolio_config` - 投资组合配置
     /// * `pricing_mode` - 定价模式（控制买卖价格计算方式）
     pub fn with_pricing_mode(
-        strategy: MACrossoverStrategy,
+        strategy: S,
         portfolio_config: &PortfolioConfig,
         pricing_mode: PricingMode,
     ) -> Result<Self> {
-        let mut portfolio = BasePortfolio::new(portfolio_config.initial_cash);
-        
-        // 提取止损止盈百分比（如果配置了的话）
-        let stop_loss_pct = portfolio_config
-            .risk_rules
-            .as_ref()
-            .and_then(|r| r.stop_loss_pct);
-        let take_profit_pct = portfolio_config
-            .risk_rules
-            .as_ref()
-            .and_then(|r| r.take_profit_pct);
-        
-        // 配置风险管理器（如果提供）
-        if let Some(ref risk_rules_config) = portfolio_config.risk_rules {
-            let risk_rules = risk_rules_config.to_risk_rules();
-            let risk_manager = aurora_portfolio::RiskManager::new(risk_rules, portfolio_config.initial_cash);
-            portfolio = portfolio.with_risk_manager(risk_manager);
-            info!("已启用风险管理");
-            
-            if stop_loss_pct.is_some() || take_profit_pct.is_some() {
-                info!(
-                    "已配置动态止损止盈: 止损={}%, 止盈={}%",
-                    stop_loss_pct.map(|v| v.to_string()).unwrap_or("未设置".to_string()),
-                    take_profit_pct.map(|v| v.to_string()).unwrap_or("未设置".to_string())
-                );
-            }
-        }
-        
-        // 配置仓位管理器（如果提供）
-        if let Some(ref position_sizing_config) = portfolio_config.position_sizing {
-            let position_strategy = position_sizing_config.to_position_sizing_strategy();
-            let position_manager = aurora_portfolio::PositionManager::new(position_strategy);
-            portfolio = portfolio.with_position_manager(position_manager);
-            info!("已启用仓位管理");
-        }
+        Self::builder(strategy)
+            .portfolio_config(portfolio_config)
+            .pricing_mode(pricing_mode)
+            .build()
+    }
 
-        info!("定价模式: {:?}", pricing_mode);
-        
-        Ok(Self {
-            strategy,
-            portfolio,
-            pricing_mode,
-            stop_loss_pct,
-            take_profit_pct,
-        })
+    /// 创建回测引擎构建器
+    ///
+    /// 构建器可以单独设置投资组合、风险规则、仓位管理、品种规则和定价模式，
+    /// 适合在库中组合自定义策略和投资组合，详见 [`BacktestEngineBuilder`]。
+    pub fn builder(strategy: S) -> BacktestEngineBuilder<S> {
+        BacktestEngineBuilder::new(strategy)
     }
 
-    /// 运行回测
+    /// 设置交易品种规则
     ///
-    /// # 参数
+    /// 设置后，回测中的每笔买卖都会按品种的价格最小变动单位和数量步长取整，
+    /// 不满足最小数量或最小名义金额的订单会被拒绝。
+    pub fn with_instrument(mut self, instrument: aurora_portfolio::Instrument) -> Self {
+        info!("交易品种规则: {:?}", instrument);
+        self.portfolio = self.portfolio.with_instrument(instrument);
+        self
+    }
+}
+
+impl<S: Strategy, P: Portfolio> BacktestEngine<S, P> {
+    /// 获取回测时钟
     ///
-    /// * `klines` - K线数据
-    /// * `data_path` - 数据文件路径（可选）
+    /// 返回的时钟随回测推进，可共享给需要读取当前回测时间的组件。
+    pub fn clock(&self) -> Arc<dyn Clock> {
+        self.clock.clone()
+    }
+
     /// 运行回测
     ///
     /// # 参数
@@ -337,8 +338,8 @@ impl BacktestEngine {
 
     /// 运行回测（支持进度回调）
     ///
-    /// # 参数
-    /// 运行回测（支持进度回调）
+    /// K线会被包装为 `MarketEvent::Kline` 后交给
+    /// [`run_events_with_progress`](Self::run_events_with_progress) 处理。
     ///
     /// # 参数
     ///
@@ -353,112 +354,146 @@ impl BacktestEngine {
         progress_callback: Option<F>,
         enable_benchmark: bool,
     ) -> Result<BacktestResult>
+    where
+        F: Fn(u8) + Send + Sync,
+    {
+        let events: Vec<MarketEvent> = klines.iter().cloned().map(MarketEvent::Kline).collect();
+        self.run_events_with_progress(&events, data_path, progress_callback, enable_benchmark)
+            .await
+    }
+
+    /// 从数据源读取全部市场事件后运行回测
+    ///
+    /// 数据源应在发送完全部事件后关闭通道，例如 `aurora_data::HistoricalReplaySource`，
+    /// 持续推送的实时数据源不会结束。事件按时间戳排序后交给
+    /// [`run_events`](Self::run_events) 处理。
+    ///
+    /// # 参数
+    ///
+    /// * `source` - 市场数据源
+    /// * `data_path` - 数据来源说明（可选），写入回测结果
+    /// * `enable_benchmark` - 是否启用基准回测（Buy & Hold策略）
+    pub async fn run_source<D>(
+        &mut self,
+        source: &mut D,
+        data_path: Option<String>,
+        enable_benchmark: bool,
+    ) -> Result<BacktestResult>
+    where
+        D: DataSource + Send + ?Sized,
+    {
+        let mut receiver = source.start().await?;
+        let mut events = Vec::new();
+        while let Some(event) = receiver.recv().await {
+            events.push(event);
+        }
+        if events.is_empty() {
+            return Err(anyhow!("数据源没有产生任何市场事件"));
+        }
+        events.sort_by_key(MarketEvent::timestamp);
+
+        info!("从数据源读取 {} 个市场事件", events.len());
+        self.run_events(&events, data_path, enable_benchmark).await
+    }
+
+    /// 基于任意市场事件序列运行回测
+    ///
+    /// 事件可以混合K线、逐笔成交、报价、深度快照和资金费率，
+    /// 策略会按顺序收到每一个事件。
+    ///
+    /// # 参数
+    ///
+    /// * `events` - 按时间排序的市场事件
+    /// * `data_path` - 数据文件路径（可选）
+    /// * `enable_benchmark` - 是否启用基准回测（Buy & Hold策略）
+    pub async fn run_events(
+        &mut self,
+        events: &[MarketEvent],
+        data_path: Option<String>,
+        enable_benchmark: bool,
+    ) -> Result<BacktestResult> {
+        self.run_events_with_progress(events, data_path, None::<fn(u8)>, enable_benchmark)
+            .await
+    }
+
+    /// 基于任意市场事件序列运行回测（支持进度回调）
+    ///
+    /// 成交价格由 [`PricingMode`] 根据事件类型确定：K线按定价模式计算，
+    /// 报价和深度快照使用真实买卖价，逐笔成交和资金费率以成交价或标记价格为中间价。
+    /// 无法确定价格的事件只会交给策略，不会触发交易或更新权益。
+    ///
+    /// # 参数
+    ///
+    /// * `events` - 按时间排序的市场事件
+    /// * `data_path` - 数据文件路径（可选）
+    /// * `progress_callback` - 进度回调函数，参数为进度百分比(0-100)
+    /// * `enable_benchmark` - 是否启用基准回测（Buy & Hold策略）
+    pub async fn run_events_with_progress<F>(
+        &mut self,
+        events: &[MarketEvent],
+        data_path: Option<String>,
+        progress_callback: Option<F>,
+        enable_benchmark: bool,
+    ) -> Result<BacktestResult>
     where
         F: Fn(u8) + Send + Sync,
     {
         info!(
             "开始回测，数据时间范围: {} - {}",
-            klines.first().map(|k| k.timestamp).unwrap_or(0),
-            klines.last().map(|k| k.timestamp).unwrap_or(0)
+            events.first().map(|e| e.timestamp()).unwrap_or(0),
+            events.last().map(|e| e.timestamp()).unwrap_or(0)
         );
 
         let mut processed_count = 0;
-        let total_count = klines.len();
+        let total_count = events.len();
         let mut last_reported_progress: u8 = 0;
+        let mut last_mark_price: Option<f64> = None;
+        let mut last_prices: Option<ExecutionPrices> = None;
+
+        let context = self.strategy_context(events);
+        self.strategy.on_start(&context);
+        let mut timer = self.strategy.timer_interval().map(TimerSchedule::new);
+
+        for market_event in events {
+            let timestamp = market_event.timestamp();
+            self.clock.advance_to(timestamp);
+            let prices = self.execution_prices(market_event);
+            if prices.is_some() {
+                last_prices = prices;
+            }
 
-        for kline in klines {
-            // 创建市场事件
-            let market_event = MarketEvent::Kline(kline.clone());
+            // 先检查挂单和止损止盈价是否被本次行情触发
+            if let Some(prices) = prices {
+                let results = self
+                    .executor
+                    .on_prices(&mut self.portfolio, &prices, timestamp)
+                    .await;
+                for result in results {
+                    self.handle_execution(result);
+                }
+            }
 
-            // 让策略处理事件
-            if let Some(signal_event) = self.strategy.on_market_event(&market_event) {
-                // 执行交易信号，使用定价模式确定实际交易价格
-                match signal_event.signal {
-                    Signal::Buy => {
-                        let buy_price = self.pricing_mode.get_buy_price(kline);
-                        debug!(
-                            "收到买入信号，信号价格: {:.2}, 实际买入价格: {:.2}",
-                            signal_event.price, buy_price
-                        );
-                        match self
-                            .portfolio
-                            .execute_buy(buy_price, signal_event.timestamp)
-                            .await
-                        {
-                            Ok(_trade) => {
-                                // 买入成功后，如果配置了止损止盈百分比，则设置止损止盈价格
-                                if self.stop_loss_pct.is_some() || self.take_profit_pct.is_some() {
-                                    if let Some(risk_manager) = self.portfolio.get_risk_manager_mut() {
-                                        let stop_loss = self.stop_loss_pct.unwrap_or(0.0);
-                                        let take_profit = self.take_profit_pct.unwrap_or(0.0);
-                                        
-                                        if stop_loss > 0.0 && take_profit > 0.0 {
-                                            risk_manager.set_stop_loss_take_profit(buy_price, stop_loss, take_profit);
-                                            debug!(
-                                                "已设置止损止盈: 入场价={:.2}, 止损={}%, 止盈={}%",
-                                                buy_price, stop_loss, take_profit
-                                            );
-                                        } else if stop_loss > 0.0 {
-                                            let stop_price = risk_manager.calculate_stop_loss(buy_price, stop_loss);
-                                            risk_manager.update_rules(
-                                                risk_manager.get_rules().clone()
-                                                    .with_stop_loss_price(stop_price)
-                                            );
-                                            debug!(
-                                                "已设置止损: 入场价={:.2}, 止损价={:.2} ({}%)",
-                                                buy_price, stop_price, stop_loss
-                                            );
-                                        } else if take_profit > 0.0 {
-                                            let take_price = risk_manager.calculate_take_profit(buy_price, take_profit);
-                                            risk_manager.update_rules(
-                                                risk_manager.get_rules().clone()
-                                                    .with_take_profit_price(take_price)
-                                            );
-                                            debug!(
-                                                "已设置止盈: 入场价={:.2}, 止盈价={:.2} ({}%)",
-                                                buy_price, take_price, take_profit
-                                            );
-                                        }
-                                    }
-                                }
-                            }
-                            Err(e) => {
-                                debug!("买入失败: {}", e);
-                            }
-                        }
-                    }
-                    Signal::Sell => {
-                        let sell_price = self.pricing_mode.get_sell_price(kline);
-                        debug!(
-                            "收到卖出信号，信号价格: {:.2}, 实际卖出价格: {:.2}",
-                            signal_event.price, sell_price
-                        );
-                        match self
-                            .portfolio
-                            .execute_sell(sell_price, signal_event.timestamp)
-                            .await
-                        {
-                            Ok(_trade) => {
-                                // 卖出成功后，清除止损止盈设置
-                                if let Some(risk_manager) = self.portfolio.get_risk_manager_mut() {
-                                    risk_manager.clear_stop_loss_take_profit();
-                                    debug!("已清除止损止盈设置");
-                                }
-                            }
-                            Err(e) => {
-                                debug!("卖出失败: {}", e);
-                            }
-                        }
-                    }
-                    Signal::Hold => {
-                        // 不做任何操作
-                    }
+            // 触发到期的定时器，定时器信号按最近一次行情价格执行
+            let due_timers = timer
+                .as_mut()
+                .map(|t| t.poll(self.clock.as_ref()))
+                .unwrap_or_default();
+            for tick in due_timers {
+                if let Some(signal_event) = self.strategy.on_timer(tick) {
+                    self.execute_signal(&signal_event, last_prices).await;
                 }
             }
 
+            // 让策略处理事件
+            if let Some(signal_event) = self.strategy.on_market_event(market_event) {
+                self.execute_signal(&signal_event, prices).await;
+            }
+
             // 更新权益曲线，使用标记价格（中间价）
-            let mark_price = self.pricing_mode.get_mark_price(kline);
-            self.portfolio.update_equity(kline.timestamp, mark_price);
+            if let Some(mark_price) = self.pricing_mode.event_mark_price(market_event) {
+                self.portfolio.update_equity(market_event.timestamp(), mark_price);
+                last_mark_price = Some(mark_price);
+            }
 
             processed_count += 1;
 
@@ -484,7 +519,7 @@ impl BacktestEngine {
             // 每处理10%的数据输出一次进度日志
             if processed_count % (total_count / 10).max(1) == 0 {
                 let progress = (processed_count as f64 / total_count as f64) * 100.0;
-                let current_equity = self.portfolio.get_total_equity(kline.close);
+                let current_equity = self.portfolio.get_total_equity(last_mark_price.unwrap_or(0.0));
                 info!(
                     "回测进度: {:.1}%, 当前权益: {:.2}",
                     progress, current_equity
@@ -492,16 +527,11 @@ impl BacktestEngine {
             }
         }
 
-        info!("回测完成，处理了 {} 条K线数据", processed_count);
+        self.strategy.on_finish();
+        info!("回测完成，处理了 {} 条市场事件", processed_count);
 
         // 计算回测报告
-        let time_period_days = if !klines.is_empty() {
-            let start_time = klines.first().unwrap().timestamp;
-            let end_time = klines.last().unwrap().timestamp;
-            (end_time - start_time) as f64 / (24.0 * 60.0 * 60.0 * 1000.0)
-        } else {
-            1.0
-        };
+        let time_period_days = time_period_days(events);
 
         // 从权益曲线获取初始权益
         let initial_equity = self.portfolio.get_equity_curve()
@@ -509,9 +539,7 @@ impl BacktestEngine {
             .map(|p| p.equity)
             .unwrap_or(self.portfolio.get_cash());
         
-        let final_equity = self.portfolio.get_total_equity(
-            klines.last().map(|k| k.close).unwrap_or(0.0)
-        );
+        let final_equity = self.portfolio.get_total_equity(last_mark_price.unwrap_or(0.0));
         
         let metrics = self.portfolio.calculate_performance(time_period_days);
         
@@ -525,7 +553,7 @@ impl BacktestEngine {
         // 根据配置决定是否运行基准策略回测（Buy & Hold）
         let result = if enable_benchmark {
             info!("开始运行基准策略（Buy & Hold）回测...");
-            let benchmark_result = self.run_benchmark(klines, initial_equity).await?;
+            let benchmark_result = self.run_benchmark(events, initial_equity).await?;
             
             // 计算 Alpha
             let benchmark_return = benchmark_result.metrics.total_return;
@@ -570,59 +598,177 @@ impl BacktestEngine {
         Ok(result)
     }
 
+    /// 执行策略产生的交易信号
+    ///
+    /// `prices` 由定价模式根据触发信号的市场事件计算，无法确定价格时放弃执行。
+    /// 信号携带的订单意图（下单规模、订单类型、止损止盈价）由 [`IntentExecutor`] 处理，
+    /// 未成交的挂单在后续事件中继续检查。成交和拒绝都会通知策略。
+    async fn execute_signal(&mut self, signal_event: &SignalEvent, prices: Option<ExecutionPrices>) {
+        if signal_event.signal == Signal::Hold {
+            return;
+        }
+        let Some(prices) = prices else {
+            debug!("收到{:?}信号，但当前事件无法确定成交价格，忽略", signal_event.signal);
+            return;
+        };
+        debug!(
+            "收到{:?}信号，信号价格: {:.2}, 买入价格: {:.2}, 卖出价格: {:.2}",
+            signal_event.signal, signal_event.price, prices.buy, prices.sell
+        );
+
+        let result = self
+            .executor
+            .submit(&mut self.portfolio, signal_event, &prices)
+            .await;
+        if let Some(result) = result.transpose() {
+            self.handle_execution(result);
+        }
+    }
+
+    /// 处理订单执行结果并通知策略
+    fn handle_execution(&mut self, result: Result<Trade, OrderRejection>) {
+        match result {
+            Ok(trade) => {
+                self.after_trade(&trade);
+                self.strategy.on_order_filled(&trade);
+            }
+            Err(rejection) => {
+                debug!("{}", rejection);
+                self.strategy.on_order_rejected(&rejection);
+            }
+        }
+    }
+
+    /// 构建传给策略 `on_start` 的运行上下文
+    fn strategy_context(&self, events: &[MarketEvent]) -> StrategyContext {
+        let mut context = StrategyContext::new(RunMode::Backtest, self.portfolio.get_cash());
+        if let Some(instrument) = self.portfolio.instrument() {
+            context = context.with_symbol(&instrument.symbol);
+        }
+        if let Some(first) = events.first() {
+            context = context.with_start_time(first.timestamp());
+        }
+        context
+    }
+
+    /// 根据定价模式计算市场事件的可成交价格
+    ///
+    /// K线事件的价格区间取开盘价、最高价和最低价，用于判断挂单和止损止盈是否触发及跳空成交价。
+    fn execution_prices(&self, market_event: &MarketEvent) -> Option<ExecutionPrices> {
+        let buy = self.pricing_mode.event_buy_price(market_event)?;
+        let sell = self.pricing_mode.event_sell_price(market_event)?;
+        let prices = ExecutionPrices::new(buy, sell);
+        Some(match market_event {
+            MarketEvent::Kline(kline) => prices.with_open(kline.open).with_range(kline.high, kline.low),
+            _ => prices,
+        })
+    }
+
+    /// 成交后的处理：买入后设置配置的止损止盈，平仓后清除
+    fn after_trade(&mut self, trade: &Trade) {
+        if trade.is_buy() {
+            self.set_protective_levels(trade.price);
+        } else if self.portfolio.get_position() <= 0.0
+            && let Some(risk_manager) = self.portfolio.get_risk_manager_mut()
+        {
+            risk_manager.clear_stop_loss_take_profit();
+            debug!("已清除止损止盈设置");
+        }
+    }
+
+    /// 根据配置的止损止盈百分比，为刚建立的仓位设置止损止盈价格
+    fn set_protective_levels(&mut self, buy_price: f64) {
+        if self.stop_loss_pct.is_none() && self.take_profit_pct.is_none() {
+            return;
+        }
+
+        let stop_loss = self.stop_loss_pct.unwrap_or(0.0);
+        let take_profit = self.take_profit_pct.unwrap_or(0.0);
+
+        let Some(risk_manager) = self.portfolio.get_risk_manager_mut() else {
+            return;
+        };
+
+        if stop_loss > 0.0 && take_profit > 0.0 {
+            risk_manager.set_stop_loss_take_profit(buy_price, stop_loss, take_profit);
+            debug!(
+                "已设置止损止盈: 入场价={:.2}, 止损={}%, 止盈={}%",
+                buy_price, stop_loss, take_profit
+            );
+        } else if stop_loss > 0.0 {
+            let stop_price = risk_manager.calculate_stop_loss(buy_price, stop_loss);
+            risk_manager.update_rules(
+                risk_manager.get_rules().clone()
+                    .with_stop_loss_price(stop_price)
+            );
+            debug!(
+                "已设置止损: 入场价={:.2}, 止损价={:.2} ({}%)",
+                buy_price, stop_price, stop_loss
+            );
+        } else if take_profit > 0.0 {
+            let take_price = risk_manager.calculate_take_profit(buy_price, take_profit);
+            risk_manager.update_rules(
+                risk_manager.get_rules().clone()
+                    .with_take_profit_price(take_price)
+            );
+            debug!(
+                "已设置止盈: 入场价={:.2}, 止盈价={:.2} ({}%)",
+                buy_price, take_price, take_profit
+            );
+        }
+    }
+
     /// 运行基准策略（Buy & Hold）回测
     ///
     /// # 参数
     ///
-    /// * `klines` - K线数据
+    /// * `events` - 市场事件
     /// * `initial_cash` - 初始资金
     ///
     /// # 返回值
     ///
     /// 返回基准策略的回测结果
-    async fn run_benchmark(&self, klines: &[Kline], initial_cash: f64) -> Result<BacktestResult> {
+    async fn run_benchmark(&self, events: &[MarketEvent], initial_cash: f64) -> Result<BacktestResult> {
         // 创建基准策略
         let mut benchmark_strategy = BuyAndHoldStrategy::new();
         
-        // 创建基准投资组合（只使用初始资金，不使用风险管理和仓位管理）
+        // 创建基准投资组合（只使用初始资金和品种规则，不使用风险管理和仓位管理）
         let mut benchmark_portfolio = BasePortfolio::new(initial_cash);
+        if let Some(instrument) = self.portfolio.instrument() {
+            benchmark_portfolio = benchmark_portfolio.with_instrument(instrument.clone());
+        }
+        let mut last_mark_price: Option<f64> = None;
         
         // 运行基准回测
-        for kline in klines {
-            let market_event = MarketEvent::Kline(kline.clone());
-            
+        for market_event in events {
             // 让基准策略处理事件
-            if let Some(signal_event) = benchmark_strategy.on_market_event(&market_event) {
+            if let Some(signal_event) = benchmark_strategy.on_market_event(market_event) {
                 match signal_event.signal {
                     Signal::Buy => {
-                        let buy_price = self.pricing_mode.get_buy_price(kline);
-                        let _ = benchmark_portfolio.execute_buy(buy_price, signal_event.timestamp).await;
+                        if let Some(buy_price) = self.pricing_mode.event_buy_price(market_event) {
+                            let _ = benchmark_portfolio.execute_buy(buy_price, signal_event.timestamp).await;
+                        }
                     }
                     Signal::Sell => {
-                        let sell_price = self.pricing_mode.get_sell_price(kline);
-                        let _ = benchmark_portfolio.execute_sell(sell_price, signal_event.timestamp).await;
+                        if let Some(sell_price) = self.pricing_mode.event_sell_price(market_event) {
+                            let _ = benchmark_portfolio.execute_sell(sell_price, signal_event.timestamp).await;
+                        }
                     }
                     Signal::Hold => {}
                 }
             }
             
             // 更新权益曲线
-            let mark_price = self.pricing_mode.get_mark_price(kline);
-            benchmark_portfolio.update_equity(kline.timestamp, mark_price);
+            if let Some(mark_price) = self.pricing_mode.event_mark_price(market_event) {
+                benchmark_portfolio.update_equity(market_event.timestamp(), mark_price);
+                last_mark_price = Some(mark_price);
+            }
         }
         
         // 计算基准回测报告
-        let time_period_days = if !klines.is_empty() {
-            let start_time = klines.first().unwrap().timestamp;
-            let end_time = klines.last().unwrap().timestamp;
-            (end_time - start_time) as f64 / (24.0 * 60.0 * 60.0 * 1000.0)
-        } else {
-            1.0
-        };
+        let time_period_days = time_period_days(events);
         
-        let final_equity = benchmark_portfolio.get_total_equity(
-            klines.last().map(|k| k.close).unwrap_or(0.0)
-        );
+        let final_equity = benchmark_portfolio.get_total_equity(last_mark_price.unwrap_or(0.0));
         
         let metrics = benchmark_portfolio.calculate_performance(time_period_days);
         let equity_curve = benchmark_portfolio.get_equity_curve().to_vec();
@@ -640,81 +786,20 @@ impl BacktestEngine {
     }
 
     /// 获取投资组合的引用
-    pub fn portfolio(&self) -> &BasePortfolio {
+    pub fn portfolio(&self) -> &P {
         &self.portfolio
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs::File;
-    use std::io::Write;
-    use tempfile::{TempDir, tempdir};
-
-    fn create_test_portfolio_config() -> PortfolioConfig {
-        PortfolioConfig {
-            initial_cash: 10000.0,
-            commission: 0.001,
-            slippage: 0.0005,
-            max_position_size: None,
-            max_positions: None,
-            risk_rules: None,
-            position_sizing: None,
+/// 计算事件序列覆盖的天数，用于年化指标
+fn time_period_days(events: &[MarketEvent]) -> f64 {
+    match (events.first(), events.last()) {
+        (Some(first), Some(last)) => {
+            (last.timestamp() - first.timestamp()) as f64 / (24.0 * 60.0 * 60.0 * 1000.0)
         }
-    }
-
-    fn create_test_csv() -> Result<(String, TempDir)> {
-        let dir = tempdir()?;
-        let file_path = dir.path().join("test_data.csv");
-        let mut file = File::create(&file_path)?;
-
-        writeln!(file, "timestamp,open,high,low,close,volume")?;
-        writeln!(file, "1640995200000,50000.0,51000.0,49000.0,50500.0,100.0")?;
-        writeln!(file, "1640995260000,50500.0,51500.0,50000.0,51000.0,120.0")?;
-        writeln!(file, "1640995320000,51000.0,52000.0,50500.0,51500.0,110.0")?;
-        writeln!(file, "1640995380000,51500.0,52500.0,51000.0,52000.0,130.0")?;
-        writeln!(file, "1640995440000,52000.0,53000.0,51500.0,52500.0,125.0")?;
-
-        Ok((file_path.to_string_lossy().to_string(), dir))
-    }
-
-    #[test]
-    fn test_load_klines_from_csv() {
-        let (csv_path, _temp_dir) = create_test_csv().unwrap();
-        let klines = load_klines_from_csv(&csv_path).unwrap();
-
-        assert_eq!(klines.len(), 5);
-        assert_eq!(klines[0].timestamp, 1640995200000);
-        assert_eq!(klines[0].close, 50500.0);
-        assert_eq!(klines[4].close, 52500.0);
-
-        // _temp_dir 在这里自动清理
-    }
-
-    #[tokio::test]
-    async fn test_backtest_engine() {
-        let (csv_path, _temp_dir) = create_test_csv().unwrap();
-        let klines = load_klines_from_csv(&csv_path).unwrap();
-
-        let strategy = MACrossoverStrategy::new(2, 3);
-        let portfolio_config = create_test_portfolio_config();
-        let mut engine = BacktestEngine::new(strategy, &portfolio_config).unwrap();
-
-        // 测试时禁用基准回测以提高测试速度
-        let result = engine.run(&klines, None, false).await;
-        assert!(result.is_ok());
-        
-        let backtest_result = result.unwrap();
-        assert_eq!(backtest_result.trades.len(), backtest_result.metrics.total_trades * 2);
-        assert!(!backtest_result.equity_curve.is_empty());
-
-        // _temp_dir 在这里自动清理
-    }
-
-    #[test]
-    fn test_nonexistent_file() {
-        let result = load_klines_from_csv("nonexistent.csv");
-        assert!(result.is_err());
+        _ => 1.0,
     }
 }
+
+#[cfg(test)]
+mod tests;
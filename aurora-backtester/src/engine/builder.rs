@@ -0,0 +1,249 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 回测引擎构建器
+
+use anyhow::{Result, anyhow};
+use aurora_config::PortfolioConfig;
+use aurora_core::{Instrument, SimulatedClock, Strategy};
+use aurora_portfolio::{
+    BasePortfolio, IntentExecutor, Portfolio, PositionManager, PositionSizingStrategy, RiskManager, RiskRules,
+};
+use std::sync::Arc;
+use tracing::info;
+
+use super::BacktestEngine;
+use crate::pricing_mode::PricingMode;
+
+/// 回测引擎构建器
+///
+/// 由 [`BacktestEngine::builder`] 创建。策略可以是任意 [`Strategy`] 实现，
+/// 投资组合可以整体替换为任意 [`Portfolio`] 实现，也可以只设置风险规则和仓位管理；
+/// 未设置投资组合时使用默认初始资金的 [`BasePortfolio`]。
+///
+/// 通过构建器设置的风险规则使用引擎的回测时钟，单日统计按事件时间重置。
+/// 直接传入的投资组合如果自带风险管理器，则沿用它原有的时钟设置。
+/// 风险规则、仓位管理和品种规则通过 [`Portfolio::set_risk_manager`] 等方法写入投资组合，
+/// 投资组合不支持时 [`build`](Self::build) 返回错误。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_backtester::{BacktestEngine, PricingMode};
+/// use aurora_core::{Kline, MarketEvent, Signal, SignalEvent, Strategy};
+/// use aurora_portfolio::{BasePortfolio, Portfolio, RiskRules};
+///
+/// /// 收盘价上涨时买入，下跌时卖出
+/// struct Momentum {
+///     last_close: Option<f64>,
+/// }
+///
+/// impl Strategy for Momentum {
+///     fn on_market_event(&mut self, event: &MarketEvent) -> Option<SignalEvent> {
+///         let MarketEvent::Kline(kline) = event else {
+///             return None;
+///         };
+///         let previous = self.last_close.replace(kline.close)?;
+///         let signal = if kline.close > previous { Signal::Buy } else { Signal::Sell };
+///         Some(SignalEvent::new(signal, kline.close, kline.timestamp))
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// let mut engine = BacktestEngine::builder(Momentum { last_close: None })
+///     .portfolio(BasePortfolio::new(5000.0))
+///     .risk_rules(RiskRules::new().with_max_drawdown(20.0))
+///     .pricing_mode(PricingMode::BidAsk { spread_pct: 0.001 })
+///     .build()?;
+///
+/// let klines: Vec<Kline> = [100.0, 101.0, 103.0, 102.0, 104.0]
+///     .iter()
+///     .enumerate()
+///     .map(|(i, &close)| Kline {
+///         timestamp: 1640995200000 + i as i64 * 60_000,
+///         open: close,
+///         high: close + 1.0,
+///         low: close - 1.0,
+///         close,
+///         volume: 10.0,
+///         symbol: None,
+///         interval: None,
+///     })
+///     .collect();
+///
+/// let result = engine.run(&klines, None, false).await?;
+/// assert_eq!(result.initial_equity, 5000.0);
+/// assert!(!engine.portfolio().get_trades().is_empty());
+/// # Ok(())
+/// # }
+/// ```
+pub struct BacktestEngineBuilder<S: Strategy, P: Portfolio = BasePortfolio> {
+    strategy: S,
+    portfolio: P,
+    risk_rules: Option<RiskRules>,
+    position_sizing: Option<PositionSizingStrategy>,
+    instrument: Option<Instrument>,
+    pricing_mode: PricingMode,
+    stop_loss_pct: Option<f64>,
+    take_profit_pct: Option<f64>,
+}
+
+impl<S: Strategy> BacktestEngineBuilder<S> {
+    /// 以给定策略创建构建器
+    pub fn new(strategy: S) -> Self {
+        Self {
+            strategy,
+            portfolio: BasePortfolio::new(PortfolioConfig::default().initial_cash),
+            risk_rules: None,
+            position_sizing: None,
+            instrument: None,
+            pricing_mode: PricingMode::default(),
+            stop_loss_pct: None,
+            take_profit_pct: None,
+        }
+    }
+
+}
+
+impl<S: Strategy, P: Portfolio> BacktestEngineBuilder<S, P> {
+    /// 按投资组合配置设置初始资金、风险规则、仓位管理、品种规则和止损止盈百分比
+    ///
+    /// 会以新的 [`BasePortfolio`] 覆盖之前设置的投资组合、风险规则、仓位管理和品种规则。
+    pub fn portfolio_config(self, config: &PortfolioConfig) -> BacktestEngineBuilder<S> {
+        let mut builder = self.portfolio(BasePortfolio::new(config.initial_cash));
+        builder.risk_rules = config.risk_rules.as_ref().map(|rules| rules.to_risk_rules());
+        builder.position_sizing = config
+            .position_sizing
+            .as_ref()
+            .map(|sizing| sizing.to_position_sizing_strategy());
+        builder.instrument = config.instrument.as_ref().map(|instrument| instrument.to_instrument());
+        builder.stop_loss_pct = config.risk_rules.as_ref().and_then(|r| r.stop_loss_pct);
+        builder.take_profit_pct = config.risk_rules.as_ref().and_then(|r| r.take_profit_pct);
+        builder
+    }
+
+    /// 使用自定义的投资组合
+    ///
+    /// 可以是配置好的 [`BasePortfolio`]，也可以是任意 [`Portfolio`] 实现。
+    /// 投资组合的现金即回测的初始资金，已配置的风险管理器、仓位管理器和品种规则保持不变。
+    pub fn portfolio<Q: Portfolio>(self, portfolio: Q) -> BacktestEngineBuilder<S, Q> {
+        BacktestEngineBuilder {
+            strategy: self.strategy,
+            portfolio,
+            risk_rules: self.risk_rules,
+            position_sizing: self.position_sizing,
+            instrument: self.instrument,
+            pricing_mode: self.pricing_mode,
+            stop_loss_pct: self.stop_loss_pct,
+            take_profit_pct: self.take_profit_pct,
+        }
+    }
+
+    /// 设置风险规则，替换投资组合原有的风险管理器
+    pub fn risk_rules(mut self, rules: RiskRules) -> Self {
+        self.risk_rules = Some(rules);
+        self
+    }
+
+    /// 设置仓位管理策略，替换投资组合原有的仓位管理器
+    pub fn position_sizing(mut self, strategy: PositionSizingStrategy) -> Self {
+        self.position_sizing = Some(strategy);
+        self
+    }
+
+    /// 设置交易品种规则
+    pub fn instrument(mut self, instrument: Instrument) -> Self {
+        self.instrument = Some(instrument);
+        self
+    }
+
+    /// 设置定价模式，默认为收盘价
+    pub fn pricing_mode(mut self, pricing_mode: PricingMode) -> Self {
+        self.pricing_mode = pricing_mode;
+        self
+    }
+
+    /// 设置买入后的止损百分比，例如 2.0 表示入场价下方 2%
+    pub fn stop_loss_pct(mut self, pct: f64) -> Self {
+        self.stop_loss_pct = Some(pct);
+        self
+    }
+
+    /// 设置买入后的止盈百分比，例如 5.0 表示入场价上方 5%
+    pub fn take_profit_pct(mut self, pct: f64) -> Self {
+        self.take_profit_pct = Some(pct);
+        self
+    }
+
+    /// 构建回测引擎
+    ///
+    /// 止损或止盈百分比为负数或非有限值时返回错误。
+    /// 设置了止损止盈但投资组合没有风险管理器时，会创建一个不含其他规则的风险管理器。
+    /// 投资组合不支持设置的风险规则、仓位管理或品种规则时返回错误。
+    pub fn build(self) -> Result<BacktestEngine<S, P>> {
+        for (name, pct) in [("止损", self.stop_loss_pct), ("止盈", self.take_profit_pct)] {
+            if let Some(pct) = pct
+                && !(pct.is_finite() && pct >= 0.0)
+            {
+                return Err(anyhow!("{}百分比必须为非负数: {}", name, pct));
+            }
+        }
+
+        let clock = Arc::new(SimulatedClock::default());
+        let mut portfolio = self.portfolio;
+        let initial_cash = portfolio.get_cash();
+
+        let protective = self.stop_loss_pct.is_some() || self.take_profit_pct.is_some();
+        let risk_rules = match self.risk_rules {
+            Some(rules) => Some(rules),
+            None if protective && portfolio.get_risk_manager_mut().is_none() => Some(RiskRules::new()),
+            None => None,
+        };
+        if let Some(rules) = risk_rules {
+            let risk_manager = RiskManager::new(rules, initial_cash).with_clock(clock.clone());
+            portfolio.set_risk_manager(risk_manager)?;
+            info!("已启用风险管理");
+        }
+        if protective {
+            info!(
+                "已配置动态止损止盈: 止损={}%, 止盈={}%",
+                self.stop_loss_pct.map(|v| v.to_string()).unwrap_or("未设置".to_string()),
+                self.take_profit_pct.map(|v| v.to_string()).unwrap_or("未设置".to_string())
+            );
+        }
+
+        if let Some(sizing) = self.position_sizing {
+            portfolio.set_position_manager(PositionManager::new(sizing))?;
+            info!("已启用仓位管理");
+        }
+
+        if let Some(instrument) = self.instrument {
+            info!("交易品种规则: {:?}", instrument);
+            portfolio.set_instrument(instrument)?;
+        }
+
+        info!("定价模式: {:?}", self.pricing_mode);
+
+        Ok(BacktestEngine {
+            strategy: self.strategy,
+            portfolio,
+            pricing_mode: self.pricing_mode,
+            executor: IntentExecutor::new(),
+            clock,
+            stop_loss_pct: self.stop_loss_pct,
+            take_profit_pct: self.take_profit_pct,
+        })
+    }
+}
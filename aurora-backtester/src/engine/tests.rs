@@ -0,0 +1,430 @@
+//! 回测引擎的单元测试模块
+
+use super::*;
+use aurora_config::StrategyParameter;
+use aurora_strategy::MACrossoverStrategy;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Write;
+use tempfile::{TempDir, tempdir};
+
+fn create_test_portfolio_config() -> PortfolioConfig {
+    PortfolioConfig {
+        initial_cash: 10000.0,
+        commission: 0.001,
+        slippage: 0.0005,
+        max_position_size: None,
+        max_positions: None,
+        risk_rules: None,
+        position_sizing: None,
+        instrument: None,
+    }
+}
+
+fn create_test_csv() -> Result<(String, TempDir)> {
+    let dir = tempdir()?;
+    let file_path = dir.path().join("test_data.csv");
+    let mut file = File::create(&file_path)?;
+
+    writeln!(file, "timestamp,open,high,low,close,volume")?;
+    writeln!(file, "1640995200000,50000.0,51000.0,49000.0,50500.0,100.0")?;
+    writeln!(file, "1640995260000,50500.0,51500.0,50000.0,51000.0,120.0")?;
+    writeln!(file, "1640995320000,51000.0,52000.0,50500.0,51500.0,110.0")?;
+    writeln!(file, "1640995380000,51500.0,52500.0,51000.0,52000.0,130.0")?;
+    writeln!(file, "1640995440000,52000.0,53000.0,51500.0,52500.0,125.0")?;
+
+    Ok((file_path.to_string_lossy().to_string(), dir))
+}
+
+#[test]
+fn test_load_klines_from_csv() {
+    let (csv_path, _temp_dir) = create_test_csv().unwrap();
+    let klines = load_klines_from_csv(&csv_path).unwrap();
+
+    assert_eq!(klines.len(), 5);
+    assert_eq!(klines[0].timestamp, 1640995200000);
+    assert_eq!(klines[0].close, 50500.0);
+    assert_eq!(klines[4].close, 52500.0);
+
+    // _temp_dir 在这里自动清理
+}
+
+#[tokio::test]
+async fn test_backtest_engine() {
+    let (csv_path, _temp_dir) = create_test_csv().unwrap();
+    let klines = load_klines_from_csv(&csv_path).unwrap();
+
+    let strategy = MACrossoverStrategy::new(2, 3);
+    let portfolio_config = create_test_portfolio_config();
+    let mut engine = BacktestEngine::new(strategy, &portfolio_config).unwrap();
+
+    // 测试时禁用基准回测以提高测试速度
+    let result = engine.run(&klines, None, false).await;
+    assert!(result.is_ok());
+
+    let backtest_result = result.unwrap();
+    assert_eq!(backtest_result.trades.len(), backtest_result.metrics.total_trades * 2);
+    assert!(!backtest_result.equity_curve.is_empty());
+
+    // _temp_dir 在这里自动清理
+}
+
+#[test]
+fn test_nonexistent_file() {
+    let result = load_klines_from_csv("nonexistent.csv");
+    assert!(result.is_err());
+}
+
+fn quote_event(timestamp: i64, bid_price: f64, ask_price: f64) -> MarketEvent {
+    MarketEvent::Quote(aurora_core::Quote {
+        symbol: "BTCUSDT".to_string(),
+        timestamp,
+        bid_price,
+        bid_quantity: 1.0,
+        ask_price,
+        ask_quantity: 1.0,
+    })
+}
+
+#[tokio::test]
+async fn test_run_events_with_mixed_market_data() {
+    let portfolio_config = create_test_portfolio_config();
+    let mut engine = BacktestEngine::new(MACrossoverStrategy::new(2, 3), &portfolio_config).unwrap();
+
+    let events = vec![
+        quote_event(1640995200000, 99.0, 101.0),
+        MarketEvent::Trade(aurora_core::TradeTick {
+            symbol: "BTCUSDT".to_string(),
+            timestamp: 1640995230000,
+            price: 105.0,
+            quantity: 0.5,
+            is_buyer_maker: false,
+            trade_id: Some(1),
+        }),
+        MarketEvent::DepthSnapshot(aurora_core::DepthSnapshot {
+            symbol: "BTCUSDT".to_string(),
+            timestamp: 1640995240000,
+            last_update_id: None,
+            bids: vec![],
+            asks: vec![],
+        }),
+        quote_event(1640995260000, 109.0, 111.0),
+    ];
+
+    let result = engine.run_events(&events, None, true).await.unwrap();
+
+    // 均线策略只处理K线，不会产生交易
+    assert!(result.trades.is_empty());
+    // 空深度快照没有价格，不更新权益曲线
+    assert_eq!(result.equity_curve.len(), 3);
+    assert_eq!(result.final_equity, 10000.0);
+
+    // 基准策略在第一个报价的卖一价买入，并以最后报价的中间价估值
+    let benchmark_curve = result.benchmark_equity_curve.unwrap();
+    let benchmark_final = benchmark_curve.last().unwrap().equity;
+    assert!(benchmark_final > 10000.0);
+    assert!(benchmark_final <= 10000.0 * 110.0 / 101.0 + 1e-6);
+    assert!(result.alpha.unwrap() < 0.0);
+}
+
+#[tokio::test]
+async fn test_engine_applies_instrument_rules() {
+    // 先下跌后上涨，触发均线金叉买入
+    let closes = [100.0, 90.0, 80.0, 90.0, 100.0, 110.0];
+    let klines: Vec<Kline> = closes
+        .iter()
+        .enumerate()
+        .map(|(i, &close)| Kline {
+            timestamp: 1640995200000 + i as i64 * 60_000,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: 1.0,
+            symbol: None,
+            interval: None,
+        })
+        .collect();
+
+    let instrument = aurora_portfolio::Instrument::new("BTCUSDT", "BTC", "USDT")
+        .with_tick_size(0.1)
+        .with_step_size(0.01);
+    let mut engine = BacktestEngine::new(MACrossoverStrategy::new(2, 3), &create_test_portfolio_config())
+        .unwrap()
+        .with_instrument(instrument);
+
+    let result = engine.run(&klines, None, false).await.unwrap();
+
+    assert!(!result.trades.is_empty());
+    for trade in &result.trades {
+        let steps = trade.quantity / 0.01;
+        assert!((steps - steps.round()).abs() < 1e-6);
+        let ticks = trade.price / 0.1;
+        assert!((ticks - ticks.round()).abs() < 1e-6);
+    }
+}
+
+#[tokio::test]
+async fn test_portfolio_config_instrument_applies_to_benchmark() {
+    let closes = [100.0, 90.0, 80.0, 90.0, 100.0, 110.0];
+    let klines: Vec<Kline> = closes
+        .iter()
+        .enumerate()
+        .map(|(i, &close)| Kline {
+            timestamp: 1640995200000 + i as i64 * 60_000,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: 1.0,
+            symbol: None,
+            interval: None,
+        })
+        .collect();
+
+    // 品种规则来自投资组合配置，数量步长为1
+    let mut portfolio_config = create_test_portfolio_config();
+    portfolio_config.instrument = Some(aurora_config::InstrumentConfig {
+        symbol: "BTCUSDT".to_string(),
+        base_asset: "BTC".to_string(),
+        quote_asset: "USDT".to_string(),
+        tick_size: 0.0,
+        step_size: 1.0,
+        min_quantity: 0.0,
+        min_notional: 0.0,
+    });
+    let mut engine = BacktestEngine::new(MACrossoverStrategy::new(2, 3), &portfolio_config).unwrap();
+    let result = engine.run(&klines, None, true).await.unwrap();
+
+    assert!(!result.trades.is_empty());
+    assert!(result.trades.iter().all(|trade| trade.quantity.fract() == 0.0));
+
+    // 基准买入同样按整数数量取整，结果与按同一规则直接买入持有一致
+    let mut expected = BasePortfolio::new(10000.0)
+        .with_instrument(portfolio_config.instrument.as_ref().unwrap().to_instrument());
+    let trade = expected.execute_buy(100.0, 1640995200000).await.unwrap();
+    assert_eq!(trade.quantity.fract(), 0.0);
+
+    let benchmark_curve = result.benchmark_equity_curve.unwrap();
+    let benchmark_final = benchmark_curve.last().unwrap().equity;
+    assert!((benchmark_final - expected.get_total_equity(110.0)).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_engine_clock_follows_events() {
+    let klines: Vec<Kline> = (0..5)
+        .map(|i| Kline {
+            timestamp: 1640995200000 + i * 3_600_000,
+            open: 100.0,
+            high: 100.0,
+            low: 100.0,
+            close: 100.0,
+            volume: 1.0,
+            symbol: None,
+            interval: None,
+        })
+        .collect();
+
+    let mut engine =
+        BacktestEngine::new(MACrossoverStrategy::new(2, 3), &create_test_portfolio_config()).unwrap();
+    let clock = engine.clock();
+    assert_eq!(clock.now(), 0);
+
+    engine.run(&klines, None, false).await.unwrap();
+
+    assert_eq!(clock.now(), klines.last().unwrap().timestamp);
+}
+
+/// 在第 `buy_at` 根K线买入、第 `sell_at` 根K线卖出的测试策略
+struct ScheduledStrategy {
+    index: usize,
+    buy_at: usize,
+    sell_at: usize,
+}
+
+impl Strategy for ScheduledStrategy {
+    fn on_market_event(&mut self, event: &MarketEvent) -> Option<SignalEvent> {
+        let MarketEvent::Kline(kline) = event else {
+            return None;
+        };
+        let index = self.index;
+        self.index += 1;
+        let signal = if index == self.buy_at {
+            Signal::Buy
+        } else if index == self.sell_at {
+            Signal::Sell
+        } else {
+            return None;
+        };
+        Some(SignalEvent::new(signal, kline.close, kline.timestamp))
+    }
+}
+
+fn klines_from_closes(closes: &[f64]) -> Vec<Kline> {
+    closes
+        .iter()
+        .enumerate()
+        .map(|(i, &close)| Kline {
+            timestamp: 1640995200000 + i as i64 * 60_000,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: 1.0,
+            symbol: None,
+            interval: None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_builder_with_custom_strategy_and_portfolio() {
+    let strategy = ScheduledStrategy { index: 0, buy_at: 1, sell_at: 3 };
+    let mut engine = BacktestEngine::builder(strategy)
+        .portfolio(BasePortfolio::new(5000.0))
+        .build()
+        .unwrap();
+
+    let result = engine
+        .run(&klines_from_closes(&[100.0, 100.0, 110.0, 120.0, 120.0]), None, true)
+        .await
+        .unwrap();
+
+    assert_eq!(result.initial_equity, 5000.0);
+    assert_eq!(result.trades.len(), 2);
+    assert!((result.final_equity - 6000.0).abs() < 1e-6);
+    assert!(result.benchmark_equity_curve.is_some());
+}
+
+/// 委托给 [`BasePortfolio`] 并记录下单次数的自定义投资组合，不支持风险管理等可选功能
+struct CountingPortfolio {
+    inner: BasePortfolio,
+    orders: usize,
+}
+
+#[async_trait::async_trait]
+impl Portfolio for CountingPortfolio {
+    async fn execute_buy(&mut self, price: f64, timestamp: i64) -> Result<Trade> {
+        self.orders += 1;
+        self.inner.execute_buy(price, timestamp).await
+    }
+
+    async fn execute_sell(&mut self, price: f64, timestamp: i64) -> Result<Trade> {
+        self.orders += 1;
+        self.inner.execute_sell(price, timestamp).await
+    }
+
+    async fn execute_buy_quantity(&mut self, price: f64, quantity: f64, timestamp: i64) -> Result<Trade> {
+        self.orders += 1;
+        self.inner.execute_buy_quantity(price, quantity, timestamp).await
+    }
+
+    async fn execute_sell_quantity(&mut self, price: f64, quantity: f64, timestamp: i64) -> Result<Trade> {
+        self.orders += 1;
+        self.inner.execute_sell_quantity(price, quantity, timestamp).await
+    }
+
+    fn get_total_equity(&self, current_price: f64) -> f64 {
+        self.inner.get_total_equity(current_price)
+    }
+
+    fn get_cash(&self) -> f64 {
+        self.inner.get_cash()
+    }
+
+    fn get_position(&self) -> f64 {
+        self.inner.get_position()
+    }
+
+    fn get_trades(&self) -> &[Trade] {
+        self.inner.get_trades()
+    }
+
+    fn update_equity(&mut self, timestamp: i64, current_price: f64) {
+        self.inner.update_equity(timestamp, current_price)
+    }
+
+    fn get_equity_curve(&self) -> &[aurora_portfolio::EquityPoint] {
+        self.inner.get_equity_curve()
+    }
+
+    fn calculate_performance(&self, time_period_days: f64) -> aurora_portfolio::PerformanceMetrics {
+        self.inner.calculate_performance(time_period_days)
+    }
+}
+
+#[tokio::test]
+async fn test_builder_with_custom_portfolio_implementation() {
+    let strategy = ScheduledStrategy { index: 0, buy_at: 1, sell_at: 3 };
+    let portfolio = CountingPortfolio { inner: BasePortfolio::new(5000.0), orders: 0 };
+    let mut engine = BacktestEngine::builder(strategy).portfolio(portfolio).build().unwrap();
+
+    let result = engine
+        .run(&klines_from_closes(&[100.0, 100.0, 110.0, 120.0, 120.0]), None, false)
+        .await
+        .unwrap();
+
+    assert_eq!(engine.portfolio().orders, 2);
+    assert_eq!(result.initial_equity, 5000.0);
+    assert_eq!(result.trades.len(), 2);
+    assert!((result.final_equity - 6000.0).abs() < 1e-6);
+
+    // 投资组合不支持的设置在构建时报错，而不是被静默忽略
+    let portfolio = CountingPortfolio { inner: BasePortfolio::new(5000.0), orders: 0 };
+    let unsupported = BacktestEngine::builder(ScheduledStrategy { index: 0, buy_at: 0, sell_at: 1 })
+        .portfolio(portfolio)
+        .stop_loss_pct(5.0)
+        .build();
+    assert!(unsupported.is_err());
+}
+
+#[tokio::test]
+async fn test_builder_stop_loss_without_risk_rules() {
+    // 没有配置风险规则时，也会创建风险管理器并在买入后设置止损价
+    let strategy = ScheduledStrategy { index: 0, buy_at: 0, sell_at: usize::MAX };
+    let mut engine = BacktestEngine::builder(strategy)
+        .stop_loss_pct(5.0)
+        .build()
+        .unwrap();
+
+    let result = engine
+        .run(&klines_from_closes(&[100.0, 98.0, 97.0]), None, false)
+        .await
+        .unwrap();
+
+    assert_eq!(result.trades.len(), 1);
+    let risk_manager = engine.portfolio.get_risk_manager_mut().unwrap();
+    assert!((risk_manager.get_rules().stop_loss_price.unwrap() - 95.0).abs() < 1e-9);
+
+    let invalid = BacktestEngine::builder(MACrossoverStrategy::new(2, 3)).take_profit_pct(-1.0).build();
+    assert!(invalid.is_err());
+}
+
+#[tokio::test]
+async fn test_run_source_matches_run() {
+    use aurora_data::HistoricalReplaySource;
+
+    let klines = klines_from_closes(&[100.0, 90.0, 80.0, 90.0, 100.0, 110.0, 100.0, 90.0]);
+
+    let mut engine = BacktestEngine::new(MACrossoverStrategy::new(2, 3), &create_test_portfolio_config()).unwrap();
+    let expected = engine.run(&klines, None, false).await.unwrap();
+
+    // 注册表创建的策略使用默认的 Box<dyn Strategy> 类型参数
+    let config = aurora_config::StrategyConfig {
+        name: "ma".to_string(),
+        strategy_type: "ma-crossover".to_string(),
+        parameters: HashMap::from([
+            ("short".to_string(), StrategyParameter::Integer(2)),
+            ("long".to_string(), StrategyParameter::Integer(3)),
+        ]),
+        enabled: true,
+    };
+    let strategy = StrategyRegistry::new().from_config(&config).unwrap();
+    let mut engine: BacktestEngine = BacktestEngine::new(strategy, &create_test_portfolio_config()).unwrap();
+    let mut source = HistoricalReplaySource::from_klines(klines);
+    let result = engine.run_source(&mut source, Some("replay".to_string()), false).await.unwrap();
+
+    assert_eq!(result.trades.len(), expected.trades.len());
+    assert_eq!(result.final_equity, expected.final_equity);
+    assert_eq!(result.data_path.as_deref(), Some("replay"));
+}
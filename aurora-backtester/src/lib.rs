@@ -44,6 +44,7 @@ mod tests {
             max_positions: None,
             risk_rules: None,
             position_sizing: None,
+            instrument: None,
         }
     }
 
@@ -80,7 +81,13 @@ mod tests {
             rt.block_on(async {
                 // 使用不存在的文件来快速失败，验证函数可以被调用
                 let portfolio_config = create_test_portfolio_config(10000.0);
-                let _ = run_backtest("nonexistent.csv", "ma-crossover", 5, 10, &portfolio_config, None).await;
+                let strategy_config = aurora_config::StrategyConfig {
+                    name: "ma-crossover".to_string(),
+                    strategy_type: "ma-crossover".to_string(),
+                    parameters: std::collections::HashMap::new(),
+                    enabled: true,
+                };
+                let _ = run_backtest("nonexistent.csv", &strategy_config, &portfolio_config, None).await;
             });
         });
 
@@ -13,16 +13,11 @@
 // limitations under the License.
 
 use anyhow::{Context, Result};
-use aurora_config::Config;
+use aurora_backtester::engine;
+use aurora_config::{Config, StrategyConfig};
 use clap::Parser;
 use tracing::{error, info};
 
-// 导入需要的模块
-mod pricing_mode;
-mod result;
-mod engine;
-mod time_utils;
-
 #[derive(Parser)]
 #[command(name = "aurora-backtester")]
 #[command(about = "Aurora项目的回测引擎")]
@@ -90,35 +85,25 @@ async fn run_with_config(config_path: &str) -> Result<()> {
         .find(|s| s.enabled)
         .context("配置文件中没有启用的策略")?;
 
-    // 从策略参数中提取short和long
-    let short = strategy
-        .parameters
-        .get("short")
-        .and_then(|p| p.as_usize())
-        .unwrap_or(10);
-
-    let long = strategy
-        .parameters
-        .get("long")
-        .and_then(|p| p.as_usize())
-        .unwrap_or(30);
-
     // 获取定价模式配置
     let pricing_mode_config = backtest_config.pricing_mode.as_ref();
 
     info!(
-        "开始回测: 数据文件={}, 策略={}, 参数={}:{}, 定价模式={:?}",
-        backtest_config.data_path, strategy.name, short, long, pricing_mode_config
+        "开始回测: 数据文件={}, 策略={} ({}), 定价模式={:?}",
+        backtest_config.data_path, strategy.name, strategy.strategy_type, pricing_mode_config
     );
 
-    // 运行回测
-    match engine::run_backtest(
+    // 运行回测，策略由注册表按配置创建
+    match engine::run_backtest_with_progress::<fn(u8)>(
         &backtest_config.data_path,
-        &strategy.strategy_type,
-        short,
-        long,
+        strategy,
         &config.portfolio,
         pricing_mode_config,
+        None,
+        None,
+        None,
+        None,
+        false,
     )
     .await
     {
@@ -143,14 +128,12 @@ async fn run_with_cli_args(cli: Cli) -> Result<()> {
         .data_path
         .context("缺少必需参数: --data-path")?;
 
-    let strategy_name = cli.strategy_name.unwrap_or_else(|| "ma-crossover".to_string());
-    let short = cli.short.unwrap_or(10);
-    let long = cli.long.unwrap_or(30);
+    let strategy_config = StrategyConfig::from_cli(cli.strategy_name, cli.short, cli.long);
     let initial_cash = cli.initial_cash.unwrap_or(10000.0);
 
     info!(
-        "开始回测: 数据文件={}, 策略={}, 参数={}:{}",
-        data_path, strategy_name, short, long
+        "开始回测: 数据文件={}, 策略={}, 参数={:?}",
+        data_path, strategy_config.strategy_type, strategy_config.parameters
     );
 
     // 创建简单的 PortfolioConfig（命令行模式不支持风险管理和仓位管理）
@@ -162,10 +145,23 @@ async fn run_with_cli_args(cli: Cli) -> Result<()> {
         max_positions: None,
         risk_rules: None,
         position_sizing: None,
+        instrument: None,
     };
 
     // 运行回测
-    match engine::run_backtest(&data_path, &strategy_name, short, long, &portfolio_config, None).await {
+    match engine::run_backtest_with_progress::<fn(u8)>(
+        &data_path,
+        &strategy_config,
+        &portfolio_config,
+        None,
+        None,
+        None,
+        None,
+        None,
+        false,
+    )
+    .await
+    {
         Ok(_) => {
             info!("回测完成");
             Ok(())
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use aurora_core::Kline;
+use aurora_core::{Kline, MarketEvent};
 use serde::{Deserialize, Serialize};
 
 /// 回测定价模式
@@ -34,13 +34,14 @@ use serde::{Deserialize, Serialize};
 ///
 /// let realistic_mode = PricingMode::BidAsk { spread_pct: 0.001 };
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
 pub enum PricingMode {
     /// 使用收盘价执行交易
     ///
     /// 这是最简单的模式，买入和卖出都使用K线的收盘价。
     /// 这种模式计算简单但不够真实，因为实际交易中
     /// 买卖价格存在价差。
+    #[default]
     Close,
 
     /// 使用买一卖一价执行交易
@@ -55,12 +56,6 @@ pub enum PricingMode {
     },
 }
 
-impl Default for PricingMode {
-    fn default() -> Self {
-        Self::Close
-    }
-}
-
 impl PricingMode {
     /// 从配置创建定价模式
     ///
@@ -106,6 +101,8 @@ impl PricingMode {
     ///     low: 95.0,
     ///     close: 102.0,
     ///     volume: 1000.0,
+    ///     symbol: None,
+    ///     interval: None,
     /// };
     ///
     /// let close_mode = PricingMode::Close;
@@ -151,6 +148,8 @@ impl PricingMode {
     ///     low: 95.0,
     ///     close: 102.0,
     ///     volume: 1000.0,
+    ///     symbol: None,
+    ///     interval: None,
     /// };
     ///
     /// let close_mode = PricingMode::Close;
@@ -186,6 +185,74 @@ impl PricingMode {
         // 无论哪种模式，权益估值都使用中间价（收盘价）
         kline.close
     }
+
+    /// 根据市场事件计算买入价格
+    ///
+    /// - K线：与 [`get_buy_price`](Self::get_buy_price) 相同
+    /// - 报价和深度快照：直接使用真实的卖一价，不再叠加模拟价差
+    /// - 逐笔成交和资金费率：以成交价或标记价格为中间价，按定价模式计算
+    ///
+    /// 无法从事件确定价格时返回 `None`。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use aurora_backtester::PricingMode;
+    /// use aurora_core::{MarketEvent, Quote};
+    ///
+    /// let event = MarketEvent::Quote(Quote {
+    ///     symbol: "BTCUSDT".to_string(),
+    ///     timestamp: 1640995200000,
+    ///     bid_price: 99.0,
+    ///     bid_quantity: 1.0,
+    ///     ask_price: 101.0,
+    ///     ask_quantity: 1.0,
+    /// });
+    ///
+    /// let mode = PricingMode::BidAsk { spread_pct: 0.001 };
+    /// assert_eq!(mode.event_buy_price(&event), Some(101.0));
+    /// assert_eq!(mode.event_sell_price(&event), Some(99.0));
+    /// ```
+    pub fn event_buy_price(&self, event: &MarketEvent) -> Option<f64> {
+        match event {
+            MarketEvent::Kline(kline) => Some(self.get_buy_price(kline)),
+            MarketEvent::Quote(quote) => Some(quote.ask_price),
+            MarketEvent::DepthSnapshot(depth) => depth.best_ask().map(|level| level.price),
+            _ => event.reference_price().map(|price| self.apply_spread(price, 1.0)),
+        }
+    }
+
+    /// 根据市场事件计算卖出价格
+    ///
+    /// 规则与 [`event_buy_price`](Self::event_buy_price) 对称，
+    /// 报价和深度快照使用真实的买一价。
+    pub fn event_sell_price(&self, event: &MarketEvent) -> Option<f64> {
+        match event {
+            MarketEvent::Kline(kline) => Some(self.get_sell_price(kline)),
+            MarketEvent::Quote(quote) => Some(quote.bid_price),
+            MarketEvent::DepthSnapshot(depth) => depth.best_bid().map(|level| level.price),
+            _ => event.reference_price().map(|price| self.apply_spread(price, -1.0)),
+        }
+    }
+
+    /// 根据市场事件获取估值价格
+    ///
+    /// K线使用 [`get_mark_price`](Self::get_mark_price)，其他事件使用事件的参考价格
+    /// （成交价、报价中间价或标记价格）。
+    pub fn event_mark_price(&self, event: &MarketEvent) -> Option<f64> {
+        match event {
+            MarketEvent::Kline(kline) => Some(self.get_mark_price(kline)),
+            _ => event.reference_price(),
+        }
+    }
+
+    /// 以中间价为基准叠加半个价差，`direction` 为 1.0 表示买入，-1.0 表示卖出
+    fn apply_spread(&self, mid_price: f64, direction: f64) -> f64 {
+        match self {
+            Self::Close => mid_price,
+            Self::BidAsk { spread_pct } => mid_price * (1.0 + direction * spread_pct / 2.0),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -200,6 +267,8 @@ mod tests {
             low: 95.0,
             close: 100.0,
             volume: 1000.0,
+            symbol: None,
+            interval: None,
         }
     }
 
@@ -258,6 +327,53 @@ mod tests {
         assert_eq!(mode2, deserialized2);
     }
 
+    #[test]
+    fn test_event_prices_for_kline_match_kline_prices() {
+        let mode = PricingMode::BidAsk { spread_pct: 0.002 };
+        let kline = create_test_kline();
+        let event = MarketEvent::Kline(kline.clone());
+
+        assert_eq!(mode.event_buy_price(&event), Some(mode.get_buy_price(&kline)));
+        assert_eq!(mode.event_sell_price(&event), Some(mode.get_sell_price(&kline)));
+        assert_eq!(mode.event_mark_price(&event), Some(100.0));
+    }
+
+    #[test]
+    fn test_event_prices_for_book_data() {
+        let mode = PricingMode::BidAsk { spread_pct: 0.002 };
+        let depth = MarketEvent::DepthSnapshot(aurora_core::DepthSnapshot {
+            symbol: "BTCUSDT".to_string(),
+            timestamp: 1,
+            last_update_id: None,
+            bids: vec![aurora_core::PriceLevel { price: 99.5, quantity: 1.0 }],
+            asks: vec![aurora_core::PriceLevel { price: 100.5, quantity: 1.0 }],
+        });
+
+        // 盘口数据使用真实买卖价，不叠加模拟价差
+        assert_eq!(mode.event_buy_price(&depth), Some(100.5));
+        assert_eq!(mode.event_sell_price(&depth), Some(99.5));
+        assert_eq!(mode.event_mark_price(&depth), Some(100.0));
+    }
+
+    #[test]
+    fn test_event_prices_for_trade() {
+        let trade = MarketEvent::Trade(aurora_core::TradeTick {
+            symbol: "BTCUSDT".to_string(),
+            timestamp: 1,
+            price: 100.0,
+            quantity: 1.0,
+            is_buyer_maker: false,
+            trade_id: None,
+        });
+
+        assert_eq!(PricingMode::Close.event_buy_price(&trade), Some(100.0));
+
+        let mode = PricingMode::BidAsk { spread_pct: 0.002 };
+        assert_eq!(mode.event_buy_price(&trade), Some(100.0 * 1.001));
+        assert_eq!(mode.event_sell_price(&trade), Some(100.0 * 0.999));
+        assert_eq!(mode.event_mark_price(&trade), Some(100.0));
+    }
+
     #[test]
     fn test_spread_with_different_prices() {
         let mode = PricingMode::BidAsk { spread_pct: 0.001 };
@@ -269,6 +385,8 @@ mod tests {
             low: 49000.0,
             close: 50000.0,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         };
 
         let buy_price1 = mode.get_buy_price(&kline1);
@@ -40,6 +40,7 @@
 
 use anyhow::Result;
 use aurora_core::Kline;
+use aurora_indicators::batch;
 use ndarray::Array1;
 use tracing::info;
 
@@ -98,6 +99,8 @@ impl VectorizedBacktestResult {
 ///         low: 95.0,
 ///         close: 100.0,
 ///         volume: 1000.0,
+///         symbol: None,
+///         interval: None,
 ///     },
 ///     Kline {
 ///         timestamp: 1640995260000,
@@ -106,13 +109,15 @@ impl VectorizedBacktestResult {
 ///         low: 98.0,
 ///         close: 105.0,
 ///         volume: 1200.0,
+///         symbol: None,
+///         interval: None,
 ///     },
 /// ];
 ///
 /// let engine = VectorizedBacktestEngine::new(10000.0);
 ///
 /// // 计算简单移动平均信号
-/// let signals = engine.calculate_ma_crossover_signals(&klines, 5, 20);
+/// let signals = engine.calculate_ma_crossover_signals(&klines, 5, 20)?;
 ///
 /// // 运行回测
 /// let result = engine.run(&klines, &signals)?;
@@ -227,6 +232,8 @@ impl VectorizedBacktestEngine {
 
     /// 计算均线交叉信号
     ///
+    /// 均线由 [`aurora_indicators::batch::sma`] 计算，与流式策略使用同一实现。
+    ///
     /// # 参数
     ///
     /// * `klines` - K线数据
@@ -235,18 +242,22 @@ impl VectorizedBacktestEngine {
     ///
     /// # 返回
     ///
-    /// 信号数组 (1=买入, -1=卖出, 0=持有)
+    /// 信号数组 (1=买入, -1=卖出, 0=持有)，均线周期为0时返回错误
     pub fn calculate_ma_crossover_signals(
         &self,
         klines: &[Kline],
         short_period: usize,
         long_period: usize,
-    ) -> Vec<i32> {
+    ) -> Result<Vec<i32>> {
+        if short_period == 0 || long_period == 0 {
+            anyhow::bail!("均线周期必须大于0: {}:{}", short_period, long_period);
+        }
+
         let closes: Vec<f64> = klines.iter().map(|k| k.close).collect();
         let closes_array = Array1::from_vec(closes.clone());
 
-        let short_ma = self.calculate_sma(&closes_array, short_period);
-        let long_ma = self.calculate_sma(&closes_array, long_period);
+        let short_ma = batch::sma(&closes_array, short_period);
+        let long_ma = batch::sma(&closes_array, long_period);
 
         let mut signals = vec![0; klines.len()];
         let mut position = 0; // 0=空仓, 1=持仓
@@ -264,24 +275,7 @@ impl VectorizedBacktestEngine {
             }
         }
 
-        signals
-    }
-
-    /// 计算简单移动平均
-    fn calculate_sma(&self, data: &Array1<f64>, period: usize) -> Array1<f64> {
-        let n = data.len();
-        let mut sma = Array1::zeros(n);
-
-        if period == 0 || period > n {
-            return sma;
-        }
-
-        for i in period - 1..n {
-            let sum: f64 = data.slice(ndarray::s![i + 1 - period..=i]).sum();
-            sma[i] = sum / period as f64;
-        }
-
-        sma
+        Ok(signals)
     }
 
     /// 提取价格数据
@@ -409,6 +403,8 @@ mod tests {
                 low: 95.0,
                 close: 100.0,
                 volume: 1000.0,
+                symbol: None,
+                interval: None,
             },
             Kline {
                 timestamp: 1640995260000,
@@ -417,6 +413,8 @@ mod tests {
                 low: 98.0,
                 close: 105.0,
                 volume: 1200.0,
+                symbol: None,
+                interval: None,
             },
             Kline {
                 timestamp: 1640995320000,
@@ -425,6 +423,8 @@ mod tests {
                 low: 103.0,
                 close: 110.0,
                 volume: 1100.0,
+                symbol: None,
+                interval: None,
             },
             Kline {
                 timestamp: 1640995380000,
@@ -433,6 +433,8 @@ mod tests {
                 low: 108.0,
                 close: 108.0,
                 volume: 1300.0,
+                symbol: None,
+                interval: None,
             },
             Kline {
                 timestamp: 1640995440000,
@@ -441,6 +443,8 @@ mod tests {
                 low: 105.0,
                 close: 112.0,
                 volume: 1250.0,
+                symbol: None,
+                interval: None,
             },
         ]
     }
@@ -454,9 +458,8 @@ mod tests {
 
     #[test]
     fn test_calculate_sma() {
-        let engine = VectorizedBacktestEngine::new(10000.0);
         let data = Array1::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
-        let sma = engine.calculate_sma(&data, 3);
+        let sma = batch::sma(&data, 3);
 
         assert_eq!(sma[2], 2.0); // (1+2+3)/3
         assert_eq!(sma[3], 3.0); // (2+3+4)/3
@@ -468,7 +471,7 @@ mod tests {
         let engine = VectorizedBacktestEngine::new(10000.0);
         let klines = create_test_klines();
 
-        let signals = engine.calculate_ma_crossover_signals(&klines, 2, 3);
+        let signals = engine.calculate_ma_crossover_signals(&klines, 2, 3).unwrap();
 
         // 信号数组长度应该与K线数组长度相同
         assert_eq!(signals.len(), klines.len());
@@ -479,6 +482,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ma_crossover_signals_reject_zero_period() {
+        let engine = VectorizedBacktestEngine::new(10000.0);
+        let klines = create_test_klines();
+
+        assert!(engine.calculate_ma_crossover_signals(&klines, 0, 3).is_err());
+        assert!(engine.calculate_ma_crossover_signals(&klines, 2, 0).is_err());
+    }
+
     #[test]
     fn test_run_backtest() {
         let engine = VectorizedBacktestEngine::new(10000.0);
@@ -2,14 +2,28 @@
 
 use anyhow::Result;
 use aurora_backtester::engine::{BacktestEngine, run_backtest};
-use aurora_config::PortfolioConfig;
+use aurora_config::{PortfolioConfig, StrategyConfig, StrategyParameter};
 use aurora_core::Kline;
 use aurora_portfolio::Portfolio;
-use aurora_strategy::MACrossoverStrategy;
+use aurora_strategy::{MACrossoverStrategy, TrendFollowingStrategy};
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
 use tempfile::{TempDir, tempdir};
 
+/// 创建双均线策略配置
+fn ma_crossover_config(short: i64, long: i64) -> StrategyConfig {
+    StrategyConfig {
+        name: "ma-crossover".to_string(),
+        strategy_type: "ma-crossover".to_string(),
+        parameters: HashMap::from([
+            ("short".to_string(), StrategyParameter::Integer(short)),
+            ("long".to_string(), StrategyParameter::Integer(long)),
+        ]),
+        enabled: true,
+    }
+}
+
 /// 创建默认的测试用 PortfolioConfig
 fn create_test_portfolio_config(initial_cash: f64) -> PortfolioConfig {
     PortfolioConfig {
@@ -20,6 +34,7 @@ fn create_test_portfolio_config(initial_cash: f64) -> PortfolioConfig {
         max_positions: None,
         risk_rules: None,
         position_sizing: None,
+        instrument: None,
     }
 }
 
@@ -79,7 +94,7 @@ async fn test_complete_backtest_flow() -> Result<()> {
 
     // 运行回测
     let portfolio_config = create_test_portfolio_config(10000.0);
-    let result = run_backtest(&csv_file, "ma-crossover", 2, 3, &portfolio_config, None).await;
+    let result = run_backtest(&csv_file, &ma_crossover_config(2, 3), &portfolio_config, None).await;
     assert!(result.is_ok());
 
     Ok(())
@@ -101,6 +116,8 @@ async fn test_kline_processing() -> Result<()> {
             low: 95.0,
             close: 102.0,
             volume: 1000.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 1640995260000,
@@ -109,6 +126,8 @@ async fn test_kline_processing() -> Result<()> {
             low: 98.0,
             close: 106.0,
             volume: 1200.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 1640995320000,
@@ -117,6 +136,8 @@ async fn test_kline_processing() -> Result<()> {
             low: 102.0,
             close: 110.0,
             volume: 1100.0,
+            symbol: None,
+            interval: None,
         },
     ];
 
@@ -135,15 +156,136 @@ async fn test_csv_loading_errors() {
     let portfolio_config = create_test_portfolio_config(10000.0);
     
     // 测试不存在的文件
-    let result = run_backtest("nonexistent_file.csv", "ma-crossover", 5, 20, &portfolio_config, None).await;
+    let result = run_backtest("nonexistent_file.csv", &ma_crossover_config(5, 20), &portfolio_config, None).await;
     assert!(result.is_err());
 
     // 测试无效的策略名称
     let (csv_file, _temp_dir) = create_test_csv_file().unwrap();
-    let result = run_backtest(&csv_file, "invalid-strategy", 5, 20, &portfolio_config, None).await;
+    let mut invalid = ma_crossover_config(5, 20);
+    invalid.strategy_type = "invalid-strategy".to_string();
+    let result = run_backtest(&csv_file, &invalid, &portfolio_config, None).await;
     assert!(result.is_err());
 }
 
+/// 测试按策略配置运行回测时的参数校验
+#[tokio::test]
+async fn test_strategy_config_validation() -> Result<()> {
+    use aurora_backtester::run_backtest_with_progress;
+
+    let (csv_file, _dir) = create_test_csv_file()?;
+    let portfolio_config = create_test_portfolio_config(10000.0);
+
+    // 拼写错误的参数名
+    let mut config = ma_crossover_config(2, 3);
+    config.parameters.insert("shrot".to_string(), StrategyParameter::Integer(2));
+    let result = run_backtest_with_progress::<fn(u8)>(
+        &csv_file, &config, &portfolio_config, None, None, None, None, None, false,
+    )
+    .await;
+    let error_msg = result.unwrap_err().to_string();
+    assert!(error_msg.contains("shrot"));
+
+    // 短期周期不小于长期周期时返回错误而不是 panic
+    let result = run_backtest_with_progress::<fn(u8)>(
+        &csv_file,
+        &ma_crossover_config(30, 10),
+        &portfolio_config,
+        None,
+        None,
+        None,
+        None,
+        None,
+        false,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // 没有参数的策略
+    let config = StrategyConfig {
+        name: "基准".to_string(),
+        strategy_type: "buy-and-hold".to_string(),
+        parameters: HashMap::new(),
+        enabled: true,
+    };
+    let result = run_backtest_with_progress::<fn(u8)>(
+        &csv_file, &config, &portfolio_config, None, None, None, None, None, false,
+    )
+    .await?;
+    assert_eq!(result.trades.len(), 1);
+
+    // RSI均值回归策略：下跌段超卖买入，反弹后RSI回到50卖出
+    let config = StrategyConfig {
+        name: "RSI均值回归".to_string(),
+        strategy_type: "rsi-reversion".to_string(),
+        parameters: HashMap::from([
+            ("period".to_string(), StrategyParameter::Integer(3)),
+            ("exit_level".to_string(), StrategyParameter::Float(50.0)),
+        ]),
+        enabled: true,
+    };
+    let result = run_backtest_with_progress::<fn(u8)>(
+        &csv_file, &config, &portfolio_config, None, None, None, None, None, false,
+    )
+    .await?;
+    assert_eq!(result.trades.len(), 2);
+
+    Ok(())
+}
+
+/// 测试趋势跟踪策略与双均线策略在同一数据上的对比
+///
+/// 数据先震荡后单边上涨：双均线在震荡段反复交叉，
+/// 趋势跟踪策略由ADX过滤掉震荡段的交叉，只在上涨段入场并持有到PSAR翻转。
+/// 两者的收益高低取决于数据，这里只检查行为差异，并输出两者的结果供对比。
+#[tokio::test]
+async fn test_trend_following_against_ma_crossover() -> Result<()> {
+    let closes: Vec<f64> = (0..60)
+        .map(|i| 100.0 + 4.0 * ((i as f64) * 2.1).sin())
+        .chain((0..40).map(|i| 100.0 + i as f64 * 2.0))
+        .chain([170.0, 160.0, 150.0])
+        .collect();
+    let klines: Vec<Kline> = closes
+        .iter()
+        .enumerate()
+        .map(|(i, &close)| Kline {
+            timestamp: 1640995200000 + i as i64 * 60000,
+            open: close,
+            high: close * 1.01,
+            low: close * 0.98,
+            close,
+            volume: 1000.0,
+            symbol: None,
+            interval: None,
+        })
+        .collect();
+    let portfolio_config = create_test_portfolio_config(10000.0);
+
+    let mut baseline = BacktestEngine::new(MACrossoverStrategy::new(3, 8), &portfolio_config)?;
+    let baseline_result = baseline.run(&klines, None, false).await?;
+
+    let strategy = TrendFollowingStrategy::new(3, 8).with_adx_period(5);
+    let mut trend = BacktestEngine::new(strategy, &portfolio_config)?;
+    let trend_result = trend.run(&klines, None, false).await?;
+
+    println!(
+        "ma-crossover: {} 笔交易, 最终权益 {:.2}; trend-following: {} 笔交易, 最终权益 {:.2}",
+        baseline_result.trades.len(),
+        baseline_result.final_equity,
+        trend_result.trades.len(),
+        trend_result.final_equity
+    );
+    assert!(baseline_result.trades.len() > 10);
+    assert_eq!(trend_result.trades.len(), 2);
+
+    // 唯一一次入场发生在上涨段，并持有到急跌前后
+    let entry = &trend_result.trades[0];
+    assert!(entry.timestamp >= klines[60].timestamp);
+    assert!(trend_result.trades[1].timestamp >= klines[99].timestamp);
+    assert!(trend_result.final_equity > trend_result.initial_equity * 1.4);
+
+    Ok(())
+}
+
 /// 测试空数据文件处理
 #[tokio::test]
 async fn test_empty_data_file() -> Result<()> {
@@ -154,7 +296,7 @@ async fn test_empty_data_file() -> Result<()> {
     writeln!(file, "timestamp,open,high,low,close,volume")?; // 只有头部
 
     let portfolio_config = create_test_portfolio_config(10000.0);
-    let result = run_backtest(&file_path.to_string_lossy(), "ma-crossover", 5, 20, &portfolio_config, None).await;
+    let result = run_backtest(&file_path.to_string_lossy(), &ma_crossover_config(5, 20), &portfolio_config, None).await;
 
     assert!(result.is_err());
 
@@ -174,7 +316,7 @@ async fn test_invalid_csv_data() -> Result<()> {
     writeln!(file, "1640995200000,50000.0,50500.0,49500.0,50000.0,100.0")?; // 一行有效数据
 
     let portfolio_config = create_test_portfolio_config(10000.0);
-    let result = run_backtest(&file_path.to_string_lossy(), "ma-crossover", 2, 3, &portfolio_config, None).await;
+    let result = run_backtest(&file_path.to_string_lossy(), &ma_crossover_config(2, 3), &portfolio_config, None).await;
 
     // 应该能处理部分无效数据，只要有一些有效数据
     assert!(result.is_ok());
@@ -191,13 +333,33 @@ async fn test_different_strategy_parameters() -> Result<()> {
     let test_cases = vec![(5, 10), (10, 20), (2, 5)];
 
     for (short, long) in test_cases {
-        let result = run_backtest(&csv_file, "ma-crossover", short, long, &portfolio_config, None).await;
+        let result = run_backtest(&csv_file, &ma_crossover_config(short, long), &portfolio_config, None).await;
         assert!(result.is_ok(), "策略参数 {}:{} 回测失败", short, long);
     }
 
     Ok(())
 }
 
+/// 测试按策略配置运行非均线策略，未给出的参数取注册表默认值
+#[tokio::test]
+async fn test_run_backtest_with_registered_strategies() -> Result<()> {
+    let (csv_file, _temp_dir) = create_test_csv_file()?;
+    let portfolio_config = create_test_portfolio_config(10000.0);
+
+    for strategy_type in ["buy-and-hold", "rsi-reversion", "squeeze-breakout", "trend-following"] {
+        let config = StrategyConfig {
+            name: strategy_type.to_string(),
+            strategy_type: strategy_type.to_string(),
+            parameters: HashMap::new(),
+            enabled: true,
+        };
+        let result = run_backtest(&csv_file, &config, &portfolio_config, None).await;
+        assert!(result.is_ok(), "{} 回测失败: {:?}", strategy_type, result.err());
+    }
+
+    Ok(())
+}
+
 /// 测试不同初始资金的回测
 #[tokio::test]
 async fn test_different_initial_cash() -> Result<()> {
@@ -207,7 +369,7 @@ async fn test_different_initial_cash() -> Result<()> {
 
     for cash in cash_amounts {
         let portfolio_config = create_test_portfolio_config(cash);
-        let result = run_backtest(&csv_file, "ma-crossover", 5, 10, &portfolio_config, None).await;
+        let result = run_backtest(&csv_file, &ma_crossover_config(5, 10), &portfolio_config, None).await;
         assert!(result.is_ok(), "初始资金 {} 回测失败", cash);
     }
 
@@ -251,9 +413,7 @@ async fn test_large_dataset_performance() -> Result<()> {
     let portfolio_config = create_test_portfolio_config(100000.0);
     let result = run_backtest(
         &file_path.to_string_lossy(),
-        "ma-crossover",
-        10,
-        30,
+        &ma_crossover_config(10, 30),
         &portfolio_config,
         None,
     )
@@ -287,6 +447,8 @@ async fn test_backtest_result_correctness() -> Result<()> {
             low: 99.0,
             close: 100.0,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 2000,
@@ -295,6 +457,8 @@ async fn test_backtest_result_correctness() -> Result<()> {
             low: 98.0,
             close: 99.0,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 3000,
@@ -303,6 +467,8 @@ async fn test_backtest_result_correctness() -> Result<()> {
             low: 97.0,
             close: 98.0,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         },
         // 上涨趋势，应该触发买入信号
         Kline {
@@ -312,6 +478,8 @@ async fn test_backtest_result_correctness() -> Result<()> {
             low: 97.5,
             close: 99.5,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 5000,
@@ -320,6 +488,8 @@ async fn test_backtest_result_correctness() -> Result<()> {
             low: 99.0,
             close: 101.0,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 6000,
@@ -328,6 +498,8 @@ async fn test_backtest_result_correctness() -> Result<()> {
             low: 100.5,
             close: 103.0,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         },
         // 下跌趋势，应该触发卖出信号
         Kline {
@@ -337,6 +509,8 @@ async fn test_backtest_result_correctness() -> Result<()> {
             low: 101.0,
             close: 102.0,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 8000,
@@ -345,6 +519,8 @@ async fn test_backtest_result_correctness() -> Result<()> {
             low: 100.0,
             close: 101.0,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 9000,
@@ -353,6 +529,8 @@ async fn test_backtest_result_correctness() -> Result<()> {
             low: 99.0,
             close: 100.0,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         },
     ];
 
@@ -404,9 +582,7 @@ async fn test_concurrent_backtests() -> Result<()> {
             let portfolio_config = create_test_portfolio_config(10000.0 + (i as f64 * 1000.0));
             run_backtest(
                 &file_clone,
-                "ma-crossover",
-                5,
-                10,
+                &ma_crossover_config(5, 10),
                 &portfolio_config,
                 None,
             )
@@ -435,9 +611,7 @@ async fn test_time_range_filtering() -> Result<()> {
     // 测试有效的时间范围
     let result = run_backtest_with_progress::<fn(u8)>(
         &csv_file,
-        "ma-crossover",
-        5,
-        10,
+        &ma_crossover_config(5, 10),
         &portfolio_config,
         None,
         None,
@@ -467,9 +641,7 @@ async fn test_time_range_no_overlap() -> Result<()> {
     // 测试完全不重叠的时间范围（数据是2021年底，我们查询2023年）
     let result = run_backtest_with_progress::<fn(u8)>(
         &csv_file,
-        "ma-crossover",
-        5,
-        10,
+        &ma_crossover_config(5, 10),
         &portfolio_config,
         None,
         None,
@@ -499,9 +671,7 @@ async fn test_invalid_time_range() -> Result<()> {
     // 开始时间晚于结束时间
     let result = run_backtest_with_progress::<fn(u8)>(
         &csv_file,
-        "ma-crossover",
-        5,
-        10,
+        &ma_crossover_config(5, 10),
         &portfolio_config,
         None,
         None,
@@ -72,6 +72,6 @@ mod types;
 // 重新导出公共API
 pub use error::{ConfigError, ConfigResult};
 pub use types::{
-    BacktestConfig, Config, DataSourceConfig, LiveConfig, LogConfig, PortfolioConfig,
+    BacktestConfig, Config, DataSourceConfig, InstrumentConfig, LiveConfig, LogConfig, PortfolioConfig,
     PositionSizingConfig, PricingModeConfig, RiskRulesConfig, StrategyConfig, StrategyParameter,
 };
@@ -151,6 +151,17 @@ impl Config {
             }
         }
 
+        // 检查交易品种规则
+        if let Some(instrument) = &self.portfolio.instrument {
+            instrument
+                .validate()
+                .map_err(|reason| ConfigError::InvalidValue {
+                    field: "portfolio.instrument".to_string(),
+                    value: instrument.symbol.clone(),
+                    reason,
+                })?;
+        }
+
         Ok(())
     }
 
@@ -157,6 +157,10 @@ pub struct PortfolioConfig {
     /// 仓位管理策略(可选)
     #[serde(default)]
     pub position_sizing: Option<PositionSizingConfig>,
+
+    /// 交易品种规则(可选)，用于对订单价格和数量取整并校验
+    #[serde(default)]
+    pub instrument: Option<InstrumentConfig>,
 }
 
 /// 风险管理规则配置
@@ -316,6 +320,71 @@ impl BenchmarkConfig {
     }
 }
 
+/// 交易品种配置
+///
+/// 描述交易对的价格最小变动单位、数量步长和最小下单限制，
+/// 数值字段缺省为 0，表示不做限制。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstrumentConfig {
+    /// 交易对符号
+    pub symbol: String,
+
+    /// 基础资产，如 BTC
+    pub base_asset: String,
+
+    /// 计价资产，如 USDT
+    pub quote_asset: String,
+
+    /// 价格最小变动单位
+    #[serde(default)]
+    pub tick_size: f64,
+
+    /// 数量步长
+    #[serde(default)]
+    pub step_size: f64,
+
+    /// 最小下单数量
+    #[serde(default)]
+    pub min_quantity: f64,
+
+    /// 最小名义金额
+    #[serde(default)]
+    pub min_notional: f64,
+}
+
+impl InstrumentConfig {
+    /// 转换为 aurora-portfolio 中的 Instrument
+    ///
+    /// 注意: 需要在使用此函数的 crate 中依赖 aurora-portfolio
+    #[cfg(feature = "portfolio-integration")]
+    pub fn to_instrument(&self) -> aurora_portfolio::Instrument {
+        aurora_portfolio::Instrument::new(&self.symbol, &self.base_asset, &self.quote_asset)
+            .with_tick_size(self.tick_size)
+            .with_step_size(self.step_size)
+            .with_min_quantity(self.min_quantity)
+            .with_min_notional(self.min_notional)
+    }
+
+    /// 检查配置是否有效
+    pub fn validate(&self) -> Result<(), String> {
+        if self.symbol.is_empty() {
+            return Err("交易品种符号不能为空".to_string());
+        }
+        let limits = [
+            ("tick_size", self.tick_size),
+            ("step_size", self.step_size),
+            ("min_quantity", self.min_quantity),
+            ("min_notional", self.min_notional),
+        ];
+        for (name, value) in limits {
+            if !value.is_finite() || value < 0.0 {
+                return Err(format!("{} 必须是非负数", name));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// 定价模式配置
 ///
 /// 用于控制回测中交易价格的计算方式
@@ -347,6 +416,10 @@ pub struct LiveConfig {
     /// 是否为模拟交易
     #[serde(default = "default_paper_trading")]
     pub paper_trading: bool,
+
+    /// 策略状态检查点文件(可选)，设置后启动时从该文件恢复策略状态，并在每根K线后更新
+    #[serde(default)]
+    pub checkpoint: Option<String>,
 }
 
 // === 默认值函数 ===
@@ -415,6 +488,7 @@ impl Default for PortfolioConfig {
             max_positions: None,
             risk_rules: None,
             position_sizing: None,
+            instrument: None,
         }
     }
 }
@@ -429,6 +503,27 @@ impl Default for LogConfig {
     }
 }
 
+impl StrategyConfig {
+    /// 由命令行参数构造策略配置
+    ///
+    /// 策略类型缺省为 `ma-crossover`，只写入给出的 `short`、`long` 周期，
+    /// 未给出的参数由策略注册表取默认值。
+    pub fn from_cli(strategy_type: Option<String>, short: Option<usize>, long: Option<usize>) -> Self {
+        let strategy_type = strategy_type.unwrap_or_else(|| "ma-crossover".to_string());
+        let parameters = [("short", short), ("long", long)]
+            .into_iter()
+            .filter_map(|(name, value)| Some((name.to_string(), StrategyParameter::Integer(value? as i64))))
+            .collect();
+
+        Self {
+            name: strategy_type.clone(),
+            strategy_type,
+            parameters,
+            enabled: true,
+        }
+    }
+}
+
 impl StrategyParameter {
     /// 尝试转换为整数
     pub fn as_i64(&self) -> Option<i64> {
@@ -112,6 +112,21 @@ mod tests {
         assert!(strategy.enabled);
     }
 
+    #[test]
+    fn test_strategy_config_from_cli() {
+        // 未给出的周期不写入参数，由注册表取默认值
+        let config = StrategyConfig::from_cli(None, Some(5), None);
+        assert_eq!(config.name, "ma-crossover");
+        assert_eq!(config.strategy_type, "ma-crossover");
+        assert!(config.enabled);
+        assert_eq!(config.parameters.len(), 1);
+        assert_eq!(config.parameters["short"].as_usize(), Some(5));
+
+        let config = StrategyConfig::from_cli(Some("buy-and-hold".to_string()), None, None);
+        assert_eq!(config.strategy_type, "buy-and-hold");
+        assert!(config.parameters.is_empty());
+    }
+
     #[test]
     fn test_backtest_config_creation() {
         let config = BacktestConfig {
@@ -136,6 +151,7 @@ mod tests {
             symbol: "ETHUSDT".to_string(),
             interval: "5m".to_string(),
             paper_trading: true,
+            checkpoint: None,
         };
 
         assert_eq!(config.symbol, "ETHUSDT");
@@ -541,4 +557,41 @@ mod tests {
         assert_eq!(deserialized.enabled, benchmark.enabled);
         assert_eq!(deserialized.data_path, benchmark.data_path);
     }
+
+    #[test]
+    fn test_portfolio_config_with_instrument() {
+        let toml_str = r#"
+            initial_cash = 10000.0
+
+            [instrument]
+            symbol = "BTCUSDT"
+            base_asset = "BTC"
+            quote_asset = "USDT"
+            tick_size = 0.01
+            step_size = 0.00001
+            min_notional = 5.0
+        "#;
+
+        let config: PortfolioConfig = toml::from_str(toml_str).unwrap();
+        let instrument = config.instrument.unwrap();
+        assert_eq!(instrument.symbol, "BTCUSDT");
+        assert_eq!(instrument.tick_size, 0.01);
+        assert_eq!(instrument.min_quantity, 0.0);
+        assert!(instrument.validate().is_ok());
+    }
+
+    #[test]
+    fn test_instrument_config_rejects_negative_limits() {
+        let instrument = InstrumentConfig {
+            symbol: "BTCUSDT".to_string(),
+            base_asset: "BTC".to_string(),
+            quote_asset: "USDT".to_string(),
+            tick_size: -0.01,
+            step_size: 0.0,
+            min_quantity: 0.0,
+            min_notional: 0.0,
+        };
+
+        assert!(instrument.validate().is_err());
+    }
 }
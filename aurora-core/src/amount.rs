@@ -0,0 +1,113 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 记账数值类型
+//!
+//! [`Amount`] 是现金余额、持仓数量、手续费等记账字段使用的数值类型：
+//!
+//! - 默认为 `f64`
+//! - 启用 `decimal` 特性后为 [`rust_decimal::Decimal`]，加减乘运算没有二进制浮点误差，
+//!   长时间回测后的余额可以与交易所对账单逐位核对
+//!
+//! 价格、数量等接口参数仍使用 `f64`，进入账户时通过 [`to_amount`] 转换。
+//! 转换取 `f64` 的最短十进制表示（`0.1` 转换为精确的 `0.1`），
+//! 因此常见的价格和数量在十进制模式下没有表示误差。
+//! 收益率、夏普比率等统计指标本身是近似值，仍使用 `f64` 计算。
+
+/// 记账数值类型（未启用 `decimal` 特性时为 `f64`）
+#[cfg(not(feature = "decimal"))]
+pub type Amount = f64;
+
+/// 记账数值类型（启用 `decimal` 特性时为十进制定点数）
+#[cfg(feature = "decimal")]
+pub type Amount = rust_decimal::Decimal;
+
+/// 零值
+#[cfg(not(feature = "decimal"))]
+pub const AMOUNT_ZERO: Amount = 0.0;
+
+/// 零值
+#[cfg(feature = "decimal")]
+pub const AMOUNT_ZERO: Amount = rust_decimal::Decimal::ZERO;
+
+/// 把 `f64` 转换为记账数值
+///
+/// 十进制模式下非有限值（NaN、无穷大）或超出表示范围的值转换为零，
+/// 调用方应在转换前完成价格和数量的有效性校验。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::{amount_to_f64, to_amount};
+///
+/// let mut cash = to_amount(1.0);
+/// for _ in 0..3 {
+///     cash -= to_amount(0.1) * to_amount(3.0);
+/// }
+/// // 十进制模式下结果精确为 0.1，默认模式下存在浮点误差
+/// assert!((amount_to_f64(cash) - 0.1).abs() < 1e-12);
+/// ```
+#[cfg(not(feature = "decimal"))]
+pub fn to_amount(value: f64) -> Amount {
+    value
+}
+
+/// 把 `f64` 转换为记账数值
+///
+/// 十进制模式下非有限值（NaN、无穷大）或超出表示范围的值转换为零，
+/// 调用方应在转换前完成价格和数量的有效性校验。
+#[cfg(feature = "decimal")]
+pub fn to_amount(value: f64) -> Amount {
+    use rust_decimal::prelude::FromPrimitive;
+    rust_decimal::Decimal::from_f64(value).unwrap_or(AMOUNT_ZERO)
+}
+
+/// 把记账数值转换为 `f64`
+#[cfg(not(feature = "decimal"))]
+pub fn amount_to_f64(value: Amount) -> f64 {
+    value
+}
+
+/// 把记账数值转换为 `f64`
+#[cfg(feature = "decimal")]
+pub fn amount_to_f64(value: Amount) -> f64 {
+    use rust_decimal::prelude::ToPrimitive;
+    value.to_f64().unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 测试与 f64 的往返转换
+    #[test]
+    fn test_round_trip() {
+        for value in [0.0, 0.1, 46500.12, 0.00001, -12.5] {
+            assert_eq!(amount_to_f64(to_amount(value)), value);
+        }
+        assert_eq!(to_amount(0.0), AMOUNT_ZERO);
+    }
+
+    /// 测试十进制模式下的精确记账
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_decimal_is_exact() {
+        let mut balance = AMOUNT_ZERO;
+        for _ in 0..10 {
+            balance += to_amount(0.1);
+        }
+        assert_eq!(balance, to_amount(1.0));
+        assert_eq!(to_amount(f64::NAN), AMOUNT_ZERO);
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 时钟抽象
+//!
+//! 风控的单日统计、策略定时器和订单过期等依赖时间的逻辑都通过 [`Clock`] 读取当前时间：
+//!
+//! - 回测使用 [`SimulatedClock`]，由引擎按市场事件的时间戳推进
+//! - 实时交易使用 [`SystemClock`]，读取系统时间
+//!
+//! 两种时钟都返回 Unix 毫秒时间戳，同一段逻辑在回测和实时交易中表现一致。
+//! 时钟通常以 `Arc<dyn Clock>` 的形式在多个组件之间共享。
+
+use std::fmt;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 一天的毫秒数
+pub const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// 时钟接口
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// 当前时间（Unix毫秒）
+    fn now(&self) -> i64;
+
+    /// 当前时间所在的自然日（自Unix纪元起的天数，UTC）
+    fn day(&self) -> i64 {
+        self.now().div_euclid(MILLIS_PER_DAY)
+    }
+}
+
+/// 系统时钟，读取当前系统时间
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    /// 创建系统时钟
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as i64)
+            .unwrap_or(0)
+    }
+}
+
+/// 模拟时钟，由市场事件的时间戳驱动
+///
+/// 时间只向前推进，乱序到达的旧事件不会让时钟倒退。
+/// 内部使用原子变量，可通过 `Arc` 共享后由引擎推进、由其他组件读取。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::{Clock, SimulatedClock};
+/// use std::sync::Arc;
+///
+/// let clock = Arc::new(SimulatedClock::new(1000));
+/// let shared: Arc<dyn Clock> = clock.clone();
+///
+/// clock.advance_to(5000);
+/// assert_eq!(shared.now(), 5000);
+///
+/// // 旧时间戳不会让时钟倒退
+/// clock.advance_to(3000);
+/// assert_eq!(shared.now(), 5000);
+/// ```
+#[derive(Debug, Default)]
+pub struct SimulatedClock {
+    now: AtomicI64,
+}
+
+impl SimulatedClock {
+    /// 创建模拟时钟，`start` 为初始时间（Unix毫秒）
+    pub fn new(start: i64) -> Self {
+        Self {
+            now: AtomicI64::new(start),
+        }
+    }
+
+    /// 推进到给定时间，早于当前时间的时间戳被忽略
+    pub fn advance_to(&self, timestamp: i64) {
+        self.now.fetch_max(timestamp, Ordering::SeqCst);
+    }
+
+    /// 推进给定的毫秒数
+    pub fn advance_by(&self, millis: i64) {
+        self.now.fetch_add(millis.max(0), Ordering::SeqCst);
+    }
+}
+
+impl Clock for SimulatedClock {
+    fn now(&self) -> i64 {
+        self.now.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 测试模拟时钟只向前推进
+    #[test]
+    fn test_simulated_clock_is_monotonic() {
+        let clock = SimulatedClock::new(0);
+
+        clock.advance_to(2000);
+        clock.advance_to(1000);
+        assert_eq!(clock.now(), 2000);
+
+        clock.advance_by(500);
+        clock.advance_by(-100);
+        assert_eq!(clock.now(), 2500);
+    }
+
+    /// 测试自然日计算
+    #[test]
+    fn test_day() {
+        let clock = SimulatedClock::new(MILLIS_PER_DAY - 1);
+        assert_eq!(clock.day(), 0);
+
+        clock.advance_by(1);
+        assert_eq!(clock.day(), 1);
+    }
+
+    /// 测试系统时钟返回当前时间
+    #[test]
+    fn test_system_clock() {
+        // 2020-01-01 之后
+        assert!(SystemClock::new().now() > 1_577_836_800_000);
+    }
+}
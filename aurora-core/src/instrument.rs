@@ -0,0 +1,360 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 交易品种元数据
+//!
+//! [`Instrument`] 描述一个交易对的交易规则：价格最小变动单位（tick size）、
+//! 数量步长（step size）、最小下单数量和最小名义金额。
+//! 投资组合、模拟经纪商和撮合引擎据此对订单进行取整和校验，
+//! 避免产生交易所会拒绝的任意精度订单。
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 交易品种规则
+///
+/// `tick_size`、`step_size`、`min_quantity`、`min_notional` 为 0 时表示不做限制。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::Instrument;
+///
+/// let btc = Instrument::new("BTCUSDT", "BTC", "USDT")
+///     .with_tick_size(0.01)
+///     .with_step_size(0.00001)
+///     .with_min_quantity(0.00001)
+///     .with_min_notional(5.0);
+///
+/// assert_eq!(btc.round_price(46500.1234), 46500.12);
+/// assert_eq!(btc.round_quantity(0.123456789), 0.12345);
+///
+/// // 名义金额不足 5 USDT 的订单会被拒绝
+/// assert!(btc.validate_order(46500.0, 0.0001).is_err());
+/// assert!(btc.validate_order(46500.0, 0.001).is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Instrument {
+    /// 交易对符号（需与下单时使用的符号一致）
+    pub symbol: String,
+    /// 基础资产，如 BTC
+    pub base_asset: String,
+    /// 计价资产，如 USDT
+    pub quote_asset: String,
+    /// 价格最小变动单位
+    pub tick_size: f64,
+    /// 数量步长
+    pub step_size: f64,
+    /// 最小下单数量
+    pub min_quantity: f64,
+    /// 最小名义金额（价格 × 数量）
+    pub min_notional: f64,
+}
+
+/// 订单不满足交易品种规则时的错误
+#[derive(Debug, Clone, PartialEq)]
+pub enum InstrumentError {
+    /// 价格无效（非正数或非有限值）
+    InvalidPrice(f64),
+    /// 数量无效（非正数或非有限值）
+    InvalidQuantity(f64),
+    /// 价格不是 tick size 的整数倍
+    PriceNotOnTick {
+        /// 订单价格
+        price: f64,
+        /// 价格最小变动单位
+        tick_size: f64,
+    },
+    /// 数量不是 step size 的整数倍
+    QuantityNotOnStep {
+        /// 订单数量
+        quantity: f64,
+        /// 数量步长
+        step_size: f64,
+    },
+    /// 数量低于最小下单数量
+    QuantityBelowMinimum {
+        /// 订单数量
+        quantity: f64,
+        /// 最小下单数量
+        min_quantity: f64,
+    },
+    /// 名义金额低于最小名义金额
+    NotionalBelowMinimum {
+        /// 订单名义金额
+        notional: f64,
+        /// 最小名义金额
+        min_notional: f64,
+    },
+}
+
+impl fmt::Display for InstrumentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InstrumentError::InvalidPrice(price) => write!(f, "无效的价格: {}", price),
+            InstrumentError::InvalidQuantity(quantity) => write!(f, "无效的数量: {}", quantity),
+            InstrumentError::PriceNotOnTick { price, tick_size } => {
+                write!(f, "价格 {} 不是最小变动单位 {} 的整数倍", price, tick_size)
+            }
+            InstrumentError::QuantityNotOnStep {
+                quantity,
+                step_size,
+            } => write!(f, "数量 {} 不是数量步长 {} 的整数倍", quantity, step_size),
+            InstrumentError::QuantityBelowMinimum {
+                quantity,
+                min_quantity,
+            } => write!(f, "数量 {} 低于最小下单数量 {}", quantity, min_quantity),
+            InstrumentError::NotionalBelowMinimum {
+                notional,
+                min_notional,
+            } => write!(f, "名义金额 {} 低于最小名义金额 {}", notional, min_notional),
+        }
+    }
+}
+
+impl std::error::Error for InstrumentError {}
+
+impl Instrument {
+    /// 创建不带任何交易限制的交易品种
+    ///
+    /// # 参数
+    ///
+    /// * `symbol` - 交易对符号
+    /// * `base_asset` - 基础资产
+    /// * `quote_asset` - 计价资产
+    pub fn new(symbol: &str, base_asset: &str, quote_asset: &str) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            base_asset: base_asset.to_string(),
+            quote_asset: quote_asset.to_string(),
+            tick_size: 0.0,
+            step_size: 0.0,
+            min_quantity: 0.0,
+            min_notional: 0.0,
+        }
+    }
+
+    /// 设置价格最小变动单位
+    pub fn with_tick_size(mut self, tick_size: f64) -> Self {
+        self.tick_size = tick_size;
+        self
+    }
+
+    /// 设置数量步长
+    pub fn with_step_size(mut self, step_size: f64) -> Self {
+        self.step_size = step_size;
+        self
+    }
+
+    /// 设置最小下单数量
+    pub fn with_min_quantity(mut self, min_quantity: f64) -> Self {
+        self.min_quantity = min_quantity;
+        self
+    }
+
+    /// 设置最小名义金额
+    pub fn with_min_notional(mut self, min_notional: f64) -> Self {
+        self.min_notional = min_notional;
+        self
+    }
+
+    /// 把价格取整到最近的 tick
+    pub fn round_price(&self, price: f64) -> f64 {
+        if self.tick_size <= 0.0 {
+            return price;
+        }
+        snap((price / self.tick_size).round() * self.tick_size, self.tick_size)
+    }
+
+    /// 把数量向下取整到 step size 的整数倍
+    ///
+    /// 向下取整保证取整后的订单不会超出可用资金或持仓。
+    pub fn round_quantity(&self, quantity: f64) -> f64 {
+        if self.step_size <= 0.0 {
+            return quantity;
+        }
+        // 加一个极小量，避免 0.3 / 0.1 = 2.9999999 这类浮点误差导致少取一个步长
+        let steps = (quantity / self.step_size + 1e-9).floor();
+        snap(steps * self.step_size, self.step_size)
+    }
+
+    /// 校验订单价格和数量是否满足交易规则
+    ///
+    /// 价格必须落在 tick 上，数量必须落在 step 上，
+    /// 且数量和名义金额不低于最小值。
+    pub fn validate_order(&self, price: f64, quantity: f64) -> Result<(), InstrumentError> {
+        if !price.is_finite() || price <= 0.0 {
+            return Err(InstrumentError::InvalidPrice(price));
+        }
+        if !quantity.is_finite() || quantity <= 0.0 {
+            return Err(InstrumentError::InvalidQuantity(quantity));
+        }
+        if !is_multiple_of(price, self.tick_size) {
+            return Err(InstrumentError::PriceNotOnTick {
+                price,
+                tick_size: self.tick_size,
+            });
+        }
+        if !is_multiple_of(quantity, self.step_size) {
+            return Err(InstrumentError::QuantityNotOnStep {
+                quantity,
+                step_size: self.step_size,
+            });
+        }
+        if quantity < self.min_quantity {
+            return Err(InstrumentError::QuantityBelowMinimum {
+                quantity,
+                min_quantity: self.min_quantity,
+            });
+        }
+        let notional = price * quantity;
+        if notional < self.min_notional {
+            return Err(InstrumentError::NotionalBelowMinimum {
+                notional,
+                min_notional: self.min_notional,
+            });
+        }
+        Ok(())
+    }
+
+    /// 取整并校验订单
+    ///
+    /// 价格取整到最近的 tick，数量向下取整到 step，然后进行校验。
+    ///
+    /// # 返回值
+    ///
+    /// 成功时返回取整后的 `(价格, 数量)`
+    pub fn normalize_order(&self, price: f64, quantity: f64) -> Result<(f64, f64), InstrumentError> {
+        let price = self.round_price(price);
+        let quantity = self.round_quantity(quantity);
+        self.validate_order(price, quantity)?;
+        Ok((price, quantity))
+    }
+}
+
+/// 判断 `value` 是否为 `unit` 的整数倍（允许浮点误差），`unit` 为 0 时不做限制
+fn is_multiple_of(value: f64, unit: f64) -> bool {
+    if unit <= 0.0 {
+        return true;
+    }
+    let ratio = value / unit;
+    (ratio - ratio.round()).abs() < 1e-6
+}
+
+/// 按 `unit` 的小数位数四舍五入，消除乘法带来的浮点噪声（如 0.30000000000000004）
+fn snap(value: f64, unit: f64) -> f64 {
+    let decimals = (-unit.log10()).ceil().clamp(0.0, 15.0) as i32;
+    let factor = 10f64.powi(decimals);
+    (value * factor).round() / factor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn btc() -> Instrument {
+        Instrument::new("BTCUSDT", "BTC", "USDT")
+            .with_tick_size(0.01)
+            .with_step_size(0.001)
+            .with_min_quantity(0.001)
+            .with_min_notional(10.0)
+    }
+
+    /// 测试价格取整到最近的tick
+    #[test]
+    fn test_round_price() {
+        let instrument = btc();
+        assert_eq!(instrument.round_price(100.004), 100.0);
+        assert_eq!(instrument.round_price(100.006), 100.01);
+        assert_eq!(instrument.round_price(0.1 + 0.2), 0.3);
+    }
+
+    /// 测试数量向下取整
+    #[test]
+    fn test_round_quantity_floors() {
+        let instrument = btc();
+        assert_eq!(instrument.round_quantity(1.2349), 1.234);
+        assert_eq!(instrument.round_quantity(0.0009), 0.0);
+        // 浮点误差不应导致少取一个步长
+        assert_eq!(instrument.round_quantity(0.3), 0.3);
+    }
+
+    /// 测试整数步长
+    #[test]
+    fn test_integer_step_size() {
+        let instrument = Instrument::new("DOGEUSDT", "DOGE", "USDT").with_step_size(1.0);
+        assert_eq!(instrument.round_quantity(123.9), 123.0);
+    }
+
+    /// 测试无限制的品种不做任何修改
+    #[test]
+    fn test_unrestricted_instrument() {
+        let instrument = Instrument::new("X", "A", "B");
+        assert_eq!(instrument.round_price(1.23456789), 1.23456789);
+        assert_eq!(instrument.round_quantity(0.123456789), 0.123456789);
+        assert!(instrument.validate_order(1.23456789, 0.123456789).is_ok());
+    }
+
+    /// 测试订单校验的各类错误
+    #[test]
+    fn test_validate_order_errors() {
+        let instrument = btc();
+
+        assert_eq!(
+            instrument.validate_order(0.0, 1.0),
+            Err(InstrumentError::InvalidPrice(0.0))
+        );
+        assert_eq!(
+            instrument.validate_order(100.0, -1.0),
+            Err(InstrumentError::InvalidQuantity(-1.0))
+        );
+        assert!(matches!(
+            instrument.validate_order(100.005, 1.0),
+            Err(InstrumentError::PriceNotOnTick { .. })
+        ));
+        assert!(matches!(
+            instrument.validate_order(100.0, 1.0005),
+            Err(InstrumentError::QuantityNotOnStep { .. })
+        ));
+        assert!(matches!(
+            Instrument::new("X", "A", "B")
+                .with_min_quantity(1.0)
+                .validate_order(100.0, 0.5),
+            Err(InstrumentError::QuantityBelowMinimum { .. })
+        ));
+        assert!(matches!(
+            instrument.validate_order(100.0, 0.05),
+            Err(InstrumentError::NotionalBelowMinimum { .. })
+        ));
+        assert!(instrument.validate_order(100.0, 0.1).is_ok());
+    }
+
+    /// 测试取整后校验
+    #[test]
+    fn test_normalize_order() {
+        let instrument = btc();
+        assert_eq!(instrument.normalize_order(100.123, 0.56789), Ok((100.12, 0.567)));
+        assert!(instrument.normalize_order(100.0, 0.0009).is_err());
+    }
+
+    /// 测试错误信息
+    #[test]
+    fn test_error_display() {
+        let err = InstrumentError::NotionalBelowMinimum {
+            notional: 5.0,
+            min_notional: 10.0,
+        };
+        assert!(err.to_string().contains("最小名义金额"));
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 订单意图
+//!
+//! [`OrderIntent`] 附加在 [`SignalEvent`](crate::SignalEvent) 上，
+//! 让策略在给出买卖方向的同时说明下单细节：
+//!
+//! - 下单规模：固定数量或占总权益的比例
+//! - 订单类型：市价、限价或止损触发
+//! - 保护性价格：止损价和止盈价
+//! - 信号置信度
+//!
+//! 不带订单意图的信号按引擎的默认方式执行（市价、由仓位管理决定规模）。
+
+use serde::{Deserialize, Serialize};
+
+/// 下单规模
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum OrderSize {
+    /// 固定数量（基础资产单位）
+    Quantity(f64),
+    /// 占当前总权益的比例（0.3 表示 30%），按成交价换算为数量
+    Weight(f64),
+}
+
+/// 订单类型
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum OrderKind {
+    /// 市价单，立即按当前价格成交
+    #[default]
+    Market,
+    /// 限价单，买单在价格不高于限价时成交，卖单在价格不低于限价时成交
+    Limit(f64),
+    /// 止损触发单，买单在价格涨到触发价时成交，卖单在价格跌到触发价时成交
+    Stop(f64),
+}
+
+/// 订单意图
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::{OrderIntent, OrderKind, OrderSize, Signal, SignalEvent};
+///
+/// // 用30%的权益在 46000 挂限价买单，止损 44000，止盈 50000
+/// let intent = OrderIntent::limit(46000.0)
+///     .with_weight(0.3)
+///     .with_stop_loss(44000.0)
+///     .with_take_profit(50000.0)
+///     .with_confidence(0.8);
+///
+/// let signal = SignalEvent::new(Signal::Buy, 46500.0, 1640995200000).with_intent(intent);
+///
+/// let intent = signal.intent.unwrap();
+/// assert_eq!(intent.kind, OrderKind::Limit(46000.0));
+/// assert_eq!(intent.size, Some(OrderSize::Weight(0.3)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct OrderIntent {
+    /// 下单规模，`None` 表示由投资组合的仓位管理决定（卖出时为全部持仓）
+    pub size: Option<OrderSize>,
+    /// 订单类型
+    pub kind: OrderKind,
+    /// 止损价（绝对价格）
+    pub stop_loss: Option<f64>,
+    /// 止盈价（绝对价格）
+    pub take_profit: Option<f64>,
+    /// 信号置信度，范围 0.0 - 1.0
+    pub confidence: Option<f64>,
+}
+
+impl OrderIntent {
+    /// 创建市价单意图
+    pub fn market() -> Self {
+        Self::default()
+    }
+
+    /// 创建限价单意图
+    pub fn limit(price: f64) -> Self {
+        Self {
+            kind: OrderKind::Limit(price),
+            ..Self::default()
+        }
+    }
+
+    /// 创建止损触发单意图
+    pub fn stop(price: f64) -> Self {
+        Self {
+            kind: OrderKind::Stop(price),
+            ..Self::default()
+        }
+    }
+
+    /// 按固定数量下单
+    pub fn with_quantity(mut self, quantity: f64) -> Self {
+        self.size = Some(OrderSize::Quantity(quantity));
+        self
+    }
+
+    /// 按总权益比例下单
+    pub fn with_weight(mut self, weight: f64) -> Self {
+        self.size = Some(OrderSize::Weight(weight));
+        self
+    }
+
+    /// 设置止损价
+    pub fn with_stop_loss(mut self, price: f64) -> Self {
+        self.stop_loss = Some(price);
+        self
+    }
+
+    /// 设置止盈价
+    pub fn with_take_profit(mut self, price: f64) -> Self {
+        self.take_profit = Some(price);
+        self
+    }
+
+    /// 设置信号置信度，超出 0.0 - 1.0 的值会被截断
+    pub fn with_confidence(mut self, confidence: f64) -> Self {
+        self.confidence = Some(confidence.clamp(0.0, 1.0));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 测试默认意图为市价单
+    #[test]
+    fn test_default_is_market() {
+        let intent = OrderIntent::market();
+        assert_eq!(intent.kind, OrderKind::Market);
+        assert!(intent.size.is_none());
+        assert!(intent.stop_loss.is_none());
+    }
+
+    /// 测试构建器方法
+    #[test]
+    fn test_builder() {
+        let intent = OrderIntent::stop(105.0)
+            .with_quantity(2.0)
+            .with_stop_loss(95.0)
+            .with_take_profit(120.0);
+
+        assert_eq!(intent.kind, OrderKind::Stop(105.0));
+        assert_eq!(intent.size, Some(OrderSize::Quantity(2.0)));
+        assert_eq!(intent.stop_loss, Some(95.0));
+        assert_eq!(intent.take_profit, Some(120.0));
+    }
+
+    /// 测试置信度截断
+    #[test]
+    fn test_confidence_is_clamped() {
+        assert_eq!(OrderIntent::market().with_confidence(1.5).confidence, Some(1.0));
+        assert_eq!(OrderIntent::market().with_confidence(-0.2).confidence, Some(0.0));
+    }
+}
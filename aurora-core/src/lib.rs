@@ -1,10 +1,15 @@
 //! Aurora 核心库 - 定义基础数据结构和通用接口
 //!
 //! 本模块提供了 Aurora 量化交易框架的核心抽象，包括：
-//! - 市场数据结构（K线数据）
+//! - 市场数据结构（K线、逐笔成交、盘口报价、深度快照、资金费率）
+//! - K线周期解析与多周期重采样
+//! - 交易品种规则（价格精度、数量步长、最小下单量）
 //! - 市场事件系统
-//! - 交易信号定义
-//! - 数据源和策略的统一接口
+//! - 交易信号与订单意图定义
+//! - 成交记录
+//! - 记账数值类型（启用 `decimal` 特性后为十进制定点数）
+//! - 回测和实时交易共用的时钟抽象
+//! - 数据源和策略的统一接口（含策略生命周期回调和状态快照）
 //!
 //! # 示例
 //!
@@ -19,6 +24,8 @@
 //!     low: 95.0,
 //!     close: 102.0,
 //!     volume: 1000.0,
+//!     symbol: None,
+//!     interval: None,
 //! };
 //!
 //! // 创建市场事件
@@ -29,6 +36,26 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc::UnboundedReceiver;
 
+mod amount;
+mod clock;
+mod instrument;
+mod intent;
+mod lifecycle;
+mod market;
+mod resample;
+mod snapshot;
+mod trade;
+
+pub use amount::{AMOUNT_ZERO, Amount, amount_to_f64, to_amount};
+pub use clock::{Clock, MILLIS_PER_DAY, SimulatedClock, SystemClock};
+pub use instrument::{Instrument, InstrumentError};
+pub use intent::{OrderIntent, OrderKind, OrderSize};
+pub use lifecycle::{OrderRejection, RunMode, StrategyContext, TimerSchedule};
+pub use market::{DepthSnapshot, FundingRate, PriceLevel, Quote, TradeTick};
+pub use resample::{Resampler, Timeframe, interval_to_ms};
+pub use snapshot::{SnapshotError, StrategySnapshot};
+pub use trade::{Trade, TradeBuilder, TradeSide};
+
 /// K线数据结构
 ///
 /// 表示一个时间周期内的价格和成交量信息，是技术分析的基础数据。
@@ -54,6 +81,8 @@ use tokio::sync::mpsc::UnboundedReceiver;
 ///     low: 45500.0,
 ///     close: 46500.0,
 ///     volume: 123.45,
+///     symbol: None,
+///     interval: None,
 /// };
 ///
 /// assert_eq!(kline.open, 46000.0);
@@ -73,16 +102,41 @@ pub struct Kline {
     pub close: f64,
     /// 成交量
     pub volume: f64,
+    /// 交易对符号（多品种数据流中用于区分K线来源，未知时为 `None`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    /// K线周期，如 "1m"、"1h"（未知时为 `None`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<String>,
+}
+
+impl Kline {
+    /// 设置交易对符号
+    pub fn with_symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_string());
+        self
+    }
+
+    /// 设置K线周期
+    pub fn with_interval(mut self, interval: &str) -> Self {
+        self.interval = Some(interval.to_string());
+        self
+    }
 }
 
 /// 市场事件枚举
 ///
 /// 统一不同类型的市场数据输出，提供事件驱动架构的基础。
-/// 目前支持K线事件，未来可扩展支持逐笔成交、订单簿等事件。
+/// 除K线外，还支持逐笔成交、最优报价、深度快照和资金费率事件，
+/// 使策略可以对盘口变化做出反应，而不必等待K线收盘。
 ///
 /// # 变体
 ///
 /// * `Kline(Kline)` - K线数据事件
+/// * `Trade(TradeTick)` - 逐笔成交事件
+/// * `Quote(Quote)` - 最优买卖报价事件
+/// * `DepthSnapshot(DepthSnapshot)` - 订单簿深度快照事件
+/// * `FundingRate(FundingRate)` - 资金费率事件
 ///
 /// # 示例
 ///
@@ -96,20 +150,71 @@ pub struct Kline {
 ///     low: 95.0,
 ///     close: 102.0,
 ///     volume: 1000.0,
+///     symbol: None,
+///     interval: None,
 /// };
 ///
 /// let event = MarketEvent::Kline(kline);
-/// match event {
+/// match &event {
 ///     MarketEvent::Kline(k) => println!("收到K线: 收盘价 {}", k.close),
+///     other => println!("收到其他事件: {:?}", other),
 /// }
+///
+/// assert_eq!(event.timestamp(), 1640995200000);
+/// assert_eq!(event.reference_price(), Some(102.0));
 /// ```
 #[derive(Debug, Clone)]
 pub enum MarketEvent {
     /// K线数据事件
     Kline(Kline),
-    // 未来可扩展：
-    // Trade(Trade),      // 逐笔成交事件
-    // OrderBook(OrderBook), // 订单簿事件
+    /// 逐笔成交事件
+    Trade(TradeTick),
+    /// 最优买卖报价事件
+    Quote(Quote),
+    /// 订单簿深度快照事件
+    DepthSnapshot(DepthSnapshot),
+    /// 资金费率事件
+    FundingRate(FundingRate),
+}
+
+impl MarketEvent {
+    /// 获取事件时间戳（Unix毫秒）
+    pub fn timestamp(&self) -> i64 {
+        match self {
+            MarketEvent::Kline(kline) => kline.timestamp,
+            MarketEvent::Trade(trade) => trade.timestamp,
+            MarketEvent::Quote(quote) => quote.timestamp,
+            MarketEvent::DepthSnapshot(depth) => depth.timestamp,
+            MarketEvent::FundingRate(funding) => funding.timestamp,
+        }
+    }
+
+    /// 获取事件所属的交易对符号
+    ///
+    /// 未标注交易对的K线返回 `None`。
+    pub fn symbol(&self) -> Option<&str> {
+        match self {
+            MarketEvent::Kline(kline) => kline.symbol.as_deref(),
+            MarketEvent::Trade(trade) => Some(&trade.symbol),
+            MarketEvent::Quote(quote) => Some(&quote.symbol),
+            MarketEvent::DepthSnapshot(depth) => Some(&depth.symbol),
+            MarketEvent::FundingRate(funding) => Some(&funding.symbol),
+        }
+    }
+
+    /// 获取事件的参考价格
+    ///
+    /// K线取收盘价，成交取成交价，报价和深度取中间价，资金费率取标记价格。
+    /// 无法确定价格时（如深度快照一侧为空）返回 `None`。
+    pub fn reference_price(&self) -> Option<f64> {
+        match self {
+            MarketEvent::Kline(kline) => Some(kline.close),
+            MarketEvent::Trade(trade) => Some(trade.price),
+            MarketEvent::Quote(quote) => Some(quote.mid_price()),
+            MarketEvent::DepthSnapshot(depth) => depth.mid_price(),
+            MarketEvent::FundingRate(funding) => funding.mark_price,
+        }
+    }
 }
 
 /// 交易信号枚举
@@ -153,6 +258,7 @@ pub enum Signal {
 /// * `signal` - 交易信号类型
 /// * `price` - 触发信号时的价格
 /// * `timestamp` - 信号产生的时间戳
+/// * `intent` - 订单意图（可选），描述下单规模、订单类型和止损止盈
 ///
 /// # 示例
 ///
@@ -163,6 +269,7 @@ pub enum Signal {
 ///     signal: Signal::Buy,
 ///     price: 46500.0,
 ///     timestamp: 1640995200000,
+///     intent: None,
 /// };
 ///
 /// assert_eq!(signal_event.signal, Signal::Buy);
@@ -176,6 +283,26 @@ pub struct SignalEvent {
     pub price: f64,
     /// 时间戳（Unix毫秒）
     pub timestamp: i64,
+    /// 订单意图，`None` 表示按引擎默认方式执行
+    pub intent: Option<OrderIntent>,
+}
+
+impl SignalEvent {
+    /// 创建不带订单意图的信号事件
+    pub fn new(signal: Signal, price: f64, timestamp: i64) -> Self {
+        Self {
+            signal,
+            price,
+            timestamp,
+            intent: None,
+        }
+    }
+
+    /// 附加订单意图
+    pub fn with_intent(mut self, intent: OrderIntent) -> Self {
+        self.intent = Some(intent);
+        self
+    }
 }
 
 /// 异步数据源统一接口
@@ -228,11 +355,21 @@ pub trait DataSource {
 ///
 /// 定义了交易策略的标准行为，支持事件驱动的信号生成。
 /// 所有策略都必须实现此trait以与回测和实时引擎兼容。
+/// 引擎会把数据源产生的所有 [`MarketEvent`] 变体交给策略，
+/// 只关心K线的策略可以对其他变体直接返回 `None`。
 ///
 /// # 方法
 ///
 /// * `on_market_event()` - 处理市场事件并可能产生交易信号
 ///
+/// 以下生命周期回调都有空的默认实现，策略按需覆盖：
+///
+/// * `on_start()` - 开始运行时调用一次
+/// * `on_order_filled()` - 订单成交（含部分成交）后调用
+/// * `on_order_rejected()` - 订单被拒绝后调用
+/// * `timer_interval()` / `on_timer()` - 按固定间隔的事件时间定时调用
+/// * `on_finish()` - 数据结束或引擎停止时调用一次
+///
 /// # 实现要求
 ///
 /// 实现此trait的类型必须是 `Send + Sync` 以支持多线程环境。
@@ -254,11 +391,14 @@ pub trait DataSource {
 ///                         signal: Signal::Buy,
 ///                         price: kline.close,
 ///                         timestamp: kline.timestamp,
+///                         intent: None,
 ///                     })
 ///                 } else {
 ///                     None
 ///                 }
 ///             }
+///             // 只关心K线的策略可以忽略其他事件
+///             _ => None,
 ///         }
 ///     }
 /// }
@@ -275,6 +415,123 @@ pub trait Strategy: Send + Sync {
     /// 如果策略决定产生交易信号，返回 `Some(SignalEvent)`；
     /// 否则返回 `None`
     fn on_market_event(&mut self, event: &MarketEvent) -> Option<SignalEvent>;
+
+    /// 开始运行时调用
+    ///
+    /// # 参数
+    ///
+    /// * `context` - 运行上下文（模式、初始资金、交易对等）
+    fn on_start(&mut self, _context: &StrategyContext) {}
+
+    /// 订单成交后调用
+    ///
+    /// 成交数量可能小于信号请求的数量（资金不足或按交易规则取整），
+    /// 策略可以据此判断是否部分成交。
+    ///
+    /// 止损、止盈和手动平仓由执行层完成，不经过策略的信号。
+    /// 根据自己发出的信号跟踪持仓的策略，收到卖出成交而自身仍记为持仓时应当回到空仓状态，
+    /// 否则会继续等待一个已经不存在的仓位的离场条件。
+    ///
+    /// # 参数
+    ///
+    /// * `trade` - 成交记录
+    fn on_order_filled(&mut self, _trade: &Trade) {}
+
+    /// 订单被拒绝后调用
+    ///
+    /// 买入被拒绝时并没有开仓，发出买入信号时就记为持仓的策略应当撤销这一状态。
+    ///
+    /// # 参数
+    ///
+    /// * `rejection` - 拒绝通知，包含订单方向和拒绝原因
+    fn on_order_rejected(&mut self, _rejection: &OrderRejection) {}
+
+    /// 定时器间隔（毫秒）
+    ///
+    /// 返回 `Some` 时引擎按事件时间每隔该间隔调用一次 [`on_timer`](Self::on_timer)。
+    /// 默认返回 `None`，不启用定时器。
+    fn timer_interval(&self) -> Option<i64> {
+        None
+    }
+
+    /// 定时器触发时调用
+    ///
+    /// # 参数
+    ///
+    /// * `timestamp` - 定时器时刻（Unix毫秒）
+    ///
+    /// # 返回值
+    ///
+    /// 可以返回交易信号，引擎按最近一次行情价格执行
+    fn on_timer(&mut self, _timestamp: i64) -> Option<SignalEvent> {
+        None
+    }
+
+    /// 数据结束或引擎停止时调用
+    fn on_finish(&mut self) {}
+
+    /// 导出策略状态快照
+    ///
+    /// 实时引擎定期保存快照作为检查点，重启后通过 [`restore`](Self::restore) 继续运行。
+    /// 默认返回 [`SnapshotError::Unsupported`]。
+    fn snapshot(&self) -> Result<StrategySnapshot, SnapshotError> {
+        Err(SnapshotError::Unsupported)
+    }
+
+    /// 从快照恢复策略状态
+    ///
+    /// # 参数
+    ///
+    /// * `snapshot` - 由同名策略导出的快照
+    ///
+    /// # 返回值
+    ///
+    /// 快照来自其他策略或参数不兼容时返回错误，此时策略状态保持不变。
+    /// 默认返回 [`SnapshotError::Unsupported`]。
+    fn restore(&mut self, _snapshot: &StrategySnapshot) -> Result<(), SnapshotError> {
+        Err(SnapshotError::Unsupported)
+    }
+}
+
+/// 装箱的策略同样实现 [`Strategy`]，所有方法转发给内部策略
+///
+/// 使 `Box<dyn Strategy>`（例如按配置动态创建的策略）可以交给按泛型接收策略的引擎。
+impl<S: Strategy + ?Sized> Strategy for Box<S> {
+    fn on_market_event(&mut self, event: &MarketEvent) -> Option<SignalEvent> {
+        (**self).on_market_event(event)
+    }
+
+    fn on_start(&mut self, context: &StrategyContext) {
+        (**self).on_start(context);
+    }
+
+    fn on_order_filled(&mut self, trade: &Trade) {
+        (**self).on_order_filled(trade);
+    }
+
+    fn on_order_rejected(&mut self, rejection: &OrderRejection) {
+        (**self).on_order_rejected(rejection);
+    }
+
+    fn timer_interval(&self) -> Option<i64> {
+        (**self).timer_interval()
+    }
+
+    fn on_timer(&mut self, timestamp: i64) -> Option<SignalEvent> {
+        (**self).on_timer(timestamp)
+    }
+
+    fn on_finish(&mut self) {
+        (**self).on_finish();
+    }
+
+    fn snapshot(&self) -> Result<StrategySnapshot, SnapshotError> {
+        (**self).snapshot()
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> Result<(), SnapshotError> {
+        (**self).restore(snapshot)
+    }
 }
 
 #[cfg(test)]
@@ -291,6 +548,8 @@ mod tests {
             low: 95.0,
             close: 102.0,
             volume: 1000.0,
+            symbol: None,
+            interval: None,
         };
 
         assert_eq!(kline.open, 100.0);
@@ -311,6 +570,8 @@ mod tests {
             low: 95.0,
             close: 102.0,
             volume: 1000.0,
+            symbol: None,
+            interval: None,
         };
 
         let kline2 = kline1.clone();
@@ -323,6 +584,8 @@ mod tests {
             low: 95.0,
             close: 103.0, // 不同的收盘价
             volume: 1000.0,
+            symbol: None,
+            interval: None,
         };
 
         assert_ne!(kline1, kline3);
@@ -347,6 +610,7 @@ mod tests {
             signal: Signal::Buy,
             price: 102.0,
             timestamp: 1640995200000,
+            intent: None,
         };
 
         assert!(matches!(signal_event.signal, Signal::Buy));
@@ -364,6 +628,8 @@ mod tests {
             low: 95.0,
             close: 102.0,
             volume: 1000.0,
+            symbol: None,
+            interval: None,
         };
 
         let event = MarketEvent::Kline(kline.clone());
@@ -372,6 +638,7 @@ mod tests {
             MarketEvent::Kline(k) => {
                 assert_eq!(k, kline);
             }
+            other => panic!("期望K线事件，实际为 {:?}", other),
         }
     }
 
@@ -385,6 +652,8 @@ mod tests {
             low: 95.0,
             close: 102.0,
             volume: 1000.0,
+            symbol: None,
+            interval: None,
         };
 
         // 验证价格逻辑关系
@@ -414,6 +683,8 @@ mod tests {
             low: 95.0,
             close: 102.0,
             volume: 1000.0,
+            symbol: None,
+            interval: None,
         };
 
         let event1 = MarketEvent::Kline(kline);
@@ -424,6 +695,141 @@ mod tests {
             (MarketEvent::Kline(k1), MarketEvent::Kline(k2)) => {
                 assert_eq!(k1, k2);
             }
+            _ => panic!("克隆后的事件类型不一致"),
         }
     }
+
+    /// 测试各类市场事件的时间戳和参考价格
+    #[test]
+    fn test_market_event_timestamp_and_price() {
+        let trade = MarketEvent::Trade(TradeTick {
+            symbol: "BTCUSDT".to_string(),
+            timestamp: 1000,
+            price: 101.0,
+            quantity: 0.1,
+            is_buyer_maker: false,
+            trade_id: Some(1),
+        });
+        assert_eq!(trade.timestamp(), 1000);
+        assert_eq!(trade.reference_price(), Some(101.0));
+
+        let quote = MarketEvent::Quote(Quote {
+            symbol: "BTCUSDT".to_string(),
+            timestamp: 2000,
+            bid_price: 99.0,
+            bid_quantity: 1.0,
+            ask_price: 101.0,
+            ask_quantity: 1.0,
+        });
+        assert_eq!(quote.timestamp(), 2000);
+        assert_eq!(quote.reference_price(), Some(100.0));
+
+        let depth = MarketEvent::DepthSnapshot(DepthSnapshot {
+            symbol: "BTCUSDT".to_string(),
+            timestamp: 3000,
+            last_update_id: None,
+            bids: vec![],
+            asks: vec![PriceLevel {
+                price: 101.0,
+                quantity: 1.0,
+            }],
+        });
+        assert_eq!(depth.timestamp(), 3000);
+        assert_eq!(depth.reference_price(), None);
+
+        let funding = MarketEvent::FundingRate(FundingRate {
+            symbol: "BTCUSDT".to_string(),
+            timestamp: 4000,
+            rate: 0.0001,
+            mark_price: Some(100.5),
+            next_funding_time: None,
+        });
+        assert_eq!(funding.timestamp(), 4000);
+        assert_eq!(funding.reference_price(), Some(100.5));
+        assert_eq!(funding.symbol(), Some("BTCUSDT"));
+    }
+
+    /// 测试K线的交易对和周期标注
+    #[test]
+    fn test_kline_identity() {
+        let kline = Kline {
+            timestamp: 1000,
+            open: 100.0,
+            high: 101.0,
+            low: 99.0,
+            close: 100.5,
+            volume: 10.0,
+            symbol: None,
+            interval: None,
+        };
+        assert_eq!(MarketEvent::Kline(kline.clone()).symbol(), None);
+
+        let kline = kline.with_symbol("ETHUSDT").with_interval("5m");
+        assert_eq!(kline.interval.as_deref(), Some("5m"));
+        assert_eq!(MarketEvent::Kline(kline).symbol(), Some("ETHUSDT"));
+    }
+
+    /// 测试生命周期回调的默认实现和覆盖
+    #[test]
+    fn test_strategy_lifecycle_hooks() {
+        struct NoHooks;
+        impl Strategy for NoHooks {
+            fn on_market_event(&mut self, _event: &MarketEvent) -> Option<SignalEvent> {
+                None
+            }
+        }
+
+        #[derive(Default)]
+        struct Recorder {
+            calls: Vec<String>,
+        }
+        impl Strategy for Recorder {
+            fn on_market_event(&mut self, _event: &MarketEvent) -> Option<SignalEvent> {
+                None
+            }
+            fn on_start(&mut self, context: &StrategyContext) {
+                self.calls.push(format!("start:{}", context.initial_cash));
+            }
+            fn on_order_filled(&mut self, trade: &Trade) {
+                self.calls.push(format!("filled:{}", trade.quantity));
+            }
+            fn on_order_rejected(&mut self, rejection: &OrderRejection) {
+                self.calls.push(format!("rejected:{}", rejection.reason));
+            }
+            fn timer_interval(&self) -> Option<i64> {
+                Some(60_000)
+            }
+            fn on_timer(&mut self, timestamp: i64) -> Option<SignalEvent> {
+                self.calls.push(format!("timer:{}", timestamp));
+                Some(SignalEvent::new(Signal::Sell, 0.0, timestamp))
+            }
+            fn on_finish(&mut self) {
+                self.calls.push("finish".to_string());
+            }
+        }
+
+        // 默认实现不做任何事情
+        let mut strategy = NoHooks;
+        strategy.on_start(&StrategyContext::new(RunMode::Backtest, 1000.0));
+        assert!(strategy.timer_interval().is_none());
+        assert!(strategy.on_timer(0).is_none());
+        strategy.on_finish();
+
+        let mut recorder = Recorder::default();
+        recorder.on_start(&StrategyContext::new(RunMode::Live, 1000.0));
+        recorder.on_order_filled(&Trade::new_buy(100.0, 2.0, 1));
+        recorder.on_order_rejected(&OrderRejection {
+            signal: Signal::Buy,
+            price: 100.0,
+            timestamp: 2,
+            reason: "现金不足".to_string(),
+        });
+        assert!(recorder.on_timer(60_000).is_some());
+        recorder.on_finish();
+
+        assert_eq!(
+            recorder.calls,
+            vec!["start:1000", "filled:2", "rejected:现金不足", "timer:60000", "finish"]
+        );
+    }
 }
@@ -0,0 +1,230 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 策略生命周期相关类型
+//!
+//! 回测引擎和实时引擎在运行过程中通过 [`Strategy`](crate::Strategy)
+//! 的生命周期回调通知策略：
+//!
+//! 1. 开始运行时调用 `on_start`，传入 [`StrategyContext`]
+//! 2. 每个市场事件调用 `on_market_event`
+//! 3. 订单成交时调用 `on_order_filled`，被拒绝时调用 `on_order_rejected`（携带 [`OrderRejection`]）
+//! 4. 策略声明了定时器间隔时，按引擎时钟调用 `on_timer`（由 [`TimerSchedule`] 计算）
+//! 5. 数据结束或引擎停止时调用 `on_finish`
+
+use crate::{Clock, Signal};
+use std::fmt;
+
+/// 运行模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunMode {
+    /// 历史数据回测
+    Backtest,
+    /// 实时（模拟）交易
+    Live,
+}
+
+/// 策略运行上下文
+///
+/// 在 `on_start` 中传给策略，描述本次运行的基本信息。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::{RunMode, StrategyContext};
+///
+/// let context = StrategyContext::new(RunMode::Backtest, 10000.0)
+///     .with_symbol("BTCUSDT")
+///     .with_interval("1h")
+///     .with_start_time(1640995200000);
+///
+/// assert_eq!(context.symbol.as_deref(), Some("BTCUSDT"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrategyContext {
+    /// 运行模式
+    pub mode: RunMode,
+    /// 初始资金
+    pub initial_cash: f64,
+    /// 交易对符号（已知时）
+    pub symbol: Option<String>,
+    /// K线周期（已知时）
+    pub interval: Option<String>,
+    /// 开始时间（Unix毫秒，回测为第一个事件的时间）
+    pub start_time: Option<i64>,
+}
+
+impl StrategyContext {
+    /// 创建运行上下文
+    pub fn new(mode: RunMode, initial_cash: f64) -> Self {
+        Self {
+            mode,
+            initial_cash,
+            symbol: None,
+            interval: None,
+            start_time: None,
+        }
+    }
+
+    /// 设置交易对符号
+    pub fn with_symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_string());
+        self
+    }
+
+    /// 设置K线周期
+    pub fn with_interval(mut self, interval: &str) -> Self {
+        self.interval = Some(interval.to_string());
+        self
+    }
+
+    /// 设置开始时间
+    pub fn with_start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+}
+
+/// 订单被拒绝的通知
+///
+/// 现金不足、风控拒绝、不满足交易品种规则等原因都会产生拒绝通知。
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderRejection {
+    /// 被拒绝订单的方向
+    pub signal: Signal,
+    /// 尝试成交的价格
+    pub price: f64,
+    /// 拒绝时间（Unix毫秒）
+    pub timestamp: i64,
+    /// 拒绝原因
+    pub reason: String,
+}
+
+impl fmt::Display for OrderRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} 订单 @ {:.2} 被拒绝: {}", self.signal, self.price, self.reason)
+    }
+}
+
+impl std::error::Error for OrderRejection {}
+
+/// 定时器调度
+///
+/// 按事件时间推进，计算两次事件之间应触发的定时器时刻。
+/// 定时器时刻对齐到间隔的整数倍，回测和实时交易因此得到相同的触发时间。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::TimerSchedule;
+///
+/// let mut timer = TimerSchedule::new(60_000);
+///
+/// // 第一个事件只确定起点
+/// assert!(timer.advance(30_000).is_empty());
+/// // 跨过两个整分钟
+/// assert_eq!(timer.advance(150_000), vec![60_000, 120_000]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimerSchedule {
+    interval: i64,
+    next: Option<i64>,
+}
+
+impl TimerSchedule {
+    /// 创建定时器调度，`interval` 为触发间隔（毫秒），必须大于0
+    pub fn new(interval: i64) -> Self {
+        Self {
+            interval: interval.max(1),
+            next: None,
+        }
+    }
+
+    /// 触发间隔（毫秒）
+    pub fn interval(&self) -> i64 {
+        self.interval
+    }
+
+    /// 推进到给定时间，返回期间到期的全部定时器时刻
+    pub fn advance(&mut self, timestamp: i64) -> Vec<i64> {
+        let mut next = match self.next {
+            Some(next) => next,
+            None => {
+                // 从第一个事件之后的整数倍时刻开始
+                self.next = Some((timestamp.div_euclid(self.interval) + 1) * self.interval);
+                return Vec::new();
+            }
+        };
+
+        let mut due = Vec::new();
+        while next <= timestamp {
+            due.push(next);
+            next += self.interval;
+        }
+        self.next = Some(next);
+        due
+    }
+
+    /// 推进到时钟的当前时间，返回期间到期的全部定时器时刻
+    pub fn poll(&mut self, clock: &dyn Clock) -> Vec<i64> {
+        self.advance(clock.now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 测试定时器按整数倍时刻触发
+    #[test]
+    fn test_timer_schedule_alignment() {
+        let mut timer = TimerSchedule::new(1000);
+
+        assert!(timer.advance(1500).is_empty());
+        assert!(timer.advance(1999).is_empty());
+        assert_eq!(timer.advance(2000), vec![2000]);
+        assert_eq!(timer.advance(4500), vec![3000, 4000]);
+        assert!(timer.advance(4600).is_empty());
+    }
+
+    /// 测试按时钟推进定时器
+    #[test]
+    fn test_timer_schedule_poll_clock() {
+        let clock = crate::SimulatedClock::new(500);
+        let mut timer = TimerSchedule::new(1000);
+
+        assert!(timer.poll(&clock).is_empty());
+        clock.advance_to(2100);
+        assert_eq!(timer.poll(&clock), vec![1000, 2000]);
+    }
+
+    /// 测试非法间隔被修正
+    #[test]
+    fn test_timer_schedule_invalid_interval() {
+        assert_eq!(TimerSchedule::new(0).interval(), 1);
+    }
+
+    /// 测试拒绝通知的显示
+    #[test]
+    fn test_order_rejection_display() {
+        let rejection = OrderRejection {
+            signal: Signal::Buy,
+            price: 100.0,
+            timestamp: 1,
+            reason: "现金不足".to_string(),
+        };
+
+        assert!(rejection.to_string().contains("现金不足"));
+    }
+}
@@ -0,0 +1,287 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 盘口与逐笔市场数据结构
+//!
+//! 本模块定义了K线之外的市场数据类型，供 [`MarketEvent`](crate::MarketEvent)
+//! 的各个变体携带：
+//!
+//! - [`TradeTick`] - 逐笔成交
+//! - [`Quote`] - 最优买卖报价（Binance bookTicker）
+//! - [`DepthSnapshot`] - 订单簿深度快照
+//! - [`FundingRate`] - 永续合约资金费率
+//!
+//! 所有价格和数量均使用 `f64`，时间戳为Unix毫秒，与 [`Kline`](crate::Kline) 保持一致。
+
+use serde::{Deserialize, Serialize};
+
+/// 逐笔成交数据
+///
+/// 表示交易所撮合产生的一笔成交。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::TradeTick;
+///
+/// let trade = TradeTick {
+///     symbol: "BTCUSDT".to_string(),
+///     timestamp: 1640995200000,
+///     price: 46500.0,
+///     quantity: 0.5,
+///     is_buyer_maker: false,
+///     trade_id: Some(12345),
+/// };
+///
+/// assert_eq!(trade.notional(), 23250.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TradeTick {
+    /// 交易对符号
+    pub symbol: String,
+    /// 成交时间（Unix毫秒）
+    pub timestamp: i64,
+    /// 成交价格
+    pub price: f64,
+    /// 成交数量
+    pub quantity: f64,
+    /// 买方是否为挂单方（true 表示主动卖出成交）
+    pub is_buyer_maker: bool,
+    /// 交易所成交ID
+    pub trade_id: Option<u64>,
+}
+
+impl TradeTick {
+    /// 计算成交金额（价格 × 数量）
+    pub fn notional(&self) -> f64 {
+        self.price * self.quantity
+    }
+}
+
+/// 最优买卖报价
+///
+/// 对应 Binance 的 bookTicker 推送，只包含盘口第一档。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::Quote;
+///
+/// let quote = Quote {
+///     symbol: "BTCUSDT".to_string(),
+///     timestamp: 1640995200000,
+///     bid_price: 99.0,
+///     bid_quantity: 2.0,
+///     ask_price: 101.0,
+///     ask_quantity: 1.5,
+/// };
+///
+/// assert_eq!(quote.mid_price(), 100.0);
+/// assert_eq!(quote.spread(), 2.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Quote {
+    /// 交易对符号
+    pub symbol: String,
+    /// 报价时间（Unix毫秒）
+    pub timestamp: i64,
+    /// 最优买价
+    pub bid_price: f64,
+    /// 最优买价挂单量
+    pub bid_quantity: f64,
+    /// 最优卖价
+    pub ask_price: f64,
+    /// 最优卖价挂单量
+    pub ask_quantity: f64,
+}
+
+impl Quote {
+    /// 买卖中间价
+    pub fn mid_price(&self) -> f64 {
+        (self.bid_price + self.ask_price) / 2.0
+    }
+
+    /// 买卖价差（卖价 - 买价）
+    pub fn spread(&self) -> f64 {
+        self.ask_price - self.bid_price
+    }
+}
+
+/// 订单簿中的一个价格档位
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct PriceLevel {
+    /// 档位价格
+    pub price: f64,
+    /// 档位挂单量
+    pub quantity: f64,
+}
+
+/// 订单簿深度快照
+///
+/// `bids` 按价格从高到低排列，`asks` 按价格从低到高排列，
+/// 与交易所推送的顺序一致。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::{DepthSnapshot, PriceLevel};
+///
+/// let depth = DepthSnapshot {
+///     symbol: "BTCUSDT".to_string(),
+///     timestamp: 1640995200000,
+///     last_update_id: Some(1),
+///     bids: vec![PriceLevel { price: 99.0, quantity: 1.0 }],
+///     asks: vec![PriceLevel { price: 101.0, quantity: 2.0 }],
+/// };
+///
+/// assert_eq!(depth.best_bid().unwrap().price, 99.0);
+/// assert_eq!(depth.mid_price(), Some(100.0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DepthSnapshot {
+    /// 交易对符号
+    pub symbol: String,
+    /// 快照时间（Unix毫秒）
+    pub timestamp: i64,
+    /// 交易所订单簿更新ID
+    pub last_update_id: Option<u64>,
+    /// 买单档位（价格从高到低）
+    pub bids: Vec<PriceLevel>,
+    /// 卖单档位（价格从低到高）
+    pub asks: Vec<PriceLevel>,
+}
+
+impl DepthSnapshot {
+    /// 最优买价档位
+    pub fn best_bid(&self) -> Option<&PriceLevel> {
+        self.bids.first()
+    }
+
+    /// 最优卖价档位
+    pub fn best_ask(&self) -> Option<&PriceLevel> {
+        self.asks.first()
+    }
+
+    /// 买卖中间价，任一侧为空时返回 `None`
+    pub fn mid_price(&self) -> Option<f64> {
+        match (self.best_bid(), self.best_ask()) {
+            (Some(bid), Some(ask)) => Some((bid.price + ask.price) / 2.0),
+            _ => None,
+        }
+    }
+}
+
+/// 永续合约资金费率
+///
+/// 对应 Binance 合约的 markPriceUpdate 推送。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::FundingRate;
+///
+/// let funding = FundingRate {
+///     symbol: "BTCUSDT".to_string(),
+///     timestamp: 1640995200000,
+///     rate: 0.0001,
+///     mark_price: Some(46500.0),
+///     next_funding_time: Some(1641024000000),
+/// };
+///
+/// assert!(funding.rate > 0.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct FundingRate {
+    /// 交易对符号
+    pub symbol: String,
+    /// 推送时间（Unix毫秒）
+    pub timestamp: i64,
+    /// 资金费率（0.0001 表示 0.01%）
+    pub rate: f64,
+    /// 标记价格
+    pub mark_price: Option<f64>,
+    /// 下次资金费结算时间（Unix毫秒）
+    pub next_funding_time: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(price: f64, quantity: f64) -> PriceLevel {
+        PriceLevel { price, quantity }
+    }
+
+    /// 测试逐笔成交金额计算
+    #[test]
+    fn test_trade_tick_notional() {
+        let trade = TradeTick {
+            symbol: "ETHUSDT".to_string(),
+            timestamp: 1,
+            price: 2000.0,
+            quantity: 1.5,
+            is_buyer_maker: true,
+            trade_id: None,
+        };
+
+        assert_eq!(trade.notional(), 3000.0);
+    }
+
+    /// 测试报价中间价和价差
+    #[test]
+    fn test_quote_mid_and_spread() {
+        let quote = Quote {
+            symbol: "BTCUSDT".to_string(),
+            timestamp: 1,
+            bid_price: 100.0,
+            bid_quantity: 1.0,
+            ask_price: 100.5,
+            ask_quantity: 1.0,
+        };
+
+        assert_eq!(quote.mid_price(), 100.25);
+        assert_eq!(quote.spread(), 0.5);
+    }
+
+    /// 测试深度快照最优档位
+    #[test]
+    fn test_depth_best_levels() {
+        let depth = DepthSnapshot {
+            symbol: "BTCUSDT".to_string(),
+            timestamp: 1,
+            last_update_id: None,
+            bids: vec![level(99.5, 1.0), level(99.0, 3.0)],
+            asks: vec![level(100.5, 2.0), level(101.0, 1.0)],
+        };
+
+        assert_eq!(depth.best_bid(), Some(&level(99.5, 1.0)));
+        assert_eq!(depth.best_ask(), Some(&level(100.5, 2.0)));
+        assert_eq!(depth.mid_price(), Some(100.0));
+    }
+
+    /// 测试空订单簿没有中间价
+    #[test]
+    fn test_depth_empty_side() {
+        let depth = DepthSnapshot {
+            symbol: "BTCUSDT".to_string(),
+            timestamp: 1,
+            last_update_id: None,
+            bids: vec![level(99.5, 1.0)],
+            asks: vec![],
+        };
+
+        assert!(depth.best_ask().is_none());
+        assert_eq!(depth.mid_price(), None);
+    }
+}
@@ -0,0 +1,438 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! K线周期与多周期重采样
+//!
+//! [`Resampler`] 把低周期K线流式聚合为高周期K线，例如把15分钟K线聚合为4小时K线。
+//! 高周期K线按UTC时间对齐，与交易所的K线划分一致：
+//!
+//! - 分钟、小时、日和3日K线按周期长度的整数倍对齐
+//! - 周线从周一 00:00 开始
+//! - 月线（`1M`）按自然月划分
+//!
+//! 已知基础K线周期时（K线的 `interval` 字段，或目前为止相邻K线的最小时间间隔），
+//! 高周期中最后一根基础K线到达时即输出完成的高周期K线；
+//! 否则在下一个高周期的第一根基础K线到达时输出。
+//! 每个高周期最多输出一次，之后到达的同一周期的基础K线被忽略。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_core::{Kline, Resampler, Timeframe};
+//!
+//! let mut resampler = Resampler::new(Timeframe::parse("1h").unwrap());
+//!
+//! let mut completed = Vec::new();
+//! for i in 0..8 {
+//!     let kline = Kline {
+//!         timestamp: i * 15 * 60_000,
+//!         open: 100.0 + i as f64,
+//!         high: 101.0 + i as f64,
+//!         low: 99.0 + i as f64,
+//!         close: 100.5 + i as f64,
+//!         volume: 10.0,
+//!         symbol: None,
+//!         interval: Some("15m".to_string()),
+//!     };
+//!     completed.extend(resampler.update(&kline));
+//! }
+//!
+//! // 8根15分钟K线聚合为2根1小时K线
+//! assert_eq!(completed.len(), 2);
+//! assert_eq!(completed[0].open, 100.0);
+//! assert_eq!(completed[0].close, 103.5);
+//! assert_eq!(completed[0].volume, 40.0);
+//! assert_eq!(completed[1].timestamp, 3_600_000);
+//! assert_eq!(completed[1].interval.as_deref(), Some("1h"));
+//! ```
+
+use crate::{Kline, MILLIS_PER_DAY};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 1970-01-01 是周四，周一对齐需要偏移4天
+const WEEK_OFFSET: i64 = 4 * MILLIS_PER_DAY;
+
+/// 解析K线周期字符串为毫秒数
+///
+/// 支持Binance的周期格式："1m"、"3m"、"5m"、"15m"、"30m"、"1h"、"2h"、"4h"、"6h"、"8h"、
+/// "12h"、"1d"、"3d"、"1w" 和 "1M"。月线按30天近似。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::interval_to_ms;
+///
+/// assert_eq!(interval_to_ms("4h"), Some(4 * 60 * 60 * 1000));
+/// assert_eq!(interval_to_ms("7m"), None);
+/// ```
+pub fn interval_to_ms(interval: &str) -> Option<i64> {
+    let minute = 60 * 1000;
+    let hour = 60 * minute;
+
+    match interval {
+        "1m" => Some(minute),
+        "3m" => Some(3 * minute),
+        "5m" => Some(5 * minute),
+        "15m" => Some(15 * minute),
+        "30m" => Some(30 * minute),
+        "1h" => Some(hour),
+        "2h" => Some(2 * hour),
+        "4h" => Some(4 * hour),
+        "6h" => Some(6 * hour),
+        "8h" => Some(8 * hour),
+        "12h" => Some(12 * hour),
+        "1d" => Some(MILLIS_PER_DAY),
+        "3d" => Some(3 * MILLIS_PER_DAY),
+        "1w" => Some(7 * MILLIS_PER_DAY),
+        "1M" => Some(30 * MILLIS_PER_DAY),
+        _ => None,
+    }
+}
+
+/// K线周期
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Timeframe {
+    /// 周期名称，如 "4h"
+    name: String,
+    /// 周期长度(毫秒)，月线为近似值
+    duration_ms: i64,
+}
+
+impl Timeframe {
+    /// 解析周期字符串，不支持的周期返回 `None`
+    pub fn parse(interval: &str) -> Option<Self> {
+        interval_to_ms(interval).map(|duration_ms| Self {
+            name: interval.to_string(),
+            duration_ms,
+        })
+    }
+
+    /// 周期名称
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 周期长度(毫秒)，月线按30天近似
+    pub fn duration_ms(&self) -> i64 {
+        self.duration_ms
+    }
+
+    /// 时间戳所在周期的开始时间
+    pub fn bucket_start(&self, timestamp: i64) -> i64 {
+        match self.name.as_str() {
+            "1M" => {
+                let (year, month, _) = civil_from_days(timestamp.div_euclid(MILLIS_PER_DAY));
+                days_from_civil(year, month, 1) * MILLIS_PER_DAY
+            }
+            "1w" => (timestamp - WEEK_OFFSET).div_euclid(self.duration_ms) * self.duration_ms + WEEK_OFFSET,
+            _ => timestamp.div_euclid(self.duration_ms) * self.duration_ms,
+        }
+    }
+
+    /// 时间戳所在周期的结束时间（下一个周期的开始时间）
+    pub fn bucket_end(&self, timestamp: i64) -> i64 {
+        match self.name.as_str() {
+            "1M" => {
+                let (year, month, _) = civil_from_days(timestamp.div_euclid(MILLIS_PER_DAY));
+                let (year, month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+                days_from_civil(year, month, 1) * MILLIS_PER_DAY
+            }
+            _ => self.bucket_start(timestamp) + self.duration_ms,
+        }
+    }
+}
+
+impl fmt::Display for Timeframe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// 自Unix纪元起的天数转换为公历年月日
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// 公历年月日转换为自Unix纪元起的天数
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// K线重采样器
+///
+/// 把单一交易对的低周期K线聚合为一个高周期的K线：
+/// 开盘价取第一根，收盘价取最后一根，最高价、最低价取极值，成交量求和。
+/// 输出K线的时间戳为高周期的开始时间，`interval` 为高周期名称。
+/// 重采样器可以序列化，用于在策略快照中保存正在形成的高周期K线。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Resampler {
+    /// 目标周期
+    timeframe: Timeframe,
+    /// 正在形成的高周期K线
+    partial: Option<Kline>,
+    /// 上一根基础K线的时间戳，用于推断基础周期
+    last_timestamp: Option<i64>,
+    /// 目前为止相邻基础K线的最小时间间隔，数据缺失造成的大间隔不会放大推断的周期
+    min_delta: Option<i64>,
+    /// 最近一根已输出的高周期K线的开始时间，已输出的周期不再重新打开
+    last_emitted: Option<i64>,
+}
+
+impl Resampler {
+    /// 创建重采样器
+    pub fn new(timeframe: Timeframe) -> Self {
+        Self {
+            timeframe,
+            partial: None,
+            last_timestamp: None,
+            min_delta: None,
+            last_emitted: None,
+        }
+    }
+
+    /// 输入一根基础K线，返回本次完成的高周期K线
+    ///
+    /// 数据跳过高周期时最多返回两根：之前未完成的高周期K线，
+    /// 以及由这根基础K线单独构成并已完成的高周期K线。
+    /// 基础K线所在的高周期已经输出过时（例如推断的基础周期偏大导致提前完成），
+    /// 这根K线被忽略并返回空。
+    pub fn update(&mut self, kline: &Kline) -> Vec<Kline> {
+        if let Some(delta) = self.last_timestamp.map(|last| kline.timestamp - last).filter(|&ms| ms > 0) {
+            self.min_delta = Some(self.min_delta.map_or(delta, |min| min.min(delta)));
+        }
+        self.last_timestamp = Some(kline.timestamp);
+        let base_ms = kline
+            .interval
+            .as_deref()
+            .and_then(interval_to_ms)
+            .or(self.min_delta)
+            .filter(|&ms| ms > 0);
+
+        let start = self.timeframe.bucket_start(kline.timestamp);
+        if self.last_emitted.is_some_and(|emitted| start <= emitted) {
+            return Vec::new();
+        }
+        let mut completed = Vec::new();
+
+        match self.partial.as_mut() {
+            Some(bar) if bar.timestamp == start => {
+                bar.high = bar.high.max(kline.high);
+                bar.low = bar.low.min(kline.low);
+                bar.close = kline.close;
+                bar.volume += kline.volume;
+            }
+            _ => {
+                completed.extend(self.partial.take());
+                self.partial = Some(Kline {
+                    timestamp: start,
+                    open: kline.open,
+                    high: kline.high,
+                    low: kline.low,
+                    close: kline.close,
+                    volume: kline.volume,
+                    symbol: kline.symbol.clone(),
+                    interval: Some(self.timeframe.name.clone()),
+                });
+            }
+        }
+
+        // 基础K线的结束时间到达高周期结束时间，高周期K线已完成
+        let end = self.timeframe.bucket_end(kline.timestamp);
+        if base_ms.is_some_and(|ms| kline.timestamp + ms >= end) {
+            completed.extend(self.partial.take());
+        }
+
+        if let Some(last) = completed.last() {
+            self.last_emitted = Some(last.timestamp);
+        }
+        completed
+    }
+
+    /// 正在形成的高周期K线，包含最近一根基础K线
+    pub fn partial(&self) -> Option<&Kline> {
+        self.partial.as_ref()
+    }
+
+    /// 取出正在形成的高周期K线，用于数据结束时输出最后一根不完整的K线
+    pub fn flush(&mut self) -> Option<Kline> {
+        let bar = self.partial.take();
+        if let Some(bar) = bar.as_ref() {
+            self.last_emitted = Some(bar.timestamp);
+        }
+        bar
+    }
+
+    /// 目标周期
+    pub fn timeframe(&self) -> &Timeframe {
+        &self.timeframe
+    }
+
+    /// 重置状态
+    pub fn reset(&mut self) {
+        self.partial = None;
+        self.last_timestamp = None;
+        self.min_delta = None;
+        self.last_emitted = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kline(timestamp: i64, close: f64, interval: Option<&str>) -> Kline {
+        Kline {
+            timestamp,
+            open: close - 0.5,
+            high: close + 1.0,
+            low: close - 1.0,
+            close,
+            volume: 1.0,
+            symbol: Some("BTCUSDT".to_string()),
+            interval: interval.map(str::to_string),
+        }
+    }
+
+    /// 测试周期解析
+    #[test]
+    fn test_timeframe_parse() {
+        let timeframe = Timeframe::parse("15m").unwrap();
+        assert_eq!(timeframe.name(), "15m");
+        assert_eq!(timeframe.duration_ms(), 15 * 60_000);
+        assert_eq!(timeframe.to_string(), "15m");
+
+        assert!(Timeframe::parse("10m").is_none());
+        assert_eq!(interval_to_ms("1w"), Some(7 * MILLIS_PER_DAY));
+    }
+
+    /// 测试周线从周一开始、月线按自然月划分
+    #[test]
+    fn test_week_and_month_alignment() {
+        // 2024-01-03 (周三) 12:00 UTC
+        let timestamp = 1_704_283_200_000;
+
+        let week = Timeframe::parse("1w").unwrap();
+        assert_eq!(week.bucket_start(timestamp), 1_704_067_200_000); // 2024-01-01 周一
+        assert_eq!(week.bucket_end(timestamp), 1_704_672_000_000);
+
+        let month = Timeframe::parse("1M").unwrap();
+        // 2024-02-15
+        let february = 1_707_955_200_000;
+        assert_eq!(month.bucket_start(february), 1_706_745_600_000); // 2024-02-01
+        assert_eq!(month.bucket_end(february), 1_709_251_200_000); // 2024-03-01，闰年29天
+        assert_eq!(month.bucket_start(timestamp), 1_704_067_200_000);
+    }
+
+    /// 测试已知基础周期时在最后一根基础K线上输出
+    #[test]
+    fn test_completes_on_last_base_bar() {
+        let mut resampler = Resampler::new(Timeframe::parse("1h").unwrap());
+        let minutes = 15 * 60_000;
+
+        for i in 0..3 {
+            assert!(resampler.update(&kline(i * minutes, 100.0 + i as f64, Some("15m"))).is_empty());
+        }
+        let partial = resampler.partial().unwrap();
+        assert_eq!(partial.close, 102.0);
+        assert_eq!(partial.volume, 3.0);
+
+        let completed = resampler.update(&kline(3 * minutes, 99.0, Some("15m")));
+        assert_eq!(completed.len(), 1);
+        let bar = &completed[0];
+        assert_eq!(bar.timestamp, 0);
+        assert_eq!(bar.open, 99.5);
+        assert_eq!(bar.high, 103.0);
+        assert_eq!(bar.low, 98.0);
+        assert_eq!(bar.close, 99.0);
+        assert_eq!(bar.volume, 4.0);
+        assert_eq!(bar.symbol.as_deref(), Some("BTCUSDT"));
+        assert!(resampler.partial().is_none());
+    }
+
+    /// 测试由相邻K线推断基础周期
+    #[test]
+    fn test_infers_base_interval() {
+        let mut resampler = Resampler::new(Timeframe::parse("30m").unwrap());
+        let minutes = 15 * 60_000;
+
+        // 第一根K线无法推断周期，第二根推断出15分钟并完成第一个30分钟周期
+        assert!(resampler.update(&kline(0, 100.0, None)).is_empty());
+        assert_eq!(resampler.update(&kline(minutes, 101.0, None)).len(), 1);
+        assert!(resampler.update(&kline(2 * minutes, 102.0, None)).is_empty());
+    }
+
+    /// 测试缺失数据时在下一个周期输出未完成的K线
+    #[test]
+    fn test_gap_flushes_previous_bucket() {
+        let mut resampler = Resampler::new(Timeframe::parse("1h").unwrap());
+        let minutes = 15 * 60_000;
+
+        resampler.update(&kline(0, 100.0, Some("15m")));
+        resampler.update(&kline(minutes, 101.0, Some("15m")));
+
+        let completed = resampler.update(&kline(5 * minutes, 105.0, Some("15m")));
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].close, 101.0);
+        assert_eq!(resampler.partial().unwrap().timestamp, 3_600_000);
+
+        assert_eq!(resampler.flush().unwrap().close, 105.0);
+        assert!(resampler.partial().is_none());
+    }
+
+    /// 测试没有 interval 的K线在数据缺失后不会提前完成高周期，也不会重复输出同一周期
+    #[test]
+    fn test_gap_without_interval_does_not_split_bucket() {
+        let minute = 60_000;
+
+        // 1分钟K线缺失6分钟，间隔7分钟不会被当作基础周期
+        let mut resampler = Resampler::new(Timeframe::parse("5m").unwrap());
+        let mut completed = Vec::new();
+        for t in [0, 1, 2, 3, 4, 11, 12, 13, 14] {
+            completed.extend(resampler.update(&kline(t * minute, 100.0 + t as f64, None)));
+        }
+        let timestamps: Vec<i64> = completed.iter().map(|bar| bar.timestamp).collect();
+        assert_eq!(timestamps, vec![0, 10 * minute]);
+        assert_eq!(completed[1].open, 110.5);
+        assert_eq!(completed[1].close, 114.0);
+        assert_eq!(completed[1].volume, 4.0);
+
+        // 数据一开始就有缺口时推断的周期偏大，已提前输出的周期不会被重新打开
+        let mut resampler = Resampler::new(Timeframe::parse("15m").unwrap());
+        let mut completed = Vec::new();
+        for t in [0, 11, 12, 13, 14, 15, 16] {
+            completed.extend(resampler.update(&kline(t * minute, 100.0 + t as f64, None)));
+        }
+        completed.extend(resampler.flush());
+        let timestamps: Vec<i64> = completed.iter().map(|bar| bar.timestamp).collect();
+        assert_eq!(timestamps, vec![0, 15 * minute]);
+        assert_eq!(completed[0].close, 111.0);
+        assert_eq!(completed[1].close, 116.0);
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 策略状态快照
+//!
+//! 策略通过 [`Strategy::snapshot`](crate::Strategy::snapshot) 导出内部状态（指标窗口、上一次的指标值等），
+//! 通过 [`Strategy::restore`](crate::Strategy::restore) 从快照恢复。
+//! 实时引擎据此在重启后从检查点继续运行，而不必重新预热指标。
+//!
+//! 快照以策略名称标记来源，状态本身是任意可序列化的值，统一以 JSON 保存。
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+/// 策略状态快照
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::StrategySnapshot;
+///
+/// let snapshot = StrategySnapshot::new("buy-and-hold", &true).unwrap();
+/// let json = snapshot.to_json().unwrap();
+///
+/// let restored = StrategySnapshot::from_json(&json).unwrap();
+/// let has_bought: bool = restored.state("buy-and-hold").unwrap();
+/// assert!(has_bought);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StrategySnapshot {
+    /// 产生快照的策略名称
+    pub strategy: String,
+    /// 策略状态
+    pub state: serde_json::Value,
+}
+
+impl StrategySnapshot {
+    /// 由策略名称和状态创建快照
+    pub fn new<T: Serialize>(strategy: &str, state: &T) -> Result<Self, SnapshotError> {
+        let state = serde_json::to_value(state)
+            .map_err(|e| SnapshotError::Serialization(e.to_string()))?;
+
+        Ok(Self {
+            strategy: strategy.to_string(),
+            state,
+        })
+    }
+
+    /// 取出状态
+    ///
+    /// # 参数
+    ///
+    /// * `expected` - 期望的策略名称，与快照记录的名称不一致时返回 [`SnapshotError::StrategyMismatch`]
+    pub fn state<T: DeserializeOwned>(&self, expected: &str) -> Result<T, SnapshotError> {
+        if self.strategy != expected {
+            return Err(SnapshotError::StrategyMismatch {
+                expected: expected.to_string(),
+                found: self.strategy.clone(),
+            });
+        }
+
+        serde_json::from_value(self.state.clone())
+            .map_err(|e| SnapshotError::Serialization(e.to_string()))
+    }
+
+    /// 序列化为 JSON 字符串
+    pub fn to_json(&self) -> Result<String, SnapshotError> {
+        serde_json::to_string_pretty(self).map_err(|e| SnapshotError::Serialization(e.to_string()))
+    }
+
+    /// 从 JSON 字符串解析
+    pub fn from_json(json: &str) -> Result<Self, SnapshotError> {
+        serde_json::from_str(json).map_err(|e| SnapshotError::Serialization(e.to_string()))
+    }
+
+    /// 保存到文件
+    ///
+    /// 先写入临时文件再重命名，写入中途退出不会破坏已有的检查点。
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), SnapshotError> {
+        let path = path.as_ref();
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, self.to_json()?).map_err(|e| SnapshotError::Io(e.to_string()))?;
+        std::fs::rename(&tmp, path).map_err(|e| SnapshotError::Io(e.to_string()))
+    }
+
+    /// 从文件加载
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, SnapshotError> {
+        let json =
+            std::fs::read_to_string(path).map_err(|e| SnapshotError::Io(e.to_string()))?;
+        Self::from_json(&json)
+    }
+}
+
+/// 快照错误
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnapshotError {
+    /// 策略不支持快照
+    Unsupported,
+    /// 快照来自其他策略
+    StrategyMismatch {
+        /// 当前策略名称
+        expected: String,
+        /// 快照记录的策略名称
+        found: String,
+    },
+    /// 快照与当前策略的参数不兼容（如均线周期不同）
+    Incompatible(String),
+    /// 序列化或反序列化失败
+    Serialization(String),
+    /// 文件读写失败
+    Io(String),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported => write!(f, "策略不支持状态快照"),
+            Self::StrategyMismatch { expected, found } => {
+                write!(f, "快照属于策略 {}，当前策略为 {}", found, expected)
+            }
+            Self::Incompatible(reason) => write!(f, "快照与策略参数不兼容: {}", reason),
+            Self::Serialization(reason) => write!(f, "快照序列化失败: {}", reason),
+            Self::Io(reason) => write!(f, "快照文件读写失败: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 测试快照往返
+    #[test]
+    fn test_snapshot_round_trip() {
+        let snapshot = StrategySnapshot::new("test", &vec![1.0, 2.0, 3.0]).unwrap();
+        let restored = StrategySnapshot::from_json(&snapshot.to_json().unwrap()).unwrap();
+
+        assert_eq!(restored, snapshot);
+        assert_eq!(restored.state::<Vec<f64>>("test").unwrap(), vec![1.0, 2.0, 3.0]);
+    }
+
+    /// 测试策略名称不一致
+    #[test]
+    fn test_snapshot_strategy_mismatch() {
+        let snapshot = StrategySnapshot::new("a", &1).unwrap();
+
+        assert_eq!(
+            snapshot.state::<i32>("b"),
+            Err(SnapshotError::StrategyMismatch {
+                expected: "b".to_string(),
+                found: "a".to_string(),
+            })
+        );
+    }
+
+    /// 测试保存和加载文件
+    #[test]
+    fn test_snapshot_save_and_load() {
+        let path = std::env::temp_dir().join(format!("aurora_snapshot_{}.json", std::process::id()));
+        let snapshot = StrategySnapshot::new("test", &42).unwrap();
+
+        snapshot.save(&path).unwrap();
+        let loaded = StrategySnapshot::load(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded, snapshot);
+        assert!(matches!(
+            StrategySnapshot::load(&path),
+            Err(SnapshotError::Io(_))
+        ));
+    }
+}
@@ -56,7 +56,7 @@ pub enum TradeSide {
 /// # 示例
 ///
 /// ```rust
-/// use aurora_portfolio::{TradeBuilder, TradeSide};
+/// use aurora_core::{TradeBuilder, TradeSide};
 ///
 /// let trade = TradeBuilder::new(TradeSide::Buy, 100.0, 10.0, 1640995200000)
 ///     .with_fee(5.0)
@@ -74,6 +74,8 @@ fn create_test_klines() -> Vec<Kline> {
             low: 95.0,
             close: 102.0,
             volume: 1000.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 1640995260000,
@@ -82,6 +84,8 @@ fn create_test_klines() -> Vec<Kline> {
             low: 98.0,
             close: 106.0,
             volume: 1200.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 1640995320000,
@@ -90,6 +94,8 @@ fn create_test_klines() -> Vec<Kline> {
             low: 102.0,
             close: 110.0,
             volume: 1100.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 1640995380000,
@@ -98,6 +104,8 @@ fn create_test_klines() -> Vec<Kline> {
             low: 105.0,
             close: 108.0,
             volume: 950.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 1640995440000,
@@ -106,6 +114,8 @@ fn create_test_klines() -> Vec<Kline> {
             low: 103.0,
             close: 105.0,
             volume: 800.0,
+            symbol: None,
+            interval: None,
         },
     ]
 }
@@ -120,6 +130,8 @@ fn test_kline_basic() {
         low: 95.0,
         close: 102.0,
         volume: 1000.0,
+        symbol: None,
+        interval: None,
     };
 
     assert_eq!(kline.timestamp, 1640995200000);
@@ -140,6 +152,8 @@ fn test_kline_traits() {
         low: 95.0,
         close: 102.0,
         volume: 1000.0,
+        symbol: None,
+        interval: None,
     };
 
     // 测试Clone
@@ -184,6 +198,7 @@ fn test_signal_event() {
         signal: Signal::Buy,
         price: 100.0,
         timestamp: 1640995200000,
+        intent: None,
     };
 
     assert_eq!(signal_event.signal, Signal::Buy);
@@ -201,6 +216,8 @@ fn test_market_event_basic() {
         low: 95.0,
         close: 102.0,
         volume: 1000.0,
+        symbol: None,
+        interval: None,
     };
 
     let event = MarketEvent::Kline(kline.clone());
@@ -210,6 +227,7 @@ fn test_market_event_basic() {
             assert_eq!(k.timestamp, kline.timestamp);
             assert_eq!(k.close, kline.close);
         }
+        other => panic!("期望K线事件，实际为 {:?}", other),
     }
 }
 
@@ -223,6 +241,8 @@ fn test_market_event_traits() {
         low: 95.0,
         close: 102.0,
         volume: 1000.0,
+        symbol: None,
+        interval: None,
     };
 
     let event = MarketEvent::Kline(kline);
@@ -233,6 +253,7 @@ fn test_market_event_traits() {
         (MarketEvent::Kline(k1), MarketEvent::Kline(k2)) => {
             assert_eq!(k1.timestamp, k2.timestamp);
         }
+        _ => panic!("克隆后的事件类型不一致"),
     }
 }
 
@@ -244,11 +265,13 @@ fn test_strategy_trait() {
             signal: Signal::Buy,
             price: 100.0,
             timestamp: 1000,
+            intent: None,
         },
         SignalEvent {
             signal: Signal::Sell,
             price: 110.0,
             timestamp: 2000,
+            intent: None,
         },
     ];
 
@@ -261,6 +284,8 @@ fn test_strategy_trait() {
         low: 95.0,
         close: 102.0,
         volume: 1000.0,
+        symbol: None,
+        interval: None,
     };
 
     let event = MarketEvent::Kline(kline);
@@ -298,6 +323,7 @@ async fn test_data_source_trait() -> Result<()> {
     while let Ok(event) = event_receiver.try_recv() {
         match event {
             MarketEvent::Kline(_) => received_count += 1,
+            other => panic!("模拟数据源不应产生 {:?}", other),
         }
     }
 
@@ -317,6 +343,8 @@ async fn test_data_source_strategy_integration() -> Result<()> {
             low: 95.0,
             close: 105.0, // 高于阈值
             volume: 1000.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 2000,
@@ -325,6 +353,8 @@ async fn test_data_source_strategy_integration() -> Result<()> {
             low: 102.0,
             close: 103.0, // 在阈值附近
             volume: 800.0,
+            symbol: None,
+            interval: None,
         },
     ];
 
@@ -332,6 +362,7 @@ async fn test_data_source_strategy_integration() -> Result<()> {
         signal: Signal::Buy,
         price: 105.0,
         timestamp: 1000,
+        intent: None,
     }];
 
     let mut data_source = MockDataSource::new(test_klines);
@@ -363,6 +394,8 @@ fn test_kline_serialization() -> Result<()> {
         low: 95.0,
         close: 102.0,
         volume: 1000.0,
+        symbol: None,
+        interval: None,
     };
 
     // 序列化
@@ -393,6 +426,8 @@ fn test_boundary_values() {
         low: 0.0001,
         close: 0.0001,
         volume: 0.001,
+        symbol: None,
+        interval: None,
     };
 
     assert_eq!(small_kline.timestamp, 1);
@@ -406,6 +441,8 @@ fn test_boundary_values() {
         low: 0.0,
         close: 0.0,
         volume: 0.0,
+        symbol: None,
+        interval: None,
     };
 
     assert_eq!(zero_kline.volume, 0.0);
@@ -421,6 +458,8 @@ fn test_equality() {
         low: 95.0,
         close: 102.0,
         volume: 1000.0,
+        symbol: None,
+        interval: None,
     };
 
     let kline2 = Kline {
@@ -430,6 +469,8 @@ fn test_equality() {
         low: 95.0,
         close: 102.0,
         volume: 1000.0,
+        symbol: None,
+        interval: None,
     };
 
     let kline3 = Kline {
@@ -439,6 +480,8 @@ fn test_equality() {
         low: 95.0,
         close: 103.0, // 不同的收盘价
         volume: 1000.0,
+        symbol: None,
+        interval: None,
     };
 
     // 测试相等的K线
@@ -467,6 +510,8 @@ fn test_type_safety() {
         low: 0.0,
         close: 0.0,
         volume: 0.0,
+        symbol: None,
+        interval: None,
     };
     let _signal: Signal = Signal::Buy;
     let _event: MarketEvent = MarketEvent::Kline(_kline);
@@ -0,0 +1,680 @@
+//! # K线变换模块
+//!
+//! 把按时间划分的K线序列转换为其他类型的图表柱，输出仍然是 [`Kline`]，
+//! 因此回测引擎和策略可以直接运行在变换后的序列上：
+//!
+//! - [`HeikinAshi`]：平均K线，一根原始K线对应一根平均K线
+//! - [`Renko`]：砖形图，价格（收盘价）每移动一个砖块大小生成一块砖，反转需要移动两块
+//! - [`RangeBars`]：等幅K线，每根K线的最高价与最低价之差恰好为一个区间大小
+//! - [`Kagi`]：卡吉图，价格反向移动超过反转幅度时，输出上一段线条
+//!
+//! 除平均K线外，砖块、等幅K线和线条的数量与原始K线无关：
+//! 一根原始K线可能完成零根或多根变换后的K线。变换后K线的时间戳为完成它的原始K线的时间戳，
+//! 同一根原始K线完成的多根K线时间戳相同。
+//!
+//! 所有变换器都实现 [`BarTransformer`]，既可以逐根K线流式更新，也可以批量变换：
+//!
+//! ```rust
+//! use aurora_core::Kline;
+//! use aurora_data::{BarTransformer, BoxSize, Renko};
+//!
+//! let klines: Vec<Kline> = [100.0, 101.5, 103.2, 101.0, 99.5]
+//!     .iter()
+//!     .enumerate()
+//!     .map(|(i, &close)| Kline {
+//!         timestamp: 1640995200000 + i as i64 * 60_000,
+//!         open: close,
+//!         high: close,
+//!         low: close,
+//!         close,
+//!         volume: 10.0,
+//!         symbol: None,
+//!         interval: None,
+//!     })
+//!     .collect();
+//!
+//! // 砖块大小为1：100→103.2涨出三块砖，回落到101时反转出第一块下跌砖，99.5再跌出一块
+//! let bricks = Renko::new(BoxSize::Fixed(1.0)).transform(&klines);
+//! let closes: Vec<f64> = bricks.iter().map(|brick| brick.close).collect();
+//! assert_eq!(closes, vec![101.0, 102.0, 103.0, 101.0, 100.0]);
+//! ```
+
+use crate::DataError;
+use aurora_core::Kline;
+use aurora_indicators::ATR;
+use std::str::FromStr;
+
+/// K线变换器
+pub trait BarTransformer {
+    /// 输入一根原始K线，返回本次完成的变换后K线
+    fn update(&mut self, kline: &Kline) -> Vec<Kline>;
+
+    /// 重置变换器状态
+    fn reset(&mut self);
+
+    /// 批量变换
+    ///
+    /// 先重置状态，再依次处理全部K线。
+    fn transform(&mut self, klines: &[Kline]) -> Vec<Kline> {
+        self.reset();
+        klines.iter().flat_map(|kline| self.update(kline)).collect()
+    }
+}
+
+/// 砖块大小、区间大小或反转幅度的计算方式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoxSize {
+    /// 固定价格幅度
+    Fixed(f64),
+    /// 收盘价的比例，例如0.01表示1%
+    Percentage(f64),
+    /// 按给定周期的ATR动态计算，ATR预热完成前不输出
+    Atr(usize),
+}
+
+impl BoxSize {
+    /// 检查参数是否合法
+    fn is_valid(&self) -> bool {
+        match *self {
+            BoxSize::Fixed(size) | BoxSize::Percentage(size) => size.is_finite() && size > 0.0,
+            BoxSize::Atr(period) => period > 0,
+        }
+    }
+}
+
+impl FromStr for BoxSize {
+    type Err = DataError;
+
+    /// 解析大小配置
+    ///
+    /// 支持三种写法：`10` 表示固定幅度，`0.5%` 表示收盘价的比例，`atr14` 表示14周期ATR。
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid = || DataError::ConfigError(format!("无效的大小配置: {}", s));
+
+        let size = if let Some(period) = s.strip_prefix("atr") {
+            BoxSize::Atr(period.parse().map_err(|_| invalid())?)
+        } else if let Some(percent) = s.strip_suffix('%') {
+            BoxSize::Percentage(percent.trim().parse::<f64>().map_err(|_| invalid())? / 100.0)
+        } else {
+            BoxSize::Fixed(s.parse().map_err(|_| invalid())?)
+        };
+
+        if size.is_valid() { Ok(size) } else { Err(invalid()) }
+    }
+}
+
+/// 按 [`BoxSize`] 逐根K线计算当前大小
+#[derive(Debug, Clone)]
+struct BoxSizer {
+    size: BoxSize,
+    atr: Option<ATR>,
+    /// 已处理的K线数量
+    count: usize,
+}
+
+impl BoxSizer {
+    fn new(size: BoxSize) -> Self {
+        assert!(size.is_valid(), "大小必须大于0: {:?}", size);
+
+        let atr = match size {
+            BoxSize::Atr(period) => Some(ATR::new(period)),
+            _ => None,
+        };
+        Self { size, atr, count: 0 }
+    }
+
+    /// 用K线更新并返回当前大小
+    ///
+    /// ATR处理的K线不足一个周期，或算出的大小不是有限正数（如收盘价为0或负数）时返回None，
+    /// 避免生成砖块的循环无法结束
+    fn update(&mut self, kline: &Kline) -> Option<f64> {
+        self.count += 1;
+        match self.size {
+            BoxSize::Fixed(size) => Some(size),
+            BoxSize::Percentage(ratio) => Some(kline.close * ratio),
+            BoxSize::Atr(period) => self
+                .atr
+                .as_mut()
+                .and_then(|atr| atr.update(kline.high, kline.low, kline.close))
+                .filter(|_| self.count >= period),
+        }
+        .filter(|&size| size > 0.0 && size.is_finite())
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        if let Some(atr) = self.atr.as_mut() {
+            atr.reset();
+        }
+    }
+}
+
+/// 由开盘价和收盘价构造变换后的K线
+fn bar(source: &Kline, open: f64, close: f64, high: f64, low: f64, volume: f64) -> Kline {
+    Kline {
+        timestamp: source.timestamp,
+        open,
+        high,
+        low,
+        close,
+        volume,
+        symbol: source.symbol.clone(),
+        interval: None,
+    }
+}
+
+/// Heikin-Ashi 平均K线
+///
+/// - 收盘价 = (开 + 高 + 低 + 收) / 4
+/// - 开盘价 = (上一根平均K线开盘价 + 上一根平均K线收盘价) / 2，第一根为 (开 + 收) / 2
+/// - 最高价 = max(高, 开盘价, 收盘价)，最低价 = min(低, 开盘价, 收盘价)
+///
+/// 时间戳、成交量、交易对和周期与原始K线相同。
+#[derive(Debug, Clone, Default)]
+pub struct HeikinAshi {
+    /// 上一根平均K线的开盘价和收盘价
+    previous: Option<(f64, f64)>,
+}
+
+impl HeikinAshi {
+    /// 创建平均K线变换器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 计算原始K线对应的平均K线
+    pub fn candle(&mut self, kline: &Kline) -> Kline {
+        let close = (kline.open + kline.high + kline.low + kline.close) / 4.0;
+        let open = match self.previous {
+            Some((open, close)) => (open + close) / 2.0,
+            None => (kline.open + kline.close) / 2.0,
+        };
+        self.previous = Some((open, close));
+
+        Kline {
+            timestamp: kline.timestamp,
+            open,
+            high: kline.high.max(open).max(close),
+            low: kline.low.min(open).min(close),
+            close,
+            volume: kline.volume,
+            symbol: kline.symbol.clone(),
+            interval: kline.interval.clone(),
+        }
+    }
+}
+
+impl BarTransformer for HeikinAshi {
+    fn update(&mut self, kline: &Kline) -> Vec<Kline> {
+        vec![self.candle(kline)]
+    }
+
+    fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+/// Renko 砖形图
+///
+/// 以收盘价为准，价格高于最后一块砖的上沿一个砖块大小时生成上涨砖，
+/// 低于下沿一个砖块大小时生成下跌砖，因此反转需要移动两个砖块大小。
+/// 第一根可用K线的收盘价作为起始价位。
+///
+/// 两次生成砖块之间的成交量计入下一次生成的第一块砖。
+#[derive(Debug, Clone)]
+pub struct Renko {
+    /// 砖块大小
+    sizer: BoxSizer,
+    /// 最后一块砖的上沿和下沿
+    last: Option<(f64, f64)>,
+    /// 尚未计入砖块的成交量
+    pending_volume: f64,
+}
+
+impl Renko {
+    /// 创建砖形图变换器
+    ///
+    /// # Panics
+    ///
+    /// 当砖块大小不大于0或ATR周期为0时会 panic
+    pub fn new(box_size: BoxSize) -> Self {
+        Self {
+            sizer: BoxSizer::new(box_size),
+            last: None,
+            pending_volume: 0.0,
+        }
+    }
+
+    /// 获取砖块大小的计算方式
+    pub fn box_size(&self) -> BoxSize {
+        self.sizer.size
+    }
+}
+
+impl BarTransformer for Renko {
+    fn update(&mut self, kline: &Kline) -> Vec<Kline> {
+        let Some(size) = self.sizer.update(kline) else {
+            return Vec::new();
+        };
+        self.pending_volume += kline.volume;
+
+        let Some((mut top, mut bottom)) = self.last else {
+            self.last = Some((kline.close, kline.close));
+            return Vec::new();
+        };
+
+        let mut bricks = Vec::new();
+        while kline.close >= top + size {
+            let volume = std::mem::take(&mut self.pending_volume);
+            bricks.push(bar(kline, top, top + size, top + size, top, volume));
+            bottom = top;
+            top += size;
+        }
+        while kline.close <= bottom - size {
+            let volume = std::mem::take(&mut self.pending_volume);
+            bricks.push(bar(kline, bottom, bottom - size, bottom, bottom - size, volume));
+            top = bottom;
+            bottom -= size;
+        }
+
+        self.last = Some((top, bottom));
+        bricks
+    }
+
+    fn reset(&mut self) {
+        self.sizer.reset();
+        self.last = None;
+        self.pending_volume = 0.0;
+    }
+}
+
+/// 正在形成的等幅K线
+#[derive(Debug, Clone, Copy)]
+struct OpenBar {
+    open: f64,
+    high: f64,
+    low: f64,
+    volume: f64,
+}
+
+/// Range Bars 等幅K线
+///
+/// 原始K线内部的价格路径近似为：阳线 开→低→高→收，阴线 开→高→低→收。
+/// 价格超出当前K线的区间时，在恰好达到区间大小的价位收盘，
+/// 并以该价位开始下一根K线，一次大幅波动可能完成多根K线。
+///
+/// 原始K线的成交量计入处理该K线开盘价之后正在形成的那根K线。
+#[derive(Debug, Clone)]
+pub struct RangeBars {
+    /// 区间大小
+    sizer: BoxSizer,
+    /// 正在形成的K线
+    current: Option<OpenBar>,
+}
+
+impl RangeBars {
+    /// 创建等幅K线变换器
+    ///
+    /// # Panics
+    ///
+    /// 当区间大小不大于0或ATR周期为0时会 panic
+    pub fn new(range: BoxSize) -> Self {
+        Self {
+            sizer: BoxSizer::new(range),
+            current: None,
+        }
+    }
+
+    /// 获取区间大小的计算方式
+    pub fn range(&self) -> BoxSize {
+        self.sizer.size
+    }
+
+    /// 处理路径上的一个价格点
+    fn push_price(&mut self, source: &Kline, price: f64, range: f64, bars: &mut Vec<Kline>) {
+        let Some(mut current) = self.current else {
+            self.current = Some(OpenBar { open: price, high: price, low: price, volume: 0.0 });
+            return;
+        };
+
+        loop {
+            let close = if price > current.low + range {
+                current.low + range
+            } else if price < current.high - range {
+                current.high - range
+            } else {
+                current.high = current.high.max(price);
+                current.low = current.low.min(price);
+                break;
+            };
+
+            current.high = current.high.max(close);
+            current.low = current.low.min(close);
+            bars.push(bar(source, current.open, close, current.high, current.low, current.volume));
+            current = OpenBar { open: close, high: close, low: close, volume: 0.0 };
+        }
+
+        self.current = Some(current);
+    }
+}
+
+impl BarTransformer for RangeBars {
+    fn update(&mut self, kline: &Kline) -> Vec<Kline> {
+        let Some(range) = self.sizer.update(kline) else {
+            return Vec::new();
+        };
+
+        let mut bars = Vec::new();
+        self.push_price(kline, kline.open, range, &mut bars);
+        if let Some(current) = self.current.as_mut() {
+            current.volume += kline.volume;
+        }
+
+        let path = if kline.close >= kline.open {
+            [kline.low, kline.high, kline.close]
+        } else {
+            [kline.high, kline.low, kline.close]
+        };
+        for price in path {
+            self.push_price(kline, price, range, &mut bars);
+        }
+
+        bars
+    }
+
+    fn reset(&mut self) {
+        self.sizer.reset();
+        self.current = None;
+    }
+}
+
+/// 卡吉线的当前方向
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum KagiDirection {
+    Up,
+    Down,
+}
+
+/// Kagi 卡吉图
+///
+/// 以收盘价为准，线条沿当前方向延伸到新的极值；
+/// 价格从极值反向移动达到反转幅度时，输出从起点到极值的这段线条，
+/// 并以极值为起点开始反方向的新线条。
+///
+/// 输出K线的开盘价为线条起点，收盘价为极值，成交量为该段线条期间的累计成交量。
+#[derive(Debug, Clone)]
+pub struct Kagi {
+    /// 反转幅度
+    sizer: BoxSizer,
+    /// 当前线条的起点
+    start: Option<f64>,
+    /// 当前线条的极值
+    extreme: f64,
+    /// 当前方向，未定时为None
+    direction: Option<KagiDirection>,
+    /// 当前线条期间的累计成交量
+    volume: f64,
+}
+
+impl Kagi {
+    /// 创建卡吉图变换器
+    ///
+    /// # Panics
+    ///
+    /// 当反转幅度不大于0或ATR周期为0时会 panic
+    pub fn new(reversal: BoxSize) -> Self {
+        Self {
+            sizer: BoxSizer::new(reversal),
+            start: None,
+            extreme: 0.0,
+            direction: None,
+            volume: 0.0,
+        }
+    }
+
+    /// 获取反转幅度的计算方式
+    pub fn reversal(&self) -> BoxSize {
+        self.sizer.size
+    }
+}
+
+impl BarTransformer for Kagi {
+    fn update(&mut self, kline: &Kline) -> Vec<Kline> {
+        let Some(reversal) = self.sizer.update(kline) else {
+            return Vec::new();
+        };
+        let price = kline.close;
+
+        let Some(start) = self.start else {
+            self.start = Some(price);
+            self.extreme = price;
+            self.volume = kline.volume;
+            return Vec::new();
+        };
+
+        let reversed = match self.direction {
+            None => {
+                if price >= start + reversal {
+                    self.direction = Some(KagiDirection::Up);
+                    self.extreme = price;
+                } else if price <= start - reversal {
+                    self.direction = Some(KagiDirection::Down);
+                    self.extreme = price;
+                }
+                None
+            }
+            Some(KagiDirection::Up) if price > self.extreme => {
+                self.extreme = price;
+                None
+            }
+            Some(KagiDirection::Down) if price < self.extreme => {
+                self.extreme = price;
+                None
+            }
+            Some(KagiDirection::Up) if price <= self.extreme - reversal => Some(KagiDirection::Down),
+            Some(KagiDirection::Down) if price >= self.extreme + reversal => Some(KagiDirection::Up),
+            Some(_) => None,
+        };
+
+        let Some(direction) = reversed else {
+            self.volume += kline.volume;
+            return Vec::new();
+        };
+
+        let line = bar(
+            kline,
+            start,
+            self.extreme,
+            start.max(self.extreme),
+            start.min(self.extreme),
+            self.volume,
+        );
+        self.start = Some(self.extreme);
+        self.extreme = price;
+        self.direction = Some(direction);
+        self.volume = kline.volume;
+        vec![line]
+    }
+
+    fn reset(&mut self) {
+        self.sizer.reset();
+        self.start = None;
+        self.extreme = 0.0;
+        self.direction = None;
+        self.volume = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kline(timestamp: i64, open: f64, high: f64, low: f64, close: f64) -> Kline {
+        Kline {
+            timestamp,
+            open,
+            high,
+            low,
+            close,
+            volume: 10.0,
+            symbol: Some("BTCUSDT".to_string()),
+            interval: Some("1m".to_string()),
+        }
+    }
+
+    fn closes(prices: &[f64]) -> Vec<Kline> {
+        prices
+            .iter()
+            .enumerate()
+            .map(|(i, &close)| kline(i as i64 * 60_000, close, close, close, close))
+            .collect()
+    }
+
+    /// 测试平均K线的计算公式
+    #[test]
+    fn test_heikin_ashi_formula() {
+        let klines = vec![kline(0, 10.0, 14.0, 8.0, 12.0), kline(60_000, 12.0, 16.0, 11.0, 15.0)];
+        let candles = HeikinAshi::new().transform(&klines);
+
+        assert_eq!(candles.len(), 2);
+        assert_eq!(candles[0].open, 11.0);
+        assert_eq!(candles[0].close, 11.0);
+        assert_eq!(candles[0].high, 14.0);
+        assert_eq!(candles[0].low, 8.0);
+
+        assert_eq!(candles[1].open, 11.0);
+        assert_eq!(candles[1].close, 13.5);
+        assert_eq!(candles[1].high, 16.0);
+        assert_eq!(candles[1].low, 11.0);
+        assert_eq!(candles[1].timestamp, 60_000);
+        assert_eq!(candles[1].volume, 10.0);
+        assert_eq!(candles[1].interval.as_deref(), Some("1m"));
+    }
+
+    /// 测试砖形图的延续和两块砖反转
+    #[test]
+    fn test_renko_bricks_and_reversal() {
+        let mut renko = Renko::new(BoxSize::Fixed(1.0));
+        let klines = closes(&[100.0, 100.9, 102.3, 101.5, 100.5, 99.9]);
+
+        let counts: Vec<usize> = klines.iter().map(|k| renko.update(k).len()).collect();
+        // 102.3 完成两块上涨砖；101.5 和 100.5 未跌破反转线 101.0 - 1.0；99.9 生成一块下跌砖
+        assert_eq!(counts, vec![0, 0, 2, 0, 0, 1]);
+
+        let bricks = Renko::new(BoxSize::Fixed(1.0)).transform(&klines);
+        let last = bricks.last().unwrap();
+        assert_eq!((last.open, last.close), (101.0, 100.0));
+        assert!(bricks.iter().all(|b| (b.high - b.low - 1.0).abs() < 1e-9));
+        assert_eq!(last.symbol.as_deref(), Some("BTCUSDT"));
+        assert!(last.interval.is_none());
+    }
+
+    /// 测试砖块成交量累计到下一块砖
+    #[test]
+    fn test_renko_volume_accumulates() {
+        let bricks = Renko::new(BoxSize::Fixed(1.0)).transform(&closes(&[100.0, 100.5, 100.8, 101.0]));
+
+        assert_eq!(bricks.len(), 1);
+        assert_eq!(bricks[0].volume, 40.0);
+    }
+
+    /// 测试ATR砖块在ATR预热完成前不输出
+    #[test]
+    fn test_renko_atr_waits_for_warm_up() {
+        let klines: Vec<Kline> = (0..30)
+            .map(|i| {
+                let close = 100.0 + i as f64;
+                kline(i * 60_000, close - 0.5, close + 1.0, close - 1.0, close)
+            })
+            .collect();
+
+        let mut renko = Renko::new(BoxSize::Atr(14));
+        let first = klines.iter().position(|k| !renko.update(k).is_empty()).unwrap();
+        // 第14根K线的收盘价作为起始价位，ATR为2，再上涨两根K线生成第一块砖
+        assert_eq!(first, 15);
+        assert_eq!(renko.box_size(), BoxSize::Atr(14));
+    }
+
+    /// 测试收盘价为0或负数时百分比大小的变换器跳过该K线而不是卡死
+    #[test]
+    fn test_percentage_box_skips_non_positive_close() {
+        let klines = closes(&[100.0, 0.0, -5.0, f64::NAN, 102.5]);
+
+        let bricks = Renko::new(BoxSize::Percentage(0.01)).transform(&klines);
+        assert_eq!(bricks.len(), 2);
+        assert!((bricks.last().unwrap().close - 102.05).abs() < 1e-9);
+
+        let bars = RangeBars::new(BoxSize::Percentage(0.01)).transform(&klines);
+        assert!(bars.iter().all(|b| b.close > 0.0));
+    }
+
+    /// 测试等幅K线的区间大小和跳空拆分
+    #[test]
+    fn test_range_bars_split_large_moves() {
+        let klines = vec![kline(0, 100.0, 100.5, 99.8, 100.2), kline(60_000, 100.2, 103.4, 100.1, 103.0)];
+        let bars = RangeBars::new(BoxSize::Fixed(1.0)).transform(&klines);
+
+        assert_eq!(bars.len(), 3);
+        for bar in &bars {
+            assert!((bar.high - bar.low - 1.0).abs() < 1e-9);
+            assert!(bar.high >= bar.open.max(bar.close) && bar.low <= bar.open.min(bar.close));
+        }
+        // 后一根K线的开盘价等于前一根的收盘价
+        for pair in bars.windows(2) {
+            assert_eq!(pair[1].open, pair[0].close);
+        }
+        assert_eq!(bars.iter().map(|b| b.volume).sum::<f64>(), 20.0);
+    }
+
+    /// 测试卡吉图只在反转时输出线条
+    #[test]
+    fn test_kagi_lines() {
+        let mut kagi = Kagi::new(BoxSize::Fixed(2.0));
+        let klines = closes(&[100.0, 103.0, 105.0, 104.0, 102.5, 106.0, 108.0, 107.5]);
+
+        let lines: Vec<Kline> = klines.iter().flat_map(|k| kagi.update(k)).collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!((lines[0].open, lines[0].close), (100.0, 105.0));
+        assert_eq!((lines[1].open, lines[1].close), (105.0, 102.5));
+        assert_eq!(lines[1].high, 105.0);
+        assert_eq!(lines[1].low, 102.5);
+    }
+
+    /// 测试批量变换与流式结果一致
+    #[test]
+    fn test_transform_matches_streaming() {
+        let klines: Vec<Kline> = (0..60)
+            .map(|i| {
+                let close = 100.0 + (i as f64 * 0.4).sin() * 5.0;
+                kline(i * 60_000, close - 0.3, close + 0.8, close - 0.9, close)
+            })
+            .collect();
+
+        let mut transformers: Vec<Box<dyn BarTransformer>> = vec![
+            Box::new(HeikinAshi::new()),
+            Box::new(Renko::new(BoxSize::Percentage(0.01))),
+            Box::new(RangeBars::new(BoxSize::Atr(5))),
+            Box::new(Kagi::new(BoxSize::Fixed(1.5))),
+        ];
+        for transformer in transformers.iter_mut() {
+            let batch = transformer.transform(&klines);
+            transformer.reset();
+            let streaming: Vec<Kline> = klines.iter().flat_map(|k| transformer.update(k)).collect();
+            assert_eq!(batch, streaming);
+            assert!(!batch.is_empty());
+        }
+    }
+
+    /// 测试大小配置解析
+    #[test]
+    fn test_box_size_from_str() {
+        assert_eq!("10".parse::<BoxSize>().unwrap(), BoxSize::Fixed(10.0));
+        assert_eq!("0.5%".parse::<BoxSize>().unwrap(), BoxSize::Percentage(0.005));
+        assert_eq!("atr14".parse::<BoxSize>().unwrap(), BoxSize::Atr(14));
+
+        assert!("0".parse::<BoxSize>().is_err());
+        assert!("atr0".parse::<BoxSize>().is_err());
+        assert!("abc".parse::<BoxSize>().is_err());
+    }
+}
@@ -172,10 +172,11 @@ impl BinanceHistoricalDownloader {
 
         info!("成功获取到 {} 条K线数据", binance_klines.len());
 
-        // 转换为标准Kline格式并验证数据
+        // 转换为标准Kline格式，标注交易对和周期，并验证数据
+        let symbol = symbol.to_uppercase();
         let klines: Vec<Kline> = binance_klines
             .into_iter()
-            .map(Kline::from)
+            .map(|kline| Kline::from(kline).with_symbol(&symbol).with_interval(interval))
             .filter(|kline| self.validate_kline(kline))
             .collect();
 
@@ -76,6 +76,8 @@ mod tests {
             low: 49000.0,
             close: 50500.0,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         }];
 
         let temp_file = "test_mod_utils.csv";
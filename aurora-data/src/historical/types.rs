@@ -77,6 +77,8 @@ impl From<BinanceKline> for Kline {
             low: binance_kline.3.parse().unwrap_or(0.0),
             close: binance_kline.4.parse().unwrap_or(0.0),
             volume: binance_kline.5.parse().unwrap_or(0.0),
+            symbol: None,
+            interval: None,
         }
     }
 }
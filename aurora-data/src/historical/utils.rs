@@ -8,7 +8,8 @@ use tracing::info;
 
 /// 解析时间间隔字符串为毫秒数
 ///
-/// 将Binance的时间间隔格式（如"1m", "5m", "1h", "1d"）转换为毫秒数。
+/// 将Binance的时间间隔格式（如"1m", "5m", "1h", "1d"）转换为毫秒数，
+/// 与 [`aurora_core::Resampler`] 使用同一张周期表。
 ///
 /// # 参数
 ///
@@ -18,32 +19,16 @@ use tracing::info;
 ///
 /// 成功时返回毫秒数，失败时返回DataError
 pub fn parse_interval_to_ms(interval: &str) -> DataResult<i64> {
-    match interval {
-        "1m" => Ok(60 * 1000),
-        "3m" => Ok(3 * 60 * 1000),
-        "5m" => Ok(5 * 60 * 1000),
-        "15m" => Ok(15 * 60 * 1000),
-        "30m" => Ok(30 * 60 * 1000),
-        "1h" => Ok(60 * 60 * 1000),
-        "2h" => Ok(2 * 60 * 60 * 1000),
-        "4h" => Ok(4 * 60 * 60 * 1000),
-        "6h" => Ok(6 * 60 * 60 * 1000),
-        "8h" => Ok(8 * 60 * 60 * 1000),
-        "12h" => Ok(12 * 60 * 60 * 1000),
-        "1d" => Ok(24 * 60 * 60 * 1000),
-        "3d" => Ok(3 * 24 * 60 * 60 * 1000),
-        "1w" => Ok(7 * 24 * 60 * 60 * 1000),
-        "1M" => Ok(30 * 24 * 60 * 60 * 1000), // 近似值
-        _ => Err(DataError::ConfigError(format!(
-            "不支持的时间间隔: {}",
-            interval
-        ))),
-    }
+    aurora_core::interval_to_ms(interval)
+        .ok_or_else(|| DataError::ConfigError(format!("不支持的时间间隔: {}", interval)))
 }
 
 /// 将K线数据保存为CSV文件
 ///
 /// 这个方法是异步的，可以处理大量数据而不阻塞。
+/// 任一K线带有交易对或周期时，额外写入 `symbol` 和 `interval` 两列，
+/// 使 [`CsvDataLoader`](crate::CsvDataLoader) 重新加载时保留K线来源；
+/// 否则保持原有的六列格式。
 ///
 /// # 参数
 ///
@@ -57,22 +42,35 @@ pub async fn save_to_csv(klines: &[Kline], file_path: &str) -> DataResult<()> {
     let mut writer = csv::Writer::from_path(file_path)
         .map_err(|e| DataError::IoError(format!("创建CSV文件失败: {}", e)))?;
 
+    let with_identity = klines
+        .iter()
+        .any(|kline| kline.symbol.is_some() || kline.interval.is_some());
+
     // 写入表头
+    let mut header = vec!["timestamp", "open", "high", "low", "close", "volume"];
+    if with_identity {
+        header.extend(["symbol", "interval"]);
+    }
     writer
-        .write_record(&["timestamp", "open", "high", "low", "close", "volume"])
+        .write_record(&header)
         .map_err(|e| DataError::IoError(format!("写入CSV表头失败: {}", e)))?;
 
     // 写入数据行
     for kline in klines {
+        let mut record = vec![
+            kline.timestamp.to_string(),
+            kline.open.to_string(),
+            kline.high.to_string(),
+            kline.low.to_string(),
+            kline.close.to_string(),
+            kline.volume.to_string(),
+        ];
+        if with_identity {
+            record.push(kline.symbol.clone().unwrap_or_default());
+            record.push(kline.interval.clone().unwrap_or_default());
+        }
         writer
-            .write_record(&[
-                kline.timestamp.to_string(),
-                kline.open.to_string(),
-                kline.high.to_string(),
-                kline.low.to_string(),
-                kline.close.to_string(),
-                kline.volume.to_string(),
-            ])
+            .write_record(&record)
             .map_err(|e| DataError::IoError(format!("写入CSV数据失败: {}", e)))?;
     }
 
@@ -172,6 +170,8 @@ mod tests {
             low: 49000.0,
             close: 50500.0,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         }
     }
 
@@ -208,6 +208,8 @@ mod tests {
                 low: 50000.0,
                 close: 51500.0,
                 volume: 200.0,
+                symbol: None,
+                interval: None,
             },
         ];
 
@@ -251,4 +253,25 @@ mod tests {
             fs::remove_file(temp_file).expect("删除测试文件失败");
         }
     }
+
+    /// 测试交易对和周期在CSV中往返保存
+    #[tokio::test]
+    async fn test_save_to_csv_round_trips_identity() {
+        let klines = vec![
+            create_test_kline().with_symbol("BTCUSDT").with_interval("1h"),
+            create_test_kline().with_symbol("ETHUSDT").with_interval("1h"),
+        ];
+        let temp_file = "test_identity_utils.csv";
+
+        save_to_csv(&klines, temp_file).await.expect("保存CSV失败");
+        let content = fs::read_to_string(temp_file).expect("读取文件失败");
+        assert!(content.starts_with("timestamp,open,high,low,close,volume,symbol,interval"));
+
+        let loaded = crate::CsvDataLoader::new()
+            .load_from_csv(temp_file)
+            .expect("加载CSV失败");
+        fs::remove_file(temp_file).expect("删除测试文件失败");
+
+        assert_eq!(loaded, klines);
+    }
 }
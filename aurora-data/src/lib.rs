@@ -10,6 +10,8 @@
 //! - **数据存储**: 将获取的数据保存为CSV格式
 //! - **数据验证**: 确保数据的完整性和有效性
 //! - **数据加载**: 从各种格式加载历史数据
+//! - **历史回放**: 把历史数据按 `DataSource` 接口回放，支持实时和倍速
+//! - **K线变换**: 平均K线、砖形图、等幅K线和卡吉图，输出仍为K线
 //!
 //! ## 支持的数据源
 //!
@@ -18,9 +20,11 @@
 //!
 //! ## 模块组织
 //!
+//! - `bars`: K线变换
 //! - `historical`: 历史数据获取
 //! - `live`: 实时数据流
 //! - `loader`: 数据加载器
+//! - `replay`: 历史数据回放
 //!
 //! ## 使用示例
 //!
@@ -147,9 +151,11 @@ impl Error for DataError {}
 pub type DataResult<T> = Result<T, DataError>;
 
 // 声明子模块
+pub mod bars;
 pub mod historical;
 pub mod live;
 pub mod loader;
+pub mod replay;
 
 /// 通用的数据源配置
 ///
@@ -356,6 +362,8 @@ mod tests {
 }
 
 // 重新导出主要的公共类型和函数
+pub use bars::{BarTransformer, BoxSize, HeikinAshi, Kagi, RangeBars, Renko};
 pub use historical::BinanceHistoricalDownloader;
-pub use live::BinanceLiveStream;
+pub use live::{BinanceLiveStream, StreamKind};
 pub use loader::CsvDataLoader;
+pub use replay::{HistoricalReplaySource, ReplaySpeed};
@@ -7,12 +7,14 @@
 //!
 //! - **WebSocket连接**: 维持与交易所的WebSocket连接
 //! - **实时K线**: 获取实时更新的K线数据
+//! - **盘口数据**: 逐笔成交、最优挂单、深度快照和资金费率
 //! - **自动重连**: 连接断开时自动重连
 //! - **数据过滤**: 只处理完成的K线数据
 //! - **错误处理**: 完整的连接和数据错误处理
 //!
 //! ## 模块组织
 //!
+//! - `parser`: 消息解析
 //! - `stream`: 流实现
 //! - `utils`: 工具函数
 //!
@@ -36,10 +38,12 @@
 //! # }
 //! ```
 
+mod parser;
 mod stream;
 mod utils;
 
 // 重新导出公共接口
+pub use parser::{StreamKind, parse_stream_message};
 pub use stream::BinanceLiveStream;
 pub use utils::stream_data;
 
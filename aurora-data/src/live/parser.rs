@@ -0,0 +1,281 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Binance WebSocket 消息解析
+//!
+//! 负责把 Binance 推送的各类JSON消息转换为 [`MarketEvent`]。
+//! 同时支持单流格式（直接是事件对象）和组合流格式
+//! （`{"stream": "...", "data": {...}}`）。
+//!
+//! 支持的流类型见 [`StreamKind`]。
+
+use crate::{DataError, DataResult};
+use aurora_core::{DepthSnapshot, FundingRate, Kline, MarketEvent, PriceLevel, Quote, TradeTick};
+use serde_json::Value;
+
+/// 订阅的数据流类型
+///
+/// 每种类型对应 Binance 的一种WebSocket流，
+/// 解析后分别产生不同的 [`MarketEvent`] 变体。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_data::StreamKind;
+///
+/// assert_eq!(StreamKind::Kline.stream_name("BTCUSDT", "1m"), "btcusdt@kline_1m");
+/// assert_eq!(StreamKind::Depth(5).stream_name("BTCUSDT", "1m"), "btcusdt@depth5");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamKind {
+    /// K线流，产生 `MarketEvent::Kline`（只推送已完成的K线）
+    Kline,
+    /// 逐笔成交流，产生 `MarketEvent::Trade`
+    Trade,
+    /// 最优挂单流，产生 `MarketEvent::Quote`
+    BookTicker,
+    /// 有限档深度流（5、10或20档），产生 `MarketEvent::DepthSnapshot`
+    Depth(u32),
+    /// 标记价格流（仅合约市场），产生 `MarketEvent::FundingRate`
+    MarkPrice,
+}
+
+impl StreamKind {
+    /// 生成该流类型在 Binance 上的流名称
+    ///
+    /// # 参数
+    ///
+    /// * `symbol` - 交易对符号，大小写均可
+    /// * `interval` - K线时间间隔，仅对 `Kline` 类型有效
+    pub fn stream_name(&self, symbol: &str, interval: &str) -> String {
+        let symbol = symbol.to_lowercase();
+        match self {
+            StreamKind::Kline => format!("{}@kline_{}", symbol, interval),
+            StreamKind::Trade => format!("{}@trade", symbol),
+            StreamKind::BookTicker => format!("{}@bookTicker", symbol),
+            StreamKind::Depth(levels) => format!("{}@depth{}", symbol, levels),
+            StreamKind::MarkPrice => format!("{}@markPrice", symbol),
+        }
+    }
+
+    /// 检查流类型参数是否被交易所支持
+    ///
+    /// 目前只需检查深度档位，Binance 只提供5、10、20档的有限深度流。
+    pub fn validate(&self) -> DataResult<()> {
+        match self {
+            StreamKind::Depth(levels) if ![5, 10, 20].contains(levels) => Err(
+                DataError::ConfigError(format!("不支持的深度档位: {}，可选值为5、10、20", levels)),
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// 解析一条WebSocket文本消息
+///
+/// # 参数
+///
+/// * `text` - JSON格式的WebSocket消息
+/// * `received_at` - 消息接收时间（Unix毫秒），用于补全不带事件时间的推送
+///   （如现货的 bookTicker 和有限档深度）
+///
+/// # 返回值
+///
+/// * `Ok(Some(event))` - 成功解析出市场事件
+/// * `Ok(None)` - 消息有效但无需产生事件（如尚未完成的K线）
+/// * `Err(DataError)` - 消息格式无法识别或字段缺失
+pub fn parse_stream_message(text: &str, received_at: i64) -> DataResult<Option<MarketEvent>> {
+    let value: Value = serde_json::from_str(text)
+        .map_err(|e| DataError::ParseError(format!("JSON解析失败: {}", e)))?;
+
+    // 组合流格式中，流名称携带了交易对和流类型信息
+    let (stream_name, data) = match value.get("data") {
+        Some(data) => (value.get("stream").and_then(Value::as_str), data),
+        None => (None, &value),
+    };
+
+    if data.get("k").is_some() {
+        return parse_kline(data);
+    }
+
+    match data.get("e").and_then(Value::as_str) {
+        Some("trade") | Some("aggTrade") => parse_trade(data).map(Some),
+        Some("bookTicker") => parse_book_ticker(data, received_at).map(Some),
+        Some("depthUpdate") => parse_depth(data, stream_name, received_at).map(Some),
+        Some("markPriceUpdate") => parse_mark_price(data).map(Some),
+        Some(other) => Err(DataError::ParseError(format!("不支持的事件类型: {}", other))),
+        None => {
+            // 现货 bookTicker 和有限档深度推送没有事件类型字段，只能通过字段特征识别
+            if data.get("lastUpdateId").is_some() {
+                parse_depth(data, stream_name, received_at).map(Some)
+            } else if data.get("b").is_some() && data.get("a").is_some() {
+                parse_book_ticker(data, received_at).map(Some)
+            } else {
+                Err(DataError::ParseError("无法识别的消息格式".to_string()))
+            }
+        }
+    }
+}
+
+/// 解析K线消息，只返回已完成的K线
+fn parse_kline(data: &Value) -> DataResult<Option<MarketEvent>> {
+    let k = &data["k"];
+
+    if !k["x"].as_bool().unwrap_or(false) {
+        return Ok(None);
+    }
+
+    let kline = Kline {
+        timestamp: k["t"].as_i64().unwrap_or(0),
+        open: decimal_field(k, "o")?,
+        high: decimal_field(k, "h")?,
+        low: decimal_field(k, "l")?,
+        close: decimal_field(k, "c")?,
+        volume: decimal_field(k, "v")?,
+        symbol: k["s"].as_str().map(str::to_string),
+        interval: k["i"].as_str().map(str::to_string),
+    };
+
+    if !super::utils::validate_kline(&kline) {
+        return Err(DataError::ParseError("K线数据验证失败".to_string()));
+    }
+
+    Ok(Some(MarketEvent::Kline(kline)))
+}
+
+/// 解析逐笔成交或归集成交消息
+fn parse_trade(data: &Value) -> DataResult<MarketEvent> {
+    // 逐笔成交使用 "t" 作为成交ID，归集成交使用 "a"
+    let trade_id = data
+        .get("t")
+        .or_else(|| data.get("a"))
+        .and_then(Value::as_u64);
+
+    Ok(MarketEvent::Trade(TradeTick {
+        symbol: symbol_field(data)?,
+        timestamp: data["T"].as_i64().or_else(|| data["E"].as_i64()).unwrap_or(0),
+        price: decimal_field(data, "p")?,
+        quantity: decimal_field(data, "q")?,
+        is_buyer_maker: data["m"].as_bool().unwrap_or(false),
+        trade_id,
+    }))
+}
+
+/// 解析最优挂单消息
+fn parse_book_ticker(data: &Value, received_at: i64) -> DataResult<MarketEvent> {
+    Ok(MarketEvent::Quote(Quote {
+        symbol: symbol_field(data)?,
+        timestamp: event_time(data).unwrap_or(received_at),
+        bid_price: decimal_field(data, "b")?,
+        bid_quantity: decimal_field(data, "B")?,
+        ask_price: decimal_field(data, "a")?,
+        ask_quantity: decimal_field(data, "A")?,
+    }))
+}
+
+/// 解析深度消息
+///
+/// 现货有限档深度使用 `bids`/`asks` 字段且不带交易对，交易对从流名称中提取；
+/// 合约深度使用 `b`/`a` 字段并带有 `s` 字段。
+fn parse_depth(
+    data: &Value,
+    stream_name: Option<&str>,
+    received_at: i64,
+) -> DataResult<MarketEvent> {
+    let symbol = match data.get("s").and_then(Value::as_str) {
+        Some(symbol) => symbol.to_uppercase(),
+        None => stream_name
+            .and_then(|name| name.split('@').next())
+            .map(str::to_uppercase)
+            .ok_or_else(|| DataError::ParseError("深度消息缺少交易对信息".to_string()))?,
+    };
+
+    let bids = data.get("bids").or_else(|| data.get("b"));
+    let asks = data.get("asks").or_else(|| data.get("a"));
+
+    Ok(MarketEvent::DepthSnapshot(DepthSnapshot {
+        symbol,
+        timestamp: event_time(data).unwrap_or(received_at),
+        last_update_id: data
+            .get("lastUpdateId")
+            .or_else(|| data.get("u"))
+            .and_then(Value::as_u64),
+        bids: parse_levels(bids)?,
+        asks: parse_levels(asks)?,
+    }))
+}
+
+/// 解析标记价格消息
+fn parse_mark_price(data: &Value) -> DataResult<MarketEvent> {
+    Ok(MarketEvent::FundingRate(FundingRate {
+        symbol: symbol_field(data)?,
+        timestamp: data["E"].as_i64().unwrap_or(0),
+        rate: decimal_field(data, "r")?,
+        mark_price: decimal_field(data, "p").ok(),
+        next_funding_time: data["T"].as_i64(),
+    }))
+}
+
+/// 解析 `[["价格", "数量"], ...]` 格式的档位列表
+fn parse_levels(levels: Option<&Value>) -> DataResult<Vec<PriceLevel>> {
+    let levels = levels
+        .and_then(Value::as_array)
+        .ok_or_else(|| DataError::ParseError("深度消息缺少档位数据".to_string()))?;
+
+    levels
+        .iter()
+        .map(|level| {
+            let price = level.get(0).and_then(parse_decimal);
+            let quantity = level.get(1).and_then(parse_decimal);
+            match (price, quantity) {
+                (Some(price), Some(quantity)) => Ok(PriceLevel { price, quantity }),
+                _ => Err(DataError::ParseError(format!("无效的档位数据: {}", level))),
+            }
+        })
+        .collect()
+}
+
+/// 读取事件时间，合约推送使用 "E"，部分推送使用 "T"
+fn event_time(data: &Value) -> Option<i64> {
+    data.get("E")
+        .or_else(|| data.get("T"))
+        .and_then(Value::as_i64)
+}
+
+/// 读取交易对字段
+fn symbol_field(data: &Value) -> DataResult<String> {
+    data.get("s")
+        .and_then(Value::as_str)
+        .map(str::to_uppercase)
+        .ok_or_else(|| DataError::ParseError("消息缺少交易对字段".to_string()))
+}
+
+/// 读取以字符串表示的数值字段（Binance 的价格和数量均为字符串）
+fn decimal_field(data: &Value, field: &str) -> DataResult<f64> {
+    data.get(field)
+        .and_then(parse_decimal)
+        .ok_or_else(|| DataError::ParseError(format!("字段 {} 缺失或不是有效数值", field)))
+}
+
+/// 把字符串或数字形式的JSON值转换为f64
+fn parse_decimal(value: &Value) -> Option<f64> {
+    match value {
+        Value::String(s) => s.parse().ok(),
+        Value::Number(n) => n.as_f64(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests;
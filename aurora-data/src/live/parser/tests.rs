@@ -0,0 +1,165 @@
+//! Binance 消息解析的单元测试模块
+
+use super::*;
+
+const RECEIVED_AT: i64 = 1_700_000_000_000;
+
+#[test]
+fn test_stream_names() {
+    assert_eq!(StreamKind::Kline.stream_name("BTCUSDT", "5m"), "btcusdt@kline_5m");
+    assert_eq!(StreamKind::Trade.stream_name("BTCUSDT", "5m"), "btcusdt@trade");
+    assert_eq!(StreamKind::BookTicker.stream_name("ethusdt", "1m"), "ethusdt@bookTicker");
+    assert_eq!(StreamKind::Depth(10).stream_name("BTCUSDT", "1m"), "btcusdt@depth10");
+    assert_eq!(StreamKind::MarkPrice.stream_name("BTCUSDT", "1m"), "btcusdt@markPrice");
+}
+
+#[test]
+fn test_validate_depth_levels() {
+    assert!(StreamKind::Depth(5).validate().is_ok());
+    assert!(StreamKind::Depth(20).validate().is_ok());
+    assert!(StreamKind::Depth(7).validate().is_err());
+    assert!(StreamKind::Trade.validate().is_ok());
+}
+
+#[test]
+fn test_parse_closed_kline_in_combined_stream() {
+    let text = r#"{
+        "stream": "btcusdt@kline_1m",
+        "data": {
+            "e": "kline",
+            "s": "BTCUSDT",
+            "k": {
+                "t": 1640995200000, "s": "BTCUSDT", "i": "1m",
+                "o": "50000.00", "c": "50500.00", "h": "51000.00", "l": "49000.00",
+                "v": "100.0", "x": true
+            }
+        }
+    }"#;
+
+    match parse_stream_message(text, RECEIVED_AT).unwrap() {
+        Some(MarketEvent::Kline(kline)) => {
+            assert_eq!(kline.timestamp, 1640995200000);
+            assert_eq!(kline.close, 50500.0);
+            assert_eq!(kline.volume, 100.0);
+            assert_eq!(kline.symbol.as_deref(), Some("BTCUSDT"));
+            assert_eq!(kline.interval.as_deref(), Some("1m"));
+        }
+        other => panic!("期望K线事件，实际为 {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_open_kline_is_skipped() {
+    let text = r#"{"e": "kline", "k": {"t": 1, "o": "1", "c": "1", "h": "1", "l": "1", "v": "1", "x": false}}"#;
+    assert!(parse_stream_message(text, RECEIVED_AT).unwrap().is_none());
+}
+
+#[test]
+fn test_parse_trade() {
+    let text = r#"{
+        "e": "trade", "E": 123456789, "s": "BTCUSDT", "t": 12345,
+        "p": "50000.00", "q": "0.001", "T": 1640995200000, "m": true, "M": true
+    }"#;
+
+    match parse_stream_message(text, RECEIVED_AT).unwrap() {
+        Some(MarketEvent::Trade(trade)) => {
+            assert_eq!(trade.symbol, "BTCUSDT");
+            assert_eq!(trade.timestamp, 1640995200000);
+            assert_eq!(trade.price, 50000.0);
+            assert_eq!(trade.quantity, 0.001);
+            assert!(trade.is_buyer_maker);
+            assert_eq!(trade.trade_id, Some(12345));
+        }
+        other => panic!("期望成交事件，实际为 {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_spot_book_ticker_uses_received_time() {
+    let text = r#"{
+        "stream": "bnbusdt@bookTicker",
+        "data": {"u": 400900217, "s": "BNBUSDT", "b": "25.35", "B": "31.21", "a": "25.36", "A": "40.66"}
+    }"#;
+
+    match parse_stream_message(text, RECEIVED_AT).unwrap() {
+        Some(MarketEvent::Quote(quote)) => {
+            assert_eq!(quote.symbol, "BNBUSDT");
+            assert_eq!(quote.timestamp, RECEIVED_AT);
+            assert_eq!(quote.bid_price, 25.35);
+            assert_eq!(quote.bid_quantity, 31.21);
+            assert_eq!(quote.ask_price, 25.36);
+            assert_eq!(quote.ask_quantity, 40.66);
+        }
+        other => panic!("期望报价事件，实际为 {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_spot_partial_depth_takes_symbol_from_stream() {
+    let text = r#"{
+        "stream": "btcusdt@depth5",
+        "data": {
+            "lastUpdateId": 160,
+            "bids": [["100.5", "2.0"], ["100.0", "1.0"]],
+            "asks": [["101.0", "3.0"]]
+        }
+    }"#;
+
+    match parse_stream_message(text, RECEIVED_AT).unwrap() {
+        Some(MarketEvent::DepthSnapshot(depth)) => {
+            assert_eq!(depth.symbol, "BTCUSDT");
+            assert_eq!(depth.timestamp, RECEIVED_AT);
+            assert_eq!(depth.last_update_id, Some(160));
+            assert_eq!(depth.bids.len(), 2);
+            assert_eq!(depth.best_bid().unwrap().price, 100.5);
+            assert_eq!(depth.best_ask().unwrap().quantity, 3.0);
+        }
+        other => panic!("期望深度事件，实际为 {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_futures_depth_update() {
+    let text = r#"{
+        "e": "depthUpdate", "E": 1571889248277, "T": 1571889248276, "s": "BTCUSDT",
+        "u": 390497878, "b": [["7403.89", "0.002"]], "a": [["7405.96", "3.340"]]
+    }"#;
+
+    match parse_stream_message(text, RECEIVED_AT).unwrap() {
+        Some(MarketEvent::DepthSnapshot(depth)) => {
+            assert_eq!(depth.symbol, "BTCUSDT");
+            assert_eq!(depth.timestamp, 1571889248277);
+            assert_eq!(depth.last_update_id, Some(390497878));
+            assert_eq!(depth.best_ask().unwrap().price, 7405.96);
+        }
+        other => panic!("期望深度事件，实际为 {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_mark_price_update() {
+    let text = r#"{
+        "e": "markPriceUpdate", "E": 1562305380000, "s": "BTCUSDT",
+        "p": "11794.15000000", "i": "11784.62659091", "r": "0.00038167", "T": 1562306400000
+    }"#;
+
+    match parse_stream_message(text, RECEIVED_AT).unwrap() {
+        Some(MarketEvent::FundingRate(funding)) => {
+            assert_eq!(funding.symbol, "BTCUSDT");
+            assert_eq!(funding.timestamp, 1562305380000);
+            assert_eq!(funding.rate, 0.00038167);
+            assert_eq!(funding.mark_price, Some(11794.15));
+            assert_eq!(funding.next_funding_time, Some(1562306400000));
+        }
+        other => panic!("期望资金费率事件，实际为 {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_invalid_messages() {
+    assert!(parse_stream_message("not json", RECEIVED_AT).is_err());
+    assert!(parse_stream_message(r#"{"foo": 1}"#, RECEIVED_AT).is_err());
+    assert!(parse_stream_message(r#"{"e": "unknown"}"#, RECEIVED_AT).is_err());
+    // 成交消息缺少价格
+    assert!(parse_stream_message(r#"{"e": "trade", "s": "BTCUSDT", "q": "1"}"#, RECEIVED_AT).is_err());
+}
@@ -2,13 +2,13 @@
 //!
 //! 包含了Binance实时数据流的具体实现。
 
+use super::parser::{StreamKind, parse_stream_message};
 use crate::{DataError, DataResult, DataSourceConfig};
 use async_trait::async_trait;
 use aurora_core::{DataSource, Kline, MarketEvent};
 use futures_util::{SinkExt, StreamExt};
-use serde_json::Value;
 use std::collections::VecDeque;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::net::TcpStream;
 use tokio::sync::mpsc::UnboundedReceiver;
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async, tungstenite::Message};
@@ -17,12 +17,13 @@ use tracing::{debug, error, info, warn};
 /// Binance实时数据流
 ///
 /// 这个结构体提供了从Binance WebSocket获取实时市场数据的功能。
-/// 支持K线数据的实时订阅和自动重连。
+/// 支持K线、逐笔成交、最优挂单、深度和标记价格流的实时订阅和自动重连。
 ///
 /// ## 功能特性
 ///
 /// - **WebSocket连接**: 维持与交易所的WebSocket连接
 /// - **实时K线**: 获取实时更新的K线数据
+/// - **盘口数据**: 通过 [`StreamKind`] 订阅成交、报价、深度和资金费率
 /// - **自动重连**: 连接断开时自动重连
 /// - **数据过滤**: 只处理完成的K线数据
 /// - **错误处理**: 完整的连接和数据错误处理
@@ -68,6 +69,9 @@ pub struct BinanceLiveStream {
 
     /// 时间间隔设置
     interval: String,
+
+    /// 订阅的流类型列表
+    stream_kinds: Vec<StreamKind>,
 }
 
 impl BinanceLiveStream {
@@ -113,6 +117,7 @@ impl BinanceLiveStream {
             is_connected: false,
             reconnect_count: 0,
             interval: "1m".to_string(), // 默认1分钟K线
+            stream_kinds: vec![StreamKind::Kline],
         }
     }
 
@@ -134,9 +139,36 @@ impl BinanceLiveStream {
         self.interval = interval.to_string();
     }
 
+    /// 设置要订阅的流类型
+    ///
+    /// 默认只订阅K线流。设置后对每个交易对订阅所有指定的流类型，
+    /// 需要在 [`connect`](Self::connect) 之前调用。
+    ///
+    /// # 参数
+    ///
+    /// * `kinds` - 流类型列表
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use aurora_data::{BinanceLiveStream, StreamKind};
+    ///
+    /// let mut stream = BinanceLiveStream::new();
+    /// stream.set_stream_kinds(&[StreamKind::Kline, StreamKind::BookTicker]);
+    /// assert_eq!(stream.stream_kinds().len(), 2);
+    /// ```
+    pub fn set_stream_kinds(&mut self, kinds: &[StreamKind]) {
+        self.stream_kinds = kinds.to_vec();
+    }
+
+    /// 获取当前订阅的流类型列表
+    pub fn stream_kinds(&self) -> &[StreamKind] {
+        &self.stream_kinds
+    }
+
     /// 连接到WebSocket并订阅指定交易对
     ///
-    /// 这个方法会建立WebSocket连接，并订阅指定交易对的K线数据。
+    /// 这个方法会建立WebSocket连接，并为每个交易对订阅所有已设置的流类型。
     ///
     /// # 参数
     ///
@@ -165,17 +197,26 @@ impl BinanceLiveStream {
             .as_ref()
             .ok_or_else(|| DataError::ConfigError("WebSocket URL未配置".to_string()))?;
 
+        for kind in &self.stream_kinds {
+            kind.validate()?;
+        }
+
         // 构建流名称列表
+        let interval = self.interval.as_str();
+        let kinds = &self.stream_kinds;
         let streams: Vec<String> = symbols
             .iter()
-            .map(|symbol| format!("{}@kline_{}", symbol.to_lowercase(), self.interval))
+            .flat_map(|symbol| kinds.iter().map(move |kind| kind.stream_name(symbol, interval)))
             .collect();
 
         let stream_params = streams.join("/");
         let url = format!("{}/stream?streams={}", ws_url, stream_params);
 
         info!("连接到WebSocket: {}", url);
-        info!("订阅的交易对: {:?}, 间隔: {}", symbols, self.interval);
+        info!(
+            "订阅的交易对: {:?}, 间隔: {}, 流类型: {:?}",
+            symbols, self.interval, self.stream_kinds
+        );
 
         // 建立WebSocket连接
         let (ws_stream, _) = connect_async(&url)
@@ -193,8 +234,9 @@ impl BinanceLiveStream {
 
     /// 获取下一个K线数据
     ///
-    /// 这个方法会从WebSocket连接读取下一条消息，解析为K线数据并返回。
-    /// 如果连接断开，会自动尝试重连。
+    /// 这个方法会持续读取市场事件，直到获得一根已完成的K线。
+    /// 如果同时订阅了其他流类型，非K线事件会被跳过；
+    /// 需要处理全部事件时请使用 [`next_event`](Self::next_event)。
     ///
     /// # 返回值
     ///
@@ -223,6 +265,47 @@ impl BinanceLiveStream {
             return Ok(Some(kline));
         }
 
+        loop {
+            match self.next_event().await? {
+                Some(MarketEvent::Kline(kline)) => return Ok(Some(kline)),
+                Some(_) => continue,
+                None => return Ok(None),
+            }
+        }
+    }
+
+    /// 获取下一个市场事件
+    ///
+    /// 从WebSocket连接读取消息并解析为 [`MarketEvent`]，
+    /// 无法识别的消息和尚未完成的K线会被跳过。
+    /// 如果连接断开，会自动尝试重连。
+    ///
+    /// # 返回值
+    ///
+    /// * `Some(MarketEvent)` - 成功获取到市场事件
+    /// * `None` - 没有数据或连接已关闭
+    /// * `Err(DataError)` - 发生错误
+    ///
+    /// # 示例
+    ///
+    /// ```rust,no_run
+    /// use aurora_data::{BinanceLiveStream, StreamKind};
+    /// use aurora_core::MarketEvent;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut stream = BinanceLiveStream::new();
+    /// stream.set_stream_kinds(&[StreamKind::Trade, StreamKind::BookTicker]);
+    /// stream.connect(&["BTCUSDT"]).await?;
+    ///
+    /// while let Some(event) = stream.next_event().await? {
+    ///     if let MarketEvent::Quote(quote) = event {
+    ///         println!("买一: {}, 卖一: {}", quote.bid_price, quote.ask_price);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn next_event(&mut self) -> DataResult<Option<MarketEvent>> {
         // 如果没有连接，返回None
         if !self.is_connected || self.ws_stream.is_none() {
             return Ok(None);
@@ -251,10 +334,25 @@ impl BinanceLiveStream {
                 Message::Text(text) => {
                     debug!("收到WebSocket消息: {}", text);
 
-                    if let Ok(kline) = self.parse_kline_message(&text) {
-                        return Ok(Some(kline));
+                    match parse_stream_message(&text, current_time_millis()) {
+                        Ok(Some(event)) => {
+                            if let MarketEvent::Kline(kline) = &event {
+                                info!(
+                                    "📊 收到完成的K线: 时间={}, 开盘={}, 最高={}, 最低={}, 收盘={}, 成交量={}",
+                                    kline.timestamp,
+                                    kline.open,
+                                    kline.high,
+                                    kline.low,
+                                    kline.close,
+                                    kline.volume
+                                );
+                            }
+                            return Ok(Some(event));
+                        }
+                        // 未完成的K线，继续等待下一条消息
+                        Ok(None) => {}
+                        Err(e) => debug!("忽略无法解析的消息: {}", e),
                     }
-                    // 如果解析失败，继续等待下一条消息
                 }
                 Message::Close(_) => {
                     info!("收到WebSocket关闭消息");
@@ -365,82 +463,19 @@ impl BinanceLiveStream {
 
         Ok(())
     }
+}
 
-    /// 解析WebSocket消息为K线数据
-    ///
-    /// 将接收到的JSON消息解析为Kline结构体。
-    /// 只处理完成的K线数据（x字段为true）。
-    ///
-    /// # 参数
-    ///
-    /// * `text` - JSON格式的WebSocket消息
-    ///
-    /// # 返回值
-    ///
-    /// 成功时返回Kline，失败时返回DataError
-    fn parse_kline_message(&self, text: &str) -> DataResult<Kline> {
-        let value: Value = serde_json::from_str(text)
-            .map_err(|e| DataError::ParseError(format!("JSON解析失败: {}", e)))?;
-
-        // 处理单个流的数据格式
-        let kline_data = if let Some(data) = value.get("data") {
-            // 多流格式: {"stream": "btcusdt@kline_1m", "data": {...}}
-            data.get("k")
-                .ok_or_else(|| DataError::ParseError("消息中缺少K线数据".to_string()))?
-        } else if let Some(k) = value.get("k") {
-            // 单流格式: {"e": "kline", "k": {...}}
-            k
-        } else {
-            return Err(DataError::ParseError("无法识别的消息格式".to_string()));
-        };
-
-        // 检查是否为完成的K线
-        let is_closed = kline_data["x"].as_bool().unwrap_or(false);
-        if !is_closed {
-            return Err(DataError::ParseError("K线尚未完成".to_string()));
-        }
-
-        // 解析K线数据
-        let kline = Kline {
-            timestamp: kline_data["t"].as_i64().unwrap_or(0),
-            open: kline_data["o"]
-                .as_str()
-                .unwrap_or("0")
-                .parse()
-                .unwrap_or(0.0),
-            high: kline_data["h"]
-                .as_str()
-                .unwrap_or("0")
-                .parse()
-                .unwrap_or(0.0),
-            low: kline_data["l"]
-                .as_str()
-                .unwrap_or("0")
-                .parse()
-                .unwrap_or(0.0),
-            close: kline_data["c"]
-                .as_str()
-                .unwrap_or("0")
-                .parse()
-                .unwrap_or(0.0),
-            volume: kline_data["v"]
-                .as_str()
-                .unwrap_or("0")
-                .parse()
-                .unwrap_or(0.0),
-        };
-
-        // 验证数据有效性
-        if !super::utils::validate_kline(&kline) {
-            return Err(DataError::ParseError("K线数据验证失败".to_string()));
-        }
-
-        info!(
-            "📊 收到完成的K线: 时间={}, 开盘={}, 最高={}, 最低={}, 收盘={}, 成交量={}",
-            kline.timestamp, kline.open, kline.high, kline.low, kline.close, kline.volume
-        );
+/// 当前系统时间（Unix毫秒）
+fn current_time_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
 
-        Ok(kline)
+impl Default for BinanceLiveStream {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -449,6 +484,7 @@ impl DataSource for BinanceLiveStream {
     /// 启动数据源并返回事件接收器
     ///
     /// 这个方法启动WebSocket连接，并返回一个接收器来接收市场事件。
+    /// 所有已订阅流类型产生的事件都会转发到接收器。
     ///
     /// # 返回值
     ///
@@ -472,11 +508,13 @@ impl DataSource for BinanceLiveStream {
         // 创建独立的实例用于后台任务，避免生命周期问题
         let config = self.config.clone();
         let interval = self.interval.clone();
+        let stream_kinds = self.stream_kinds.clone();
         let symbols_owned: Vec<String> = self.subscribed_symbols.clone();
 
         tokio::spawn(async move {
             let mut stream_clone = BinanceLiveStream::with_config(config);
             stream_clone.interval = interval;
+            stream_clone.stream_kinds = stream_kinds;
             let symbols: Vec<&str> = symbols_owned.iter().map(|s| s.as_str()).collect();
 
             if let Err(e) = stream_clone.connect(&symbols).await {
@@ -485,10 +523,9 @@ impl DataSource for BinanceLiveStream {
             }
 
             loop {
-                match stream_clone.next_kline().await {
-                    Ok(Some(kline)) => {
-                        let event = MarketEvent::Kline(kline);
-                        if let Err(_) = tx.send(event) {
+                match stream_clone.next_event().await {
+                    Ok(Some(event)) => {
+                        if tx.send(event).is_err() {
                             info!("事件接收器已关闭，停止数据流");
                             break;
                         }
@@ -498,7 +535,7 @@ impl DataSource for BinanceLiveStream {
                         break;
                     }
                     Err(e) => {
-                        error!("获取K线数据失败: {}", e);
+                        error!("获取市场数据失败: {}", e);
                         // 可以选择继续或者退出
                         tokio::time::sleep(Duration::from_secs(1)).await;
                     }
@@ -2,9 +2,9 @@
 //!
 //! 包含了实时数据处理相关的工具函数。
 
+use super::parser::StreamKind;
 use super::stream::BinanceLiveStream;
-use aurora_core::Kline;
-use std::time::Duration;
+use aurora_core::{Kline, MarketEvent};
 use tracing::{error, info, warn};
 
 /// 验证K线数据的有效性
@@ -54,7 +54,7 @@ pub fn validate_kline(kline: &Kline) -> bool {
 /// # 参数
 ///
 /// * `symbol` - 交易对符号
-/// * `stream_type` - 流类型（"kline" 或 "trade"）
+/// * `stream_type` - 流类型（"kline"、"trade"、"ticker"、"depth" 或 "funding"）
 /// * `interval` - K线时间间隔（仅对kline类型有效）
 ///
 /// # 返回值
@@ -97,37 +97,41 @@ pub fn validate_kline(kline: &Kline) -> bool {
 /// # }
 /// ```
 pub async fn stream_data(symbol: &str, stream_type: &str, interval: &str) -> anyhow::Result<()> {
-    match stream_type {
-        "kline" => {
-            let mut stream = BinanceLiveStream::new();
-            stream.set_interval(interval);
-            stream
-                .connect(&[symbol])
-                .await
-                .map_err(|e| anyhow::anyhow!("连接失败: {}", e))?;
-
-            loop {
-                match stream.next_kline().await {
-                    Ok(Some(_kline)) => {
-                        // K线数据已在stream层记录，这里不再重复记录
-                    }
-                    Ok(None) => {
-                        info!("连接已关闭");
-                        break;
-                    }
-                    Err(e) => {
-                        error!("获取数据错误: {}", e);
-                        break;
-                    }
-                }
-            }
-        }
-        "trade" => {
-            return Err(anyhow::anyhow!("trade流类型暂未实现"));
-        }
+    let kind = match stream_type {
+        "kline" => StreamKind::Kline,
+        "trade" => StreamKind::Trade,
+        "ticker" => StreamKind::BookTicker,
+        "depth" => StreamKind::Depth(5),
+        "funding" => StreamKind::MarkPrice,
         _ => {
             return Err(anyhow::anyhow!("不支持的流类型: {}", stream_type));
         }
+    };
+
+    let mut stream = BinanceLiveStream::new();
+    stream.set_interval(interval);
+    stream.set_stream_kinds(&[kind]);
+    stream
+        .connect(&[symbol])
+        .await
+        .map_err(|e| anyhow::anyhow!("连接失败: {}", e))?;
+
+    loop {
+        match stream.next_event().await {
+            // K线数据已在stream层记录，这里不再重复记录
+            Ok(Some(MarketEvent::Kline(_))) => {}
+            Ok(Some(event)) => {
+                info!("收到市场事件: {:?}", event);
+            }
+            Ok(None) => {
+                info!("连接已关闭");
+                break;
+            }
+            Err(e) => {
+                error!("获取数据错误: {}", e);
+                break;
+            }
+        }
     }
 
     Ok(())
@@ -147,6 +151,8 @@ mod tests {
             low: 49000.0,
             close: 50500.0,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         }
     }
 
@@ -165,6 +171,8 @@ mod tests {
             low: 51000.0,
             close: 50500.0,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         };
         assert!(!validate_kline(&invalid_kline1));
 
@@ -176,6 +184,8 @@ mod tests {
             low: 49000.0,
             close: 50500.0,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         };
         assert!(!validate_kline(&invalid_kline2));
 
@@ -187,6 +197,8 @@ mod tests {
             low: 49000.0,
             close: 50500.0,
             volume: -100.0, // 负成交量
+            symbol: None,
+            interval: None,
         };
         assert!(!validate_kline(&invalid_kline3));
 
@@ -198,6 +210,8 @@ mod tests {
             low: 49000.0,
             close: 50500.0,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         };
         assert!(!validate_kline(&invalid_kline4));
     }
@@ -172,6 +172,8 @@ mod tests {
             low: 95.0,
             close: 102.0,
             volume: 1000.0,
+            symbol: None,
+            interval: None,
         };
 
         assert!(loader.validate_kline(&kline));
@@ -187,6 +189,8 @@ mod tests {
             low: 95.0,
             close: 102.0,
             volume: 1000.0,
+            symbol: None,
+            interval: None,
         };
 
         assert!(!loader.validate_kline(&kline));
@@ -202,6 +206,8 @@ mod tests {
             low: 95.0,
             close: 102.0,
             volume: 1000.0,
+            symbol: None,
+            interval: None,
         };
 
         assert!(!loader.validate_kline(&kline));
@@ -1,5 +1,9 @@
 use anyhow::Result;
-use aurora_data::{historical, live};
+use aurora_core::Kline;
+use aurora_data::{
+    BarTransformer, BoxSize, CsvDataLoader, HeikinAshi, Kagi, RangeBars, Renko, historical, live,
+};
+use aurora_indicators::VolumeProfileOutput;
 use clap::{Parser, Subcommand};
 use tracing::{error, info};
 
@@ -42,7 +46,7 @@ enum Commands {
         #[arg(short, long)]
         symbol: String,
 
-        /// 流类型 (kline, trade)
+        /// 流类型 (kline, trade, ticker, depth, funding)
         #[arg(long, default_value = "kline")]
         stream_type: String,
 
@@ -50,6 +54,44 @@ enum Commands {
         #[arg(short, long, default_value = "1m")]
         interval: String,
     },
+
+    /// 分析数据文件的成交量分布
+    Profile {
+        /// CSV数据文件路径
+        #[arg(short, long)]
+        input: String,
+
+        /// 价格层数量
+        #[arg(short, long, default_value_t = 24)]
+        buckets: usize,
+
+        /// 价值区覆盖的成交量比例
+        #[arg(long, default_value_t = 0.7)]
+        value_area: f64,
+
+        /// 交易时段长度(分钟)，设置后按时段分别统计
+        #[arg(long)]
+        session_minutes: Option<i64>,
+    },
+
+    /// 把数据文件转换为平均K线、砖形图、等幅K线或卡吉图
+    Transform {
+        /// CSV数据文件路径
+        #[arg(short, long)]
+        input: String,
+
+        /// 输出文件路径
+        #[arg(short, long)]
+        output: String,
+
+        /// 变换类型 (heikin-ashi, renko, range, kagi)
+        #[arg(short, long)]
+        kind: String,
+
+        /// 砖块大小、区间大小或反转幅度 (例如: 10, 0.5%, atr14)
+        #[arg(long)]
+        size: Option<String>,
+    },
 }
 
 #[tokio::main]
@@ -100,11 +142,127 @@ async fn main() -> Result<()> {
                 Err(e) => error!("流接收失败: {}", e),
             }
         }
+
+        Commands::Profile {
+            input,
+            buckets,
+            value_area,
+            session_minutes,
+        } => {
+            info!("开始分析成交量分布: {}", input);
+
+            match analyze_profile(&input, buckets, value_area, session_minutes) {
+                Ok(_) => info!("成交量分布分析完成"),
+                Err(e) => error!("分析失败: {}", e),
+            }
+        }
+
+        Commands::Transform {
+            input,
+            output,
+            kind,
+            size,
+        } => {
+            info!("开始转换K线: {} -> {} ({})", input, output, kind);
+
+            match transform_file(&input, &output, &kind, size.as_deref()).await {
+                Ok(count) => info!("K线转换完成，共输出 {} 根", count),
+                Err(e) => error!("转换失败: {}", e),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 加载数据文件并输出成交量分布
+fn analyze_profile(
+    input: &str,
+    buckets: usize,
+    value_area: f64,
+    session_minutes: Option<i64>,
+) -> Result<()> {
+    anyhow::ensure!(buckets > 0, "价格层数量必须大于0");
+    anyhow::ensure!(value_area > 0.0 && value_area <= 1.0, "价值区比例必须在(0, 1]内");
+
+    let klines = CsvDataLoader::new().load_from_csv(input)?;
+    anyhow::ensure!(!klines.is_empty(), "数据文件为空: {}", input);
+
+    match session_minutes {
+        None => {
+            if let Some(profile) = VolumeProfileOutput::from_klines(&klines, buckets, value_area) {
+                print_profile("全部数据", &profile, true);
+            }
+        }
+        Some(minutes) => {
+            anyhow::ensure!(minutes > 0, "交易时段长度必须大于0");
+            for (start, session) in split_sessions(&klines, minutes * 60_000) {
+                if let Some(profile) = VolumeProfileOutput::from_klines(session, buckets, value_area) {
+                    print_profile(&format!("时段 {}", start), &profile, false);
+                }
+            }
+        }
     }
 
     Ok(())
 }
 
+/// 按类型和大小配置创建K线变换器
+fn create_transformer(kind: &str, size: Option<&str>) -> Result<Box<dyn BarTransformer>> {
+    if kind == "heikin-ashi" {
+        return Ok(Box::new(HeikinAshi::new()));
+    }
+
+    let size: BoxSize = size
+        .ok_or_else(|| anyhow::anyhow!("{} 变换需要指定 --size", kind))?
+        .parse()?;
+    match kind {
+        "renko" => Ok(Box::new(Renko::new(size))),
+        "range" => Ok(Box::new(RangeBars::new(size))),
+        "kagi" => Ok(Box::new(Kagi::new(size))),
+        _ => anyhow::bail!("不支持的变换类型: {}", kind),
+    }
+}
+
+/// 加载数据文件，变换后保存为CSV，返回输出的K线数量
+async fn transform_file(input: &str, output: &str, kind: &str, size: Option<&str>) -> Result<usize> {
+    let mut transformer = create_transformer(kind, size)?;
+    let klines = CsvDataLoader::new().load_from_csv(input)?;
+
+    let bars = transformer.transform(&klines);
+    anyhow::ensure!(!bars.is_empty(), "变换后没有完整的K线，请减小大小配置");
+
+    historical::utils::save_to_csv(&bars, output).await?;
+    Ok(bars.len())
+}
+
+/// 按时段切分K线，返回每个时段的开始时间戳和K线
+fn split_sessions(klines: &[Kline], session_ms: i64) -> Vec<(i64, &[Kline])> {
+    klines
+        .chunk_by(|a, b| a.timestamp.div_euclid(session_ms) == b.timestamp.div_euclid(session_ms))
+        .map(|session| (session[0].timestamp.div_euclid(session_ms) * session_ms, session))
+        .collect()
+}
+
+/// 打印成交量分布，`with_levels` 为真时同时打印每个价格层
+fn print_profile(label: &str, profile: &VolumeProfileOutput, with_levels: bool) {
+    println!(
+        "{}: POC={:.4} VAH={:.4} VAL={:.4} 总成交量={:.4}",
+        label,
+        profile.point_of_control,
+        profile.value_area_high,
+        profile.value_area_low,
+        profile.total_volume
+    );
+
+    if with_levels {
+        for level in profile.levels.iter().rev() {
+            let marker = if profile.is_in_value_area(level.mid()) { "*" } else { " " };
+            println!("{} {:>12.4} - {:<12.4} {:>16.4}", marker, level.low, level.high, level.volume);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -431,4 +589,126 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_profile_command() {
+        let args = vec!["aurora-data", "profile", "--input", "btc.csv"];
+
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            Commands::Profile {
+                input,
+                buckets,
+                value_area,
+                session_minutes,
+            } => {
+                assert_eq!(input, "btc.csv");
+                assert_eq!(buckets, 24); // 默认值
+                assert_eq!(value_area, 0.7);
+                assert!(session_minutes.is_none());
+            }
+            _ => panic!("预期Profile命令"),
+        }
+
+        let args = vec![
+            "aurora-data",
+            "profile",
+            "-i",
+            "btc.csv",
+            "-b",
+            "50",
+            "--value-area",
+            "0.8",
+            "--session-minutes",
+            "1440",
+        ];
+
+        match Cli::try_parse_from(args).unwrap().command {
+            Commands::Profile {
+                buckets,
+                value_area,
+                session_minutes,
+                ..
+            } => {
+                assert_eq!(buckets, 50);
+                assert_eq!(value_area, 0.8);
+                assert_eq!(session_minutes, Some(1440));
+            }
+            _ => panic!("预期Profile命令"),
+        }
+    }
+
+    #[test]
+    fn test_split_sessions() {
+        let kline = |timestamp: i64| Kline {
+            timestamp,
+            open: 100.0,
+            high: 101.0,
+            low: 99.0,
+            close: 100.0,
+            volume: 1.0,
+            symbol: None,
+            interval: None,
+        };
+        let hour = 3_600_000;
+        let klines = vec![kline(0), kline(hour / 2), kline(hour), kline(hour * 3 + 1)];
+
+        let sessions = split_sessions(&klines, hour);
+        assert_eq!(sessions.len(), 3);
+        assert_eq!(sessions[0].0, 0);
+        assert_eq!(sessions[0].1.len(), 2);
+        assert_eq!(sessions[1].0, hour);
+        assert_eq!(sessions[2].0, hour * 3);
+    }
+
+    #[test]
+    fn test_analyze_profile_invalid_args() {
+        assert!(analyze_profile("nonexistent.csv", 24, 0.7, None).is_err());
+        assert!(analyze_profile("nonexistent.csv", 0, 0.7, None).is_err());
+        assert!(analyze_profile("nonexistent.csv", 24, 1.5, None).is_err());
+    }
+
+    #[test]
+    fn test_transform_command() {
+        let args = vec![
+            "aurora-data",
+            "transform",
+            "-i",
+            "btc.csv",
+            "-o",
+            "btc_renko.csv",
+            "-k",
+            "renko",
+            "--size",
+            "atr14",
+        ];
+
+        match Cli::try_parse_from(args).unwrap().command {
+            Commands::Transform {
+                input,
+                output,
+                kind,
+                size,
+            } => {
+                assert_eq!(input, "btc.csv");
+                assert_eq!(output, "btc_renko.csv");
+                assert_eq!(kind, "renko");
+                assert_eq!(size, Some("atr14".to_string()));
+            }
+            _ => panic!("预期Transform命令"),
+        }
+    }
+
+    #[test]
+    fn test_create_transformer() {
+        assert!(create_transformer("heikin-ashi", None).is_ok());
+        assert!(create_transformer("renko", Some("10")).is_ok());
+        assert!(create_transformer("range", Some("0.5%")).is_ok());
+        assert!(create_transformer("kagi", Some("atr14")).is_ok());
+
+        assert!(create_transformer("renko", None).is_err());
+        assert!(create_transformer("renko", Some("-1")).is_err());
+        assert!(create_transformer("point-figure", Some("10")).is_err());
+    }
 }
@@ -0,0 +1,267 @@
+//! # 历史数据回放模块
+//!
+//! 把CSV文件或内存中的历史K线按 [`DataSource`] 接口重新播放，
+//! 使实时引擎等基于数据源的组件可以离线运行在录制好的数据上。
+//!
+//! 回放速度由 [`ReplaySpeed`] 控制：
+//!
+//! - `AsFastAsPossible`: 不等待，连续发送全部事件
+//! - `RealTime`: 按事件时间戳之间的真实间隔发送
+//! - `Multiplier(n)`: 以n倍速发送（间隔缩短为原来的 1/n）
+
+use crate::{CsvDataLoader, DataResult};
+use async_trait::async_trait;
+use aurora_core::{DataSource, Kline, MarketEvent};
+use std::time::Duration;
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+use tracing::{debug, info};
+
+/// 回放速度
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ReplaySpeed {
+    /// 不等待，尽快发送全部事件
+    #[default]
+    AsFastAsPossible,
+    /// 按事件之间的真实时间间隔发送
+    RealTime,
+    /// 按给定倍速发送，倍数不大于0时等同于 `AsFastAsPossible`
+    Multiplier(f64),
+}
+
+impl ReplaySpeed {
+    /// 计算两个事件之间应等待的时间
+    ///
+    /// # 参数
+    ///
+    /// * `elapsed_ms` - 两个事件时间戳之差（毫秒）
+    fn delay(&self, elapsed_ms: i64) -> Option<Duration> {
+        let factor = match self {
+            ReplaySpeed::AsFastAsPossible => return None,
+            ReplaySpeed::RealTime => 1.0,
+            ReplaySpeed::Multiplier(factor) if *factor > 0.0 => *factor,
+            ReplaySpeed::Multiplier(_) => return None,
+        };
+
+        if elapsed_ms <= 0 {
+            return None;
+        }
+        Some(Duration::from_secs_f64(elapsed_ms as f64 / 1000.0 / factor))
+    }
+}
+
+/// 历史数据回放源
+///
+/// 按时间顺序回放历史市场事件，实现 [`DataSource`] 接口。
+/// 所有事件发送完毕后通道关闭，接收方据此判断回放结束。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::{DataSource, Kline};
+/// use aurora_data::{HistoricalReplaySource, ReplaySpeed};
+///
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// let klines: Vec<Kline> = (0..3)
+///     .map(|i| Kline {
+///         timestamp: 1640995200000 + i * 60_000,
+///         open: 100.0,
+///         high: 101.0,
+///         low: 99.0,
+///         close: 100.5,
+///         volume: 10.0,
+///         symbol: None,
+///         interval: None,
+///     })
+///     .collect();
+///
+/// // 以600倍速回放（1分钟K线间隔为100毫秒）
+/// let mut source = HistoricalReplaySource::from_klines(klines)
+///     .with_speed(ReplaySpeed::Multiplier(600.0));
+/// let mut receiver = source.start().await?;
+///
+/// let mut count = 0;
+/// while let Some(_event) = receiver.recv().await {
+///     count += 1;
+/// }
+/// assert_eq!(count, 3);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HistoricalReplaySource {
+    /// 待回放的事件（按时间戳排序）
+    events: Vec<MarketEvent>,
+
+    /// 回放速度
+    speed: ReplaySpeed,
+}
+
+impl HistoricalReplaySource {
+    /// 由内存中的市场事件创建回放源
+    ///
+    /// 事件按时间戳稳定排序，相同时间戳的事件保持原有顺序。
+    pub fn from_events(mut events: Vec<MarketEvent>) -> Self {
+        events.sort_by_key(|event| event.timestamp());
+        Self {
+            events,
+            speed: ReplaySpeed::default(),
+        }
+    }
+
+    /// 由内存中的K线创建回放源
+    pub fn from_klines(klines: Vec<Kline>) -> Self {
+        Self::from_events(klines.into_iter().map(MarketEvent::Kline).collect())
+    }
+
+    /// 由CSV文件创建回放源
+    ///
+    /// 使用 [`CsvDataLoader`] 加载并校验K线数据。
+    ///
+    /// # 错误
+    ///
+    /// 文件不存在、格式错误或没有有效K线时返回相应的 [`DataError`](crate::DataError)
+    pub fn from_csv(file_path: &str) -> DataResult<Self> {
+        let klines = CsvDataLoader::new().load_from_csv(file_path)?;
+        Ok(Self::from_klines(klines))
+    }
+
+    /// 设置回放速度
+    pub fn with_speed(mut self, speed: ReplaySpeed) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// 回放速度
+    pub fn speed(&self) -> ReplaySpeed {
+        self.speed
+    }
+
+    /// 待回放的事件数量
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// 是否没有待回放的事件
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+#[async_trait]
+impl DataSource for HistoricalReplaySource {
+    /// 启动回放并返回事件接收器
+    ///
+    /// 回放在后台任务中进行，接收器被丢弃时提前停止。
+    async fn start(&mut self) -> anyhow::Result<UnboundedReceiver<MarketEvent>> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let events = self.events.clone();
+        let speed = self.speed;
+
+        info!("开始回放 {} 个历史事件，速度: {:?}", events.len(), speed);
+
+        tokio::spawn(async move {
+            let mut previous: Option<i64> = None;
+
+            for event in events {
+                let timestamp = event.timestamp();
+                if let Some(delay) = previous.and_then(|prev| speed.delay(timestamp - prev)) {
+                    tokio::time::sleep(delay).await;
+                }
+                previous = Some(timestamp);
+
+                if tx.send(event).is_err() {
+                    debug!("事件接收器已关闭，停止回放");
+                    return;
+                }
+            }
+
+            info!("历史数据回放完成");
+        });
+
+        Ok(rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::time::Instant;
+    use tempfile::NamedTempFile;
+
+    fn kline(timestamp: i64, close: f64) -> Kline {
+        Kline {
+            timestamp,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: 1.0,
+            symbol: None,
+            interval: None,
+        }
+    }
+
+    async fn collect(source: &mut HistoricalReplaySource) -> Vec<MarketEvent> {
+        let mut receiver = source.start().await.unwrap();
+        let mut events = Vec::new();
+        while let Some(event) = receiver.recv().await {
+            events.push(event);
+        }
+        events
+    }
+
+    /// 测试按时间顺序回放全部事件
+    #[tokio::test]
+    async fn test_replay_in_timestamp_order() {
+        let mut source =
+            HistoricalReplaySource::from_klines(vec![kline(3000, 3.0), kline(1000, 1.0), kline(2000, 2.0)]);
+        assert_eq!(source.len(), 3);
+
+        let timestamps: Vec<i64> = collect(&mut source).await.iter().map(|e| e.timestamp()).collect();
+        assert_eq!(timestamps, vec![1000, 2000, 3000]);
+    }
+
+    /// 测试倍速回放按比例等待
+    #[tokio::test]
+    async fn test_replay_multiplier_pacing() {
+        // 事件间隔1秒，100倍速下每个间隔约10毫秒
+        let mut source = HistoricalReplaySource::from_klines(vec![
+            kline(0, 1.0),
+            kline(1000, 2.0),
+            kline(2000, 3.0),
+        ])
+        .with_speed(ReplaySpeed::Multiplier(100.0));
+
+        let started = Instant::now();
+        assert_eq!(collect(&mut source).await.len(), 3);
+        assert!(started.elapsed() >= Duration::from_millis(20));
+    }
+
+    /// 测试回放速度的等待时间计算
+    #[test]
+    fn test_replay_speed_delay() {
+        assert_eq!(ReplaySpeed::AsFastAsPossible.delay(1000), None);
+        assert_eq!(ReplaySpeed::RealTime.delay(1500), Some(Duration::from_millis(1500)));
+        assert_eq!(ReplaySpeed::Multiplier(10.0).delay(1000), Some(Duration::from_millis(100)));
+        assert_eq!(ReplaySpeed::Multiplier(0.0).delay(1000), None);
+        assert_eq!(ReplaySpeed::RealTime.delay(0), None);
+    }
+
+    /// 测试从CSV文件创建回放源
+    #[tokio::test]
+    async fn test_replay_from_csv() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(temp_file, "timestamp,open,high,low,close,volume").unwrap();
+        writeln!(temp_file, "1640995200000,100.0,105.0,95.0,102.0,1000.0").unwrap();
+        writeln!(temp_file, "1640995260000,102.0,107.0,100.0,105.0,1500.0").unwrap();
+
+        let mut source = HistoricalReplaySource::from_csv(temp_file.path().to_str().unwrap()).unwrap();
+        let events = collect(&mut source).await;
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[1], MarketEvent::Kline(k) if k.close == 105.0));
+        assert!(HistoricalReplaySource::from_csv("nonexistent.csv").is_err());
+    }
+}
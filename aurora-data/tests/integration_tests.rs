@@ -26,6 +26,8 @@ fn create_test_kline(
         close,
         volume,
         timestamp,
+        symbol: None,
+        interval: None,
     }
 }
 
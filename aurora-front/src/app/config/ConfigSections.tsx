@@ -297,6 +297,22 @@ export function StrategiesSection({ strategies, onChange }: StrategiesSectionPro
                     placeholder={field.placeholder}
                     className="w-full"
                   />
+                ) : field.type === 'select' ? (
+                  <Select
+                    value={strategy.parameters[field.name] as string || ''}
+                    onValueChange={(value) => updateParameter(field.name, value)}
+                  >
+                    <SelectTrigger className="w-full">
+                      <SelectValue placeholder={field.placeholder} />
+                    </SelectTrigger>
+                    <SelectContent>
+                      {field.options?.map((option) => (
+                        <SelectItem key={option.value} value={option.value}>
+                          {option.label}
+                        </SelectItem>
+                      ))}
+                    </SelectContent>
+                  </Select>
                 ) : field.type === 'checkbox' ? (
                   <label className="inline-flex items-center">
                     <input
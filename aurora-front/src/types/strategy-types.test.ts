@@ -20,6 +20,9 @@ import {
   getDefaultParameters,
   validateStrategyParameters,
   MACrossoverParametersSchema,
+  RSIReversionParametersSchema,
+  SqueezeBreakoutParametersSchema,
+  TrendFollowingParametersSchema,
 } from './strategy-types';
 
 describe('strategy-types', () => {
@@ -80,6 +83,78 @@ describe('strategy-types', () => {
     });
   });
 
+  describe('RSIReversionParametersSchema', () => {
+    // 测试默认参数可以通过验证
+    it('should validate default RSI reversion parameters', () => {
+      const params = getDefaultParameters('rsi-reversion');
+      expect(() => RSIReversionParametersSchema.parse(params)).not.toThrow();
+    });
+
+    // 测试平仓水平默认与超买线相同，与后端默认行为一致
+    it('should default exit level to overbought', () => {
+      const params = getDefaultParameters('rsi-reversion');
+      expect(params.exit_level).toBe(params.overbought);
+    });
+
+    // 测试超卖线必须小于超买线
+    it('should reject when oversold >= overbought', () => {
+      const invalidParams = { period: 14, oversold: 70, overbought: 30 };
+      expect(() => RSIReversionParametersSchema.parse(invalidParams)).toThrow();
+    });
+
+    // 测试平仓水平必须位于超卖线和超买线之间
+    it('should reject exit level outside thresholds', () => {
+      const invalidParams = { period: 14, oversold: 30, overbought: 70, exit_level: 20 };
+      expect(() => RSIReversionParametersSchema.parse(invalidParams)).toThrow();
+    });
+
+    // 测试未知的趋势过滤器
+    it('should reject unknown trend filter', () => {
+      const invalidParams = { period: 14, oversold: 30, overbought: 70, trend_filter: 'sma' };
+      expect(() => RSIReversionParametersSchema.parse(invalidParams)).toThrow();
+    });
+  });
+
+  describe('SqueezeBreakoutParametersSchema', () => {
+    // 测试默认参数可以通过验证
+    it('should validate default squeeze breakout parameters', () => {
+      const params = getDefaultParameters('squeeze-breakout');
+      expect(() => SqueezeBreakoutParametersSchema.parse(params)).not.toThrow();
+    });
+
+    // 测试MACD快线周期必须小于慢线周期
+    it('should reject when macd_fast >= macd_slow', () => {
+      const invalidParams = { bb_period: 20, bb_std_dev: 2, kc_period: 20, kc_multiplier: 1.5, macd_fast: 30 };
+      expect(() => SqueezeBreakoutParametersSchema.parse(invalidParams)).toThrow();
+    });
+
+    // 测试止盈倍数不能为负数
+    it('should reject negative target multiplier', () => {
+      const invalidParams = { bb_period: 20, bb_std_dev: 2, kc_period: 20, kc_multiplier: 1.5, target_atr: -1 };
+      expect(() => SqueezeBreakoutParametersSchema.parse(invalidParams)).toThrow();
+    });
+  });
+
+  describe('TrendFollowingParametersSchema', () => {
+    // 测试默认参数可以通过验证
+    it('should validate default trend following parameters', () => {
+      const params = getDefaultParameters('trend-following');
+      expect(() => TrendFollowingParametersSchema.parse(params)).not.toThrow();
+    });
+
+    // 测试快速EMA周期必须小于慢速EMA周期
+    it('should reject when ema_fast >= ema_slow', () => {
+      const invalidParams = { entry: 'ema', ema_fast: 30, ema_slow: 10 };
+      expect(() => TrendFollowingParametersSchema.parse(invalidParams)).toThrow();
+    });
+
+    // 测试PSAR加速因子起始值不能大于最大值
+    it('should reject psar acceleration above maximum', () => {
+      const invalidParams = { psar_acceleration: 0.3, psar_max_acceleration: 0.2 };
+      expect(() => TrendFollowingParametersSchema.parse(invalidParams)).toThrow();
+    });
+  });
+
   describe('getStrategyDefinition', () => {
     // 测试获取存在的策略定义
     it('should return strategy definition for valid type', () => {
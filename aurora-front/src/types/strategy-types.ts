@@ -41,6 +41,134 @@ export const MACrossoverParametersSchema = z.object({
 
 export type MACrossoverParameters = z.infer<typeof MACrossoverParametersSchema>;
 
+/**
+ * RSI均值回归策略参数Schema
+ */
+export const RSIReversionParametersSchema = z.object({
+  // RSI周期
+  period: z.number().int().positive('RSI周期必须为正整数'),
+  // 超卖线
+  oversold: z.number().gt(0, '超卖线必须大于0').lt(100, '超卖线必须小于100'),
+  // 超买线
+  overbought: z.number().gt(0, '超买线必须大于0').lt(100, '超买线必须小于100'),
+  // RSI恢复平仓水平
+  exit_level: z.number().optional(),
+  // 时间止损的最长持仓K线数,0表示不启用
+  max_holding: z.number().int().nonnegative('最长持仓K线数不能为负数').optional(),
+  // 趋势过滤器
+  trend_filter: z.enum(['none', 'ema', 'adx']).optional(),
+  // EMA过滤周期
+  ema_period: z.number().int().positive('EMA周期必须为正整数').optional(),
+  // ADX过滤周期
+  adx_period: z.number().int().positive('ADX周期必须为正整数').optional(),
+  // ADX阈值
+  adx_threshold: z.number().min(0, 'ADX阈值不能小于0').max(100, 'ADX阈值不能大于100').optional(),
+}).refine(
+  (data) => data.oversold < data.overbought,
+  {
+    message: '超卖线必须小于超买线',
+    path: ['oversold'],
+  }
+).refine(
+  (data) => data.exit_level === undefined || (data.exit_level > data.oversold && data.exit_level <= data.overbought),
+  {
+    message: '平仓水平必须高于超卖线且不高于超买线',
+    path: ['exit_level'],
+  }
+);
+
+export type RSIReversionParameters = z.infer<typeof RSIReversionParametersSchema>;
+
+/**
+ * 挤压突破策略参数Schema
+ */
+export const SqueezeBreakoutParametersSchema = z.object({
+  // 布林带周期
+  bb_period: z.number().int().positive('布林带周期必须为正整数'),
+  // 布林带标准差倍数
+  bb_std_dev: z.number().positive('标准差倍数必须为正数'),
+  // 肯特纳通道周期
+  kc_period: z.number().int().positive('肯特纳通道周期必须为正整数'),
+  // 肯特纳通道ATR倍数
+  kc_multiplier: z.number().positive('ATR倍数必须为正数'),
+  // 挤压至少持续的K线数
+  min_squeeze: z.number().int().positive('挤压持续K线数必须为正整数').optional(),
+  // 动量确认指标
+  momentum: z.enum(['macd', 'roc']).optional(),
+  // MACD快线周期
+  macd_fast: z.number().int().positive('MACD快线周期必须为正整数').optional(),
+  // MACD慢线周期
+  macd_slow: z.number().int().positive('MACD慢线周期必须为正整数').optional(),
+  // MACD信号线周期
+  macd_signal: z.number().int().positive('MACD信号线周期必须为正整数').optional(),
+  // ROC周期
+  roc_period: z.number().int().positive('ROC周期必须为正整数').optional(),
+  // ATR周期
+  atr_period: z.number().int().positive('ATR周期必须为正整数').optional(),
+  // 止损ATR倍数
+  stop_atr: z.number().positive('止损ATR倍数必须为正数').optional(),
+  // 止盈ATR倍数,0表示不设止盈
+  target_atr: z.number().nonnegative('止盈ATR倍数不能为负数').optional(),
+  // 是否使用跟踪止损
+  trailing: z.boolean().optional(),
+}).refine(
+  (data) => data.momentum === 'roc' || (data.macd_fast ?? 12) < (data.macd_slow ?? 26),
+  {
+    message: 'MACD快线周期必须小于慢线周期',
+    path: ['macd_fast'],
+  }
+);
+
+export type SqueezeBreakoutParameters = z.infer<typeof SqueezeBreakoutParametersSchema>;
+
+/**
+ * 趋势跟踪策略参数Schema
+ */
+export const TrendFollowingParametersSchema = z.object({
+  // 入场交叉信号
+  entry: z.enum(['ema', 'macd']).optional(),
+  // 快速EMA周期
+  ema_fast: z.number().int().positive('快速EMA周期必须为正整数').optional(),
+  // 慢速EMA周期
+  ema_slow: z.number().int().positive('慢速EMA周期必须为正整数').optional(),
+  // MACD快线周期
+  macd_fast: z.number().int().positive('MACD快线周期必须为正整数').optional(),
+  // MACD慢线周期
+  macd_slow: z.number().int().positive('MACD慢线周期必须为正整数').optional(),
+  // MACD信号线周期
+  macd_signal: z.number().int().positive('MACD信号线周期必须为正整数').optional(),
+  // 交叉后仍可入场的K线数
+  cross_lookback: z.number().int().positive('交叉有效K线数必须为正整数').optional(),
+  // 是否使用ADX趋势强度过滤
+  adx_filter: z.boolean().optional(),
+  // ADX周期
+  adx_period: z.number().int().positive('ADX周期必须为正整数').optional(),
+  // PSAR加速因子起始值
+  psar_acceleration: z.number().positive('加速因子必须为正数').max(1, '加速因子不能大于1').optional(),
+  // PSAR加速因子最大值
+  psar_max_acceleration: z.number().positive('加速因子最大值必须为正数').max(1, '加速因子最大值不能大于1').optional(),
+}).refine(
+  (data) => data.entry === 'macd' || (data.ema_fast ?? 10) < (data.ema_slow ?? 30),
+  {
+    message: '快速EMA周期必须小于慢速EMA周期',
+    path: ['ema_fast'],
+  }
+).refine(
+  (data) => data.entry !== 'macd' || (data.macd_fast ?? 12) < (data.macd_slow ?? 26),
+  {
+    message: 'MACD快线周期必须小于慢线周期',
+    path: ['macd_fast'],
+  }
+).refine(
+  (data) => (data.psar_acceleration ?? 0.02) <= (data.psar_max_acceleration ?? 0.2),
+  {
+    message: '加速因子起始值不能大于最大值',
+    path: ['psar_acceleration'],
+  }
+);
+
+export type TrendFollowingParameters = z.infer<typeof TrendFollowingParametersSchema>;
+
 // ==================== 策略类型注册表 ====================
 
 /**
@@ -123,14 +251,366 @@ export const STRATEGY_TYPES: StrategyTypeDefinition[] = [
       },
     ],
   },
-  // 未来可以添加更多策略类型
-  // {
-  //   type: 'rsi-strategy',
-  //   name: 'RSI策略',
-  //   description: '基于相对强弱指标(RSI)的超买超卖信号',
-  //   parametersSchema: RSIParametersSchema,
-  //   fields: [...],
-  // },
+  {
+    type: 'rsi-reversion',
+    name: 'RSI均值回归策略',
+    description: 'RSI进入超卖区时买入,RSI恢复或持仓超时后卖出,可选EMA/ADX趋势过滤',
+    parametersSchema: RSIReversionParametersSchema,
+    fields: [
+      {
+        name: 'period',
+        label: 'RSI周期',
+        type: 'number',
+        defaultValue: 14,
+        placeholder: '2-50',
+        description: '计算RSI的K线数,常用14',
+        min: 1,
+        max: 200,
+        step: 1,
+        required: true,
+      },
+      {
+        name: 'oversold',
+        label: '超卖线',
+        type: 'number',
+        defaultValue: 30,
+        placeholder: '10-40',
+        description: 'RSI低于此值时买入',
+        min: 1,
+        max: 99,
+        step: 1,
+        required: true,
+      },
+      {
+        name: 'overbought',
+        label: '超买线',
+        type: 'number',
+        defaultValue: 70,
+        placeholder: '60-90',
+        description: '未设置平仓水平时,RSI达到此值卖出',
+        min: 1,
+        max: 99,
+        step: 1,
+        required: true,
+      },
+      {
+        name: 'exit_level',
+        label: '平仓水平',
+        type: 'number',
+        defaultValue: 70,
+        placeholder: '70',
+        description: 'RSI回升到此值即平仓,默认与超买线相同,需高于超卖线且不高于超买线',
+        min: 1,
+        max: 99,
+        step: 1,
+      },
+      {
+        name: 'max_holding',
+        label: '最长持仓K线数',
+        type: 'number',
+        defaultValue: 0,
+        placeholder: '0',
+        description: '时间止损,持仓达到该K线数仍未恢复则卖出,0表示不启用',
+        min: 0,
+        step: 1,
+      },
+      {
+        name: 'trend_filter',
+        label: '趋势过滤',
+        type: 'select',
+        defaultValue: 'none',
+        description: 'EMA:收盘价高于EMA才买入;ADX:ADX低于阈值才买入',
+        options: [
+          { label: '不过滤', value: 'none' },
+          { label: 'EMA', value: 'ema' },
+          { label: 'ADX', value: 'adx' },
+        ],
+      },
+      {
+        name: 'ema_period',
+        label: 'EMA周期',
+        type: 'number',
+        defaultValue: 200,
+        description: '趋势过滤为EMA时使用',
+        min: 1,
+        step: 1,
+      },
+      {
+        name: 'adx_period',
+        label: 'ADX周期',
+        type: 'number',
+        defaultValue: 14,
+        description: '趋势过滤为ADX时使用',
+        min: 1,
+        step: 1,
+      },
+      {
+        name: 'adx_threshold',
+        label: 'ADX阈值',
+        type: 'number',
+        defaultValue: 25,
+        description: 'ADX低于此值视为震荡行情,允许买入',
+        min: 0,
+        max: 100,
+        step: 1,
+      },
+    ],
+  },
+  {
+    type: 'squeeze-breakout',
+    name: '挤压突破策略',
+    description: '布林带收缩进肯特纳通道后,按动量方向突破入场,ATR止损止盈',
+    parametersSchema: SqueezeBreakoutParametersSchema,
+    fields: [
+      {
+        name: 'bb_period',
+        label: '布林带周期',
+        type: 'number',
+        defaultValue: 20,
+        placeholder: '10-50',
+        description: '计算布林带的K线数,常用20',
+        min: 1,
+        max: 200,
+        step: 1,
+        required: true,
+      },
+      {
+        name: 'bb_std_dev',
+        label: '布林带标准差倍数',
+        type: 'number',
+        defaultValue: 2,
+        placeholder: '1.5-3',
+        description: '布林带上下轨距中轨的标准差倍数',
+        min: 0.1,
+        max: 5,
+        step: 0.1,
+        required: true,
+      },
+      {
+        name: 'kc_period',
+        label: '肯特纳通道周期',
+        type: 'number',
+        defaultValue: 20,
+        placeholder: '10-50',
+        description: '计算肯特纳通道EMA和ATR的K线数',
+        min: 1,
+        max: 200,
+        step: 1,
+        required: true,
+      },
+      {
+        name: 'kc_multiplier',
+        label: '肯特纳通道ATR倍数',
+        type: 'number',
+        defaultValue: 1.5,
+        placeholder: '1-2',
+        description: '通道上下轨距中轨的ATR倍数',
+        min: 0.1,
+        max: 5,
+        step: 0.1,
+        required: true,
+      },
+      {
+        name: 'min_squeeze',
+        label: '最少挤压K线数',
+        type: 'number',
+        defaultValue: 1,
+        description: '挤压至少持续该K线数后,释放时才允许入场',
+        min: 1,
+        step: 1,
+      },
+      {
+        name: 'momentum',
+        label: '动量确认',
+        type: 'select',
+        defaultValue: 'macd',
+        description: 'MACD:柱状图为正才入场;ROC:变动率为正才入场',
+        options: [
+          { label: 'MACD', value: 'macd' },
+          { label: 'ROC', value: 'roc' },
+        ],
+      },
+      {
+        name: 'macd_fast',
+        label: 'MACD快线周期',
+        type: 'number',
+        defaultValue: 12,
+        description: '动量确认为MACD时使用',
+        min: 1,
+        step: 1,
+      },
+      {
+        name: 'macd_slow',
+        label: 'MACD慢线周期',
+        type: 'number',
+        defaultValue: 26,
+        description: '动量确认为MACD时使用,需大于快线周期',
+        min: 1,
+        step: 1,
+      },
+      {
+        name: 'macd_signal',
+        label: 'MACD信号线周期',
+        type: 'number',
+        defaultValue: 9,
+        description: '动量确认为MACD时使用',
+        min: 1,
+        step: 1,
+      },
+      {
+        name: 'roc_period',
+        label: 'ROC周期',
+        type: 'number',
+        defaultValue: 12,
+        description: '动量确认为ROC时使用',
+        min: 1,
+        step: 1,
+      },
+      {
+        name: 'atr_period',
+        label: 'ATR周期',
+        type: 'number',
+        defaultValue: 14,
+        description: '计算止损止盈距离的ATR周期',
+        min: 1,
+        step: 1,
+      },
+      {
+        name: 'stop_atr',
+        label: '止损ATR倍数',
+        type: 'number',
+        defaultValue: 2,
+        description: '入场价下方该倍数ATR处止损',
+        min: 0.1,
+        step: 0.1,
+      },
+      {
+        name: 'target_atr',
+        label: '止盈ATR倍数',
+        type: 'number',
+        defaultValue: 0,
+        description: '入场价上方该倍数ATR处止盈,0表示不设止盈',
+        min: 0,
+        step: 0.1,
+      },
+      {
+        name: 'trailing',
+        label: '跟踪止损',
+        type: 'checkbox',
+        defaultValue: true,
+        description: '止损随持仓期间的最高收盘价上移',
+      },
+    ],
+  },
+  {
+    type: 'trend-following',
+    name: '趋势跟踪策略',
+    description: 'EMA或MACD交叉入场,ADX确认趋势强度,PSAR翻转时离场',
+    parametersSchema: TrendFollowingParametersSchema,
+    fields: [
+      {
+        name: 'entry',
+        label: '入场信号',
+        type: 'select',
+        defaultValue: 'ema',
+        description: 'EMA:快速EMA上穿慢速EMA;MACD:MACD线上穿信号线',
+        options: [
+          { label: 'EMA交叉', value: 'ema' },
+          { label: 'MACD交叉', value: 'macd' },
+        ],
+      },
+      {
+        name: 'ema_fast',
+        label: '快速EMA周期',
+        type: 'number',
+        defaultValue: 10,
+        description: '入场信号为EMA时使用',
+        min: 1,
+        step: 1,
+      },
+      {
+        name: 'ema_slow',
+        label: '慢速EMA周期',
+        type: 'number',
+        defaultValue: 30,
+        description: '入场信号为EMA时使用,需大于快速周期',
+        min: 2,
+        step: 1,
+      },
+      {
+        name: 'macd_fast',
+        label: 'MACD快线周期',
+        type: 'number',
+        defaultValue: 12,
+        description: '入场信号为MACD时使用',
+        min: 1,
+        step: 1,
+      },
+      {
+        name: 'macd_slow',
+        label: 'MACD慢线周期',
+        type: 'number',
+        defaultValue: 26,
+        description: '入场信号为MACD时使用,需大于快线周期',
+        min: 1,
+        step: 1,
+      },
+      {
+        name: 'macd_signal',
+        label: 'MACD信号线周期',
+        type: 'number',
+        defaultValue: 9,
+        description: '入场信号为MACD时使用',
+        min: 1,
+        step: 1,
+      },
+      {
+        name: 'cross_lookback',
+        label: '交叉有效K线数',
+        type: 'number',
+        defaultValue: 5,
+        description: '交叉发生后该K线数内满足趋势条件仍可入场',
+        min: 1,
+        step: 1,
+      },
+      {
+        name: 'adx_filter',
+        label: 'ADX趋势强度过滤',
+        type: 'checkbox',
+        defaultValue: true,
+        description: 'ADX高于25时才入场',
+      },
+      {
+        name: 'adx_period',
+        label: 'ADX周期',
+        type: 'number',
+        defaultValue: 14,
+        description: '启用ADX过滤时使用',
+        min: 1,
+        step: 1,
+      },
+      {
+        name: 'psar_acceleration',
+        label: 'PSAR加速因子',
+        type: 'number',
+        defaultValue: 0.02,
+        description: 'PSAR加速因子起始值,越大止损跟得越紧',
+        min: 0.001,
+        max: 1,
+        step: 0.01,
+      },
+      {
+        name: 'psar_max_acceleration',
+        label: 'PSAR加速因子最大值',
+        type: 'number',
+        defaultValue: 0.2,
+        description: '加速因子上限,不能小于起始值',
+        min: 0.001,
+        max: 1,
+        step: 0.01,
+      },
+    ],
+  },
 ];
 
 /**
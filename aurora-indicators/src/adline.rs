@@ -52,10 +52,16 @@
 //! println!("当前 A/D Line: {:.2}", result3);
 //! ```
 
+use aurora_core::Kline;
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
+
 /// Accumulation/Distribution Line 指标结构
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ADLine {
     // 状态
     ad_line: f64,  // 当前 A/D Line 值
+    count: usize,  // 已处理的数据点数量
 }
 
 impl ADLine {
@@ -63,6 +69,7 @@ impl ADLine {
     pub fn new() -> Self {
         Self {
             ad_line: 0.0,
+            count: 0,
         }
     }
 
@@ -92,6 +99,7 @@ impl ADLine {
         
         // 累加到 A/D Line
         self.ad_line += mf_volume;
+        self.count += 1;
         
         self.ad_line
     }
@@ -104,6 +112,12 @@ impl ADLine {
     /// 重置指标状态
     pub fn reset(&mut self) {
         self.ad_line = 0.0;
+        self.count = 0;
+    }
+
+    /// 是否已接收过数据
+    pub fn is_ready(&self) -> bool {
+        self.count > 0
     }
 }
 
@@ -113,5 +127,25 @@ impl Default for ADLine {
     }
 }
 
+impl Indicator for ADLine {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        Some(self.update(kline.high, kline.low, kline.close, kline.volume))
+    }
+
+    fn is_ready(&self) -> bool {
+        ADLine::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        ADLine::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        1
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -16,10 +16,12 @@
 //!
 //! ADX衡量趋势的强度，但不判断方向，是趋势策略的重要过滤器。
 
-use crate::EMA;
+use crate::{EMA, Indicator, IndicatorValue};
+use aurora_core::Kline;
+use serde::{Deserialize, Serialize};
 
 /// ADX指标输出
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct ADXOutput {
     /// ADX值 - 趋势强度
     pub adx: f64,
@@ -50,7 +52,7 @@ pub struct ADXOutput {
 /// - ADX < 20: 弱趋势或盘整
 /// - +DI > -DI: 上升趋势
 /// - -DI > +DI: 下降趋势
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ADX {
     /// ADX周期
     period: usize,
@@ -300,5 +302,35 @@ impl ADX {
     }
 }
 
+impl Indicator for ADX {
+    type Output = ADXOutput;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<ADXOutput> {
+        self.update(kline.high, kline.low, kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        ADX::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        ADX::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period + 1
+    }
+}
+
+impl From<ADXOutput> for IndicatorValue {
+    fn from(output: ADXOutput) -> Self {
+        IndicatorValue::from_fields(vec![
+            ("adx", output.adx),
+            ("plus_di", output.plus_di),
+            ("minus_di", output.minus_di),
+        ])
+    }
+}
+
 #[cfg(test)]
 mod tests;
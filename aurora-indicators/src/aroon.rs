@@ -0,0 +1,198 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Aroon (阿隆指标)
+//!
+//! Aroon 由 Tushar Chande 提出，用最近一次最高价/最低价距今的周期数衡量趋势的强弱和新旧：
+//! 刚创出新高时 Aroon Up 为100，新高距今越久数值越低。
+//!
+//! # 计算公式
+//!
+//! - Aroon Up = 100 × (N - 最近N+1根K线中最高价距今的周期数) / N
+//! - Aroon Down = 100 × (N - 最近N+1根K线中最低价距今的周期数) / N
+//! - Aroon Oscillator = Aroon Up - Aroon Down
+//!
+//! 极值出现多次时取最近的一次。
+//!
+//! # 使用场景
+//!
+//! - **趋势识别**: Up 高于70且 Down 低于30表示强势上涨，反之表示强势下跌
+//! - **趋势反转**: Up 与 Down 交叉可能预示趋势变化
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::Aroon;
+//!
+//! let mut aroon = Aroon::new(5);
+//!
+//! for i in 0..5 {
+//!     assert!(aroon.update(100.0 + i as f64, 90.0 + i as f64).is_none());
+//! }
+//! let output = aroon.update(105.0, 95.0).unwrap();
+//! assert_eq!(output.up, 100.0);
+//! assert_eq!(output.down, 0.0);
+//! ```
+
+use aurora_core::Kline;
+use crate::{Indicator, IndicatorValue};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Aroon 输出结构
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AroonOutput {
+    /// Aroon Up (0到100)
+    pub up: f64,
+    /// Aroon Down (0到100)
+    pub down: f64,
+    /// Aroon Oscillator = Up - Down (-100到100)
+    pub oscillator: f64,
+}
+
+/// Aroon 阿隆指标
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Aroon {
+    /// 计算周期
+    period: usize,
+    /// 最近 N + 1 个最高价
+    highs: VecDeque<f64>,
+    /// 最近 N + 1 个最低价
+    lows: VecDeque<f64>,
+}
+
+impl Aroon {
+    /// 创建新的Aroon指标
+    ///
+    /// # 参数
+    ///
+    /// * `period` - 计算周期，通常使用25
+    ///
+    /// # Panics
+    ///
+    /// 当 `period` 为 0 时会 panic
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "Aroon周期必须大于0");
+
+        Self {
+            period,
+            highs: VecDeque::with_capacity(period + 1),
+            lows: VecDeque::with_capacity(period + 1),
+        }
+    }
+
+    /// 更新指标并计算新的Aroon值
+    ///
+    /// # 参数
+    ///
+    /// * `high` - 最高价
+    /// * `low` - 最低价
+    ///
+    /// # 返回值
+    ///
+    /// - `Some(AroonOutput)` - 已有 N + 1 个数据点时返回Aroon值
+    /// - `None` - 数据不足
+    pub fn update(&mut self, high: f64, low: f64) -> Option<AroonOutput> {
+        self.highs.push_back(high);
+        self.lows.push_back(low);
+        if self.highs.len() > self.period + 1 {
+            self.highs.pop_front();
+            self.lows.pop_front();
+        }
+        if !self.is_ready() {
+            return None;
+        }
+
+        let up = self.score(Self::bars_since(&self.highs, |a, b| a >= b));
+        let down = self.score(Self::bars_since(&self.lows, |a, b| a <= b));
+        Some(AroonOutput {
+            up,
+            down,
+            oscillator: up - down,
+        })
+    }
+
+    /// 最近一次极值距今的周期数
+    ///
+    /// `better(a, b)` 为 true 表示 a 不劣于 b，相等时取较新的值
+    fn bars_since(values: &VecDeque<f64>, better: impl Fn(f64, f64) -> bool) -> usize {
+        let mut best = 0;
+        for (i, &value) in values.iter().enumerate() {
+            if better(value, values[best]) {
+                best = i;
+            }
+        }
+        values.len() - 1 - best
+    }
+
+    fn score(&self, bars_since: usize) -> f64 {
+        100.0 * (self.period - bars_since) as f64 / self.period as f64
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.highs.clear();
+        self.lows.clear();
+    }
+
+    /// 获取周期设置
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// 检查是否已准备好输出结果
+    pub fn is_ready(&self) -> bool {
+        self.highs.len() == self.period + 1
+    }
+}
+
+// 默认参数: 25周期
+impl Default for Aroon {
+    fn default() -> Self {
+        Self::new(25)
+    }
+}
+
+impl Indicator for Aroon {
+    type Output = AroonOutput;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<AroonOutput> {
+        self.update(kline.high, kline.low)
+    }
+
+    fn is_ready(&self) -> bool {
+        Aroon::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        Aroon::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period + 1
+    }
+}
+
+impl From<AroonOutput> for IndicatorValue {
+    fn from(output: AroonOutput) -> Self {
+        IndicatorValue::from_fields(vec![
+            ("oscillator", output.oscillator),
+            ("up", output.up),
+            ("down", output.down),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests;
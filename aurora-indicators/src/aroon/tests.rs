@@ -0,0 +1,158 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::test_data::{ATR_HIGHS, ATR_LOWS};
+use approx::assert_relative_eq;
+
+#[test]
+fn test_aroon_new() {
+    let aroon = Aroon::new(25);
+    assert_eq!(aroon.period(), 25);
+    assert!(!aroon.is_ready());
+    assert_eq!(Aroon::default().period(), 25);
+}
+
+#[test]
+#[should_panic(expected = "Aroon周期必须大于0")]
+fn test_aroon_zero_period() {
+    Aroon::new(0);
+}
+
+#[test]
+fn test_aroon_insufficient_data() {
+    let mut aroon = Aroon::new(5);
+    for _ in 0..5 {
+        assert!(aroon.update(110.0, 90.0).is_none());
+    }
+    assert!(aroon.update(110.0, 90.0).is_some());
+}
+
+/// 参考值：StockCharts ChartSchool ATR示例表（cs-atr.xls）的QQQ最高价和最低价的14日Aroon，
+/// 窗口为最近15根K线，Up/Down = 100 × (14 - 距最高/最低价的K线数) / 14。
+/// 第17根K线的最高价50.65之后不再创新高，Aroon Up每根K线下降100/14；第23到25根K线连续创新低，Aroon Down为100
+#[test]
+fn test_aroon_reference_values() {
+    let mut aroon = Aroon::new(14);
+    let outputs: Vec<_> = ATR_HIGHS
+        .iter()
+        .zip(ATR_LOWS.iter())
+        .map(|(&high, &low)| aroon.update(high, low))
+        .collect();
+
+    assert!(outputs[..14].iter().all(Option::is_none));
+    let expected = [
+        (14, 100.0, 0.0),
+        (16, 100.0, 100.0 * 2.0 / 14.0),
+        (17, 100.0 * 13.0 / 14.0, 100.0 / 14.0),
+        (22, 100.0 * 8.0 / 14.0, 100.0),
+        (25, 100.0 * 5.0 / 14.0, 100.0 * 13.0 / 14.0),
+        (29, 100.0 / 14.0, 100.0 * 9.0 / 14.0),
+    ];
+    for (index, up, down) in expected {
+        let output = outputs[index].unwrap();
+        assert_relative_eq!(output.up, up, epsilon = 1e-10);
+        assert_relative_eq!(output.down, down, epsilon = 1e-10);
+    }
+}
+
+/// 周期5逐项计算：
+/// 最高价 10, 12, 11, 9, 8, 7：最高价12距今4个周期，Up = 100 × (5 - 4) / 5 = 20
+/// 最低价 9, 8, 7, 6, 5, 4：最低价4就在当前，Down = 100
+#[test]
+fn test_aroon_worked_example() {
+    let mut aroon = Aroon::new(5);
+    let highs = [10.0, 12.0, 11.0, 9.0, 8.0, 7.0];
+    let lows = [9.0, 8.0, 7.0, 6.0, 5.0, 4.0];
+
+    let mut result = None;
+    for (high, low) in highs.iter().zip(lows.iter()) {
+        result = aroon.update(*high, *low);
+    }
+
+    let output = result.unwrap();
+    assert_eq!(output.up, 20.0);
+    assert_eq!(output.down, 100.0);
+    assert_eq!(output.oscillator, -80.0);
+}
+
+#[test]
+fn test_aroon_oldest_extreme() {
+    let mut aroon = Aroon::new(4);
+    // 最高价在窗口最旧的位置，最低价在窗口最新的位置
+    let data = [(20.0, 15.0), (18.0, 14.0), (17.0, 13.0), (16.0, 12.0), (15.0, 10.0)];
+
+    let mut result = None;
+    for (high, low) in data {
+        result = aroon.update(high, low);
+    }
+
+    let output = result.unwrap();
+    assert_eq!(output.up, 0.0);
+    assert_eq!(output.down, 100.0);
+}
+
+#[test]
+fn test_aroon_ties_use_most_recent() {
+    let mut aroon = Aroon::new(3);
+    let mut result = None;
+    for _ in 0..4 {
+        result = aroon.update(10.0, 5.0);
+    }
+
+    let output = result.unwrap();
+    assert_eq!(output.up, 100.0);
+    assert_eq!(output.down, 100.0);
+    assert_eq!(output.oscillator, 0.0);
+}
+
+#[test]
+fn test_aroon_sliding_window() {
+    let mut aroon = Aroon::new(2);
+    aroon.update(10.0, 5.0);
+    aroon.update(12.0, 6.0);
+    // 窗口: 高 10, 12, 11 → 最高价距今1个周期
+    let output = aroon.update(11.0, 7.0).unwrap();
+    assert_eq!(output.up, 50.0);
+    assert_eq!(output.down, 0.0);
+
+    // 窗口: 高 12, 11, 9 → 最高价距今2个周期
+    let output = aroon.update(9.0, 4.0).unwrap();
+    assert_eq!(output.up, 0.0);
+    assert_eq!(output.down, 100.0);
+}
+
+#[test]
+fn test_aroon_indicator_value() {
+    let value = IndicatorValue::from(AroonOutput {
+        up: 80.0,
+        down: 20.0,
+        oscillator: 60.0,
+    });
+    assert_eq!(value.primary(), 60.0);
+    assert_eq!(value.get("up"), Some(80.0));
+}
+
+#[test]
+fn test_aroon_reset() {
+    let mut aroon = Aroon::new(2);
+    for _ in 0..3 {
+        aroon.update(10.0, 5.0);
+    }
+    assert!(aroon.is_ready());
+
+    aroon.reset();
+    assert!(!aroon.is_ready());
+    assert!(aroon.update(10.0, 5.0).is_none());
+}
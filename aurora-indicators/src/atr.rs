@@ -16,7 +16,9 @@
 //!
 //! ATR衡量市场波动程度，不考虑价格方向。
 
-use crate::EMA;
+use crate::{EMA, Indicator};
+use aurora_core::Kline;
+use serde::{Deserialize, Serialize};
 
 /// ATR (Average True Range) 指标
 ///
@@ -36,7 +38,7 @@ use crate::EMA;
 /// - 衡量市场波动程度
 /// - 设置止损位置
 /// - 调整仓位大小
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ATR {
     /// ATR周期
     period: usize,
@@ -157,5 +159,25 @@ impl ATR {
     }
 }
 
+impl Indicator for ATR {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.high, kline.low, kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        ATR::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        ATR::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        1
+    }
+}
+
 #[cfg(test)]
 mod tests;
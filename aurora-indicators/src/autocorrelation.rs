@@ -0,0 +1,148 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autocorrelation - 自相关系数
+//!
+//! 计算序列与其滞后 k 期自身的相关程度：正值说明序列有延续性（动量），
+//! 负值说明序列倾向于反转（均值回归）。
+//!
+//! # 计算公式
+//!
+//! ρ(k) = Σ(xₜ - μ)(xₜ₋ₖ - μ) / Σ(xₜ - μ)²
+//!
+//! 其中 μ 为N周期均值，分子对窗口内所有相隔k期的数据对求和。
+//! 序列在窗口内不变时为0。价格序列的自相关几乎总是接近1，
+//! 判断动量或均值回归时一般传入收益率（例如用 [`ROC`](crate::ROC) 串联）。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::Autocorrelation;
+//!
+//! let mut acf = Autocorrelation::new(4, 1);
+//! for value in [1.0, -1.0, 1.0] {
+//!     assert!(acf.update(value).is_none());
+//! }
+//!
+//! // 正负交替的序列强烈负相关
+//! assert_eq!(acf.update(-1.0), Some(-0.75));
+//! ```
+
+use aurora_core::Kline;
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Autocorrelation 自相关系数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Autocorrelation {
+    /// 计算周期
+    period: usize,
+    /// 滞后期数
+    lag: usize,
+    /// 历史数据
+    values: VecDeque<f64>,
+}
+
+impl Autocorrelation {
+    /// 创建新的自相关系数实例
+    ///
+    /// # 参数
+    ///
+    /// * `period` - 计算周期
+    /// * `lag` - 滞后期数,通常使用1
+    ///
+    /// # Panics
+    ///
+    /// 当 `lag` 为 0 或不小于 `period` 时会 panic
+    pub fn new(period: usize, lag: usize) -> Self {
+        assert!(lag > 0 && lag < period, "滞后期数必须大于0且小于周期");
+
+        Self {
+            period,
+            lag,
+            values: VecDeque::with_capacity(period),
+        }
+    }
+
+    /// 更新指标并计算新的自相关系数
+    ///
+    /// # 返回值
+    ///
+    /// - `Some(f64)` - 累积了 period 个数据点后返回自相关系数
+    /// - `None` - 数据不足
+    pub fn update(&mut self, value: f64) -> Option<f64> {
+        self.values.push_back(value);
+        if self.values.len() > self.period {
+            self.values.pop_front();
+        }
+        if !self.is_ready() {
+            return None;
+        }
+
+        let mean = self.values.iter().sum::<f64>() / self.period as f64;
+        let denominator: f64 = self.values.iter().map(|v| (v - mean).powi(2)).sum();
+        if denominator == 0.0 {
+            return Some(0.0);
+        }
+
+        let numerator: f64 = (self.lag..self.period)
+            .map(|t| (self.values[t] - mean) * (self.values[t - self.lag] - mean))
+            .sum();
+        Some(numerator / denominator)
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.values.clear();
+    }
+
+    /// 获取周期设置
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// 获取滞后期数
+    pub fn lag(&self) -> usize {
+        self.lag
+    }
+
+    /// 检查是否已准备好输出结果
+    pub fn is_ready(&self) -> bool {
+        self.values.len() == self.period
+    }
+}
+
+impl Indicator for Autocorrelation {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        Autocorrelation::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        Autocorrelation::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests;
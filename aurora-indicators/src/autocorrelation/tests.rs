@@ -0,0 +1,64 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use approx::assert_relative_eq;
+
+#[test]
+#[should_panic(expected = "滞后期数必须大于0且小于周期")]
+fn test_autocorrelation_lag_too_large() {
+    Autocorrelation::new(5, 5);
+}
+
+#[test]
+#[should_panic(expected = "滞后期数必须大于0且小于周期")]
+fn test_autocorrelation_zero_lag() {
+    Autocorrelation::new(5, 0);
+}
+
+/// 参考值（按定义手工计算）：x = [1, 2, 3, 4]，μ = 2.5，分子1.25，分母5
+#[test]
+fn test_autocorrelation_trend() {
+    let mut acf = Autocorrelation::new(4, 1);
+    assert_eq!(acf.period(), 4);
+    assert_eq!(acf.lag(), 1);
+    for value in [1.0, 2.0, 3.0] {
+        assert!(acf.update(value).is_none());
+    }
+    assert_relative_eq!(acf.update(4.0).unwrap(), 0.25, epsilon = 1e-12);
+}
+
+/// 参考值（按定义手工计算）：x = [1, -1, 1, -1]，滞后2期完全同向
+#[test]
+fn test_autocorrelation_lag_two() {
+    let mut acf = Autocorrelation::new(4, 2);
+    let mut last = None;
+    for value in [1.0, -1.0, 1.0, -1.0] {
+        last = acf.update(value);
+    }
+    // 分子 = 1 + 1 = 2，分母 = 4
+    assert_relative_eq!(last.unwrap(), 0.5, epsilon = 1e-12);
+}
+
+#[test]
+fn test_autocorrelation_constant_and_reset() {
+    let mut acf = Autocorrelation::new(3, 1);
+    for _ in 0..3 {
+        acf.update(7.0);
+    }
+    assert_eq!(acf.update(7.0), Some(0.0));
+
+    acf.reset();
+    assert!(!acf.is_ready());
+    assert!(acf.update(1.0).is_none());
+}
@@ -0,0 +1,348 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 批量指标计算
+//!
+//! 对整段价格序列或K线序列一次性计算指标，返回与输入等长的输出序列，
+//! 供研究脚本和向量化回测引擎使用。
+//!
+//! 批量函数内部逐点调用各指标的流式 `update`，因此结果与流式计算逐元素相同，
+//! 两种用法共享同一份实现：
+//!
+//! - 单值指标返回 `Array1<f64>`，预热阶段为 `NaN`
+//! - 多值指标返回 `Vec<Option<输出>>`，预热阶段为 `None`，可用 [`field`] 取出某一条线
+//! - 只依赖收盘价的指标接受 `Array1<f64>`，其余指标接受 `&[Kline]`
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::batch;
+//! use ndarray::Array1;
+//!
+//! let closes = Array1::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+//! let sma = batch::sma(&closes, 3);
+//!
+//! assert!(sma[1].is_nan());
+//! assert_eq!(sma[2], 2.0);
+//! assert_eq!(sma[4], 4.0);
+//!
+//! let macd = batch::macd(&closes, 2, 4, 2);
+//! let histogram = batch::field(&macd, |output| output.histogram);
+//! assert_eq!(histogram.len(), 5);
+//! ```
+
+use crate::{
+    ADLine, ADX, ADXOutput, ATR, Aroon, AroonOutput, Autocorrelation, Beta, BollingerBands,
+    BollingerBandsOutput, CCI, CMF, Correlation, DEMA, DonchianChannels, DonchianChannelsOutput, EMA,
+    HMA, Hurst, Ichimoku, IchimokuOutput, Indicator, KAMA, KeltnerChannels, KeltnerChannelsOutput,
+    LinearRegression, LinearRegressionOutput, MA, MACD, MACDOutput, MFI, OBV, PSAR, PSAROutput, ROC,
+    RSI, StdDev, Stochastic, StochasticOutput, Supertrend, SupertrendOutput, TEMA, TRIX, VWAP,
+    VolumeProfile, VolumeProfileOutput, WMA, WilliamsR, ZLEMA, ZScore,
+};
+use aurora_core::Kline;
+use ndarray::Array1;
+
+/// 对K线序列批量计算任意指标
+///
+/// 指标从当前状态继续计算，通常传入新创建的指标。
+pub fn compute<I: Indicator>(mut indicator: I, klines: &[Kline]) -> Vec<Option<I::Output>> {
+    klines.iter().map(|kline| indicator.update_kline(kline)).collect()
+}
+
+/// 把单值输出序列转换为数组，`None` 转换为 `NaN`
+pub fn to_array(series: &[Option<f64>]) -> Array1<f64> {
+    series.iter().map(|value| value.unwrap_or(f64::NAN)).collect()
+}
+
+/// 取多值输出序列中的一条线，`None` 转换为 `NaN`
+pub fn field<T, F: Fn(&T) -> f64>(series: &[Option<T>], select: F) -> Array1<f64> {
+    series
+        .iter()
+        .map(|output| output.as_ref().map_or(f64::NAN, &select))
+        .collect()
+}
+
+/// 从K线序列提取收盘价
+pub fn closes(klines: &[Kline]) -> Array1<f64> {
+    klines.iter().map(|kline| kline.close).collect()
+}
+
+/// 逐点计算价格序列
+fn over_prices<T>(prices: &Array1<f64>, mut update: impl FnMut(f64) -> Option<T>) -> Vec<Option<T>> {
+    prices.iter().map(|&price| update(price)).collect()
+}
+
+/// 逐点计算单值的价格序列
+fn price_series(prices: &Array1<f64>, update: impl FnMut(f64) -> Option<f64>) -> Array1<f64> {
+    to_array(&over_prices(prices, update))
+}
+
+/// 逐点计算单值的K线序列
+fn kline_series<I: Indicator<Output = f64>>(indicator: I, klines: &[Kline]) -> Array1<f64> {
+    to_array(&compute(indicator, klines))
+}
+
+/// 简单移动平均
+pub fn sma(prices: &Array1<f64>, period: usize) -> Array1<f64> {
+    let mut ma = MA::new(period);
+    price_series(prices, |price| ma.update(price))
+}
+
+/// 指数移动平均
+pub fn ema(prices: &Array1<f64>, period: usize) -> Array1<f64> {
+    let mut ema = EMA::new(period);
+    price_series(prices, |price| Some(ema.update(price)))
+}
+
+/// 相对强弱指数
+pub fn rsi(prices: &Array1<f64>, period: usize) -> Array1<f64> {
+    let mut rsi = RSI::new(period);
+    price_series(prices, |price| rsi.update(price))
+}
+
+/// 变动率
+pub fn roc(prices: &Array1<f64>, period: usize) -> Array1<f64> {
+    let mut roc = ROC::new(period);
+    price_series(prices, |price| roc.update(price))
+}
+
+/// 标准差
+pub fn stddev(prices: &Array1<f64>, period: usize) -> Array1<f64> {
+    let mut stddev = StdDev::new(period);
+    price_series(prices, |price| stddev.update(price))
+}
+
+/// 布林带
+pub fn bollinger(
+    prices: &Array1<f64>,
+    period: usize,
+    std_dev_multiplier: f64,
+) -> Vec<Option<BollingerBandsOutput>> {
+    let mut bands = BollingerBands::new(period, std_dev_multiplier);
+    over_prices(prices, |price| bands.update(price))
+}
+
+/// MACD
+pub fn macd(
+    prices: &Array1<f64>,
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+) -> Vec<Option<MACDOutput>> {
+    let mut macd = MACD::new(fast_period, slow_period, signal_period);
+    over_prices(prices, |price| Some(macd.update(price)))
+}
+
+/// 加权移动平均
+pub fn wma(prices: &Array1<f64>, period: usize) -> Array1<f64> {
+    let mut wma = WMA::new(period);
+    price_series(prices, |price| wma.update(price))
+}
+
+/// 双重指数移动平均
+pub fn dema(prices: &Array1<f64>, period: usize) -> Array1<f64> {
+    let mut dema = DEMA::new(period);
+    price_series(prices, |price| Some(dema.update(price)))
+}
+
+/// 三重指数移动平均
+pub fn tema(prices: &Array1<f64>, period: usize) -> Array1<f64> {
+    let mut tema = TEMA::new(period);
+    price_series(prices, |price| Some(tema.update(price)))
+}
+
+/// 赫尔移动平均
+pub fn hma(prices: &Array1<f64>, period: usize) -> Array1<f64> {
+    let mut hma = HMA::new(period);
+    price_series(prices, |price| hma.update(price))
+}
+
+/// 考夫曼自适应移动平均
+pub fn kama(prices: &Array1<f64>, period: usize, fast_period: usize, slow_period: usize) -> Array1<f64> {
+    let mut kama = KAMA::new(period, fast_period, slow_period);
+    price_series(prices, |price| kama.update(price))
+}
+
+/// 零滞后指数移动平均
+pub fn zlema(prices: &Array1<f64>, period: usize) -> Array1<f64> {
+    let mut zlema = ZLEMA::new(period);
+    price_series(prices, |price| zlema.update(price))
+}
+
+/// 三重指数平滑变动率
+pub fn trix(prices: &Array1<f64>, period: usize) -> Array1<f64> {
+    let mut trix = TRIX::new(period);
+    price_series(prices, |price| trix.update(price))
+}
+
+/// 标准分数
+pub fn zscore(prices: &Array1<f64>, period: usize) -> Array1<f64> {
+    let mut zscore = ZScore::new(period);
+    price_series(prices, |price| zscore.update(price))
+}
+
+/// 线性回归
+pub fn linreg(prices: &Array1<f64>, period: usize) -> Vec<Option<LinearRegressionOutput>> {
+    let mut linreg = LinearRegression::new(period);
+    over_prices(prices, |price| linreg.update(price))
+}
+
+/// 自相关系数
+pub fn autocorrelation(values: &Array1<f64>, period: usize, lag: usize) -> Array1<f64> {
+    let mut acf = Autocorrelation::new(period, lag);
+    price_series(values, |value| acf.update(value))
+}
+
+/// 赫斯特指数
+pub fn hurst(prices: &Array1<f64>, period: usize) -> Array1<f64> {
+    let mut hurst = Hurst::new(period);
+    price_series(prices, |price| hurst.update(price))
+}
+
+/// 两个序列的滚动相关系数
+///
+/// # Panics
+///
+/// 两个序列长度不同时会 panic
+pub fn correlation(x: &Array1<f64>, y: &Array1<f64>, period: usize) -> Array1<f64> {
+    assert_eq!(x.len(), y.len(), "两个序列长度必须相同");
+    let mut correlation = Correlation::new(period);
+    x.iter().zip(y.iter()).map(|(&x, &y)| correlation.update(x, y).unwrap_or(f64::NAN)).collect()
+}
+
+/// 资产相对基准的滚动贝塔系数
+///
+/// # Panics
+///
+/// 两个序列长度不同时会 panic
+pub fn beta(asset: &Array1<f64>, benchmark: &Array1<f64>, period: usize) -> Array1<f64> {
+    assert_eq!(asset.len(), benchmark.len(), "两个序列长度必须相同");
+    let mut beta = Beta::new(period);
+    asset
+        .iter()
+        .zip(benchmark.iter())
+        .map(|(&asset, &benchmark)| beta.update(asset, benchmark).unwrap_or(f64::NAN))
+        .collect()
+}
+
+/// 平均真实波幅
+pub fn atr(klines: &[Kline], period: usize) -> Array1<f64> {
+    kline_series(ATR::new(period), klines)
+}
+
+/// 随机震荡指标
+pub fn stochastic(klines: &[Kline], k_period: usize, d_period: usize) -> Vec<Option<StochasticOutput>> {
+    compute(Stochastic::new(k_period, d_period), klines)
+}
+
+/// 能量潮
+pub fn obv(klines: &[Kline]) -> Array1<f64> {
+    kline_series(OBV::new(), klines)
+}
+
+/// 平均动向指数
+pub fn adx(klines: &[Kline], period: usize) -> Vec<Option<ADXOutput>> {
+    compute(ADX::new(period), klines)
+}
+
+/// 商品通道指数
+pub fn cci(klines: &[Kline], period: usize) -> Array1<f64> {
+    kline_series(CCI::new(period), klines)
+}
+
+/// 威廉指标
+pub fn williams_r(klines: &[Kline], period: usize) -> Array1<f64> {
+    kline_series(WilliamsR::new(period), klines)
+}
+
+/// 肯特纳通道
+pub fn keltner(klines: &[Kline], period: usize, multiplier: f64) -> Vec<Option<KeltnerChannelsOutput>> {
+    compute(KeltnerChannels::new(period, multiplier), klines)
+}
+
+/// 资金流量指数
+pub fn mfi(klines: &[Kline], period: usize) -> Array1<f64> {
+    kline_series(MFI::new(period), klines)
+}
+
+/// 成交量加权平均价，`period` 为0时为累积模式
+pub fn vwap(klines: &[Kline], period: usize) -> Array1<f64> {
+    kline_series(VWAP::new(period), klines)
+}
+
+/// 抛物线转向指标
+pub fn psar(klines: &[Kline], acceleration: f64, max_acceleration: f64) -> Vec<Option<PSAROutput>> {
+    compute(PSAR::new(acceleration, max_acceleration), klines)
+}
+
+/// 一目均衡表
+pub fn ichimoku(
+    klines: &[Kline],
+    tenkan_period: usize,
+    kijun_period: usize,
+    senkou_b_period: usize,
+) -> Vec<Option<IchimokuOutput>> {
+    compute(Ichimoku::new(tenkan_period, kijun_period, senkou_b_period), klines)
+}
+
+/// 佳庆资金流
+pub fn cmf(klines: &[Kline], period: usize) -> Array1<f64> {
+    kline_series(CMF::new(period), klines)
+}
+
+/// 累积/派发线
+pub fn adline(klines: &[Kline]) -> Array1<f64> {
+    kline_series(ADLine::new(), klines)
+}
+
+/// 阿隆指标
+pub fn aroon(klines: &[Kline], period: usize) -> Vec<Option<AroonOutput>> {
+    compute(Aroon::new(period), klines)
+}
+
+/// 唐奇安通道
+pub fn donchian(klines: &[Kline], period: usize) -> Vec<Option<DonchianChannelsOutput>> {
+    compute(DonchianChannels::new(period), klines)
+}
+
+/// 超级趋势
+pub fn supertrend(klines: &[Kline], period: usize, multiplier: f64) -> Vec<Option<SupertrendOutput>> {
+    compute(Supertrend::new(period, multiplier), klines)
+}
+
+/// 滚动窗口的成交量分布
+pub fn volume_profile(klines: &[Kline], period: usize, bucket_count: usize) -> Vec<Option<VolumeProfileOutput>> {
+    compute(VolumeProfile::new(period, bucket_count), klines)
+}
+
+/// 按交易时段统计的成交量分布，`session_ms` 为时段长度(毫秒)
+pub fn session_volume_profile(
+    klines: &[Kline],
+    session_ms: i64,
+    bucket_count: usize,
+) -> Vec<Option<VolumeProfileOutput>> {
+    compute(VolumeProfile::session(session_ms, bucket_count), klines)
+}
+
+/// 对整段K线一次性计算成交量分布，K线为空时返回 `None`
+pub fn volume_profile_summary(
+    klines: &[Kline],
+    bucket_count: usize,
+    value_area: f64,
+) -> Option<VolumeProfileOutput> {
+    VolumeProfileOutput::from_klines(klines, bucket_count, value_area)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,280 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use approx::assert_relative_eq;
+
+fn klines(count: usize) -> Vec<Kline> {
+    (0..count)
+        .map(|i| {
+            // 带波动的上涨序列，涨跌交替，保证所有指标都能产生输出
+            let close = 100.0 + i as f64 * 0.5 + if i % 3 == 0 { 2.0 } else { -1.0 };
+            Kline {
+                timestamp: i as i64 * 60_000,
+                open: close - 0.5,
+                high: close + 1.5 + (i % 4) as f64 * 0.25,
+                low: close - 1.5,
+                close,
+                volume: 100.0 + (i % 7) as f64 * 10.0,
+                symbol: None,
+                interval: None,
+            }
+        })
+        .collect()
+}
+
+/// 逐元素比较单值序列，`None` 对应 `NaN`
+fn assert_series_eq(batch: &Array1<f64>, streaming: &[Option<f64>]) {
+    assert_eq!(batch.len(), streaming.len());
+    for (i, (value, expected)) in batch.iter().zip(streaming).enumerate() {
+        match expected {
+            Some(expected) => assert_relative_eq!(*value, *expected, epsilon = 1e-12),
+            None => assert!(value.is_nan(), "第 {} 个元素应为 NaN，实际为 {}", i, value),
+        }
+    }
+}
+
+/// 测试价格序列指标的批量输出与流式输出一致
+#[test]
+fn test_price_indicators_match_streaming() {
+    let data = klines(80);
+    let prices = closes(&data);
+
+    let mut ma = MA::new(10);
+    let expected: Vec<_> = prices.iter().map(|&p| ma.update(p)).collect();
+    assert_series_eq(&sma(&prices, 10), &expected);
+
+    let mut ema_indicator = EMA::new(10);
+    let expected: Vec<_> = prices.iter().map(|&p| Some(ema_indicator.update(p))).collect();
+    assert_series_eq(&ema(&prices, 10), &expected);
+
+    let mut rsi_indicator = RSI::new(14);
+    let expected: Vec<_> = prices.iter().map(|&p| rsi_indicator.update(p)).collect();
+    assert_series_eq(&rsi(&prices, 14), &expected);
+
+    let mut roc_indicator = ROC::new(12);
+    let expected: Vec<_> = prices.iter().map(|&p| roc_indicator.update(p)).collect();
+    assert_series_eq(&roc(&prices, 12), &expected);
+
+    let mut stddev_indicator = StdDev::new(20);
+    let expected: Vec<_> = prices.iter().map(|&p| stddev_indicator.update(p)).collect();
+    assert_series_eq(&stddev(&prices, 20), &expected);
+
+    let mut bands = BollingerBands::new(20, 2.0);
+    let expected: Vec<_> = prices.iter().map(|&p| bands.update(p)).collect();
+    assert_eq!(bollinger(&prices, 20, 2.0), expected);
+
+    let mut wma_indicator = WMA::new(10);
+    let expected: Vec<_> = prices.iter().map(|&p| wma_indicator.update(p)).collect();
+    assert_series_eq(&wma(&prices, 10), &expected);
+
+    let mut dema_indicator = DEMA::new(10);
+    let expected: Vec<_> = prices.iter().map(|&p| Some(dema_indicator.update(p))).collect();
+    assert_series_eq(&dema(&prices, 10), &expected);
+
+    let mut tema_indicator = TEMA::new(10);
+    let expected: Vec<_> = prices.iter().map(|&p| Some(tema_indicator.update(p))).collect();
+    assert_series_eq(&tema(&prices, 10), &expected);
+
+    let mut hma_indicator = HMA::new(9);
+    let expected: Vec<_> = prices.iter().map(|&p| hma_indicator.update(p)).collect();
+    assert_series_eq(&hma(&prices, 9), &expected);
+
+    let mut kama_indicator = KAMA::new(10, 2, 30);
+    let expected: Vec<_> = prices.iter().map(|&p| kama_indicator.update(p)).collect();
+    assert_series_eq(&kama(&prices, 10, 2, 30), &expected);
+
+    let mut zlema_indicator = ZLEMA::new(10);
+    let expected: Vec<_> = prices.iter().map(|&p| zlema_indicator.update(p)).collect();
+    assert_series_eq(&zlema(&prices, 10), &expected);
+
+    let mut trix_indicator = TRIX::new(15);
+    let expected: Vec<_> = prices.iter().map(|&p| trix_indicator.update(p)).collect();
+    assert_series_eq(&trix(&prices, 15), &expected);
+
+    let mut macd_indicator = MACD::new(12, 26, 9);
+    let expected: Vec<_> = prices.iter().map(|&p| Some(macd_indicator.update(p))).collect();
+    assert_eq!(macd(&prices, 12, 26, 9), expected);
+
+    let mut zscore_indicator = ZScore::new(20);
+    let expected: Vec<_> = prices.iter().map(|&p| zscore_indicator.update(p)).collect();
+    assert_series_eq(&zscore(&prices, 20), &expected);
+
+    let mut linreg_indicator = LinearRegression::new(20);
+    let expected: Vec<_> = prices.iter().map(|&p| linreg_indicator.update(p)).collect();
+    assert_eq!(linreg(&prices, 20), expected);
+
+    let mut acf = Autocorrelation::new(20, 2);
+    let expected: Vec<_> = prices.iter().map(|&p| acf.update(p)).collect();
+    assert_series_eq(&autocorrelation(&prices, 20, 2), &expected);
+
+    let mut hurst_indicator = Hurst::new(32);
+    let expected: Vec<_> = prices.iter().map(|&p| hurst_indicator.update(p)).collect();
+    assert_series_eq(&hurst(&prices, 32), &expected);
+}
+
+/// 测试两个序列的指标与流式输出一致
+#[test]
+fn test_pair_indicators_match_streaming() {
+    let data = klines(60);
+    let x = closes(&data);
+    let y: Array1<f64> = data.iter().map(|k| k.volume).collect();
+
+    let mut correlation_indicator = Correlation::new(10);
+    let expected: Vec<_> = x.iter().zip(y.iter()).map(|(&a, &b)| correlation_indicator.update(a, b)).collect();
+    assert_series_eq(&correlation(&x, &y, 10), &expected);
+
+    let mut beta_indicator = Beta::new(10);
+    let expected: Vec<_> = x.iter().zip(y.iter()).map(|(&a, &b)| beta_indicator.update(a, b)).collect();
+    assert_series_eq(&beta(&x, &y, 10), &expected);
+}
+
+/// 测试两个序列长度不同
+#[test]
+#[should_panic(expected = "两个序列长度必须相同")]
+fn test_pair_indicators_length_mismatch() {
+    correlation(&Array1::zeros(3), &Array1::zeros(4), 2);
+}
+
+/// 测试K线序列指标的批量输出与流式输出一致
+#[test]
+fn test_kline_indicators_match_streaming() {
+    let data = klines(120);
+
+    let mut atr_indicator = ATR::new(14);
+    let expected: Vec<_> = data.iter().map(|k| atr_indicator.update(k.high, k.low, k.close)).collect();
+    assert_series_eq(&atr(&data, 14), &expected);
+
+    let mut obv_indicator = OBV::new();
+    let expected: Vec<_> = data.iter().map(|k| Some(obv_indicator.update(k.close, k.volume))).collect();
+    assert_series_eq(&obv(&data), &expected);
+
+    let mut cci_indicator = CCI::new(20);
+    let expected: Vec<_> = data.iter().map(|k| cci_indicator.update(k.high, k.low, k.close)).collect();
+    assert_series_eq(&cci(&data, 20), &expected);
+
+    let mut williams = WilliamsR::new(14);
+    let expected: Vec<_> = data.iter().map(|k| williams.update(k.high, k.low, k.close)).collect();
+    assert_series_eq(&williams_r(&data, 14), &expected);
+
+    let mut mfi_indicator = MFI::new(14);
+    let expected: Vec<_> = data
+        .iter()
+        .map(|k| mfi_indicator.update(k.high, k.low, k.close, k.volume))
+        .collect();
+    assert_series_eq(&mfi(&data, 14), &expected);
+
+    for period in [0, 10] {
+        let mut vwap_indicator = VWAP::new(period);
+        let expected: Vec<_> = data
+            .iter()
+            .map(|k| vwap_indicator.update(k.high, k.low, k.close, k.volume))
+            .collect();
+        assert_series_eq(&vwap(&data, period), &expected);
+    }
+
+    let mut cmf_indicator = CMF::new(20);
+    let expected: Vec<_> = data
+        .iter()
+        .map(|k| cmf_indicator.update(k.high, k.low, k.close, k.volume))
+        .collect();
+    assert_series_eq(&cmf(&data, 20), &expected);
+
+    let mut adline_indicator = ADLine::new();
+    let expected: Vec<_> = data
+        .iter()
+        .map(|k| Some(adline_indicator.update(k.high, k.low, k.close, k.volume)))
+        .collect();
+    assert_series_eq(&adline(&data), &expected);
+
+    let mut stochastic_indicator = Stochastic::new(14, 3);
+    let expected: Vec<_> = data.iter().map(|k| stochastic_indicator.update(k.high, k.low, k.close)).collect();
+    assert_eq!(stochastic(&data, 14, 3), expected);
+
+    let mut adx_indicator = ADX::new(14);
+    let expected: Vec<_> = data.iter().map(|k| adx_indicator.update(k.high, k.low, k.close)).collect();
+    assert_eq!(adx(&data, 14), expected);
+
+    let mut channels = KeltnerChannels::new(20, 2.0);
+    let expected: Vec<_> = data.iter().map(|k| channels.update(k.high, k.low, k.close)).collect();
+    assert_eq!(keltner(&data, 20, 2.0), expected);
+
+    let mut psar_indicator = PSAR::new(0.02, 0.2);
+    let expected: Vec<_> = data.iter().map(|k| psar_indicator.update(k.high, k.low, k.close)).collect();
+    assert_eq!(psar(&data, 0.02, 0.2), expected);
+
+    let mut ichimoku_indicator = Ichimoku::new(9, 26, 52);
+    let expected: Vec<_> = data.iter().map(|k| ichimoku_indicator.update(k.high, k.low, k.close)).collect();
+    assert_eq!(ichimoku(&data, 9, 26, 52), expected);
+
+    let mut aroon_indicator = Aroon::new(25);
+    let expected: Vec<_> = data.iter().map(|k| aroon_indicator.update(k.high, k.low)).collect();
+    assert_eq!(aroon(&data, 25), expected);
+
+    let mut channels = DonchianChannels::new(20);
+    let expected: Vec<_> = data.iter().map(|k| channels.update(k.high, k.low)).collect();
+    assert_eq!(donchian(&data, 20), expected);
+
+    let mut supertrend_indicator = Supertrend::new(10, 3.0);
+    let expected: Vec<_> = data.iter().map(|k| supertrend_indicator.update(k.high, k.low, k.close)).collect();
+    assert_eq!(supertrend(&data, 10, 3.0), expected);
+
+    let mut profile = VolumeProfile::new(20, 12);
+    let expected: Vec<_> = data.iter().map(|k| profile.update(k)).collect();
+    assert_eq!(volume_profile(&data, 20, 12), expected);
+
+    let mut profile = VolumeProfile::session(10 * 60_000, 12);
+    let expected: Vec<_> = data.iter().map(|k| profile.update(k)).collect();
+    assert_eq!(session_volume_profile(&data, 10 * 60_000, 12), expected);
+}
+
+/// 测试整段数据的成交量分布与覆盖全部数据的滚动窗口一致
+#[test]
+fn test_volume_profile_summary() {
+    let data = klines(50);
+
+    let summary = volume_profile_summary(&data, 12, 0.7).unwrap();
+    let rolling = volume_profile(&data, 50, 12).pop().unwrap().unwrap();
+    assert_eq!(summary, rolling);
+    assert!(volume_profile_summary(&[], 12, 0.7).is_none());
+}
+
+/// 测试输出长度与输入一致，预热阶段为 NaN
+#[test]
+fn test_sma_warm_up_and_values() {
+    let prices = Array1::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+    let result = sma(&prices, 3);
+
+    assert_eq!(result.len(), 5);
+    assert!(result[0].is_nan());
+    assert!(result[1].is_nan());
+    assert_eq!(result[2], 2.0);
+    assert_eq!(result[3], 3.0);
+    assert_eq!(result[4], 4.0);
+
+    assert_eq!(sma(&Array1::from_vec(vec![]), 3).len(), 0);
+}
+
+/// 测试泛型计算与多值输出的字段提取
+#[test]
+fn test_compute_and_field() {
+    let data = klines(40);
+    let outputs = compute(BollingerBands::new(10, 2.0), &data);
+    let upper = field(&outputs, |output| output.upper);
+
+    assert_eq!(outputs.len(), data.len());
+    assert!(upper[8].is_nan());
+    assert_eq!(upper[9], outputs[9].as_ref().unwrap().upper);
+    assert_eq!(to_array(&[None, Some(1.0)])[1], 1.0);
+}
@@ -0,0 +1,111 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Beta - 滚动贝塔系数
+//!
+//! 计算资产序列对基准序列最近N个值的回归斜率，衡量资产随基准变动的幅度。
+//!
+//! # 计算公式
+//!
+//! β = Cov(资产, 基准) / Var(基准)
+//!
+//! 基准在窗口内不变时 β 为0。
+//!
+//! # 使用场景
+//!
+//! - **市场风险**: 传入资产和市场指数的收益率，得到市场Beta
+//! - **配对交易**: 传入两个资产的价格，得到对冲比率，价差 = 资产 - β × 基准
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::Beta;
+//!
+//! let mut beta = Beta::new(3);
+//! beta.update(0.02, 0.01);
+//! beta.update(-0.04, -0.02);
+//! let value = beta.update(0.06, 0.03).unwrap();
+//! assert!((value - 2.0).abs() < 1e-9);
+//! ```
+
+use crate::correlation::PairWindow;
+use serde::{Deserialize, Serialize};
+
+/// Beta 滚动贝塔系数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Beta {
+    /// 资产和基准的滚动窗口
+    window: PairWindow,
+}
+
+impl Beta {
+    /// 创建新的滚动贝塔系数实例
+    ///
+    /// # 参数
+    ///
+    /// * `period` - 计算周期,至少为2
+    ///
+    /// # Panics
+    ///
+    /// 当 `period` 小于 2 时会 panic
+    pub fn new(period: usize) -> Self {
+        assert!(period > 1, "Beta周期必须大于1");
+
+        Self {
+            window: PairWindow::new(period),
+        }
+    }
+
+    /// 更新指标并计算新的贝塔系数
+    ///
+    /// # 参数
+    ///
+    /// * `asset` - 资产序列的新值
+    /// * `benchmark` - 基准序列的新值
+    ///
+    /// # 返回值
+    ///
+    /// - `Some(f64)` - 累积了 period 对数据后返回贝塔系数
+    /// - `None` - 数据不足
+    pub fn update(&mut self, asset: f64, benchmark: f64) -> Option<f64> {
+        self.window.push(benchmark, asset);
+        if !self.is_ready() {
+            return None;
+        }
+
+        let (cov, var_benchmark, _) = self.window.moments();
+        if var_benchmark == 0.0 {
+            return Some(0.0);
+        }
+        Some(cov / var_benchmark)
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.window.clear();
+    }
+
+    /// 获取周期设置
+    pub fn period(&self) -> usize {
+        self.window.period()
+    }
+
+    /// 检查是否已准备好输出结果
+    pub fn is_ready(&self) -> bool {
+        self.window.is_full()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,60 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use approx::assert_relative_eq;
+
+#[test]
+#[should_panic(expected = "Beta周期必须大于1")]
+fn test_beta_period_too_small() {
+    Beta::new(1);
+}
+
+/// 参考值（按定义手工计算）：基准 [1, 2, 3]，资产 [1, 3, 2]，Cov = 1/3，Var = 2/3
+#[test]
+fn test_beta_values() {
+    let mut beta = Beta::new(3);
+    assert_eq!(beta.period(), 3);
+    assert!(beta.update(1.0, 1.0).is_none());
+    assert!(beta.update(3.0, 2.0).is_none());
+    assert_relative_eq!(beta.update(2.0, 3.0).unwrap(), 0.5, epsilon = 1e-12);
+}
+
+/// 测试线性关系的斜率不受截距影响
+#[test]
+fn test_beta_hedge_ratio() {
+    let mut beta = Beta::new(5);
+    let mut last = None;
+    for benchmark in [100.0, 102.0, 99.0, 105.0, 103.0] {
+        last = beta.update(50.0 - 1.5 * benchmark, benchmark);
+    }
+    assert_relative_eq!(last.unwrap(), -1.5, epsilon = 1e-9);
+}
+
+#[test]
+fn test_beta_constant_benchmark() {
+    let mut beta = Beta::new(2);
+    beta.update(1.0, 3.0);
+    assert_eq!(beta.update(2.0, 3.0), Some(0.0));
+}
+
+#[test]
+fn test_beta_reset() {
+    let mut beta = Beta::new(2);
+    beta.update(1.0, 1.0);
+    beta.update(2.0, 2.0);
+    assert!(beta.is_ready());
+
+    beta.reset();
+    assert!(!beta.is_ready());
+}
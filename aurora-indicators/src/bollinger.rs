@@ -16,11 +16,13 @@
 //!
 //! 布林带由中轨、上轨和下轨组成，用于衡量价格的波动范围。
 
-use crate::MA;
+use crate::{Indicator, IndicatorValue, MA};
+use aurora_core::Kline;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 /// 布林带指标的输出
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct BollingerBandsOutput {
     /// 上轨 (Upper Band)
     pub upper: f64,
@@ -52,7 +54,7 @@ pub struct BollingerBandsOutput {
 /// # 内存复杂度
 ///
 /// 空间复杂度为O(N)，其中N是周期长度
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BollingerBands {
     /// 周期长度
     period: usize,
@@ -227,5 +229,35 @@ impl BollingerBands {
     }
 }
 
+impl Indicator for BollingerBands {
+    type Output = BollingerBandsOutput;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<BollingerBandsOutput> {
+        self.update(kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        BollingerBands::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        BollingerBands::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period
+    }
+}
+
+impl From<BollingerBandsOutput> for IndicatorValue {
+    fn from(output: BollingerBandsOutput) -> Self {
+        IndicatorValue::from_fields(vec![
+            ("middle", output.middle),
+            ("upper", output.upper),
+            ("lower", output.lower),
+        ])
+    }
+}
+
 #[cfg(test)]
 mod tests;
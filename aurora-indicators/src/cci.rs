@@ -43,11 +43,15 @@
 //! let result = cci.update(105.0, 95.0, 100.0);
 //! ```
 
+use aurora_core::Kline;
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 /// CCI (Commodity Channel Index) 商品通道指数
 ///
 /// 衡量当前价格相对于统计平均值的偏离程度
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CCI {
     /// 计算周期
     period: usize,
@@ -209,5 +213,25 @@ impl CCI {
     }
 }
 
+impl Indicator for CCI {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.high, kline.low, kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        CCI::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        CCI::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -59,9 +59,13 @@
 //! }
 //! ```
 
+use aurora_core::Kline;
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 /// Chaikin Money Flow 指标结构
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CMF {
     // 参数
     period: usize,
@@ -149,6 +153,11 @@ impl CMF {
     pub fn period(&self) -> usize {
         self.period
     }
+
+    /// 是否已积累足够的数据
+    pub fn is_ready(&self) -> bool {
+        self.money_flow_volumes.len() >= self.period
+    }
 }
 
 impl Default for CMF {
@@ -160,5 +169,25 @@ impl Default for CMF {
     }
 }
 
+impl Indicator for CMF {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.high, kline.low, kline.close, kline.volume)
+    }
+
+    fn is_ready(&self) -> bool {
+        CMF::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        CMF::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period
+    }
+}
+
 #[cfg(test)]
 mod tests;
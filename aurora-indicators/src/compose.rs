@@ -0,0 +1,181 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 指标组合
+//!
+//! 把一个指标的输出作为另一个指标的输入，例如EMA的RSI、OBV的布林带：
+//!
+//! - [`Chain`] 把前一个指标的单值输出构造成K线（开高低收均为该值，时间戳和成交量沿用原K线）
+//!   传给后一个指标
+//! - [`Select`] 从多值输出中选出一条线，使MACD、布林带等也能作为链的输入
+//!
+//! 通常通过 [`Indicator::then`] 和 [`Indicator::select`] 构造。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_core::Kline;
+//! use aurora_indicators::{BollingerBands, EMA, Indicator, MACD, OBV, RSI};
+//!
+//! // EMA的RSI
+//! let mut rsi_of_ema = EMA::new(10).then(RSI::new(14));
+//! assert_eq!(rsi_of_ema.warm_up_period(), 2);
+//!
+//! // OBV的布林带
+//! let obv_bands = OBV::new().then(BollingerBands::new(20, 2.0));
+//! assert_eq!(obv_bands.warm_up_period(), 20);
+//!
+//! // MACD柱状图的EMA
+//! let smoothed = MACD::new(12, 26, 9)
+//!     .select(|output| output.histogram)
+//!     .then(EMA::new(5));
+//! assert_eq!(smoothed.warm_up_period(), 1);
+//!
+//! let kline = Kline {
+//!     timestamp: 0,
+//!     open: 100.0,
+//!     high: 100.0,
+//!     low: 100.0,
+//!     close: 100.0,
+//!     volume: 1.0,
+//!     symbol: None,
+//!     interval: None,
+//! };
+//! assert_eq!(rsi_of_ema.update_kline(&kline), None);
+//! ```
+
+use crate::Indicator;
+use aurora_core::Kline;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 指标链
+///
+/// 前一个指标产生输出后，以该值构造K线更新后一个指标；
+/// 前一个指标处于预热阶段时，后一个指标不更新。
+///
+/// 后一个指标看到的K线开高低收相同，因此依赖高低价区间的指标（如ATR）
+/// 在链中的含义会退化，适合放在链尾的是只依赖收盘价或成交量的指标。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Chain<A, B> {
+    /// 输入指标
+    source: A,
+    /// 以输入指标的输出为价格的指标
+    target: B,
+}
+
+impl<A, B> Chain<A, B>
+where
+    A: Indicator<Output = f64>,
+    B: Indicator,
+{
+    /// 创建指标链
+    pub fn new(source: A, target: B) -> Self {
+        Self { source, target }
+    }
+
+    /// 输入指标
+    pub fn source(&self) -> &A {
+        &self.source
+    }
+
+    /// 链尾指标
+    pub fn target(&self) -> &B {
+        &self.target
+    }
+}
+
+impl<A, B> Indicator for Chain<A, B>
+where
+    A: Indicator<Output = f64>,
+    B: Indicator,
+{
+    type Output = B::Output;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<B::Output> {
+        let value = self.source.update_kline(kline)?;
+        let derived = Kline {
+            open: value,
+            high: value,
+            low: value,
+            close: value,
+            ..kline.clone()
+        };
+        self.target.update_kline(&derived)
+    }
+
+    fn is_ready(&self) -> bool {
+        self.target.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.source.reset();
+        self.target.reset();
+    }
+
+    fn warm_up_period(&self) -> usize {
+        // 输入指标第一次输出时，链尾指标收到第一个值
+        self.source.warm_up_period() + self.target.warm_up_period() - 1
+    }
+}
+
+/// 从多值输出中选出一条线
+#[derive(Clone)]
+pub struct Select<I: Indicator> {
+    /// 被选择的指标
+    inner: I,
+    /// 从输出中取值
+    select: fn(&I::Output) -> f64,
+}
+
+impl<I: Indicator> Select<I> {
+    /// 创建选择器
+    pub fn new(inner: I, select: fn(&I::Output) -> f64) -> Self {
+        Self { inner, select }
+    }
+
+    /// 被选择的指标
+    pub fn inner(&self) -> &I {
+        &self.inner
+    }
+}
+
+impl<I: Indicator> fmt::Debug for Select<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Select").field("inner", &self.inner).finish()
+    }
+}
+
+impl<I: Indicator> Indicator for Select<I> {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.inner.update_kline(kline).map(|output| (self.select)(&output))
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.inner.warm_up_period()
+    }
+}
+
+#[cfg(test)]
+mod tests;
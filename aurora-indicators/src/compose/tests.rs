@@ -0,0 +1,101 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{BollingerBands, EMA, MACD, OBV, RSI};
+use approx::assert_relative_eq;
+
+fn kline(close: f64, volume: f64) -> Kline {
+    Kline {
+        timestamp: 0,
+        open: close,
+        high: close + 1.0,
+        low: close - 1.0,
+        close,
+        volume,
+        symbol: None,
+        interval: None,
+    }
+}
+
+fn closes() -> Vec<f64> {
+    (0..60).map(|i| 100.0 + (i as f64 * 0.7).sin() * 5.0 + i as f64 * 0.2).collect()
+}
+
+/// 测试EMA的RSI与手动串联计算一致
+#[test]
+fn test_chain_matches_manual_composition() {
+    let mut chain = EMA::new(5).then(RSI::new(14));
+    let mut ema = EMA::new(5);
+    let mut rsi = RSI::new(14);
+
+    for close in closes() {
+        let expected = rsi.update(ema.update(close));
+        let actual = chain.update_kline(&kline(close, 1.0));
+
+        match expected {
+            Some(expected) => assert_relative_eq!(actual.unwrap(), expected),
+            None => assert!(actual.is_none()),
+        }
+    }
+}
+
+/// 测试OBV的布林带及预热长度
+#[test]
+fn test_chain_warm_up_period() {
+    let mut chain = OBV::new().then(BollingerBands::new(5, 2.0));
+    assert_eq!(chain.warm_up_period(), 5);
+
+    for i in 0..5 {
+        let output = chain.update_kline(&kline(100.0 + i as f64, 10.0));
+        assert_eq!(output.is_some(), i == 4);
+    }
+    assert!(chain.is_ready());
+
+    chain.reset();
+    assert!(!chain.is_ready());
+    assert_eq!(chain.update_kline(&kline(100.0, 10.0)), None);
+}
+
+/// 测试预热阶段的输入指标不会推进链尾指标
+#[test]
+fn test_chain_skips_source_warm_up() {
+    let mut chain = crate::MA::new(3).then(crate::MA::new(2));
+    assert_eq!(chain.warm_up_period(), 4);
+
+    let outputs: Vec<_> = [1.0, 2.0, 3.0, 4.0, 5.0]
+        .iter()
+        .map(|&close| chain.update_kline(&kline(close, 1.0)))
+        .collect();
+
+    // MA3: -, -, 2, 3, 4；MA2(MA3): -, -, -, 2.5, 3.5
+    assert_eq!(outputs, vec![None, None, None, Some(2.5), Some(3.5)]);
+}
+
+/// 测试从多值输出中选择一条线再串联
+#[test]
+fn test_select_then_chain() {
+    let mut selected = MACD::new(3, 6, 3).select(|output| output.histogram);
+    let mut smoothed = MACD::new(3, 6, 3).select(|output| output.histogram).then(EMA::new(3));
+    let mut macd = MACD::new(3, 6, 3);
+    let mut ema = EMA::new(3);
+
+    for close in closes() {
+        let histogram = macd.update(close).histogram;
+        let k = kline(close, 1.0);
+
+        assert_eq!(selected.update_kline(&k), Some(histogram));
+        assert_relative_eq!(smoothed.update_kline(&k).unwrap(), ema.update(histogram));
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Correlation - 滚动相关系数
+//!
+//! 计算两个序列最近N个值的皮尔逊相关系数，取值 -1 到 1，
+//! 用于筛选配对交易标的、监控对冲关系是否失效。
+//!
+//! # 计算公式
+//!
+//! ρ = Cov(X, Y) / (σx × σy)
+//!
+//! 任一序列在窗口内不变时相关系数为0。价格序列通常高度相关，
+//! 判断资产联动时一般传入收益率。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::Correlation;
+//!
+//! let mut correlation = Correlation::new(3);
+//! correlation.update(1.0, 10.0);
+//! correlation.update(2.0, 20.0);
+//! assert_eq!(correlation.update(3.0, 30.0), Some(1.0));
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// 两个序列的滚动窗口
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct PairWindow {
+    /// 计算周期
+    period: usize,
+    /// 成对的历史数据
+    pairs: VecDeque<(f64, f64)>,
+}
+
+impl PairWindow {
+    pub(crate) fn new(period: usize) -> Self {
+        Self {
+            period,
+            pairs: VecDeque::with_capacity(period),
+        }
+    }
+
+    pub(crate) fn push(&mut self, x: f64, y: f64) {
+        self.pairs.push_back((x, y));
+        if self.pairs.len() > self.period {
+            self.pairs.pop_front();
+        }
+    }
+
+    /// 总体协方差和两个序列的总体方差 (Cov(X, Y), Var(X), Var(Y))
+    pub(crate) fn moments(&self) -> (f64, f64, f64) {
+        let n = self.pairs.len() as f64;
+        let (sum_x, sum_y) = self
+            .pairs
+            .iter()
+            .fold((0.0, 0.0), |(sx, sy), &(x, y)| (sx + x, sy + y));
+        let (mean_x, mean_y) = (sum_x / n, sum_y / n);
+
+        let (cov, var_x, var_y) = self.pairs.iter().fold((0.0, 0.0, 0.0), |(c, vx, vy), &(x, y)| {
+            let (dx, dy) = (x - mean_x, y - mean_y);
+            (c + dx * dy, vx + dx * dx, vy + dy * dy)
+        });
+        (cov / n, var_x / n, var_y / n)
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.pairs.clear();
+    }
+
+    pub(crate) fn period(&self) -> usize {
+        self.period
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.pairs.len() == self.period
+    }
+}
+
+/// Correlation 滚动相关系数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Correlation {
+    /// 两个序列的滚动窗口
+    window: PairWindow,
+}
+
+impl Correlation {
+    /// 创建新的滚动相关系数实例
+    ///
+    /// # 参数
+    ///
+    /// * `period` - 计算周期,至少为2
+    ///
+    /// # Panics
+    ///
+    /// 当 `period` 小于 2 时会 panic
+    pub fn new(period: usize) -> Self {
+        assert!(period > 1, "相关系数周期必须大于1");
+
+        Self {
+            window: PairWindow::new(period),
+        }
+    }
+
+    /// 更新指标并计算新的相关系数
+    ///
+    /// # 参数
+    ///
+    /// * `x` - 第一个序列的新值
+    /// * `y` - 第二个序列的新值
+    ///
+    /// # 返回值
+    ///
+    /// - `Some(f64)` - 累积了 period 对数据后返回相关系数
+    /// - `None` - 数据不足
+    pub fn update(&mut self, x: f64, y: f64) -> Option<f64> {
+        self.window.push(x, y);
+        if !self.is_ready() {
+            return None;
+        }
+
+        let (cov, var_x, var_y) = self.window.moments();
+        if var_x == 0.0 || var_y == 0.0 {
+            return Some(0.0);
+        }
+        Some((cov / (var_x * var_y).sqrt()).clamp(-1.0, 1.0))
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.window.clear();
+    }
+
+    /// 获取周期设置
+    pub fn period(&self) -> usize {
+        self.window.period()
+    }
+
+    /// 检查是否已准备好输出结果
+    pub fn is_ready(&self) -> bool {
+        self.window.is_full()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,81 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use approx::assert_relative_eq;
+
+#[test]
+#[should_panic(expected = "相关系数周期必须大于1")]
+fn test_correlation_period_too_small() {
+    Correlation::new(1);
+}
+
+#[test]
+fn test_perfect_correlation() {
+    let mut positive = Correlation::new(4);
+    let mut negative = Correlation::new(4);
+    let mut last = (None, None);
+    for x in [1.0, 2.0, 3.0, 4.0] {
+        last = (positive.update(x, 2.0 * x), negative.update(x, 10.0 - 2.0 * x));
+    }
+
+    assert_relative_eq!(last.0.unwrap(), 1.0, epsilon = 1e-12);
+    assert_relative_eq!(last.1.unwrap(), -1.0, epsilon = 1e-12);
+}
+
+/// 参考值（按定义手工计算）：x = [1, 2, 3], y = [1, 3, 2]，Cov = 1/3，Var = 2/3
+#[test]
+fn test_correlation_values() {
+    let mut correlation = Correlation::new(3);
+    assert_eq!(correlation.period(), 3);
+    assert!(correlation.update(1.0, 1.0).is_none());
+    assert!(correlation.update(2.0, 3.0).is_none());
+    assert_relative_eq!(correlation.update(3.0, 2.0).unwrap(), 0.5, epsilon = 1e-12);
+
+    // 窗口滑动为 x = [2, 3, 4], y = [3, 2, 1]
+    assert_relative_eq!(correlation.update(4.0, 1.0).unwrap(), -1.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_correlation_constant_series() {
+    let mut correlation = Correlation::new(3);
+    for x in [1.0, 2.0, 3.0] {
+        correlation.update(x, 5.0);
+    }
+    assert_eq!(correlation.update(4.0, 5.0), Some(0.0));
+}
+
+#[test]
+fn test_correlation_reset() {
+    let mut correlation = Correlation::new(2);
+    correlation.update(1.0, 1.0);
+    correlation.update(2.0, 2.0);
+    assert!(correlation.is_ready());
+
+    correlation.reset();
+    assert!(!correlation.is_ready());
+    assert!(correlation.update(1.0, 1.0).is_none());
+}
+
+#[test]
+fn test_pair_window_moments() {
+    let mut window = PairWindow::new(3);
+    window.push(1.0, 1.0);
+    window.push(2.0, 3.0);
+    window.push(3.0, 2.0);
+
+    let (cov, var_x, var_y) = window.moments();
+    assert_relative_eq!(cov, 1.0 / 3.0, epsilon = 1e-12);
+    assert_relative_eq!(var_x, 2.0 / 3.0, epsilon = 1e-12);
+    assert_relative_eq!(var_y, 2.0 / 3.0, epsilon = 1e-12);
+}
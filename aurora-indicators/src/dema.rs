@@ -0,0 +1,125 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 双重指数移动平均线 (Double Exponential Moving Average) 指标
+//!
+//! DEMA 由 Patrick Mulloy 提出，用EMA及其二次平滑的差值抵消EMA的滞后。
+//!
+//! # 计算公式
+//!
+//! - EMA1 = EMA(价格, N)
+//! - EMA2 = EMA(EMA1, N)
+//! - DEMA = 2 × EMA1 - EMA2
+//!
+//! 与 [`EMA`] 一样以第一个价格作为初始值，从第一个数据点开始输出。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::DEMA;
+//!
+//! let mut dema = DEMA::new(3);
+//!
+//! assert_eq!(dema.update(1.0), 1.0);
+//! assert_eq!(dema.update(2.0), 1.75);
+//! assert_eq!(dema.update(3.0), 2.75);
+//! ```
+
+use aurora_core::Kline;
+use crate::{EMA, Indicator};
+use serde::{Deserialize, Serialize};
+
+/// 双重指数移动平均线 (Double Exponential Moving Average) 指标
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DEMA {
+    /// 价格的EMA
+    ema1: EMA,
+    /// EMA1的EMA
+    ema2: EMA,
+}
+
+impl DEMA {
+    /// 创建新的DEMA指标
+    ///
+    /// # 参数
+    ///
+    /// * `period` - EMA周期，必须大于0
+    ///
+    /// # Panics
+    ///
+    /// 如果周期为0，函数会panic
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "DEMA周期必须大于0");
+
+        Self {
+            ema1: EMA::new(period),
+            ema2: EMA::new(period),
+        }
+    }
+
+    /// 更新指标并返回最新的DEMA值
+    ///
+    /// # 参数
+    ///
+    /// * `price` - 新的价格数据
+    pub fn update(&mut self, price: f64) -> f64 {
+        let ema1 = self.ema1.update(price);
+        let ema2 = self.ema2.update(ema1);
+        2.0 * ema1 - ema2
+    }
+
+    /// 获取当前的DEMA值
+    pub fn value(&self) -> Option<f64> {
+        Some(2.0 * self.ema1.value()? - self.ema2.value()?)
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.ema1.reset();
+        self.ema2.reset();
+    }
+
+    /// 获取周期设置
+    pub fn period(&self) -> usize {
+        self.ema1.period()
+    }
+
+    /// 检查指标是否已有数据
+    pub fn is_ready(&self) -> bool {
+        self.ema2.is_ready()
+    }
+}
+
+impl Indicator for DEMA {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        Some(self.update(kline.close))
+    }
+
+    fn is_ready(&self) -> bool {
+        DEMA::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        DEMA::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,103 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::test_data::MOVAVG_CLOSES;
+use approx::assert_relative_eq;
+
+#[test]
+fn test_dema_new() {
+    let dema = DEMA::new(10);
+    assert_eq!(dema.period(), 10);
+    assert!(!dema.is_ready());
+    assert_eq!(dema.value(), None);
+}
+
+#[test]
+#[should_panic(expected = "DEMA周期必须大于0")]
+fn test_dema_zero_period() {
+    DEMA::new(0);
+}
+
+/// 参考值：StockCharts ChartSchool 移动平均线示例表（cs-movavg.xls）的30日收盘价的10日DEMA = 2 × EMA - EMA(EMA)，取最后8个值（保留四位小数）。
+/// EMA以第一个价格为初值，与公布的10日EMA的一致性见 `test_ema_matches_stockcharts`
+#[test]
+fn test_dema_reference_values() {
+    let mut dema = DEMA::new(10);
+    let outputs: Vec<f64> = MOVAVG_CLOSES.iter().map(|&price| dema.update(price)).collect();
+
+    let expected = [23.9280, 23.7424, 23.5701, 23.5153, 23.2576, 23.2048, 22.9347, 22.6601];
+    for (output, expected) in outputs[22..].iter().zip(expected) {
+        assert_relative_eq!(*output, expected, epsilon = 1e-4);
+    }
+}
+
+/// 周期3（α = 0.5）逐步计算：
+/// EMA1: 1, 1.5, 2.25, 3.125
+/// EMA2: 1, 1.25, 1.75, 2.4375
+/// DEMA: 1, 1.75, 2.75, 3.8125
+#[test]
+fn test_dema_worked_example() {
+    let mut dema = DEMA::new(3);
+    let outputs: Vec<f64> = [1.0, 2.0, 3.0, 4.0].iter().map(|&p| dema.update(p)).collect();
+
+    assert_eq!(outputs, vec![1.0, 1.75, 2.75, 3.8125]);
+    assert_eq!(dema.value(), Some(3.8125));
+}
+
+#[test]
+fn test_dema_less_lag_than_ema() {
+    let mut dema = DEMA::new(10);
+    let mut ema = EMA::new(10);
+    let mut last = (0.0, 0.0);
+
+    for i in 0..50 {
+        let price = 100.0 + i as f64;
+        last = (dema.update(price), ema.update(price));
+    }
+
+    let price = 149.0;
+    assert!((price - last.0).abs() < (price - last.1).abs());
+}
+
+#[test]
+fn test_dema_tracks_linear_trend() {
+    // 线性趋势下DEMA的一阶滞后被完全抵消
+    let mut dema = DEMA::new(5);
+    let mut result = 0.0;
+    for i in 0..300 {
+        result = dema.update(10.0 + 2.0 * i as f64);
+    }
+    assert_relative_eq!(result, 10.0 + 2.0 * 299.0, epsilon = 1e-6);
+}
+
+#[test]
+fn test_dema_constant_prices() {
+    let mut dema = DEMA::new(7);
+    for _ in 0..20 {
+        assert_relative_eq!(dema.update(50.0), 50.0);
+    }
+}
+
+#[test]
+fn test_dema_reset() {
+    let mut dema = DEMA::new(3);
+    dema.update(1.0);
+    dema.update(2.0);
+    assert!(dema.is_ready());
+
+    dema.reset();
+    assert!(!dema.is_ready());
+    assert_eq!(dema.update(5.0), 5.0);
+}
@@ -0,0 +1,259 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 背离识别
+//!
+//! 比较价格的相邻两个波段高点（或低点）与震荡指标在同一根K线上的值：
+//!
+//! | 类型 | 价格 | 指标 | 含义 |
+//! |------|------|------|------|
+//! | 常规看跌 | 更高的高点 | 更低的高点 | 上涨动能衰竭，可能反转 |
+//! | 隐藏看跌 | 更低的高点 | 更高的高点 | 下跌趋势延续 |
+//! | 常规看涨 | 更低的低点 | 更高的低点 | 下跌动能衰竭，可能反转 |
+//! | 隐藏看涨 | 更高的低点 | 更低的低点 | 上涨趋势延续 |
+//!
+//! 波段高低点用 [`Fractals`] 识别，需要等待右侧N根K线确认，
+//! 因此背离在第二个波段点之后第N根K线上输出。
+//! 震荡指标可以是任意单值指标，多值指标用 [`Indicator::select`] 选出一条线。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::{DivergenceDetector, Indicator, MACD, RSI};
+//!
+//! // RSI背离，分形两侧各3根K线
+//! let rsi_divergence = DivergenceDetector::new(RSI::new(14), 3);
+//!
+//! // MACD柱状图背离，两个波段点最多相隔40根K线
+//! let macd_divergence =
+//!     DivergenceDetector::new(MACD::new(12, 26, 9).select(|output| output.histogram), 3)
+//!         .with_max_distance(40);
+//! assert_eq!(macd_divergence.max_distance(), 40);
+//! ```
+
+use aurora_core::Kline;
+use crate::{Fractals, Indicator, PatternDirection, SwingKind};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// 背离类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DivergenceKind {
+    /// 常规看涨背离
+    RegularBullish,
+    /// 常规看跌背离
+    RegularBearish,
+    /// 隐藏看涨背离
+    HiddenBullish,
+    /// 隐藏看跌背离
+    HiddenBearish,
+}
+
+impl DivergenceKind {
+    /// 背离方向
+    pub fn direction(&self) -> PatternDirection {
+        match self {
+            DivergenceKind::RegularBullish | DivergenceKind::HiddenBullish => PatternDirection::Bullish,
+            DivergenceKind::RegularBearish | DivergenceKind::HiddenBearish => PatternDirection::Bearish,
+        }
+    }
+
+    /// 是否为隐藏背离
+    pub fn is_hidden(&self) -> bool {
+        matches!(self, DivergenceKind::HiddenBullish | DivergenceKind::HiddenBearish)
+    }
+}
+
+/// 参与比较的波段点
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DivergencePoint {
+    /// 波段点所在K线的时间戳
+    pub timestamp: i64,
+    /// 波段点价格
+    pub price: f64,
+    /// 同一根K线上的指标值
+    pub oscillator: f64,
+}
+
+/// 识别出的背离
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Divergence {
+    /// 背离类型
+    pub kind: DivergenceKind,
+    /// 前一个波段点
+    pub previous: DivergencePoint,
+    /// 后一个波段点
+    pub current: DivergencePoint,
+    /// 确认背离的K线时间戳
+    pub confirmed_at: i64,
+}
+
+/// 已处理K线的时间戳和指标值
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Bar {
+    timestamp: i64,
+    oscillator: Option<f64>,
+}
+
+/// 背离识别器
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DivergenceDetector<I> {
+    /// 震荡指标
+    oscillator: I,
+    /// 价格波段识别
+    fractals: Fractals,
+    /// 两个波段点之间的最大K线数
+    max_distance: usize,
+    /// 最近 N+1 根K线，最早的一根是待确认的波段点
+    bars: VecDeque<Bar>,
+    /// 最近的波段高点及其K线序号
+    last_high: Option<(usize, DivergencePoint)>,
+    /// 最近的波段低点及其K线序号
+    last_low: Option<(usize, DivergencePoint)>,
+}
+
+impl<I: Indicator<Output = f64>> DivergenceDetector<I> {
+    /// 创建新的背离识别器
+    ///
+    /// # 参数
+    ///
+    /// * `oscillator` - 震荡指标，每根K线都由识别器更新
+    /// * `span` - 分形两侧的K线数量
+    ///
+    /// # Panics
+    ///
+    /// 当 `span` 为 0 时会 panic
+    pub fn new(oscillator: I, span: usize) -> Self {
+        Self {
+            oscillator,
+            fractals: Fractals::new(span),
+            max_distance: 60,
+            bars: VecDeque::with_capacity(span + 1),
+            last_high: None,
+            last_low: None,
+        }
+    }
+
+    /// 设置两个波段点之间的最大K线数，默认60
+    pub fn with_max_distance(mut self, max_distance: usize) -> Self {
+        self.max_distance = max_distance;
+        self
+    }
+
+    /// 更新并返回本根K线确认的背离
+    pub fn update(&mut self, kline: &Kline) -> Vec<Divergence> {
+        let oscillator = self.oscillator.update_kline(kline);
+        self.bars.push_back(Bar {
+            timestamp: kline.timestamp,
+            oscillator,
+        });
+        if self.bars.len() > self.fractals.span() + 1 {
+            self.bars.pop_front();
+        }
+
+        let mut divergences = Vec::new();
+        for swing in self.fractals.update(kline.high, kline.low) {
+            let pivot = self.bars[0];
+            // 指标还在预热的波段点不参与比较
+            let Some(value) = pivot.oscillator else {
+                continue;
+            };
+            let point = DivergencePoint {
+                timestamp: pivot.timestamp,
+                price: swing.price,
+                oscillator: value,
+            };
+
+            let last = match swing.kind {
+                SwingKind::High => self.last_high.replace((swing.index, point)),
+                SwingKind::Low => self.last_low.replace((swing.index, point)),
+            };
+            let Some((index, previous)) = last else {
+                continue;
+            };
+            if swing.index - index > self.max_distance {
+                continue;
+            }
+
+            if let Some(kind) = Self::classify(swing.kind, &previous, &point) {
+                divergences.push(Divergence {
+                    kind,
+                    previous,
+                    current: point,
+                    confirmed_at: kline.timestamp,
+                });
+            }
+        }
+
+        divergences
+    }
+
+    fn classify(kind: SwingKind, previous: &DivergencePoint, current: &DivergencePoint) -> Option<DivergenceKind> {
+        let price_higher = current.price > previous.price;
+        let price_lower = current.price < previous.price;
+        let oscillator_higher = current.oscillator > previous.oscillator;
+        let oscillator_lower = current.oscillator < previous.oscillator;
+
+        match kind {
+            SwingKind::High if price_higher && oscillator_lower => Some(DivergenceKind::RegularBearish),
+            SwingKind::High if price_lower && oscillator_higher => Some(DivergenceKind::HiddenBearish),
+            SwingKind::Low if price_lower && oscillator_higher => Some(DivergenceKind::RegularBullish),
+            SwingKind::Low if price_higher && oscillator_lower => Some(DivergenceKind::HiddenBullish),
+            _ => None,
+        }
+    }
+
+    /// 重置识别器和震荡指标
+    pub fn reset(&mut self) {
+        self.oscillator.reset();
+        self.fractals.reset();
+        self.bars.clear();
+        self.last_high = None;
+        self.last_low = None;
+    }
+
+    /// 获取震荡指标
+    pub fn oscillator(&self) -> &I {
+        &self.oscillator
+    }
+
+    /// 获取两个波段点之间的最大K线数
+    pub fn max_distance(&self) -> usize {
+        self.max_distance
+    }
+}
+
+impl<I: Indicator<Output = f64>> Indicator for DivergenceDetector<I> {
+    type Output = Vec<Divergence>;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<Vec<Divergence>> {
+        Some(self.update(kline))
+    }
+
+    // 每根K线都输出本根确认的背离，可能为空
+    fn is_ready(&self) -> bool {
+        true
+    }
+
+    fn reset(&mut self) {
+        DivergenceDetector::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests;
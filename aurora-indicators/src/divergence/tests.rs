@@ -0,0 +1,192 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::RSI;
+
+/// 按顺序返回预设值的震荡指标
+#[derive(Debug, Clone)]
+struct Scripted {
+    values: Vec<Option<f64>>,
+    position: usize,
+}
+
+impl Scripted {
+    fn new(values: Vec<Option<f64>>) -> Self {
+        Self { values, position: 0 }
+    }
+}
+
+impl Indicator for Scripted {
+    type Output = f64;
+
+    fn update_kline(&mut self, _kline: &Kline) -> Option<f64> {
+        let value = self.values.get(self.position).copied().flatten();
+        self.position += 1;
+        value
+    }
+
+    fn is_ready(&self) -> bool {
+        self.position > 0
+    }
+
+    fn reset(&mut self) {
+        self.position = 0;
+    }
+
+    fn warm_up_period(&self) -> usize {
+        1
+    }
+}
+
+fn kline(i: usize, high: f64, low: f64) -> Kline {
+    Kline {
+        timestamp: i as i64 * 60_000,
+        open: low,
+        high,
+        low,
+        close: high,
+        volume: 100.0,
+        symbol: None,
+        interval: None,
+    }
+}
+
+/// 运行识别器，返回每根K线确认的背离
+fn run<I: Indicator<Output = f64>>(
+    detector: &mut DivergenceDetector<I>,
+    bars: &[(f64, f64)],
+) -> Vec<Vec<Divergence>> {
+    bars.iter()
+        .enumerate()
+        .map(|(i, &(high, low))| detector.update(&kline(i, high, low)))
+        .collect()
+}
+
+/// 高点12(指标70)、13(指标65)，低点8.5(指标60)、8(指标62)
+const REGULAR_BARS: [(f64, f64); 7] =
+    [(10.0, 8.0), (12.0, 9.0), (11.0, 8.5), (13.0, 9.5), (12.0, 9.0), (12.5, 8.0), (13.0, 9.0)];
+
+fn regular_oscillator() -> Scripted {
+    Scripted::new(vec![Some(50.0), Some(70.0), Some(60.0), Some(65.0), Some(55.0), Some(62.0), Some(50.0)])
+}
+
+#[test]
+fn test_regular_divergences() {
+    let mut detector = DivergenceDetector::new(regular_oscillator(), 1);
+    let outputs = run(&mut detector, &REGULAR_BARS);
+
+    for (i, output) in outputs.iter().enumerate() {
+        if i != 4 && i != 6 {
+            assert!(output.is_empty(), "第 {} 根K线不应有背离", i);
+        }
+    }
+
+    // 价格创新高而指标走低
+    assert_eq!(
+        outputs[4],
+        vec![Divergence {
+            kind: DivergenceKind::RegularBearish,
+            previous: DivergencePoint { timestamp: 60_000, price: 12.0, oscillator: 70.0 },
+            current: DivergencePoint { timestamp: 180_000, price: 13.0, oscillator: 65.0 },
+            confirmed_at: 240_000,
+        }]
+    );
+
+    // 价格创新低而指标走高
+    assert_eq!(outputs[6].len(), 1);
+    let bullish = outputs[6][0];
+    assert_eq!(bullish.kind, DivergenceKind::RegularBullish);
+    assert_eq!(bullish.previous.timestamp, 120_000);
+    assert_eq!(bullish.current.timestamp, 300_000);
+    assert_eq!(bullish.current.price, 8.0);
+    assert_eq!(bullish.confirmed_at, 360_000);
+}
+
+#[test]
+fn test_hidden_bullish_divergence() {
+    let bars = [(10.0, 9.0), (10.5, 8.0), (11.0, 9.0), (11.5, 8.5), (12.0, 9.5)];
+    let oscillator = Scripted::new(vec![Some(50.0), Some(40.0), Some(45.0), Some(30.0), Some(50.0)]);
+    let mut detector = DivergenceDetector::new(oscillator, 1);
+
+    let outputs = run(&mut detector, &bars);
+    assert_eq!(outputs[4].len(), 1);
+    assert_eq!(outputs[4][0].kind, DivergenceKind::HiddenBullish);
+    assert!(outputs[4][0].kind.is_hidden());
+    assert_eq!(outputs[4][0].kind.direction(), PatternDirection::Bullish);
+}
+
+#[test]
+fn test_hidden_bearish_divergence() {
+    let bars = [(10.0, 9.0), (12.0, 10.0), (10.5, 9.5), (11.5, 10.0), (10.0, 9.0)];
+    let oscillator = Scripted::new(vec![Some(50.0), Some(60.0), Some(55.0), Some(65.0), Some(50.0)]);
+    let mut detector = DivergenceDetector::new(oscillator, 1);
+
+    let outputs = run(&mut detector, &bars);
+    assert_eq!(outputs[4].len(), 1);
+    assert_eq!(outputs[4][0].kind, DivergenceKind::HiddenBearish);
+    assert_eq!(outputs[4][0].kind.direction(), PatternDirection::Bearish);
+}
+
+/// 测试波段点相隔太远时不比较
+#[test]
+fn test_max_distance() {
+    let mut detector = DivergenceDetector::new(regular_oscillator(), 1).with_max_distance(1);
+    assert_eq!(detector.max_distance(), 1);
+
+    let outputs = run(&mut detector, &REGULAR_BARS);
+    assert!(outputs.iter().all(Vec::is_empty));
+}
+
+/// 测试指标预热中的波段点被跳过
+#[test]
+fn test_skips_pivots_without_oscillator() {
+    let mut values = regular_oscillator().values;
+    values[1] = None;
+    let mut detector = DivergenceDetector::new(Scripted::new(values), 1);
+
+    let outputs = run(&mut detector, &REGULAR_BARS);
+    assert!(outputs[4].is_empty());
+    assert_eq!(outputs[6].len(), 1);
+}
+
+#[test]
+fn test_reset() {
+    let mut detector = DivergenceDetector::new(regular_oscillator(), 1);
+    run(&mut detector, &REGULAR_BARS[..5]);
+
+    Indicator::reset(&mut detector);
+    assert_eq!(detector.oscillator().position, 0);
+
+    // 重置后重新识别
+    let outputs = run(&mut detector, &REGULAR_BARS);
+    assert_eq!(outputs[4].len(), 1);
+}
+
+/// 测试与真实指标组合并作为指标使用
+#[test]
+fn test_with_rsi_as_indicator() {
+    let mut detector = DivergenceDetector::new(RSI::new(3), 2);
+    assert_eq!(Indicator::warm_up_period(&detector), 1);
+
+    for i in 0..40 {
+        let base = 100.0 + (i as f64 * 0.7).sin() * 5.0;
+        let output = detector.update_kline(&kline(i, base + 1.0, base - 1.0));
+        assert!(output.is_some());
+        for divergence in output.unwrap() {
+            assert!(divergence.previous.timestamp < divergence.current.timestamp);
+            assert!(divergence.current.timestamp < divergence.confirmed_at);
+        }
+    }
+}
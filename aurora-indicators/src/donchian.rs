@@ -0,0 +1,183 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Donchian Channels - 唐奇安通道
+//!
+//! 唐奇安通道由 Richard Donchian 提出，以N周期内的最高价和最低价作为上下轨，
+//! 是海龟交易法则等突破系统的基础。
+//!
+//! # 计算公式
+//!
+//! 1. 上轨 = N周期内的最高价
+//! 2. 下轨 = N周期内的最低价
+//! 3. 中轨 = (上轨 + 下轨) / 2
+//!
+//! 窗口包含当前K线。判断突破时通常与上一根K线的通道比较。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::DonchianChannels;
+//!
+//! let mut dc = DonchianChannels::new(3);
+//!
+//! dc.update(105.0, 95.0);
+//! dc.update(110.0, 98.0);
+//! let channels = dc.update(108.0, 92.0).unwrap();
+//!
+//! assert_eq!(channels.upper, 110.0);
+//! assert_eq!(channels.lower, 92.0);
+//! assert_eq!(channels.middle, 101.0);
+//! ```
+
+use aurora_core::Kline;
+use crate::{Indicator, IndicatorValue};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Donchian Channels 输出结构
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DonchianChannelsOutput {
+    /// 上轨(N周期最高价)
+    pub upper: f64,
+    /// 中轨
+    pub middle: f64,
+    /// 下轨(N周期最低价)
+    pub lower: f64,
+}
+
+/// Donchian Channels 唐奇安通道
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DonchianChannels {
+    /// 计算周期
+    period: usize,
+    /// 最高价历史数据
+    highs: VecDeque<f64>,
+    /// 最低价历史数据
+    lows: VecDeque<f64>,
+}
+
+impl DonchianChannels {
+    /// 创建新的Donchian Channels指标实例
+    ///
+    /// # 参数
+    ///
+    /// * `period` - 计算周期,通常使用20
+    ///
+    /// # Panics
+    ///
+    /// 当 `period` 为 0 时会 panic
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "Donchian Channels周期必须大于0");
+
+        Self {
+            period,
+            highs: VecDeque::with_capacity(period),
+            lows: VecDeque::with_capacity(period),
+        }
+    }
+
+    /// 更新指标并计算新的通道值
+    ///
+    /// # 参数
+    ///
+    /// * `high` - 最高价
+    /// * `low` - 最低价
+    ///
+    /// # 返回值
+    ///
+    /// - `Some(DonchianChannelsOutput)` - 累积了 period 个数据点后返回通道值
+    /// - `None` - 数据不足
+    pub fn update(&mut self, high: f64, low: f64) -> Option<DonchianChannelsOutput> {
+        self.highs.push_back(high);
+        self.lows.push_back(low);
+        if self.highs.len() > self.period {
+            self.highs.pop_front();
+            self.lows.pop_front();
+        }
+
+        self.value()
+    }
+
+    /// 获取当前的通道值
+    pub fn value(&self) -> Option<DonchianChannelsOutput> {
+        if !self.is_ready() {
+            return None;
+        }
+
+        let upper = self.highs.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let lower = self.lows.iter().copied().fold(f64::INFINITY, f64::min);
+        Some(DonchianChannelsOutput {
+            upper,
+            middle: (upper + lower) / 2.0,
+            lower,
+        })
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.highs.clear();
+        self.lows.clear();
+    }
+
+    /// 获取周期设置
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// 检查是否已准备好输出结果
+    pub fn is_ready(&self) -> bool {
+        self.highs.len() == self.period
+    }
+}
+
+// 默认参数: 20周期
+impl Default for DonchianChannels {
+    fn default() -> Self {
+        Self::new(20)
+    }
+}
+
+impl Indicator for DonchianChannels {
+    type Output = DonchianChannelsOutput;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<DonchianChannelsOutput> {
+        self.update(kline.high, kline.low)
+    }
+
+    fn is_ready(&self) -> bool {
+        DonchianChannels::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        DonchianChannels::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period
+    }
+}
+
+impl From<DonchianChannelsOutput> for IndicatorValue {
+    fn from(output: DonchianChannelsOutput) -> Self {
+        IndicatorValue::from_fields(vec![
+            ("middle", output.middle),
+            ("upper", output.upper),
+            ("lower", output.lower),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests;
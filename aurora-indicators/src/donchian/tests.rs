@@ -0,0 +1,131 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::test_data::{ATR_HIGHS, ATR_LOWS};
+use approx::assert_relative_eq;
+
+#[test]
+fn test_donchian_new() {
+    let dc = DonchianChannels::new(20);
+    assert_eq!(dc.period(), 20);
+    assert!(!dc.is_ready());
+    assert_eq!(DonchianChannels::default().period(), 20);
+}
+
+#[test]
+#[should_panic(expected = "Donchian Channels周期必须大于0")]
+fn test_donchian_zero_period() {
+    DonchianChannels::new(0);
+}
+
+#[test]
+fn test_donchian_insufficient_data() {
+    let mut dc = DonchianChannels::new(4);
+    for _ in 0..3 {
+        assert!(dc.update(110.0, 90.0).is_none());
+    }
+    assert!(dc.update(110.0, 90.0).is_some());
+}
+
+/// 参考值：StockCharts ChartSchool ATR示例表（cs-atr.xls）的QQQ最高价和最低价的10日唐奇安通道，
+/// 第25根K线的最低价41.55把下轨拉低，直到它移出窗口前下轨保持不变
+#[test]
+fn test_donchian_reference_values() {
+    let mut dc = DonchianChannels::new(10);
+    let outputs: Vec<_> = ATR_HIGHS
+        .iter()
+        .zip(ATR_LOWS.iter())
+        .map(|(&high, &low)| dc.update(high, low))
+        .collect();
+
+    assert!(outputs[..9].iter().all(Option::is_none));
+    let expected = [
+        (9, 50.19, 47.79),
+        (14, 50.36, 48.64),
+        (16, 50.65, 48.86),
+        (22, 50.65, 48.08),
+        (24, 50.65, 41.55),
+        (26, 50.43, 41.55),
+        (29, 50.29, 41.55),
+    ];
+    for (index, upper, lower) in expected {
+        let output = outputs[index].unwrap();
+        assert_eq!((output.upper, output.lower), (upper, lower));
+        assert_relative_eq!(output.middle, (upper + lower) / 2.0, epsilon = 1e-10);
+    }
+}
+
+/// 周期3逐窗口计算：
+/// 窗口 (105, 95), (110, 98), (108, 92) → 上轨110，下轨92，中轨101
+/// 滑动后 (110, 98), (108, 92), (104, 96) → 上轨110，下轨92
+/// 再滑动 (108, 92), (104, 96), (103, 97) → 上轨108，下轨92
+/// 再滑动 (104, 96), (103, 97), (102, 99) → 上轨104，下轨96，中轨100
+#[test]
+fn test_donchian_worked_example() {
+    let mut dc = DonchianChannels::new(3);
+    let data = [(105.0, 95.0), (110.0, 98.0), (108.0, 92.0), (104.0, 96.0), (103.0, 97.0), (102.0, 99.0)];
+    let outputs: Vec<_> = data.iter().map(|&(h, l)| dc.update(h, l)).collect();
+
+    let expect = |upper: f64, lower: f64| {
+        Some(DonchianChannelsOutput {
+            upper,
+            middle: (upper + lower) / 2.0,
+            lower,
+        })
+    };
+    assert_eq!(outputs[2], expect(110.0, 92.0));
+    assert_eq!(outputs[3], expect(110.0, 92.0));
+    assert_eq!(outputs[4], expect(108.0, 92.0));
+    assert_eq!(outputs[5], expect(104.0, 96.0));
+    assert_eq!(outputs[5].unwrap().middle, 100.0);
+}
+
+#[test]
+fn test_donchian_breakout_against_previous_channel() {
+    let mut dc = DonchianChannels::new(5);
+    let mut previous = None;
+    for _ in 0..5 {
+        previous = dc.update(110.0, 90.0);
+    }
+
+    // 新高突破上一根K线的上轨
+    let high = 115.0;
+    assert!(high > previous.unwrap().upper);
+    assert_eq!(dc.update(high, 100.0).unwrap().upper, 115.0);
+}
+
+#[test]
+fn test_donchian_indicator_value() {
+    let value = IndicatorValue::from(DonchianChannelsOutput {
+        upper: 110.0,
+        middle: 100.0,
+        lower: 90.0,
+    });
+    assert_eq!(value.primary(), 100.0);
+    assert_eq!(value.get("upper"), Some(110.0));
+    assert_eq!(value.get("lower"), Some(90.0));
+}
+
+#[test]
+fn test_donchian_reset() {
+    let mut dc = DonchianChannels::new(2);
+    dc.update(110.0, 90.0);
+    dc.update(110.0, 90.0);
+    assert!(dc.is_ready());
+
+    dc.reset();
+    assert!(!dc.is_ready());
+    assert_eq!(dc.value(), None);
+}
@@ -16,6 +16,10 @@
 //!
 //! EMA对近期数据赋予更高权重，相比SMA更快地响应价格变化。
 
+use aurora_core::Kline;
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
+
 /// 指数移动平均线 (Exponential Moving Average) 指标
 ///
 /// EMA通过给予近期数据更高的权重来平滑价格数据，使其对最新价格变化更加敏感。
@@ -37,7 +41,7 @@
 /// # 内存复杂度
 ///
 /// 空间复杂度为O(1)，只需存储上一个EMA值和平滑系数
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EMA {
     /// EMA周期长度
     period: usize,
@@ -143,5 +147,25 @@ impl EMA {
     }
 }
 
+impl Indicator for EMA {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        Some(self.update(kline.close))
+    }
+
+    fn is_ready(&self) -> bool {
+        EMA::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        EMA::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        1
+    }
+}
+
 #[cfg(test)]
 mod tests;
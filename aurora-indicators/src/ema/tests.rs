@@ -14,6 +14,7 @@
 
 use super::*;
 use approx::assert_relative_eq;
+use crate::test_data::{MOVAVG_CLOSES, MOVAVG_EMA_10};
 
 /// 测试EMA创建
 #[test]
@@ -188,3 +189,17 @@ fn test_ema_single_period() {
     let result2 = ema.update(200.0);
     assert_relative_eq!(result2, 200.0, epsilon = 1e-10);
 }
+
+/// 参考值：StockCharts ChartSchool 移动平均线示例表公布的10日EMA
+///
+/// 表格以前10日SMA为初值，本库以第一个价格为初值，
+/// 初值的差异按 (9/11)^n 衰减，从第16根K线起两者之差小于公布值的精度0.01
+#[test]
+fn test_ema_matches_stockcharts() {
+    let mut ema = EMA::new(10);
+    let outputs: Vec<f64> = MOVAVG_CLOSES.iter().map(|&price| ema.update(price)).collect();
+
+    for (output, expected) in outputs[15..].iter().zip(&MOVAVG_EMA_10[6..]) {
+        assert!((output - expected).abs() < 0.01, "{} != {}", output, expected);
+    }
+}
@@ -0,0 +1,367 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 信号事件检测
+//!
+//! 把指标数值序列转换为离散事件，策略可以用这些检测器代替手写的状态机：
+//!
+//! - [`CrossAbove`] / [`CrossBelow`] 检测一条线上穿/下穿另一条线（或固定水平线）
+//! - [`Threshold`] 判断数值高于/低于给定水平
+//! - [`Rising`] / [`Falling`] 检测数值连续上升/下降
+//!
+//! 每个检测器都支持回看长度（lookback）：交叉检测器在事件发生后的 `lookback` 次更新内保持触发，
+//! 阈值和趋势检测器要求条件连续满足 `lookback` 次才触发。默认回看长度为1，即只看当前这次更新。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::{CrossAbove, Threshold};
+//!
+//! let mut golden_cross = CrossAbove::new();
+//! assert!(!golden_cross.update(9.0, 10.0));
+//! assert!(golden_cross.update(11.0, 10.0));
+//! assert!(!golden_cross.update(12.0, 10.0));
+//!
+//! let mut oversold = Threshold::below(30.0).with_lookback(2);
+//! assert!(!oversold.update(25.0));
+//! assert!(oversold.update(28.0));
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// 交叉检测的公共状态
+///
+/// 以差值的符号判断交叉：差值从不大于0变为大于0即为一次交叉。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Crossing {
+    /// 交叉后保持触发的更新次数
+    lookback: usize,
+    /// 上一次的差值
+    previous: Option<f64>,
+    /// 距上一次交叉经过的更新次数
+    bars_since: Option<usize>,
+}
+
+impl Crossing {
+    fn new() -> Self {
+        Self {
+            lookback: 1,
+            previous: None,
+            bars_since: None,
+        }
+    }
+
+    fn update(&mut self, diff: f64) -> bool {
+        let crossed = matches!(self.previous, Some(previous) if previous <= 0.0 && diff > 0.0);
+        self.previous = Some(diff);
+
+        self.bars_since = if crossed {
+            Some(0)
+        } else {
+            self.bars_since.map(|bars| bars + 1)
+        };
+        self.bars_since.is_some_and(|bars| bars < self.lookback)
+    }
+
+    fn reset(&mut self) {
+        self.previous = None;
+        self.bars_since = None;
+    }
+}
+
+/// 连续满足条件的计数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Streak {
+    /// 触发所需的连续次数
+    required: usize,
+    /// 当前连续次数
+    count: usize,
+}
+
+impl Streak {
+    fn new(required: usize) -> Self {
+        assert!(required > 0, "回看长度必须大于0");
+        Self { required, count: 0 }
+    }
+
+    fn push(&mut self, condition: bool) -> bool {
+        self.count = if condition { self.count + 1 } else { 0 };
+        self.count >= self.required
+    }
+}
+
+/// 上穿检测
+///
+/// 上一次 `value <= reference` 且本次 `value > reference` 时触发，
+/// 例如短期均线上穿长期均线（金叉）。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossAbove {
+    crossing: Crossing,
+}
+
+impl CrossAbove {
+    /// 创建上穿检测器，只在交叉发生的那次更新触发
+    pub fn new() -> Self {
+        Self {
+            crossing: Crossing::new(),
+        }
+    }
+
+    /// 设置回看长度：交叉发生后的 `lookback` 次更新内（含交叉当次）都触发
+    ///
+    /// # Panics
+    ///
+    /// 回看长度为0时panic
+    pub fn with_lookback(mut self, lookback: usize) -> Self {
+        assert!(lookback > 0, "回看长度必须大于0");
+        self.crossing.lookback = lookback;
+        self
+    }
+
+    /// 用新的一对数值更新，返回是否触发
+    pub fn update(&mut self, value: f64, reference: f64) -> bool {
+        self.crossing.update(value - reference)
+    }
+
+    /// 是否已有上一次的数值，可以检测交叉
+    pub fn is_ready(&self) -> bool {
+        self.crossing.previous.is_some()
+    }
+
+    /// 重置状态
+    pub fn reset(&mut self) {
+        self.crossing.reset();
+    }
+
+    /// 回看长度
+    pub fn lookback(&self) -> usize {
+        self.crossing.lookback
+    }
+}
+
+impl Default for CrossAbove {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 下穿检测
+///
+/// 上一次 `value >= reference` 且本次 `value < reference` 时触发，
+/// 例如短期均线下穿长期均线（死叉）。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossBelow {
+    crossing: Crossing,
+}
+
+impl CrossBelow {
+    /// 创建下穿检测器，只在交叉发生的那次更新触发
+    pub fn new() -> Self {
+        Self {
+            crossing: Crossing::new(),
+        }
+    }
+
+    /// 设置回看长度：交叉发生后的 `lookback` 次更新内（含交叉当次）都触发
+    ///
+    /// # Panics
+    ///
+    /// 回看长度为0时panic
+    pub fn with_lookback(mut self, lookback: usize) -> Self {
+        assert!(lookback > 0, "回看长度必须大于0");
+        self.crossing.lookback = lookback;
+        self
+    }
+
+    /// 用新的一对数值更新，返回是否触发
+    pub fn update(&mut self, value: f64, reference: f64) -> bool {
+        self.crossing.update(reference - value)
+    }
+
+    /// 是否已有上一次的数值，可以检测交叉
+    pub fn is_ready(&self) -> bool {
+        self.crossing.previous.is_some()
+    }
+
+    /// 重置状态
+    pub fn reset(&mut self) {
+        self.crossing.reset();
+    }
+
+    /// 回看长度
+    pub fn lookback(&self) -> usize {
+        self.crossing.lookback
+    }
+}
+
+impl Default for CrossBelow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 阈值检测
+///
+/// 数值严格高于（或低于）给定水平时满足条件，连续满足 `lookback` 次时触发，
+/// 例如RSI低于30视为超卖。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Threshold {
+    /// 阈值水平
+    level: f64,
+    /// true 表示高于阈值，false 表示低于阈值
+    above: bool,
+    streak: Streak,
+}
+
+impl Threshold {
+    /// 数值高于 `level` 时触发
+    pub fn above(level: f64) -> Self {
+        Self {
+            level,
+            above: true,
+            streak: Streak::new(1),
+        }
+    }
+
+    /// 数值低于 `level` 时触发
+    pub fn below(level: f64) -> Self {
+        Self {
+            level,
+            above: false,
+            streak: Streak::new(1),
+        }
+    }
+
+    /// 设置回看长度：条件需连续满足的次数
+    ///
+    /// # Panics
+    ///
+    /// 回看长度为0时panic
+    pub fn with_lookback(mut self, lookback: usize) -> Self {
+        self.streak = Streak::new(lookback);
+        self
+    }
+
+    /// 用新的数值更新，返回是否触发
+    pub fn update(&mut self, value: f64) -> bool {
+        let condition = if self.above {
+            value > self.level
+        } else {
+            value < self.level
+        };
+        self.streak.push(condition)
+    }
+
+    /// 重置状态
+    pub fn reset(&mut self) {
+        self.streak.count = 0;
+    }
+
+    /// 阈值水平
+    pub fn level(&self) -> f64 {
+        self.level
+    }
+
+    /// 回看长度
+    pub fn lookback(&self) -> usize {
+        self.streak.required
+    }
+}
+
+/// 上升检测
+///
+/// 数值连续 `lookback` 次严格高于前一个值时触发。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Rising {
+    /// 上一次的数值
+    previous: Option<f64>,
+    streak: Streak,
+}
+
+impl Rising {
+    /// 创建上升检测器
+    ///
+    /// # Panics
+    ///
+    /// 回看长度为0时panic
+    pub fn new(lookback: usize) -> Self {
+        Self {
+            previous: None,
+            streak: Streak::new(lookback),
+        }
+    }
+
+    /// 用新的数值更新，返回是否触发
+    pub fn update(&mut self, value: f64) -> bool {
+        let condition = self.previous.is_some_and(|previous| value > previous);
+        self.previous = Some(value);
+        self.streak.push(condition)
+    }
+
+    /// 重置状态
+    pub fn reset(&mut self) {
+        self.previous = None;
+        self.streak.count = 0;
+    }
+
+    /// 回看长度
+    pub fn lookback(&self) -> usize {
+        self.streak.required
+    }
+}
+
+/// 下降检测
+///
+/// 数值连续 `lookback` 次严格低于前一个值时触发。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Falling {
+    /// 上一次的数值
+    previous: Option<f64>,
+    streak: Streak,
+}
+
+impl Falling {
+    /// 创建下降检测器
+    ///
+    /// # Panics
+    ///
+    /// 回看长度为0时panic
+    pub fn new(lookback: usize) -> Self {
+        Self {
+            previous: None,
+            streak: Streak::new(lookback),
+        }
+    }
+
+    /// 用新的数值更新，返回是否触发
+    pub fn update(&mut self, value: f64) -> bool {
+        let condition = self.previous.is_some_and(|previous| value < previous);
+        self.previous = Some(value);
+        self.streak.push(condition)
+    }
+
+    /// 重置状态
+    pub fn reset(&mut self) {
+        self.previous = None;
+        self.streak.count = 0;
+    }
+
+    /// 回看长度
+    pub fn lookback(&self) -> usize {
+        self.streak.required
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,97 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// 测试上穿和下穿只在交叉当次触发
+#[test]
+fn test_cross_above_and_below() {
+    let mut above = CrossAbove::new();
+    let mut below = CrossBelow::new();
+    let values = [(9.0, 10.0), (11.0, 10.0), (12.0, 10.0), (9.0, 10.0), (8.0, 10.0)];
+
+    let ups: Vec<bool> = values.iter().map(|&(v, r)| above.update(v, r)).collect();
+    let downs: Vec<bool> = values.iter().map(|&(v, r)| below.update(v, r)).collect();
+
+    assert_eq!(ups, vec![false, true, false, false, false]);
+    assert_eq!(downs, vec![false, false, false, true, false]);
+}
+
+/// 测试从相等位置穿越也视为交叉
+#[test]
+fn test_cross_from_equal() {
+    let mut above = CrossAbove::new();
+    assert!(!above.is_ready());
+    assert!(!above.update(10.0, 10.0));
+    assert!(above.is_ready());
+    assert!(above.update(10.5, 10.0));
+
+    let mut below = CrossBelow::new();
+    assert!(!below.update(10.0, 10.0));
+    assert!(below.update(9.5, 10.0));
+}
+
+/// 测试交叉的回看长度
+#[test]
+fn test_cross_lookback() {
+    let mut above = CrossAbove::new().with_lookback(3);
+    assert_eq!(above.lookback(), 3);
+
+    let fired: Vec<bool> = [1.0, 3.0, 4.0, 5.0, 6.0]
+        .iter()
+        .map(|&value| above.update(value, 2.0))
+        .collect();
+    assert_eq!(fired, vec![false, true, true, true, false]);
+
+    above.reset();
+    assert!(!above.is_ready());
+    assert!(!above.update(6.0, 2.0));
+}
+
+/// 测试阈值检测及连续满足次数
+#[test]
+fn test_threshold() {
+    let mut overbought = Threshold::above(70.0);
+    assert!(!overbought.update(70.0));
+    assert!(overbought.update(75.0));
+
+    let mut oversold = Threshold::below(30.0).with_lookback(2);
+    let fired: Vec<bool> = [25.0, 28.0, 35.0, 20.0, 22.0]
+        .iter()
+        .map(|&value| oversold.update(value))
+        .collect();
+    assert_eq!(fired, vec![false, true, false, false, true]);
+    assert_eq!(oversold.level(), 30.0);
+}
+
+/// 测试上升和下降检测
+#[test]
+fn test_rising_and_falling() {
+    let mut rising = Rising::new(2);
+    let mut falling = Falling::new(1);
+    let values = [1.0, 2.0, 3.0, 3.0, 2.0, 4.0, 5.0];
+
+    let ups: Vec<bool> = values.iter().map(|&v| rising.update(v)).collect();
+    let downs: Vec<bool> = values.iter().map(|&v| falling.update(v)).collect();
+
+    assert_eq!(ups, vec![false, false, true, false, false, false, true]);
+    assert_eq!(downs, vec![false, false, false, false, true, false, false]);
+}
+
+/// 测试回看长度为0时panic
+#[test]
+#[should_panic(expected = "回看长度必须大于0")]
+fn test_zero_lookback_panics() {
+    Rising::new(0);
+}
@@ -0,0 +1,144 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 赫尔移动平均线 (Hull Moving Average) 指标
+//!
+//! HMA 由 Alan Hull 提出，组合不同周期的WMA抵消滞后，再用短周期WMA平滑，
+//! 兼顾响应速度和平滑程度。
+//!
+//! # 计算公式
+//!
+//! - 原始序列 = 2 × WMA(价格, N/2) - WMA(价格, N)
+//! - HMA = WMA(原始序列, √N)
+//!
+//! 其中 N/2 和 √N 向下取整且至少为1。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::HMA;
+//!
+//! let mut hma = HMA::new(4);
+//!
+//! // 周期4时，线性上涨序列上的HMA没有滞后
+//! let mut result = None;
+//! for price in [1.0, 2.0, 3.0, 4.0, 5.0] {
+//!     result = hma.update(price);
+//! }
+//! assert!((result.unwrap() - 5.0).abs() < 1e-10);
+//! ```
+
+use aurora_core::Kline;
+use crate::{Indicator, WMA};
+use serde::{Deserialize, Serialize};
+
+/// 赫尔移动平均线 (Hull Moving Average) 指标
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HMA {
+    /// 周期长度
+    period: usize,
+    /// N/2 周期的WMA
+    half_wma: WMA,
+    /// N 周期的WMA
+    full_wma: WMA,
+    /// 对原始序列做平滑的 √N 周期WMA
+    smooth_wma: WMA,
+}
+
+impl HMA {
+    /// 创建新的HMA指标
+    ///
+    /// # 参数
+    ///
+    /// * `period` - 周期，必须大于0，通常使用9或16
+    ///
+    /// # Panics
+    ///
+    /// 如果周期为0，函数会panic
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "HMA周期必须大于0");
+
+        let half = (period / 2).max(1);
+        let smooth = ((period as f64).sqrt().floor() as usize).max(1);
+        Self {
+            period,
+            half_wma: WMA::new(half),
+            full_wma: WMA::new(period),
+            smooth_wma: WMA::new(smooth),
+        }
+    }
+
+    /// 更新指标并返回最新的HMA值
+    ///
+    /// # 参数
+    ///
+    /// * `price` - 新的价格数据
+    ///
+    /// # 返回值
+    ///
+    /// * `Some(f64)` - 已有 N + √N - 1 个数据点时返回HMA值
+    /// * `None` - 数据点不足
+    pub fn update(&mut self, price: f64) -> Option<f64> {
+        let half = self.half_wma.update(price);
+        let full = self.full_wma.update(price)?;
+        // 半周期WMA比全周期WMA先就绪
+        let raw = 2.0 * half? - full;
+        self.smooth_wma.update(raw)
+    }
+
+    /// 获取当前的HMA值
+    pub fn value(&self) -> Option<f64> {
+        self.smooth_wma.value()
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.half_wma.reset();
+        self.full_wma.reset();
+        self.smooth_wma.reset();
+    }
+
+    /// 获取周期设置
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// 检查指标是否已准备好
+    pub fn is_ready(&self) -> bool {
+        self.smooth_wma.is_ready()
+    }
+}
+
+impl Indicator for HMA {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        HMA::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        HMA::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period + self.smooth_wma.period() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests;
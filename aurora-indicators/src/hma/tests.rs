@@ -0,0 +1,118 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::test_data::MOVAVG_CLOSES;
+use approx::assert_relative_eq;
+
+#[test]
+fn test_hma_new() {
+    let hma = HMA::new(9);
+    assert_eq!(hma.period(), 9);
+    assert_eq!(hma.half_wma.period(), 4);
+    assert_eq!(hma.smooth_wma.period(), 3);
+    assert!(!hma.is_ready());
+}
+
+#[test]
+#[should_panic(expected = "HMA周期必须大于0")]
+fn test_hma_zero_period() {
+    HMA::new(0);
+}
+
+#[test]
+fn test_hma_warm_up() {
+    let mut hma = HMA::new(9);
+    assert_eq!(Indicator::warm_up_period(&hma), 11);
+
+    for i in 0..10 {
+        assert_eq!(hma.update(100.0 + i as f64), None);
+    }
+    assert!(hma.update(110.0).is_some());
+}
+
+/// 参考值：StockCharts ChartSchool 移动平均线示例表（cs-movavg.xls）的30日收盘价的9日HMA，
+/// 即 WMA(2 × WMA(4) - WMA(9), 3)，第11根K线起输出，保留四位小数
+#[test]
+fn test_hma_reference_values() {
+    let mut hma = HMA::new(9);
+    let outputs: Vec<_> = MOVAVG_CLOSES.iter().map(|&price| hma.update(price)).collect();
+
+    assert!(outputs[..10].iter().all(Option::is_none));
+    let expected = [
+        (10, 22.2920),
+        (13, 22.4370),
+        (16, 23.9042),
+        (17, 24.1856),
+        (26, 22.9003),
+        (29, 22.3761),
+    ];
+    for (index, value) in expected {
+        assert_relative_eq!(outputs[index].unwrap(), value, epsilon = 1e-4);
+    }
+}
+
+/// 周期4（N/2 = 2，√N = 2）逐项计算：
+/// 第4个点: WMA4 = 3, WMA2 = 11/3, 原始值 = 13/3
+/// 第5个点: WMA4 = 4, WMA2 = 14/3, 原始值 = 16/3
+/// HMA = (13/3 × 1 + 16/3 × 2) / 3 = 5
+#[test]
+fn test_hma_worked_example() {
+    let mut hma = HMA::new(4);
+    let outputs: Vec<_> = [1.0, 2.0, 3.0, 4.0, 5.0].iter().map(|&p| hma.update(p)).collect();
+
+    assert_eq!(&outputs[..4], &[None, None, None, None]);
+    assert_relative_eq!(outputs[4].unwrap(), 5.0, epsilon = 1e-10);
+}
+
+#[test]
+fn test_hma_lag_on_linear_trend() {
+    // 线性序列上WMA(N)的滞后为 (N-1)/3 个周期：
+    // 周期16时原始序列滞后 2×7/3 - 15/3 = -1/3，再经WMA(4)平滑滞后 1，合计 2/3 个周期
+    let mut hma = HMA::new(16);
+    let mut wma = WMA::new(16);
+    let mut result = (None, None);
+    for i in 0..40 {
+        let price = 50.0 + 1.5 * i as f64;
+        result = (hma.update(price), wma.update(price));
+    }
+
+    let price = 50.0 + 1.5 * 39.0;
+    assert_relative_eq!(result.0.unwrap(), price - 1.5 * 2.0 / 3.0, epsilon = 1e-9);
+    assert_relative_eq!(result.1.unwrap(), price - 1.5 * 5.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_hma_constant_prices() {
+    let mut hma = HMA::new(9);
+    let mut result = None;
+    for _ in 0..20 {
+        result = hma.update(25.0);
+    }
+    assert_relative_eq!(result.unwrap(), 25.0, epsilon = 1e-10);
+}
+
+#[test]
+fn test_hma_reset() {
+    let mut hma = HMA::new(4);
+    for price in [1.0, 2.0, 3.0, 4.0, 5.0] {
+        hma.update(price);
+    }
+    assert!(hma.is_ready());
+
+    hma.reset();
+    assert!(!hma.is_ready());
+    assert_eq!(hma.value(), None);
+    assert_eq!(hma.update(1.0), None);
+}
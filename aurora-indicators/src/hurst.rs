@@ -0,0 +1,194 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hurst Exponent - 赫斯特指数
+//!
+//! 赫斯特指数衡量序列的长期记忆性：
+//!
+//! - **H > 0.5**: 趋势延续（持续性），适合趋势跟随
+//! - **H ≈ 0.5**: 随机游走
+//! - **H < 0.5**: 均值回归（反持续性），适合均值回归策略
+//!
+//! # 计算方法（重标极差分析 R/S）
+//!
+//! 1. 取窗口内N+1个价格的N个对数收益率
+//! 2. 对子区间长度 n = N, N/2, N/4, ...（不小于8），把最近的收益率分成若干段长度为n的子区间
+//! 3. 每段计算离差累积和的极差R与标准差S，取各段 R/S 的平均值
+//! 4. 对 ln(R/S) 和 ln(n) 做线性回归，斜率即为H
+//!
+//! 小样本下 R/S 估计偏高，随机游走通常得到0.55左右。
+//! 收益率全部相同、无法估计时返回0.5。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::Hurst;
+//!
+//! let mut hurst = Hurst::new(16);
+//!
+//! // 涨跌交替的价格强烈均值回归
+//! let mut value = None;
+//! for i in 0..17 {
+//!     value = hurst.update(if i % 2 == 0 { 100.0 } else { 101.0 });
+//! }
+//! assert!(value.unwrap() < 0.1);
+//! ```
+
+use aurora_core::Kline;
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// 最小子区间长度
+const MIN_CHUNK: usize = 8;
+
+/// Hurst Exponent 赫斯特指数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Hurst {
+    /// 收益率个数
+    period: usize,
+    /// 价格历史数据(period + 1个)
+    prices: VecDeque<f64>,
+}
+
+impl Hurst {
+    /// 创建新的赫斯特指数实例
+    ///
+    /// # 参数
+    ///
+    /// * `period` - 参与计算的收益率个数,至少为16,通常使用100
+    ///
+    /// # Panics
+    ///
+    /// 当 `period` 小于 16 时会 panic
+    pub fn new(period: usize) -> Self {
+        assert!(period >= 2 * MIN_CHUNK, "赫斯特指数周期不能小于16");
+
+        Self {
+            period,
+            prices: VecDeque::with_capacity(period + 1),
+        }
+    }
+
+    /// 更新指标并计算新的赫斯特指数
+    ///
+    /// # 返回值
+    ///
+    /// - `Some(f64)` - 累积了 period + 1 个价格后返回赫斯特指数
+    /// - `None` - 数据不足
+    pub fn update(&mut self, price: f64) -> Option<f64> {
+        self.prices.push_back(price);
+        if self.prices.len() > self.period + 1 {
+            self.prices.pop_front();
+        }
+        if !self.is_ready() {
+            return None;
+        }
+
+        let returns: Vec<f64> = (1..self.prices.len())
+            .map(|i| (self.prices[i] / self.prices[i - 1]).ln())
+            .collect();
+
+        let mut points = Vec::new();
+        let mut size = self.period;
+        while size >= MIN_CHUNK {
+            if let Some(rs) = Self::average_rescaled_range(&returns, size) {
+                points.push(((size as f64).ln(), rs.ln()));
+            }
+            size /= 2;
+        }
+
+        if points.len() < 2 {
+            return Some(0.5);
+        }
+        Some(Self::slope(&points))
+    }
+
+    /// 以最近的数据分段，计算各段 R/S 的平均值
+    fn average_rescaled_range(returns: &[f64], size: usize) -> Option<f64> {
+        let ratios: Vec<f64> = returns
+            .rchunks_exact(size)
+            .filter_map(|chunk| {
+                let mean = chunk.iter().sum::<f64>() / size as f64;
+                let std_dev = (chunk.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / size as f64).sqrt();
+                if std_dev == 0.0 {
+                    return None;
+                }
+
+                let (mut cumulative, mut max, mut min) = (0.0, f64::NEG_INFINITY, f64::INFINITY);
+                for r in chunk {
+                    cumulative += r - mean;
+                    max = max.max(cumulative);
+                    min = min.min(cumulative);
+                }
+                Some((max - min) / std_dev)
+            })
+            .filter(|ratio| *ratio > 0.0)
+            .collect();
+
+        if ratios.is_empty() {
+            return None;
+        }
+        Some(ratios.iter().sum::<f64>() / ratios.len() as f64)
+    }
+
+    /// 最小二乘斜率
+    fn slope(points: &[(f64, f64)]) -> f64 {
+        let n = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let (sxy, sxx) = points.iter().fold((0.0, 0.0), |(sxy, sxx), (x, y)| {
+            (sxy + (x - mean_x) * (y - mean_y), sxx + (x - mean_x).powi(2))
+        });
+        sxy / sxx
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.prices.clear();
+    }
+
+    /// 获取周期设置
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// 检查是否已准备好输出结果
+    pub fn is_ready(&self) -> bool {
+        self.prices.len() == self.period + 1
+    }
+}
+
+impl Indicator for Hurst {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        Hurst::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        Hurst::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period + 1
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,78 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+#[test]
+#[should_panic(expected = "赫斯特指数周期不能小于16")]
+fn test_hurst_period_too_small() {
+    Hurst::new(15);
+}
+
+#[test]
+fn test_hurst_warm_up() {
+    let mut hurst = Hurst::new(16);
+    assert_eq!(hurst.period(), 16);
+    for i in 0..16 {
+        assert!(hurst.update(100.0 + i as f64).is_none());
+    }
+    assert!(hurst.update(120.0).is_some());
+    assert_eq!(Indicator::warm_up_period(&hurst), 17);
+}
+
+/// 涨跌交替时每段 R/S 都为1，斜率为0
+#[test]
+fn test_hurst_mean_reverting() {
+    let mut hurst = Hurst::new(32);
+    let mut value = None;
+    for i in 0..40 {
+        value = hurst.update(if i % 2 == 0 { 100.0 } else { 101.0 });
+    }
+    assert!(value.unwrap().abs() < 1e-6);
+}
+
+/// 收益率持续增大的序列有很强的持续性
+#[test]
+fn test_hurst_persistent() {
+    let mut hurst = Hurst::new(64);
+    let mut price: f64 = 100.0;
+    let mut value = None;
+    for i in 0..65 {
+        price *= (0.0005 * i as f64).exp();
+        value = hurst.update(price);
+    }
+    assert!(value.unwrap() > 0.9);
+}
+
+/// 价格不变时无法估计
+#[test]
+fn test_hurst_constant_prices() {
+    let mut hurst = Hurst::new(16);
+    let mut value = None;
+    for _ in 0..17 {
+        value = hurst.update(100.0);
+    }
+    assert_eq!(value, Some(0.5));
+}
+
+#[test]
+fn test_hurst_reset() {
+    let mut hurst = Hurst::new(16);
+    for i in 0..17 {
+        hurst.update(100.0 + i as f64);
+    }
+    assert!(hurst.is_ready());
+
+    hurst.reset();
+    assert!(!hurst.is_ready());
+}
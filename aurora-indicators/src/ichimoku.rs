@@ -59,10 +59,13 @@
 //! }
 //! ```
 
+use aurora_core::Kline;
+use crate::{Indicator, IndicatorValue};
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 /// Ichimoku 输出结构
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct IchimokuOutput {
     /// 转换线 (Tenkan-sen)
     pub tenkan_sen: f64,
@@ -77,6 +80,7 @@ pub struct IchimokuOutput {
 }
 
 /// Ichimoku 指标结构
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Ichimoku {
     // 参数
     tenkan_period: usize,      // 转换线周期
@@ -221,6 +225,11 @@ impl Ichimoku {
         self.lows.clear();
         self.closes.clear();
     }
+
+    /// 是否已积累足够计算所有线的数据
+    pub fn is_ready(&self) -> bool {
+        self.highs.len() >= self.senkou_b_period
+    }
 }
 
 impl Default for Ichimoku {
@@ -234,5 +243,37 @@ impl Default for Ichimoku {
     }
 }
 
+impl Indicator for Ichimoku {
+    type Output = IchimokuOutput;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<IchimokuOutput> {
+        self.update(kline.high, kline.low, kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        Ichimoku::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        Ichimoku::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.senkou_b_period
+    }
+}
+
+impl From<IchimokuOutput> for IndicatorValue {
+    fn from(output: IchimokuOutput) -> Self {
+        IndicatorValue::from_fields(vec![
+            ("tenkan_sen", output.tenkan_sen),
+            ("kijun_sen", output.kijun_sen),
+            ("senkou_span_a", output.senkou_span_a),
+            ("senkou_span_b", output.senkou_span_b),
+            ("chikou_span", output.chikou_span),
+        ])
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,194 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 指标统一接口
+//!
+//! 各指标的 `update` 方法参数不同（收盘价、高低收、成交量等），
+//! [`Indicator`] 统一为从 [`Kline`] 更新，使策略、Web API 和配置可以泛化地使用指标：
+//!
+//! - 静态使用时，`Output` 是指标自己的输出类型（如 [`MACDOutput`](crate::MACDOutput)）
+//! - 动态使用时，通过 [`Indicator::boxed`] 转换为 [`BoxedIndicator`]，
+//!   输出统一为按字段命名的 [`IndicatorValue`]
+//! - 组合使用时，通过 [`Indicator::then`] 把输出接到另一个指标，
+//!   通过 [`Indicator::select`] 从多值输出中选出一条线
+
+use crate::{Chain, Select};
+use aurora_core::Kline;
+use serde::Serialize;
+use std::fmt;
+
+/// 指标统一接口
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::Kline;
+/// use aurora_indicators::{Indicator, MA};
+///
+/// let mut ma = MA::new(2);
+/// assert_eq!(Indicator::warm_up_period(&ma), 2);
+///
+/// let kline = |close: f64| Kline {
+///     timestamp: 0,
+///     open: close,
+///     high: close,
+///     low: close,
+///     close,
+///     volume: 1.0,
+///     symbol: None,
+///     interval: None,
+/// };
+///
+/// assert_eq!(ma.update_kline(&kline(100.0)), None);
+/// assert_eq!(ma.update_kline(&kline(102.0)), Some(101.0));
+/// ```
+pub trait Indicator: Send + Sync + fmt::Debug {
+    /// 指标输出类型
+    type Output: Clone + fmt::Debug;
+
+    /// 用一根K线更新指标
+    ///
+    /// # 返回值
+    ///
+    /// 数据足够时返回最新输出，预热阶段返回 `None`
+    fn update_kline(&mut self, kline: &Kline) -> Option<Self::Output>;
+
+    /// 是否已完成预热，可以产生输出
+    fn is_ready(&self) -> bool;
+
+    /// 清空内部状态，回到初始状态
+    fn reset(&mut self);
+
+    /// 预热长度：产生第一个输出所需的K线数量
+    fn warm_up_period(&self) -> usize;
+
+    /// 转换为输出统一为 [`IndicatorValue`] 的动态指标
+    fn boxed(self) -> BoxedIndicator
+    where
+        Self: Sized + 'static,
+        Self::Output: Into<IndicatorValue>,
+    {
+        Box::new(Dynamic(self))
+    }
+
+    /// 把输出作为下一个指标的输入，见 [`Chain`]
+    fn then<B: Indicator>(self, next: B) -> Chain<Self, B>
+    where
+        Self: Indicator<Output = f64> + Sized,
+    {
+        Chain::new(self, next)
+    }
+
+    /// 从多值输出中选出一条线，见 [`Select`]
+    fn select(self, select: fn(&Self::Output) -> f64) -> Select<Self>
+    where
+        Self: Sized,
+    {
+        Select::new(self, select)
+    }
+}
+
+/// 动态指标，输出统一为 [`IndicatorValue`]
+pub type BoxedIndicator = Box<dyn Indicator<Output = IndicatorValue>>;
+
+/// 按字段命名的指标输出
+///
+/// 单值指标只有一个 `value` 字段；多值指标（如MACD）每条线一个字段，
+/// 第一个字段为主值。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_indicators::{IndicatorValue, MACDOutput};
+///
+/// let value = IndicatorValue::from(MACDOutput {
+///     macd: 1.5,
+///     signal: 1.0,
+///     histogram: 0.5,
+/// });
+///
+/// assert_eq!(value.primary(), 1.5);
+/// assert_eq!(value.get("histogram"), Some(0.5));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IndicatorValue {
+    fields: Vec<(&'static str, f64)>,
+}
+
+impl IndicatorValue {
+    /// 创建单值输出
+    pub fn single(value: f64) -> Self {
+        Self::from_fields(vec![("value", value)])
+    }
+
+    /// 由命名字段创建输出，第一个字段为主值
+    pub fn from_fields(fields: Vec<(&'static str, f64)>) -> Self {
+        Self { fields }
+    }
+
+    /// 主值（第一个字段）
+    pub fn primary(&self) -> f64 {
+        self.fields.first().map_or(f64::NAN, |(_, value)| *value)
+    }
+
+    /// 按名称取字段值
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| *value)
+    }
+
+    /// 全部字段
+    pub fn fields(&self) -> &[(&'static str, f64)] {
+        &self.fields
+    }
+}
+
+impl From<f64> for IndicatorValue {
+    fn from(value: f64) -> Self {
+        Self::single(value)
+    }
+}
+
+/// 把静态指标适配为动态指标
+#[derive(Debug)]
+struct Dynamic<T>(T);
+
+impl<T> Indicator for Dynamic<T>
+where
+    T: Indicator,
+    T::Output: Into<IndicatorValue>,
+{
+    type Output = IndicatorValue;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<IndicatorValue> {
+        self.0.update_kline(kline).map(Into::into)
+    }
+
+    fn is_ready(&self) -> bool {
+        self.0.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.0.warm_up_period()
+    }
+}
+
+#[cfg(test)]
+mod tests;
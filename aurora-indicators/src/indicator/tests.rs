@@ -0,0 +1,117 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use crate::{IndicatorParams, IndicatorRegistry, MACD, MA, PSAROutput, RSI};
+
+fn klines(count: usize) -> Vec<Kline> {
+    (0..count)
+        .map(|i| {
+            // 带波动的上涨序列，保证所有指标都能产生输出
+            let close = 100.0 + i as f64 + if i % 2 == 0 { 1.5 } else { -1.5 };
+            Kline {
+                timestamp: i as i64 * 60_000,
+                open: close - 0.5,
+                high: close + 2.0,
+                low: close - 2.0,
+                close,
+                volume: 100.0 + i as f64,
+                symbol: None,
+                interval: None,
+            }
+        })
+        .collect()
+}
+
+/// 测试预热长度与第一个输出出现的位置一致
+#[test]
+fn test_warm_up_period_matches_first_output() {
+    let registry = IndicatorRegistry::new();
+    let data = klines(120);
+
+    for name in registry.names() {
+        let mut indicator = registry.create(name, &IndicatorParams::new()).unwrap();
+        let warm_up = indicator.warm_up_period();
+
+        for (i, kline) in data.iter().enumerate() {
+            let output = indicator.update_kline(kline);
+            if i + 1 < warm_up {
+                assert!(output.is_none(), "{} 在第 {} 根K线提前输出", name, i + 1);
+                assert!(!indicator.is_ready(), "{} 在第 {} 根K线提前就绪", name, i + 1);
+            } else {
+                assert!(output.is_some(), "{} 在第 {} 根K线没有输出", name, i + 1);
+                assert!(indicator.is_ready(), "{} 在第 {} 根K线未就绪", name, i + 1);
+            }
+        }
+    }
+}
+
+/// 测试重置后重新预热
+#[test]
+fn test_reset_restarts_warm_up() {
+    let mut rsi = RSI::new(14);
+    let data = klines(5);
+    for kline in &data {
+        rsi.update_kline(kline);
+    }
+    assert!(Indicator::is_ready(&rsi));
+
+    Indicator::reset(&mut rsi);
+    assert!(!Indicator::is_ready(&rsi));
+    assert!(rsi.update_kline(&data[0]).is_none());
+}
+
+/// 测试静态和动态指标输出一致
+#[test]
+fn test_boxed_output_matches_typed_output() {
+    let mut typed = MACD::new(3, 6, 2);
+    let mut boxed = MACD::new(3, 6, 2).boxed();
+
+    for kline in klines(20) {
+        let expected = typed.update_kline(&kline).unwrap();
+        let value = boxed.update_kline(&kline).unwrap();
+        assert_eq!(value.primary(), expected.macd);
+        assert_eq!(value.get("signal"), Some(expected.signal));
+        assert_eq!(value.get("histogram"), Some(expected.histogram));
+    }
+}
+
+/// 测试指标输出值
+#[test]
+fn test_indicator_value_fields() {
+    let value = IndicatorValue::from(42.0);
+    assert_eq!(value.primary(), 42.0);
+    assert_eq!(value.get("value"), Some(42.0));
+    assert_eq!(value.get("missing"), None);
+
+    let psar = IndicatorValue::from(PSAROutput {
+        sar: 95.0,
+        is_uptrend: false,
+    });
+    assert_eq!(psar.get("trend"), Some(-1.0));
+
+    assert!(IndicatorValue::from_fields(Vec::new()).primary().is_nan());
+}
+
+/// 测试可以泛化地使用指标
+#[test]
+fn test_generic_usage() {
+    fn last_output<I: Indicator>(indicator: &mut I, data: &[Kline]) -> Option<I::Output> {
+        data.iter().filter_map(|kline| indicator.update_kline(kline)).last()
+    }
+
+    let data = klines(10);
+    let mut ma = MA::new(10);
+    let expected = data.iter().map(|k| k.close).sum::<f64>() / 10.0;
+    assert!((last_output(&mut ma, &data).unwrap() - expected).abs() < 1e-9);
+}
@@ -0,0 +1,184 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 考夫曼自适应移动平均线 (Kaufman's Adaptive Moving Average) 指标
+//!
+//! KAMA 由 Perry Kaufman 提出，根据价格的效率比率调整平滑系数：
+//! 趋势明确时接近快速EMA，震荡时接近慢速EMA，从而减少震荡市中的假信号。
+//!
+//! # 计算公式
+//!
+//! - 效率比率 ER = |P(t) - P(t-N)| / Σ|P(i) - P(i-1)|（最近N次变化）
+//! - 平滑系数 SC = (ER × (快速SC - 慢速SC) + 慢速SC)²
+//!   其中 快速SC = 2 / (fast + 1)，慢速SC = 2 / (slow + 1)
+//! - KAMA(t) = KAMA(t-1) + SC × (P(t) - KAMA(t-1))
+//!
+//! 需要 N + 1 个数据点后才开始输出，第一个输出以前一个价格作为 KAMA(t-1)。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::KAMA;
+//!
+//! let mut kama = KAMA::default(); // 10, 2, 30
+//!
+//! for i in 0..10 {
+//!     assert_eq!(kama.update(100.0 + i as f64), None);
+//! }
+//! assert!(kama.update(110.0).is_some());
+//! ```
+
+use aurora_core::Kline;
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// 考夫曼自适应移动平均线 (Kaufman's Adaptive Moving Average) 指标
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KAMA {
+    /// 效率比率周期
+    period: usize,
+    /// 快速平滑系数 2 / (fast + 1)
+    fast_sc: f64,
+    /// 慢速平滑系数 2 / (slow + 1)
+    slow_sc: f64,
+    /// 最近 N + 1 个价格
+    prices: VecDeque<f64>,
+    /// 当前KAMA值
+    current: Option<f64>,
+}
+
+impl KAMA {
+    /// 创建新的KAMA指标
+    ///
+    /// # 参数
+    ///
+    /// * `period` - 效率比率周期，通常使用10
+    /// * `fast_period` - 快速EMA周期，通常使用2
+    /// * `slow_period` - 慢速EMA周期，通常使用30
+    ///
+    /// # Panics
+    ///
+    /// 周期为0或 `fast_period >= slow_period` 时panic
+    pub fn new(period: usize, fast_period: usize, slow_period: usize) -> Self {
+        assert!(period > 0 && fast_period > 0, "KAMA周期必须大于0");
+        assert!(fast_period < slow_period, "KAMA快速周期必须小于慢速周期");
+
+        Self {
+            period,
+            fast_sc: 2.0 / (fast_period as f64 + 1.0),
+            slow_sc: 2.0 / (slow_period as f64 + 1.0),
+            prices: VecDeque::with_capacity(period + 1),
+            current: None,
+        }
+    }
+
+    /// 更新指标并返回最新的KAMA值
+    ///
+    /// # 参数
+    ///
+    /// * `price` - 新的价格数据
+    ///
+    /// # 返回值
+    ///
+    /// * `Some(f64)` - 已有 N + 1 个数据点时返回KAMA值
+    /// * `None` - 数据点不足
+    pub fn update(&mut self, price: f64) -> Option<f64> {
+        self.prices.push_back(price);
+        if self.prices.len() > self.period + 1 {
+            self.prices.pop_front();
+        }
+        if self.prices.len() <= self.period {
+            return None;
+        }
+
+        let er = self.efficiency_ratio();
+        let sc = (er * (self.fast_sc - self.slow_sc) + self.slow_sc).powi(2);
+        let previous = self
+            .current
+            .unwrap_or(self.prices[self.prices.len() - 2]);
+
+        let kama = previous + sc * (price - previous);
+        self.current = Some(kama);
+        Some(kama)
+    }
+
+    /// 当前窗口的效率比率，价格没有变化时为0
+    fn efficiency_ratio(&self) -> f64 {
+        let change = (self.prices[self.prices.len() - 1] - self.prices[0]).abs();
+        let volatility: f64 = self
+            .prices
+            .iter()
+            .zip(self.prices.iter().skip(1))
+            .map(|(prev, curr)| (curr - prev).abs())
+            .sum();
+
+        if volatility == 0.0 {
+            0.0
+        } else {
+            change / volatility
+        }
+    }
+
+    /// 获取当前的KAMA值
+    pub fn value(&self) -> Option<f64> {
+        self.current
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.prices.clear();
+        self.current = None;
+    }
+
+    /// 获取效率比率周期
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// 检查指标是否已有输出
+    pub fn is_ready(&self) -> bool {
+        self.current.is_some()
+    }
+}
+
+// 默认参数: Kaufman 推荐的 10, 2, 30
+impl Default for KAMA {
+    fn default() -> Self {
+        Self::new(10, 2, 30)
+    }
+}
+
+impl Indicator for KAMA {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        KAMA::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        KAMA::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period + 1
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,115 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::test_data::MOVAVG_CLOSES;
+use approx::assert_relative_eq;
+
+#[test]
+fn test_kama_new() {
+    let kama = KAMA::new(10, 2, 30);
+    assert_eq!(kama.period(), 10);
+    assert_relative_eq!(kama.fast_sc, 2.0 / 3.0);
+    assert_relative_eq!(kama.slow_sc, 2.0 / 31.0);
+    assert!(!kama.is_ready());
+}
+
+#[test]
+#[should_panic(expected = "KAMA周期必须大于0")]
+fn test_kama_zero_period() {
+    KAMA::new(0, 2, 30);
+}
+
+#[test]
+#[should_panic(expected = "KAMA快速周期必须小于慢速周期")]
+fn test_kama_invalid_fast_slow() {
+    KAMA::new(10, 30, 2);
+}
+
+/// 参考值：StockCharts ChartSchool 移动平均线示例表（cs-movavg.xls）的30日收盘价的KAMA(10, 2, 30)，
+/// 第11根K线起输出，首个值以前一收盘价为初值；取最后8个值（保留四位小数）
+#[test]
+fn test_kama_reference_values() {
+    let mut kama = KAMA::new(10, 2, 30);
+    let outputs: Vec<f64> = MOVAVG_CLOSES.iter().filter_map(|&price| kama.update(price)).collect();
+
+    assert_eq!(outputs.len(), 20);
+    let expected = [23.5158, 23.5059, 23.4993, 23.4865, 23.3977, 23.3827, 23.2659, 23.1423];
+    for (output, expected) in outputs[12..].iter().zip(expected) {
+        assert_relative_eq!(*output, expected, epsilon = 1e-4);
+    }
+}
+
+/// 周期2、快速2、慢速30的逐步计算：
+/// 第3个点: ER = 2/2 = 1，SC = (2/3)² = 4/9，KAMA = 2 + 4/9 × (3 - 2) = 22/9
+/// 第4个点（价格持平）: ER = 1/1 = 1，KAMA = 22/9 + 4/9 × (3 - 22/9) = 218/81
+#[test]
+fn test_kama_worked_example() {
+    let mut kama = KAMA::new(2, 2, 30);
+    assert_eq!(kama.update(1.0), None);
+    assert_eq!(kama.update(2.0), None);
+    assert_relative_eq!(kama.update(3.0).unwrap(), 22.0 / 9.0, epsilon = 1e-10);
+    assert_relative_eq!(kama.update(3.0).unwrap(), 218.0 / 81.0, epsilon = 1e-10);
+}
+
+#[test]
+fn test_kama_efficiency_ratio() {
+    let mut kama = KAMA::new(4, 2, 30);
+    // 净变化1，总波动 1+1+1+2 = 5
+    for price in [10.0, 11.0, 10.0, 11.0, 9.0] {
+        kama.update(price);
+    }
+    assert_relative_eq!(kama.efficiency_ratio(), 0.2);
+}
+
+#[test]
+fn test_kama_adapts_to_market() {
+    // 趋势市中KAMA紧跟价格，震荡市中几乎不动
+    let mut trending = KAMA::default();
+    let mut choppy = KAMA::default();
+    let mut trend_value = None;
+    let mut choppy_value = None;
+
+    for i in 0..40 {
+        trend_value = trending.update(100.0 + i as f64);
+        choppy_value = choppy.update(if i % 2 == 0 { 100.0 } else { 102.0 });
+    }
+
+    assert!((139.0 - trend_value.unwrap()).abs() < 2.0);
+    let choppy_value = choppy_value.unwrap();
+    assert!(choppy_value > 100.0 && choppy_value < 102.0);
+}
+
+#[test]
+fn test_kama_flat_prices() {
+    let mut kama = KAMA::new(3, 2, 30);
+    let mut result = None;
+    for _ in 0..10 {
+        result = kama.update(50.0);
+    }
+    assert_eq!(result, Some(50.0));
+}
+
+#[test]
+fn test_kama_reset() {
+    let mut kama = KAMA::new(2, 2, 30);
+    for price in [1.0, 2.0, 3.0] {
+        kama.update(price);
+    }
+    assert!(kama.is_ready());
+
+    kama.reset();
+    assert!(!kama.is_ready());
+    assert_eq!(kama.update(1.0), None);
+}
@@ -44,10 +44,12 @@
 //! }
 //! ```
 
-use crate::{EMA, ATR};
+use crate::{ATR, EMA, Indicator, IndicatorValue};
+use aurora_core::Kline;
+use serde::{Deserialize, Serialize};
 
 /// Keltner Channels 输出结构
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct KeltnerChannelsOutput {
     /// 上轨
     pub upper: f64,
@@ -60,6 +62,7 @@ pub struct KeltnerChannelsOutput {
 /// Keltner Channels 肯特纳通道
 ///
 /// 基于EMA和ATR构建的价格通道
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeltnerChannels {
     /// EMA周期(存储以备将来扩展使用)
     #[allow(dead_code)]
@@ -256,5 +259,35 @@ impl Default for KeltnerChannels {
     }
 }
 
+impl Indicator for KeltnerChannels {
+    type Output = KeltnerChannelsOutput;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<KeltnerChannelsOutput> {
+        self.update(kline.high, kline.low, kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        KeltnerChannels::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        KeltnerChannels::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        1
+    }
+}
+
+impl From<KeltnerChannelsOutput> for IndicatorValue {
+    fn from(output: KeltnerChannelsOutput) -> Self {
+        IndicatorValue::from_fields(vec![
+            ("middle", output.middle),
+            ("upper", output.upper),
+            ("lower", output.lower),
+        ])
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,33 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 价格水平
+//!
+//! 计算枢轴点、斐波那契回撤位和支撑阻力区，作为止损和止盈的参考价位，
+//! 可与 [`ATR::stop_loss`](crate::ATR::stop_loss) 配合使用。
+//!
+//! - [`PivotPoints`] 按交易时段计算经典、Camarilla、Woodie 枢轴点
+//! - [`Fractals`] 和 [`ZigZag`] 识别波段高低点
+//! - [`FibonacciRetracement`] 由最近的波段高低点计算斐波那契回撤位
+//! - [`SupportResistance`] 把相近的波段高低点聚合为支撑阻力区
+
+mod fibonacci;
+mod pivot;
+mod swing;
+mod zones;
+
+pub use fibonacci::{FibonacciLevels, FibonacciRetracement};
+pub use pivot::{PivotLevels, PivotMethod, PivotPoints};
+pub use swing::{Fractals, Swing, SwingKind, SwingSource, ZigZag};
+pub use zones::{SupportResistance, Zone};
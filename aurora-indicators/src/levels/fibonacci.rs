@@ -0,0 +1,236 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fibonacci Retracement - 斐波那契回撤
+//!
+//! 在最近一段波段（低点到高点或高点到低点）上按斐波那契比例划分价位，
+//! 回调常在 38.2%、50%、61.8% 附近止跌，延伸位 127.2%、161.8% 常作为目标位。
+//!
+//! # 计算公式
+//!
+//! 记波段区间 R = 波段高点 - 波段低点：
+//!
+//! - 上涨波段（低点在前）：回撤位 = 高点 - R × 比例，延伸位 = 低点 + R × 比例
+//! - 下跌波段（高点在前）：回撤位 = 低点 + R × 比例，延伸位 = 高点 - R × 比例
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::FibonacciLevels;
+//!
+//! // 从100涨到200的上涨波段
+//! let fib = FibonacciLevels::new(200.0, 100.0, true);
+//!
+//! assert_eq!(fib.retracement(0.5), 150.0);
+//! assert!((fib.retracement(0.618) - 138.2).abs() < 1e-9);
+//! assert!((fib.extension(1.618) - 261.8).abs() < 1e-9);
+//! ```
+
+use aurora_core::Kline;
+use super::swing::{Swing, SwingDetector, SwingKind, SwingSource};
+use crate::{Indicator, IndicatorValue};
+use serde::{Deserialize, Serialize};
+
+/// 斐波那契价位
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FibonacciLevels {
+    /// 波段高点
+    pub swing_high: f64,
+    /// 波段低点
+    pub swing_low: f64,
+    /// 是否为上涨波段（低点在前，高点在后）
+    pub is_uptrend: bool,
+}
+
+impl FibonacciLevels {
+    /// 常用回撤比例
+    pub const RETRACEMENT_RATIOS: [f64; 7] = [0.0, 0.236, 0.382, 0.5, 0.618, 0.786, 1.0];
+
+    /// 常用延伸比例
+    pub const EXTENSION_RATIOS: [f64; 3] = [1.272, 1.618, 2.618];
+
+    /// 由波段高点和低点创建斐波那契价位
+    pub fn new(swing_high: f64, swing_low: f64, is_uptrend: bool) -> Self {
+        Self {
+            swing_high,
+            swing_low,
+            is_uptrend,
+        }
+    }
+
+    /// 波段区间
+    pub fn range(&self) -> f64 {
+        self.swing_high - self.swing_low
+    }
+
+    /// 按比例计算回撤位
+    pub fn retracement(&self, ratio: f64) -> f64 {
+        if self.is_uptrend {
+            self.swing_high - self.range() * ratio
+        } else {
+            self.swing_low + self.range() * ratio
+        }
+    }
+
+    /// 按比例计算延伸位
+    pub fn extension(&self, ratio: f64) -> f64 {
+        if self.is_uptrend {
+            self.swing_low + self.range() * ratio
+        } else {
+            self.swing_high - self.range() * ratio
+        }
+    }
+
+    /// 常用回撤位，按 [`RETRACEMENT_RATIOS`](Self::RETRACEMENT_RATIOS) 的顺序返回 (比例, 价格)
+    pub fn retracements(&self) -> Vec<(f64, f64)> {
+        Self::RETRACEMENT_RATIOS
+            .iter()
+            .map(|&ratio| (ratio, self.retracement(ratio)))
+            .collect()
+    }
+
+    /// 低于价格的最近回撤位
+    pub fn nearest_support(&self, price: f64) -> Option<f64> {
+        self.retracements()
+            .into_iter()
+            .map(|(_, level)| level)
+            .filter(|&level| level < price)
+            .max_by(f64::total_cmp)
+    }
+
+    /// 高于价格的最近回撤位
+    pub fn nearest_resistance(&self, price: f64) -> Option<f64> {
+        self.retracements()
+            .into_iter()
+            .map(|(_, level)| level)
+            .filter(|&level| level > price)
+            .min_by(f64::total_cmp)
+    }
+}
+
+/// Fibonacci Retracement 自动斐波那契回撤
+///
+/// 识别波段高低点，以最近的一个波段高点和一个波段低点计算斐波那契价位。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FibonacciRetracement {
+    /// 波段识别方法
+    source: SwingSource,
+    /// 波段识别器
+    detector: SwingDetector,
+    /// 最近的波段高点
+    last_high: Option<Swing>,
+    /// 最近的波段低点
+    last_low: Option<Swing>,
+}
+
+impl FibonacciRetracement {
+    /// 创建新的自动斐波那契回撤
+    ///
+    /// # 参数
+    ///
+    /// * `source` - 波段高低点的识别方法
+    ///
+    /// # Panics
+    ///
+    /// 当识别方法的参数不合法时会 panic
+    pub fn new(source: SwingSource) -> Self {
+        Self {
+            source,
+            detector: SwingDetector::new(source),
+            last_high: None,
+            last_low: None,
+        }
+    }
+
+    /// 更新指标
+    ///
+    /// # 返回值
+    ///
+    /// - `Some(FibonacciLevels)` - 已识别出至少一个波段高点和一个波段低点
+    /// - `None` - 波段点不足
+    pub fn update(&mut self, high: f64, low: f64) -> Option<FibonacciLevels> {
+        for swing in self.detector.update(high, low) {
+            match swing.kind {
+                SwingKind::High => self.last_high = Some(swing),
+                SwingKind::Low => self.last_low = Some(swing),
+            }
+        }
+
+        self.value()
+    }
+
+    /// 获取当前的斐波那契价位
+    pub fn value(&self) -> Option<FibonacciLevels> {
+        let (high, low) = (self.last_high?, self.last_low?);
+        Some(FibonacciLevels::new(high.price, low.price, high.index >= low.index))
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.detector.reset();
+        self.last_high = None;
+        self.last_low = None;
+    }
+
+    /// 获取波段识别方法
+    pub fn source(&self) -> SwingSource {
+        self.source
+    }
+
+    /// 检查是否已准备好输出结果
+    pub fn is_ready(&self) -> bool {
+        self.last_high.is_some() && self.last_low.is_some()
+    }
+}
+
+impl Indicator for FibonacciRetracement {
+    type Output = FibonacciLevels;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<FibonacciLevels> {
+        self.update(kline.high, kline.low)
+    }
+
+    fn is_ready(&self) -> bool {
+        FibonacciRetracement::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        FibonacciRetracement::reset(self);
+    }
+
+    // 实际所需的K线数量取决于价格走势，这里给出识别出两个波段点的最少K线数
+    fn warm_up_period(&self) -> usize {
+        match self.source {
+            SwingSource::Fractal(span) => 2 * span + 1,
+            SwingSource::ZigZag(_) => 3,
+        }
+    }
+}
+
+impl From<FibonacciLevels> for IndicatorValue {
+    fn from(output: FibonacciLevels) -> Self {
+        IndicatorValue::from_fields(vec![
+            ("retracement_618", output.retracement(0.618)),
+            ("retracement_236", output.retracement(0.236)),
+            ("retracement_382", output.retracement(0.382)),
+            ("retracement_500", output.retracement(0.5)),
+            ("retracement_786", output.retracement(0.786)),
+            ("swing_high", output.swing_high),
+            ("swing_low", output.swing_low),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests;
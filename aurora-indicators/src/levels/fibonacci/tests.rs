@@ -0,0 +1,97 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use approx::assert_relative_eq;
+
+/// 参考值（按公式手工计算）：波段 100 -> 200
+#[test]
+fn test_uptrend_levels() {
+    let fib = FibonacciLevels::new(200.0, 100.0, true);
+
+    assert_eq!(fib.range(), 100.0);
+    assert_relative_eq!(fib.retracement(0.236), 176.4, epsilon = 1e-9);
+    assert_relative_eq!(fib.retracement(0.382), 161.8, epsilon = 1e-9);
+    assert_relative_eq!(fib.retracement(0.786), 121.4, epsilon = 1e-9);
+    assert_eq!(fib.retracement(0.0), 200.0);
+    assert_eq!(fib.retracement(1.0), 100.0);
+    assert_relative_eq!(fib.extension(1.272), 227.2, epsilon = 1e-9);
+}
+
+/// 参考值（按公式手工计算）：波段 200 -> 100
+#[test]
+fn test_downtrend_levels() {
+    let fib = FibonacciLevels::new(200.0, 100.0, false);
+
+    assert_relative_eq!(fib.retracement(0.382), 138.2, epsilon = 1e-9);
+    assert_relative_eq!(fib.retracement(0.618), 161.8, epsilon = 1e-9);
+    assert_relative_eq!(fib.extension(1.618), 38.2, epsilon = 1e-9);
+}
+
+#[test]
+fn test_retracements_and_nearest() {
+    let fib = FibonacciLevels::new(200.0, 100.0, true);
+
+    let levels = fib.retracements();
+    assert_eq!(levels.len(), FibonacciLevels::RETRACEMENT_RATIOS.len());
+    assert_eq!(levels[3], (0.5, 150.0));
+
+    assert_eq!(fib.nearest_support(155.0), Some(150.0));
+    assert_relative_eq!(fib.nearest_resistance(155.0).unwrap(), 161.8, epsilon = 1e-9);
+    assert_eq!(fib.nearest_support(100.0), None);
+    assert_eq!(fib.nearest_resistance(200.0), None);
+}
+
+/// 测试由之字形波段点自动计算
+#[test]
+fn test_auto_retracement_from_zigzag() {
+    let mut fib = FibonacciRetracement::new(SwingSource::ZigZag(0.05));
+    assert_eq!(fib.source(), SwingSource::ZigZag(0.05));
+
+    assert!(fib.update(101.0, 99.0).is_none());
+    // 只有波段低点
+    assert!(fib.update(111.0, 108.0).is_none());
+    assert!(!fib.is_ready());
+
+    // 确认高点111，低点在前为上涨波段
+    let levels = fib.update(104.0, 100.0).unwrap();
+    assert_eq!(levels, FibonacciLevels::new(111.0, 99.0, true));
+
+    // 确认新低点100，低点在后为下跌波段
+    let levels = fib.update(112.0, 109.0).unwrap();
+    assert_eq!(levels, FibonacciLevels::new(111.0, 100.0, false));
+}
+
+/// 测试由分形波段点自动计算
+#[test]
+fn test_auto_retracement_from_fractals() {
+    let mut fib = FibonacciRetracement::new(SwingSource::Fractal(1));
+    assert_eq!(Indicator::warm_up_period(&fib), 3);
+
+    let bars = [(10.0, 8.0), (12.0, 9.0), (11.0, 7.0), (13.0, 10.0)];
+    let outputs: Vec<_> = bars.iter().map(|&(high, low)| fib.update(high, low)).collect();
+
+    assert!(outputs[2].is_none());
+    assert_eq!(outputs[3], Some(FibonacciLevels::new(12.0, 7.0, false)));
+
+    fib.reset();
+    assert!(!fib.is_ready());
+}
+
+#[test]
+fn test_fibonacci_indicator_value() {
+    let value = IndicatorValue::from(FibonacciLevels::new(200.0, 100.0, true));
+    assert_relative_eq!(value.primary(), 138.2, epsilon = 1e-9);
+    assert_eq!(value.get("retracement_500"), Some(150.0));
+    assert_eq!(value.get("swing_high"), Some(200.0));
+}
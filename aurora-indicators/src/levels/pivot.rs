@@ -0,0 +1,276 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pivot Points - 枢轴点
+//!
+//! 枢轴点由上一交易时段的最高价、最低价和收盘价计算，
+//! 给出当前时段的中枢和若干支撑位、阻力位。
+//!
+//! # 计算公式
+//!
+//! 记上一时段的最高价为H、最低价为L、收盘价为C，区间 R = H - L：
+//!
+//! - **经典**: P = (H + L + C) / 3，R1 = 2P - L，S1 = 2P - H，R2 = P + R，S2 = P - R，
+//!   R3 = H + 2(P - L)，S3 = L - 2(H - P)，R4 = R3 + R，S4 = S3 - R
+//! - **Woodie**: P = (H + L + 2C) / 4，其余同经典
+//! - **Camarilla**: P = (H + L + C) / 3，Rn = C + R × 1.1 / k，Sn = C - R × 1.1 / k，
+//!   k 依次为 12、6、4、2
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::{PivotLevels, PivotMethod};
+//!
+//! let levels = PivotLevels::calculate(PivotMethod::Classic, 110.0, 90.0, 100.0);
+//!
+//! assert_eq!(levels.pivot, 100.0);
+//! assert_eq!(levels.r1, 110.0);
+//! assert_eq!(levels.s1, 90.0);
+//! assert_eq!(levels.nearest_support(105.0), Some(100.0));
+//! ```
+
+use aurora_core::Kline;
+use crate::{Indicator, IndicatorValue};
+use serde::{Deserialize, Serialize};
+
+/// 枢轴点计算方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PivotMethod {
+    /// 经典枢轴点
+    Classic,
+    /// Camarilla 枢轴点，价位更贴近收盘价，适合日内反转交易
+    Camarilla,
+    /// Woodie 枢轴点，加大收盘价的权重
+    Woodie,
+}
+
+/// 枢轴点价位
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PivotLevels {
+    /// 中枢
+    pub pivot: f64,
+    /// 第一阻力位
+    pub r1: f64,
+    /// 第二阻力位
+    pub r2: f64,
+    /// 第三阻力位
+    pub r3: f64,
+    /// 第四阻力位
+    pub r4: f64,
+    /// 第一支撑位
+    pub s1: f64,
+    /// 第二支撑位
+    pub s2: f64,
+    /// 第三支撑位
+    pub s3: f64,
+    /// 第四支撑位
+    pub s4: f64,
+}
+
+impl PivotLevels {
+    /// 由上一时段的最高价、最低价和收盘价计算枢轴点
+    pub fn calculate(method: PivotMethod, high: f64, low: f64, close: f64) -> Self {
+        let range = high - low;
+
+        match method {
+            PivotMethod::Classic | PivotMethod::Woodie => {
+                let pivot = if method == PivotMethod::Classic {
+                    (high + low + close) / 3.0
+                } else {
+                    (high + low + 2.0 * close) / 4.0
+                };
+                let r3 = high + 2.0 * (pivot - low);
+                let s3 = low - 2.0 * (high - pivot);
+                Self {
+                    pivot,
+                    r1: 2.0 * pivot - low,
+                    r2: pivot + range,
+                    r3,
+                    r4: r3 + range,
+                    s1: 2.0 * pivot - high,
+                    s2: pivot - range,
+                    s3,
+                    s4: s3 - range,
+                }
+            }
+            PivotMethod::Camarilla => {
+                let step = |k: f64| range * 1.1 / k;
+                Self {
+                    pivot: (high + low + close) / 3.0,
+                    r1: close + step(12.0),
+                    r2: close + step(6.0),
+                    r3: close + step(4.0),
+                    r4: close + step(2.0),
+                    s1: close - step(12.0),
+                    s2: close - step(6.0),
+                    s3: close - step(4.0),
+                    s4: close - step(2.0),
+                }
+            }
+        }
+    }
+
+    /// 全部价位，从低到高排列
+    pub fn levels(&self) -> [f64; 9] {
+        let mut levels = [
+            self.s4, self.s3, self.s2, self.s1, self.pivot, self.r1, self.r2, self.r3, self.r4,
+        ];
+        levels.sort_by(f64::total_cmp);
+        levels
+    }
+
+    /// 低于价格的最近价位
+    pub fn nearest_support(&self, price: f64) -> Option<f64> {
+        self.levels().into_iter().rev().find(|&level| level < price)
+    }
+
+    /// 高于价格的最近价位
+    pub fn nearest_resistance(&self, price: f64) -> Option<f64> {
+        self.levels().into_iter().find(|&level| level > price)
+    }
+}
+
+/// Pivot Points 枢轴点
+///
+/// 按交易时段统计最高价、最低价和收盘价，新时段开始时由上一时段计算枢轴点。
+/// 时段按UTC时间戳对齐。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PivotPoints {
+    /// 计算方法
+    method: PivotMethod,
+    /// 时段长度(毫秒)
+    session_ms: i64,
+    /// 当前时段编号
+    session_id: Option<i64>,
+    /// 当前时段的最高价、最低价和收盘价
+    session_hlc: Option<(f64, f64, f64)>,
+    /// 由上一时段计算的枢轴点
+    levels: Option<PivotLevels>,
+}
+
+impl PivotPoints {
+    /// 创建新的Pivot Points指标实例
+    ///
+    /// # 参数
+    ///
+    /// * `method` - 计算方法
+    /// * `session_ms` - 时段长度(毫秒)，日线枢轴点为 `86_400_000`
+    ///
+    /// # Panics
+    ///
+    /// 当 `session_ms` 不大于0时会 panic
+    pub fn new(method: PivotMethod, session_ms: i64) -> Self {
+        assert!(session_ms > 0, "交易时段长度必须大于0");
+
+        Self {
+            method,
+            session_ms,
+            session_id: None,
+            session_hlc: None,
+            levels: None,
+        }
+    }
+
+    /// 更新指标
+    ///
+    /// # 返回值
+    ///
+    /// - `Some(PivotLevels)` - 上一时段结束后返回当前时段的枢轴点
+    /// - `None` - 还处于第一个时段
+    pub fn update(&mut self, kline: &Kline) -> Option<PivotLevels> {
+        let session_id = kline.timestamp.div_euclid(self.session_ms);
+        if self.session_id != Some(session_id) {
+            if let Some((high, low, close)) = self.session_hlc.take() {
+                self.levels = Some(PivotLevels::calculate(self.method, high, low, close));
+            }
+            self.session_id = Some(session_id);
+        }
+
+        self.session_hlc = Some(match self.session_hlc {
+            Some((high, low, _)) => (high.max(kline.high), low.min(kline.low), kline.close),
+            None => (kline.high, kline.low, kline.close),
+        });
+
+        self.levels
+    }
+
+    /// 获取当前时段的枢轴点
+    pub fn value(&self) -> Option<PivotLevels> {
+        self.levels
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.session_id = None;
+        self.session_hlc = None;
+        self.levels = None;
+    }
+
+    /// 获取计算方法
+    pub fn method(&self) -> PivotMethod {
+        self.method
+    }
+
+    /// 检查是否已准备好输出结果
+    pub fn is_ready(&self) -> bool {
+        self.levels.is_some()
+    }
+}
+
+// 默认参数: 经典日线枢轴点
+impl Default for PivotPoints {
+    fn default() -> Self {
+        Self::new(PivotMethod::Classic, 86_400_000)
+    }
+}
+
+impl Indicator for PivotPoints {
+    type Output = PivotLevels;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<PivotLevels> {
+        self.update(kline)
+    }
+
+    fn is_ready(&self) -> bool {
+        PivotPoints::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        PivotPoints::reset(self);
+    }
+
+    // 实际所需的K线数量取决于时段内的K线数，至少需要两个时段各一根
+    fn warm_up_period(&self) -> usize {
+        2
+    }
+}
+
+impl From<PivotLevels> for IndicatorValue {
+    fn from(output: PivotLevels) -> Self {
+        IndicatorValue::from_fields(vec![
+            ("pivot", output.pivot),
+            ("r1", output.r1),
+            ("r2", output.r2),
+            ("r3", output.r3),
+            ("r4", output.r4),
+            ("s1", output.s1),
+            ("s2", output.s2),
+            ("s3", output.s3),
+            ("s4", output.s4),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,131 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use approx::assert_relative_eq;
+
+fn kline(timestamp: i64, high: f64, low: f64, close: f64) -> Kline {
+    Kline {
+        timestamp,
+        open: close,
+        high,
+        low,
+        close,
+        volume: 100.0,
+        symbol: None,
+        interval: None,
+    }
+}
+
+/// 参考值（按公式手工计算）：H=110, L=90, C=105
+#[test]
+fn test_classic_pivot_levels() {
+    let levels = PivotLevels::calculate(PivotMethod::Classic, 110.0, 90.0, 105.0);
+
+    assert_relative_eq!(levels.pivot, 101.666_666_666_666_67, epsilon = 1e-9);
+    assert_relative_eq!(levels.r1, 113.333_333_333_333_33, epsilon = 1e-9);
+    assert_relative_eq!(levels.s1, 93.333_333_333_333_33, epsilon = 1e-9);
+    assert_relative_eq!(levels.r2, 121.666_666_666_666_67, epsilon = 1e-9);
+    assert_relative_eq!(levels.s2, 81.666_666_666_666_67, epsilon = 1e-9);
+    assert_relative_eq!(levels.r3, 133.333_333_333_333_33, epsilon = 1e-9);
+    assert_relative_eq!(levels.s3, 73.333_333_333_333_33, epsilon = 1e-9);
+    assert_relative_eq!(levels.r4, 153.333_333_333_333_33, epsilon = 1e-9);
+    assert_relative_eq!(levels.s4, 53.333_333_333_333_33, epsilon = 1e-9);
+}
+
+/// 参考值（按公式手工计算）：H=110, L=90, C=105
+#[test]
+fn test_woodie_pivot_levels() {
+    let levels = PivotLevels::calculate(PivotMethod::Woodie, 110.0, 90.0, 105.0);
+
+    assert_relative_eq!(levels.pivot, 102.5, epsilon = 1e-9);
+    assert_relative_eq!(levels.r1, 115.0, epsilon = 1e-9);
+    assert_relative_eq!(levels.s1, 95.0, epsilon = 1e-9);
+    assert_relative_eq!(levels.r2, 122.5, epsilon = 1e-9);
+    assert_relative_eq!(levels.s2, 82.5, epsilon = 1e-9);
+}
+
+/// 参考值（按公式手工计算）：H=110, L=90, C=105，R × 1.1 = 22
+#[test]
+fn test_camarilla_pivot_levels() {
+    let levels = PivotLevels::calculate(PivotMethod::Camarilla, 110.0, 90.0, 105.0);
+
+    assert_relative_eq!(levels.r1, 105.0 + 22.0 / 12.0, epsilon = 1e-9);
+    assert_relative_eq!(levels.r2, 105.0 + 22.0 / 6.0, epsilon = 1e-9);
+    assert_relative_eq!(levels.r3, 110.5, epsilon = 1e-9);
+    assert_relative_eq!(levels.r4, 116.0, epsilon = 1e-9);
+    assert_relative_eq!(levels.s3, 99.5, epsilon = 1e-9);
+    assert_relative_eq!(levels.s4, 94.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_pivot_nearest_levels() {
+    let levels = PivotLevels::calculate(PivotMethod::Classic, 110.0, 90.0, 100.0);
+
+    assert_eq!(levels.levels()[0], levels.s4);
+    assert_eq!(levels.levels()[8], levels.r4);
+    assert_eq!(levels.nearest_support(105.0), Some(100.0));
+    assert_eq!(levels.nearest_resistance(105.0), Some(110.0));
+    assert_eq!(levels.nearest_support(levels.s4), None);
+    assert_eq!(levels.nearest_resistance(1000.0), None);
+}
+
+/// 测试按时段计算并在新时段切换
+#[test]
+fn test_pivot_points_sessions() {
+    let day = 86_400_000;
+    let mut pivots = PivotPoints::new(PivotMethod::Classic, day);
+    assert!(!pivots.is_ready());
+
+    // 第一天没有输出
+    assert!(pivots.update(&kline(0, 105.0, 95.0, 100.0)).is_none());
+    assert!(pivots.update(&kline(day / 2, 110.0, 90.0, 105.0)).is_none());
+
+    // 第二天使用第一天的 H=110, L=90, C=105
+    let levels = pivots.update(&kline(day, 120.0, 100.0, 118.0)).unwrap();
+    assert_eq!(levels, PivotLevels::calculate(PivotMethod::Classic, 110.0, 90.0, 105.0));
+
+    // 同一天内保持不变
+    assert_eq!(pivots.update(&kline(day + 1, 130.0, 80.0, 90.0)), Some(levels));
+
+    // 第三天使用第二天的 H=130, L=80, C=90
+    let next = pivots.update(&kline(day * 2, 100.0, 90.0, 95.0)).unwrap();
+    assert_eq!(next, PivotLevels::calculate(PivotMethod::Classic, 130.0, 80.0, 90.0));
+}
+
+#[test]
+fn test_pivot_points_reset() {
+    let mut pivots = PivotPoints::default();
+    assert_eq!(pivots.method(), PivotMethod::Classic);
+    pivots.update(&kline(0, 105.0, 95.0, 100.0));
+    pivots.update(&kline(86_400_000, 105.0, 95.0, 100.0));
+    assert!(pivots.is_ready());
+
+    pivots.reset();
+    assert!(!pivots.is_ready());
+    assert!(pivots.value().is_none());
+}
+
+#[test]
+#[should_panic(expected = "交易时段长度必须大于0")]
+fn test_pivot_points_invalid_session() {
+    PivotPoints::new(PivotMethod::Woodie, 0);
+}
+
+#[test]
+fn test_pivot_indicator_value() {
+    let value = IndicatorValue::from(PivotLevels::calculate(PivotMethod::Classic, 110.0, 90.0, 100.0));
+    assert_eq!(value.primary(), 100.0);
+    assert_eq!(value.get("r1"), Some(110.0));
+    assert_eq!(value.get("s1"), Some(90.0));
+}
@@ -0,0 +1,283 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 波段高低点识别
+//!
+//! - **分形 (Fractals)**: 某根K线的最高价严格高于左右各N根K线时为波段高点，
+//!   最低价严格低于左右各N根K线时为波段低点，需要等待右侧N根K线才能确认
+//! - **之字形 (ZigZag)**: 价格从极值反向运动超过阈值比例时，确认该极值为波段高点或低点
+//!
+//! 两者都只在确认时输出一次波段点，波段点的序号是它所在K线的序号（从0开始）。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::{SwingKind, ZigZag};
+//!
+//! let mut zigzag = ZigZag::new(0.05); // 反向5%确认
+//!
+//! assert!(zigzag.update(101.0, 99.0).is_none());
+//!
+//! // 自低点上涨超过5%，确认第一根K线的低点
+//! let swing = zigzag.update(111.0, 108.0).unwrap();
+//! assert_eq!(swing.kind, SwingKind::Low);
+//! assert_eq!(swing.price, 99.0);
+//! assert_eq!(swing.index, 0);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// 波段点类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SwingKind {
+    /// 波段高点
+    High,
+    /// 波段低点
+    Low,
+}
+
+/// 波段高低点
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Swing {
+    /// 类型
+    pub kind: SwingKind,
+    /// 价格
+    pub price: f64,
+    /// 所在K线的序号
+    pub index: usize,
+}
+
+/// Fractals 分形
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Fractals {
+    /// 左右两侧的K线数量
+    span: usize,
+    /// 最近 2N+1 根K线的最高价和最低价
+    window: VecDeque<(f64, f64)>,
+    /// 已处理的K线数量
+    count: usize,
+}
+
+impl Fractals {
+    /// 创建新的分形识别器
+    ///
+    /// # 参数
+    ///
+    /// * `span` - 左右两侧的K线数量，经典分形为2
+    ///
+    /// # Panics
+    ///
+    /// 当 `span` 为 0 时会 panic
+    pub fn new(span: usize) -> Self {
+        assert!(span > 0, "分形两侧K线数量必须大于0");
+
+        Self {
+            span,
+            window: VecDeque::with_capacity(2 * span + 1),
+            count: 0,
+        }
+    }
+
+    /// 更新并返回本次确认的波段点
+    ///
+    /// 确认的是 N 根K线之前的那根K线。同一根K线可能同时是波段高点和低点，
+    /// 此时先返回高点。
+    pub fn update(&mut self, high: f64, low: f64) -> Vec<Swing> {
+        self.window.push_back((high, low));
+        self.count += 1;
+        if self.window.len() > 2 * self.span + 1 {
+            self.window.pop_front();
+        }
+        if self.window.len() < 2 * self.span + 1 {
+            return Vec::new();
+        }
+
+        let (center_high, center_low) = self.window[self.span];
+        let others = || {
+            self.window
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != self.span)
+                .map(|(_, bar)| *bar)
+        };
+        let index = self.count - self.span - 1;
+
+        let mut swings = Vec::new();
+        if others().all(|(high, _)| center_high > high) {
+            swings.push(Swing { kind: SwingKind::High, price: center_high, index });
+        }
+        if others().all(|(_, low)| center_low < low) {
+            swings.push(Swing { kind: SwingKind::Low, price: center_low, index });
+        }
+        swings
+    }
+
+    /// 重置状态
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.count = 0;
+    }
+
+    /// 获取两侧K线数量
+    pub fn span(&self) -> usize {
+        self.span
+    }
+}
+
+/// 之字形的当前方向
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum ZigZagState {
+    /// 方向未定，记录最高点和最低点
+    Undecided { high: (f64, usize), low: (f64, usize) },
+    /// 上涨中，记录最高点
+    Up { extreme: (f64, usize) },
+    /// 下跌中，记录最低点
+    Down { extreme: (f64, usize) },
+}
+
+/// ZigZag 之字形
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZigZag {
+    /// 确认反转所需的比例
+    threshold: f64,
+    /// 当前状态
+    state: Option<ZigZagState>,
+    /// 已处理的K线数量
+    count: usize,
+}
+
+impl ZigZag {
+    /// 创建新的之字形识别器
+    ///
+    /// # 参数
+    ///
+    /// * `threshold` - 确认反转所需的反向运动比例，例如0.05表示5%
+    ///
+    /// # Panics
+    ///
+    /// 当 `threshold` 不大于0时会 panic
+    pub fn new(threshold: f64) -> Self {
+        assert!(threshold > 0.0, "之字形反转阈值必须大于0");
+
+        Self {
+            threshold,
+            state: None,
+            count: 0,
+        }
+    }
+
+    /// 更新并返回本次确认的波段点
+    pub fn update(&mut self, high: f64, low: f64) -> Option<Swing> {
+        let index = self.count;
+        self.count += 1;
+
+        let Some(state) = self.state else {
+            self.state = Some(ZigZagState::Undecided { high: (high, index), low: (low, index) });
+            return None;
+        };
+
+        let (state, swing) = match state {
+            ZigZagState::Undecided { high: top, low: bottom } => {
+                if high >= bottom.0 * (1.0 + self.threshold) {
+                    (ZigZagState::Up { extreme: (high, index) }, Some(Self::swing(SwingKind::Low, bottom)))
+                } else if low <= top.0 * (1.0 - self.threshold) {
+                    (ZigZagState::Down { extreme: (low, index) }, Some(Self::swing(SwingKind::High, top)))
+                } else {
+                    let top = if high > top.0 { (high, index) } else { top };
+                    let bottom = if low < bottom.0 { (low, index) } else { bottom };
+                    (ZigZagState::Undecided { high: top, low: bottom }, None)
+                }
+            }
+            ZigZagState::Up { extreme } => {
+                if high > extreme.0 {
+                    (ZigZagState::Up { extreme: (high, index) }, None)
+                } else if low <= extreme.0 * (1.0 - self.threshold) {
+                    (ZigZagState::Down { extreme: (low, index) }, Some(Self::swing(SwingKind::High, extreme)))
+                } else {
+                    (state, None)
+                }
+            }
+            ZigZagState::Down { extreme } => {
+                if low < extreme.0 {
+                    (ZigZagState::Down { extreme: (low, index) }, None)
+                } else if high >= extreme.0 * (1.0 + self.threshold) {
+                    (ZigZagState::Up { extreme: (high, index) }, Some(Self::swing(SwingKind::Low, extreme)))
+                } else {
+                    (state, None)
+                }
+            }
+        };
+
+        self.state = Some(state);
+        swing
+    }
+
+    fn swing(kind: SwingKind, (price, index): (f64, usize)) -> Swing {
+        Swing { kind, price, index }
+    }
+
+    /// 重置状态
+    pub fn reset(&mut self) {
+        self.state = None;
+        self.count = 0;
+    }
+
+    /// 获取反转阈值
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+}
+
+/// 波段高低点的识别方法
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SwingSource {
+    /// 分形，值为左右两侧的K线数量
+    Fractal(usize),
+    /// 之字形，值为反转阈值比例
+    ZigZag(f64),
+}
+
+/// 按识别方法创建的波段识别器
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) enum SwingDetector {
+    Fractal(Fractals),
+    ZigZag(ZigZag),
+}
+
+impl SwingDetector {
+    pub(crate) fn new(source: SwingSource) -> Self {
+        match source {
+            SwingSource::Fractal(span) => Self::Fractal(Fractals::new(span)),
+            SwingSource::ZigZag(threshold) => Self::ZigZag(ZigZag::new(threshold)),
+        }
+    }
+
+    pub(crate) fn update(&mut self, high: f64, low: f64) -> Vec<Swing> {
+        match self {
+            Self::Fractal(fractals) => fractals.update(high, low),
+            Self::ZigZag(zigzag) => zigzag.update(high, low).into_iter().collect(),
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        match self {
+            Self::Fractal(fractals) => fractals.reset(),
+            Self::ZigZag(zigzag) => zigzag.reset(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
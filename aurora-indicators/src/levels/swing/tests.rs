@@ -0,0 +1,125 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+#[test]
+#[should_panic(expected = "分形两侧K线数量必须大于0")]
+fn test_fractals_zero_span() {
+    Fractals::new(0);
+}
+
+#[test]
+#[should_panic(expected = "之字形反转阈值必须大于0")]
+fn test_zigzag_zero_threshold() {
+    ZigZag::new(0.0);
+}
+
+/// 测试分形在右侧K线到齐后确认
+#[test]
+fn test_fractal_high_and_low() {
+    let mut fractals = Fractals::new(2);
+    assert_eq!(fractals.span(), 2);
+
+    let bars = [(10.0, 5.0), (11.0, 6.0), (15.0, 7.0), (12.0, 4.0), (11.0, 6.0)];
+    for &(high, low) in &bars[..4] {
+        assert!(fractals.update(high, low).is_empty());
+    }
+
+    // 第5根K线确认第3根K线的高点
+    let swings = fractals.update(bars[4].0, bars[4].1);
+    assert_eq!(swings, vec![Swing { kind: SwingKind::High, price: 15.0, index: 2 }]);
+
+    // 第6根K线确认第4根K线的低点
+    let swings = fractals.update(12.0, 6.5);
+    assert_eq!(swings, vec![Swing { kind: SwingKind::Low, price: 4.0, index: 3 }]);
+}
+
+/// 测试相等的价格不构成分形
+#[test]
+fn test_fractal_requires_strict_extreme() {
+    let mut fractals = Fractals::new(1);
+    fractals.update(10.0, 5.0);
+    fractals.update(10.0, 6.0);
+    assert!(fractals.update(9.0, 5.5).is_empty());
+}
+
+/// 测试外包线同时是波段高点和低点
+#[test]
+fn test_fractal_outside_bar() {
+    let mut fractals = Fractals::new(1);
+    fractals.update(10.0, 5.0);
+    fractals.update(12.0, 3.0);
+    let swings = fractals.update(10.0, 5.0);
+
+    assert_eq!(swings.len(), 2);
+    assert_eq!(swings[0].kind, SwingKind::High);
+    assert_eq!(swings[1].kind, SwingKind::Low);
+}
+
+/// 测试之字形交替确认高低点
+#[test]
+fn test_zigzag_alternates() {
+    let mut zigzag = ZigZag::new(0.1);
+    assert_eq!(zigzag.threshold(), 0.1);
+
+    assert!(zigzag.update(100.0, 100.0).is_none());
+    // 下跌超过10%，确认最初的高点
+    let swing = zigzag.update(92.0, 89.0).unwrap();
+    assert_eq!(swing, Swing { kind: SwingKind::High, price: 100.0, index: 0 });
+
+    // 创新低只更新极值
+    assert!(zigzag.update(88.0, 80.0).is_none());
+    assert!(zigzag.update(87.0, 82.0).is_none());
+
+    // 自80反弹超过10%，确认低点
+    let swing = zigzag.update(88.5, 85.0).unwrap();
+    assert_eq!(swing, Swing { kind: SwingKind::Low, price: 80.0, index: 2 });
+
+    assert!(zigzag.update(95.0, 90.0).is_none());
+    let swing = zigzag.update(90.0, 85.0).unwrap();
+    assert_eq!(swing, Swing { kind: SwingKind::High, price: 95.0, index: 5 });
+}
+
+/// 测试小于阈值的波动被忽略
+#[test]
+fn test_zigzag_ignores_noise() {
+    let mut zigzag = ZigZag::new(0.05);
+    for (high, low) in [(101.0, 99.0), (102.0, 100.0), (101.0, 98.0), (102.5, 100.0)] {
+        assert!(zigzag.update(high, low).is_none());
+    }
+
+    zigzag.reset();
+    zigzag.update(100.0, 100.0);
+    // 重置后序号重新计数
+    assert_eq!(zigzag.update(106.0, 104.0).unwrap().index, 0);
+}
+
+#[test]
+fn test_swing_detector_sources() {
+    let mut fractal = SwingDetector::new(SwingSource::Fractal(1));
+    let mut zigzag = SwingDetector::new(SwingSource::ZigZag(0.05));
+
+    let bars = [(100.0, 99.0), (110.0, 105.0), (104.0, 101.0)];
+    let fractal_swings: Vec<Swing> = bars.iter().flat_map(|&(h, l)| fractal.update(h, l)).collect();
+    let zigzag_swings: Vec<Swing> = bars.iter().flat_map(|&(h, l)| zigzag.update(h, l)).collect();
+
+    assert_eq!(fractal_swings, vec![Swing { kind: SwingKind::High, price: 110.0, index: 1 }]);
+    assert_eq!(zigzag_swings, vec![
+        Swing { kind: SwingKind::Low, price: 99.0, index: 0 },
+        Swing { kind: SwingKind::High, price: 110.0, index: 1 },
+    ]);
+
+    fractal.reset();
+    assert!(fractal.update(100.0, 99.0).is_empty());
+}
@@ -0,0 +1,270 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support / Resistance - 支撑阻力区
+//!
+//! 价格多次在相近位置形成波段高点或低点，说明该位置有持续的买卖力量。
+//! 这里把相对距离在容差以内的波段点聚合为一个价格区间，
+//! 触及次数越多的区间越可靠。价格下方的区间为支撑，上方的区间为阻力。
+//!
+//! # 计算方法
+//!
+//! 1. 用分形或之字形识别波段高低点
+//! 2. 新的波段点与已有区间的距离不超过 价格 × 容差 时并入该区间，否则新建区间
+//! 3. 区间数量超过上限时，丢弃最久未被触及的区间
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::{SupportResistance, SwingSource};
+//!
+//! let mut sr = SupportResistance::new(SwingSource::ZigZag(0.05), 0.01);
+//!
+//! // 两次在100附近止跌
+//! for (high, low) in [(101.0, 100.0), (110.0, 108.0), (104.0, 100.5), (112.0, 109.0), (105.0, 103.0)] {
+//!     sr.update(high, low);
+//! }
+//!
+//! let support = sr.nearest_support(105.0).unwrap();
+//! assert_eq!(support.touches, 2);
+//! assert!(support.contains(100.2));
+//! ```
+
+use aurora_core::Kline;
+use super::swing::{SwingDetector, SwingSource};
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
+
+/// 支撑阻力区
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Zone {
+    /// 区间下沿
+    pub low: f64,
+    /// 区间上沿
+    pub high: f64,
+    /// 波段点触及次数
+    pub touches: usize,
+    /// 最近一次触及的K线序号
+    pub last_touch: usize,
+}
+
+impl Zone {
+    /// 区间中点
+    pub fn mid(&self) -> f64 {
+        (self.low + self.high) / 2.0
+    }
+
+    /// 判断价格是否处于区间内
+    pub fn contains(&self, price: f64) -> bool {
+        price >= self.low && price <= self.high
+    }
+
+    /// 价格到区间的距离，处于区间内时为0
+    fn distance(&self, price: f64) -> f64 {
+        (self.low - price).max(price - self.high).max(0.0)
+    }
+}
+
+/// Support / Resistance 支撑阻力区
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupportResistance {
+    /// 波段识别方法
+    source: SwingSource,
+    /// 波段识别器
+    detector: SwingDetector,
+    /// 聚合波段点的相对容差
+    tolerance: f64,
+    /// 区间数量上限
+    max_zones: usize,
+    /// 输出所需的最少触及次数
+    min_touches: usize,
+    /// 已识别的区间
+    zones: Vec<Zone>,
+}
+
+impl SupportResistance {
+    /// 创建新的支撑阻力区识别器
+    ///
+    /// # 参数
+    ///
+    /// * `source` - 波段高低点的识别方法
+    /// * `tolerance` - 聚合波段点的相对容差，例如0.005表示0.5%
+    ///
+    /// # Panics
+    ///
+    /// 当 `tolerance` 小于0或识别方法的参数不合法时会 panic
+    pub fn new(source: SwingSource, tolerance: f64) -> Self {
+        assert!(tolerance >= 0.0, "支撑阻力容差不能为负数");
+
+        Self {
+            source,
+            detector: SwingDetector::new(source),
+            tolerance,
+            max_zones: 20,
+            min_touches: 1,
+            zones: Vec::new(),
+        }
+    }
+
+    /// 设置区间数量上限，默认20
+    ///
+    /// # Panics
+    ///
+    /// 当 `max_zones` 为 0 时会 panic
+    pub fn with_max_zones(mut self, max_zones: usize) -> Self {
+        assert!(max_zones > 0, "区间数量上限必须大于0");
+        self.max_zones = max_zones;
+        self
+    }
+
+    /// 设置输出所需的最少触及次数，默认1
+    pub fn with_min_touches(mut self, min_touches: usize) -> Self {
+        self.min_touches = min_touches;
+        self
+    }
+
+    /// 更新并返回当前的支撑阻力区，按价格从低到高排列
+    pub fn update(&mut self, high: f64, low: f64) -> Vec<Zone> {
+        for swing in self.detector.update(high, low) {
+            self.add_touch(swing.price, swing.index);
+        }
+
+        self.zones()
+    }
+
+    fn add_touch(&mut self, price: f64, index: usize) {
+        let tolerance = price.abs() * self.tolerance;
+        let nearest = self
+            .zones
+            .iter_mut()
+            .filter(|zone| zone.distance(price) <= tolerance)
+            .min_by(|a, b| a.distance(price).total_cmp(&b.distance(price)));
+
+        match nearest {
+            Some(zone) => {
+                zone.low = zone.low.min(price);
+                zone.high = zone.high.max(price);
+                zone.touches += 1;
+                zone.last_touch = index;
+            }
+            None => {
+                self.zones.push(Zone {
+                    low: price,
+                    high: price,
+                    touches: 1,
+                    last_touch: index,
+                });
+                if self.zones.len() > self.max_zones {
+                    let stale = (0..self.zones.len())
+                        .min_by_key(|&i| self.zones[i].last_touch)
+                        .unwrap_or(0);
+                    self.zones.remove(stale);
+                }
+            }
+        }
+    }
+
+    /// 获取触及次数满足要求的区间，按价格从低到高排列
+    pub fn zones(&self) -> Vec<Zone> {
+        let mut zones: Vec<Zone> = self
+            .zones
+            .iter()
+            .filter(|zone| zone.touches >= self.min_touches)
+            .copied()
+            .collect();
+        zones.sort_by(|a, b| a.low.total_cmp(&b.low));
+        zones
+    }
+
+    /// 完全低于价格的最近区间
+    pub fn nearest_support(&self, price: f64) -> Option<Zone> {
+        self.zones().into_iter().rev().find(|zone| zone.high < price)
+    }
+
+    /// 完全高于价格的最近区间
+    pub fn nearest_resistance(&self, price: f64) -> Option<Zone> {
+        self.zones().into_iter().find(|zone| zone.low > price)
+    }
+
+    /// 计算基于支撑阻力区的止损价格
+    ///
+    /// 多头止损放在最近支撑区的下沿，空头止损放在最近阻力区的上沿。
+    ///
+    /// # 参数
+    ///
+    /// * `entry_price` - 入场价格
+    /// * `is_long` - 是否为多头持仓
+    ///
+    /// # 返回值
+    ///
+    /// 返回建议的止损价格，没有对应的区间时返回None
+    pub fn stop_loss(&self, entry_price: f64, is_long: bool) -> Option<f64> {
+        if is_long {
+            self.nearest_support(entry_price).map(|zone| zone.low)
+        } else {
+            self.nearest_resistance(entry_price).map(|zone| zone.high)
+        }
+    }
+
+    /// 计算基于支撑阻力区的止盈价格
+    ///
+    /// 多头目标为最近阻力区的下沿，空头目标为最近支撑区的上沿。
+    pub fn take_profit(&self, entry_price: f64, is_long: bool) -> Option<f64> {
+        if is_long {
+            self.nearest_resistance(entry_price).map(|zone| zone.low)
+        } else {
+            self.nearest_support(entry_price).map(|zone| zone.high)
+        }
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.detector.reset();
+        self.zones.clear();
+    }
+
+    /// 获取波段识别方法
+    pub fn source(&self) -> SwingSource {
+        self.source
+    }
+
+    /// 获取聚合容差
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+}
+
+impl Indicator for SupportResistance {
+    type Output = Vec<Zone>;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<Vec<Zone>> {
+        Some(self.update(kline.high, kline.low))
+    }
+
+    // 每根K线都输出当前的区间，可能为空
+    fn is_ready(&self) -> bool {
+        true
+    }
+
+    fn reset(&mut self) {
+        SupportResistance::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests;
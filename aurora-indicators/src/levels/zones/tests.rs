@@ -0,0 +1,118 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+/// 依次形成波段低点100、高点110、低点100.5、高点112
+fn sample() -> SupportResistance {
+    let mut sr = SupportResistance::new(SwingSource::ZigZag(0.05), 0.01);
+    for (high, low) in [(101.0, 100.0), (110.0, 108.0), (104.0, 100.5), (112.0, 109.0), (105.0, 103.0)] {
+        sr.update(high, low);
+    }
+    sr
+}
+
+#[test]
+fn test_zones_cluster_nearby_swings() {
+    let sr = sample();
+    assert_eq!(sr.tolerance(), 0.01);
+    assert_eq!(sr.source(), SwingSource::ZigZag(0.05));
+
+    let zones = sr.zones();
+    assert_eq!(zones.len(), 3);
+    assert_eq!(zones[0], Zone { low: 100.0, high: 100.5, touches: 2, last_touch: 2 });
+    assert_eq!(zones[1].mid(), 110.0);
+    assert_eq!(zones[2].mid(), 112.0);
+}
+
+#[test]
+fn test_nearest_zones_and_stops() {
+    let sr = sample();
+
+    assert_eq!(sr.nearest_support(105.0).unwrap().low, 100.0);
+    assert_eq!(sr.nearest_resistance(105.0).unwrap().low, 110.0);
+    // 价格处于区间内时不算作支撑或阻力
+    assert_eq!(sr.nearest_resistance(110.0).unwrap().low, 112.0);
+    assert!(sr.nearest_support(100.2).is_none());
+
+    assert_eq!(sr.stop_loss(105.0, true), Some(100.0));
+    assert_eq!(sr.take_profit(105.0, true), Some(110.0));
+    assert_eq!(sr.stop_loss(105.0, false), Some(110.0));
+    assert_eq!(sr.take_profit(105.0, false), Some(100.5));
+    assert_eq!(sr.stop_loss(120.0, false), None);
+}
+
+#[test]
+fn test_min_touches_filter() {
+    let mut sr = SupportResistance::new(SwingSource::ZigZag(0.05), 0.01).with_min_touches(2);
+    for (high, low) in [(101.0, 100.0), (110.0, 108.0), (104.0, 100.5), (112.0, 109.0), (105.0, 103.0)] {
+        sr.update(high, low);
+    }
+
+    let zones = sr.zones();
+    assert_eq!(zones.len(), 1);
+    assert_eq!(zones[0].touches, 2);
+}
+
+/// 测试超过上限时丢弃最久未触及的区间
+#[test]
+fn test_max_zones_drops_stale() {
+    let mut sr = SupportResistance::new(SwingSource::ZigZag(0.05), 0.0).with_max_zones(2);
+    for (high, low) in [(101.0, 100.0), (110.0, 108.0), (104.0, 100.5), (112.0, 109.0), (105.0, 103.0)] {
+        sr.update(high, low);
+    }
+
+    // 容差为0时形成4个区间，只保留最近的两个
+    let prices: Vec<f64> = sr.zones().iter().map(Zone::mid).collect();
+    assert_eq!(prices, vec![100.5, 112.0]);
+}
+
+#[test]
+fn test_zone_contains() {
+    let zone = Zone { low: 100.0, high: 101.0, touches: 3, last_touch: 10 };
+    assert!(zone.contains(100.5));
+    assert!(!zone.contains(101.5));
+    assert_eq!(zone.distance(102.0), 1.0);
+    assert_eq!(zone.distance(100.5), 0.0);
+}
+
+#[test]
+fn test_support_resistance_indicator() {
+    let mut sr = SupportResistance::new(SwingSource::Fractal(1), 0.01);
+    let kline = |high: f64, low: f64| Kline {
+        timestamp: 0,
+        open: low,
+        high,
+        low,
+        close: high,
+        volume: 1.0,
+        symbol: None,
+        interval: None,
+    };
+
+    // 每根K线都有输出
+    assert_eq!(sr.update_kline(&kline(10.0, 9.0)), Some(Vec::new()));
+    sr.update_kline(&kline(12.0, 10.0));
+    let zones = sr.update_kline(&kline(11.0, 9.5)).unwrap();
+    assert_eq!(zones.len(), 1);
+    assert_eq!(zones[0].mid(), 12.0);
+
+    Indicator::reset(&mut sr);
+    assert!(sr.zones().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "支撑阻力容差不能为负数")]
+fn test_negative_tolerance() {
+    SupportResistance::new(SwingSource::Fractal(2), -0.1);
+}
@@ -25,6 +25,16 @@
 //! - **ADX (平均动向指数)**: 衡量趋势强度但不判断方向
 //! - **PSAR (抛物线转向指标)**: 用于确定止损位和趋势反转点
 //! - **Ichimoku (一目均衡表)**: 综合性趋势系统，提供多维度市场信息
+//! - **Supertrend (超级趋势)**: 基于ATR的趋势跟随轨道，常用作移动止损
+//! - **Aroon (阿隆指标)**: 以最近高低点距今的周期数衡量趋势强弱
+//! - **TRIX (三重指数平滑变动率)**: 三重EMA的变动率，过滤短期波动
+//!
+//! ## 移动平均线
+//! - **WMA (加权移动平均线)**: 按时间线性加权的移动平均线
+//! - **DEMA / TEMA (双重/三重指数移动平均线)**: 抵消EMA滞后的多重平滑均线
+//! - **HMA (赫尔移动平均线)**: 组合不同周期WMA的低滞后均线
+//! - **KAMA (考夫曼自适应移动平均线)**: 按效率比率调整平滑速度的均线
+//! - **ZLEMA (零滞后指数移动平均线)**: 修正输入价格以减小滞后的EMA
 //!
 //! ## 动量指标
 //! - **RSI (相对强弱指数)**: 衡量价格变动速度和幅度
@@ -38,6 +48,14 @@
 //! - **ATR (平均真实波幅)**: 衡量市场波动程度
 //! - **StdDev (标准差)**: 衡量价格相对于平均值的离散程度
 //! - **Keltner Channels (肯特纳通道)**: 基于ATR的价格通道
+//! - **Donchian Channels (唐奇安通道)**: N周期最高价和最低价构成的突破通道
+//!
+//! ## 统计指标
+//! - **ZScore (标准分数)**: 价格偏离均值的标准差倍数，均值回归的基础
+//! - **LinearRegression (线性回归)**: 滚动拟合的斜率、截距、R²和预测值
+//! - **Correlation / Beta (相关系数/贝塔系数)**: 两个序列的滚动相关性和回归斜率，用于配对交易
+//! - **Autocorrelation (自相关系数)**: 序列与其滞后自身的相关程度
+//! - **Hurst (赫斯特指数)**: 判断序列是趋势延续、随机游走还是均值回归
 //!
 //! ## 成交量指标
 //! - **OBV (能量潮)**: 通过成交量变化预测价格趋势
@@ -45,6 +63,39 @@
 //! - **VWAP (成交量加权平均价)**: 计算成交量加权的平均价格
 //! - **CMF (佳庆资金流)**: 衡量特定时期内资金流入和流出情况
 //! - **ADLine (累积/派发线)**: 通过价格和成交量关系识别供需变化
+//! - **Volume Profile (成交量分布)**: 按价格层统计成交量，给出控制点和价值区
+//!
+//! # 统一接口
+//!
+//! 所有指标都实现 [`Indicator`] trait，从 [`Kline`](aurora_core::Kline) 更新并给出类型化的输出。
+//! [`IndicatorRegistry`] 按名称和参数动态创建指标，输出统一为 [`IndicatorValue`]。
+//!
+//! # 组合与事件
+//!
+//! [`Chain`] 把一个指标的输出接到另一个指标（如EMA的RSI、OBV的布林带）。
+//! [`CrossAbove`]、[`CrossBelow`]、[`Threshold`]、[`Rising`]、[`Falling`] 把数值序列转换为交易事件，
+//! 策略可以由这些部件组装，而不必手写状态机。
+//!
+//! # K线形态
+//!
+//! [`CandlePatterns`] 识别吞没、锤子线、十字星、早晨之星等经典K线形态，
+//! 输出带方向和强度的 [`Pattern`]，可作为信号来源或入场过滤条件。
+//!
+//! # 价格水平
+//!
+//! [`PivotPoints`] 按交易时段计算经典、Camarilla、Woodie 枢轴点，
+//! [`FibonacciRetracement`] 由 [`Fractals`] 或 [`ZigZag`] 识别的波段高低点计算斐波那契回撤位，
+//! [`SupportResistance`] 把相近的波段点聚合为支撑阻力区，可作为止损和止盈的参考价位。
+//!
+//! # 背离
+//!
+//! [`DivergenceDetector`] 比较价格波段高低点与震荡指标（RSI、MACD柱状图、OBV、MFI等），
+//! 在确认的K线上输出常规和隐藏的看涨/看跌背离及所涉及波段点的时间戳。
+//!
+//! # 批量计算
+//!
+//! [`batch`] 模块对整段价格序列（`ndarray::Array1<f64>`）或K线序列一次性计算指标，
+//! 结果与逐点流式计算完全一致，供研究和向量化回测使用。
 //!
 //! # 设计原则
 //!
@@ -71,6 +122,14 @@
 //! ```
 
 // 模块导出
+pub mod batch;
+mod indicator;
+mod registry;
+mod compose;
+mod events;
+mod pattern;
+mod levels;
+mod divergence;
 mod ma;
 mod ema;
 mod rsi;
@@ -91,6 +150,38 @@ mod psar;
 mod ichimoku;
 mod cmf;
 mod adline;
+mod wma;
+mod dema;
+mod tema;
+mod hma;
+mod kama;
+mod zlema;
+mod trix;
+mod aroon;
+mod donchian;
+mod supertrend;
+mod zscore;
+mod linreg;
+mod correlation;
+mod beta;
+mod autocorrelation;
+mod hurst;
+mod volume_profile;
+
+#[cfg(test)]
+mod test_data;
+
+// 公开导出统一接口和注册表
+pub use indicator::{BoxedIndicator, Indicator, IndicatorValue};
+pub use registry::{IndicatorError, IndicatorFactory, IndicatorParams, IndicatorRegistry};
+pub use compose::{Chain, Select};
+pub use events::{CrossAbove, CrossBelow, Falling, Rising, Threshold};
+pub use pattern::{CandlePatterns, Pattern, PatternDirection, PatternKind};
+pub use divergence::{Divergence, DivergenceDetector, DivergenceKind, DivergencePoint};
+pub use levels::{
+    FibonacciLevels, FibonacciRetracement, Fractals, PivotLevels, PivotMethod, PivotPoints,
+    SupportResistance, Swing, SwingKind, SwingSource, Zone, ZigZag,
+};
 
 // 公开导出所有指标
 pub use ma::MA;
@@ -113,3 +204,20 @@ pub use psar::{PSAR, PSAROutput};
 pub use ichimoku::{Ichimoku, IchimokuOutput};
 pub use cmf::CMF;
 pub use adline::ADLine;
+pub use wma::WMA;
+pub use dema::DEMA;
+pub use tema::TEMA;
+pub use hma::HMA;
+pub use kama::KAMA;
+pub use zlema::ZLEMA;
+pub use trix::TRIX;
+pub use aroon::{Aroon, AroonOutput};
+pub use donchian::{DonchianChannels, DonchianChannelsOutput};
+pub use supertrend::{Supertrend, SupertrendOutput};
+pub use zscore::ZScore;
+pub use linreg::{LinearRegression, LinearRegressionOutput};
+pub use correlation::Correlation;
+pub use beta::Beta;
+pub use autocorrelation::Autocorrelation;
+pub use hurst::Hurst;
+pub use volume_profile::{PriceLevel, ProfileWindow, VolumeProfile, VolumeProfileOutput};
@@ -0,0 +1,189 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Linear Regression - 线性回归
+//!
+//! 对最近N个价格做最小二乘直线拟合，斜率反映趋势方向和速度，
+//! 决定系数 R² 反映价格沿直线运动的程度（趋势的"干净"程度）。
+//!
+//! # 计算公式
+//!
+//! 以窗口内最早的价格为 x = 0，依次到当前价格 x = N - 1：
+//!
+//! 1. 斜率: b = (NΣxy - ΣxΣy) / (NΣx² - (Σx)²)
+//! 2. 截距: a = (Σy - bΣx) / N
+//! 3. 当前拟合值: a + b(N - 1)
+//! 4. 预测值（下一根K线）: a + bN
+//! 5. 决定系数: R² = 相关系数的平方，价格不变时为0
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::LinearRegression;
+//!
+//! let mut linreg = LinearRegression::new(5);
+//! for price in [1.0, 3.0, 2.0, 5.0] {
+//!     assert!(linreg.update(price).is_none());
+//! }
+//!
+//! let output = linreg.update(4.0).unwrap();
+//! assert!((output.slope - 0.8).abs() < 1e-9);
+//! assert!((output.forecast - 5.4).abs() < 1e-9);
+//! assert!((output.r_squared - 0.64).abs() < 1e-9);
+//! ```
+
+use aurora_core::Kline;
+use crate::{Indicator, IndicatorValue};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Linear Regression 输出结构
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LinearRegressionOutput {
+    /// 当前K线的拟合值
+    pub value: f64,
+    /// 斜率(每根K线的变化量)
+    pub slope: f64,
+    /// 截距(窗口内最早一根K线的拟合值)
+    pub intercept: f64,
+    /// 决定系数
+    pub r_squared: f64,
+    /// 下一根K线的预测值
+    pub forecast: f64,
+}
+
+/// Linear Regression 线性回归
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinearRegression {
+    /// 计算周期
+    period: usize,
+    /// 价格历史数据
+    prices: VecDeque<f64>,
+}
+
+impl LinearRegression {
+    /// 创建新的线性回归指标实例
+    ///
+    /// # 参数
+    ///
+    /// * `period` - 计算周期,至少为2
+    ///
+    /// # Panics
+    ///
+    /// 当 `period` 小于 2 时会 panic
+    pub fn new(period: usize) -> Self {
+        assert!(period > 1, "线性回归周期必须大于1");
+
+        Self {
+            period,
+            prices: VecDeque::with_capacity(period),
+        }
+    }
+
+    /// 更新指标并计算新的回归结果
+    ///
+    /// # 返回值
+    ///
+    /// - `Some(LinearRegressionOutput)` - 累积了 period 个数据点后返回回归结果
+    /// - `None` - 数据不足
+    pub fn update(&mut self, price: f64) -> Option<LinearRegressionOutput> {
+        self.prices.push_back(price);
+        if self.prices.len() > self.period {
+            self.prices.pop_front();
+        }
+
+        self.value()
+    }
+
+    /// 获取当前的回归结果
+    pub fn value(&self) -> Option<LinearRegressionOutput> {
+        if !self.is_ready() {
+            return None;
+        }
+
+        let n = self.period as f64;
+        let sum_x = n * (n - 1.0) / 2.0;
+        let sum_xx = (n - 1.0) * n * (2.0 * n - 1.0) / 6.0;
+        let (sum_y, sum_xy, sum_yy) = self.prices.iter().enumerate().fold(
+            (0.0, 0.0, 0.0),
+            |(sy, sxy, syy), (x, &y)| (sy + y, sxy + x as f64 * y, syy + y * y),
+        );
+
+        let sxx = n * sum_xx - sum_x * sum_x;
+        let sxy = n * sum_xy - sum_x * sum_y;
+        let syy = n * sum_yy - sum_y * sum_y;
+
+        let slope = sxy / sxx;
+        let intercept = (sum_y - slope * sum_x) / n;
+        let r_squared = if syy > 0.0 { (sxy * sxy / (sxx * syy)).min(1.0) } else { 0.0 };
+
+        Some(LinearRegressionOutput {
+            value: intercept + slope * (n - 1.0),
+            slope,
+            intercept,
+            r_squared,
+            forecast: intercept + slope * n,
+        })
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.prices.clear();
+    }
+
+    /// 获取周期设置
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// 检查是否已准备好输出结果
+    pub fn is_ready(&self) -> bool {
+        self.prices.len() == self.period
+    }
+}
+
+impl Indicator for LinearRegression {
+    type Output = LinearRegressionOutput;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<LinearRegressionOutput> {
+        self.update(kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        LinearRegression::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        LinearRegression::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period
+    }
+}
+
+impl From<LinearRegressionOutput> for IndicatorValue {
+    fn from(output: LinearRegressionOutput) -> Self {
+        IndicatorValue::from_fields(vec![
+            ("value", output.value),
+            ("slope", output.slope),
+            ("intercept", output.intercept),
+            ("r_squared", output.r_squared),
+            ("forecast", output.forecast),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,82 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use approx::assert_relative_eq;
+
+#[test]
+#[should_panic(expected = "线性回归周期必须大于1")]
+fn test_linreg_period_too_small() {
+    LinearRegression::new(1);
+}
+
+/// 参考值（按公式手工计算）：y = [1, 3, 2, 5, 4]
+#[test]
+fn test_linreg_values() {
+    let mut linreg = LinearRegression::new(5);
+    assert_eq!(linreg.period(), 5);
+    for price in [1.0, 3.0, 2.0, 5.0] {
+        assert!(linreg.update(price).is_none());
+    }
+
+    let output = linreg.update(4.0).unwrap();
+    assert_relative_eq!(output.slope, 0.8, epsilon = 1e-9);
+    assert_relative_eq!(output.intercept, 1.4, epsilon = 1e-9);
+    assert_relative_eq!(output.value, 4.6, epsilon = 1e-9);
+    assert_relative_eq!(output.forecast, 5.4, epsilon = 1e-9);
+    assert_relative_eq!(output.r_squared, 0.64, epsilon = 1e-9);
+}
+
+/// 测试完美直线
+#[test]
+fn test_linreg_perfect_line() {
+    let mut linreg = LinearRegression::new(4);
+    let mut output = None;
+    for i in 0..10 {
+        output = linreg.update(100.0 - 2.0 * i as f64);
+    }
+
+    let output = output.unwrap();
+    assert_relative_eq!(output.slope, -2.0, epsilon = 1e-9);
+    assert_relative_eq!(output.value, 82.0, epsilon = 1e-9);
+    assert_relative_eq!(output.forecast, 80.0, epsilon = 1e-9);
+    assert_relative_eq!(output.r_squared, 1.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_linreg_constant_prices() {
+    let mut linreg = LinearRegression::new(3);
+    linreg.update(50.0);
+    linreg.update(50.0);
+    let output = linreg.update(50.0).unwrap();
+
+    assert_eq!(output.slope, 0.0);
+    assert_eq!(output.value, 50.0);
+    assert_eq!(output.r_squared, 0.0);
+}
+
+#[test]
+fn test_linreg_reset_and_indicator_value() {
+    let mut linreg = LinearRegression::new(2);
+    linreg.update(1.0);
+    let output = linreg.update(2.0).unwrap();
+
+    let value = IndicatorValue::from(output);
+    assert_eq!(value.primary(), 2.0);
+    assert_eq!(value.get("slope"), Some(1.0));
+    assert_eq!(value.get("forecast"), Some(3.0));
+
+    linreg.reset();
+    assert!(!linreg.is_ready());
+    assert!(linreg.value().is_none());
+}
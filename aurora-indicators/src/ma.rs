@@ -16,6 +16,9 @@
 //!
 //! 计算指定周期内价格的算术平均值，是最基础的趋势跟踪指标。
 
+use aurora_core::Kline;
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 /// 移动平均线 (Moving Average) 指标
@@ -38,7 +41,7 @@ use std::collections::VecDeque;
 /// # 时间复杂度
 ///
 /// 每次更新的时间复杂度为O(1)，通过维护累计和避免重复计算。
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MA {
     /// 移动平均的周期长度
     period: usize,
@@ -141,5 +144,25 @@ impl MA {
     }
 }
 
+impl Indicator for MA {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        MA::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        MA::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -16,10 +16,12 @@
 //!
 //! MACD用于判断趋势变化和买卖时机。
 
-use crate::EMA;
+use crate::{EMA, Indicator, IndicatorValue};
+use aurora_core::Kline;
+use serde::{Deserialize, Serialize};
 
 /// MACD指标输出
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct MACDOutput {
     /// MACD线（快线-慢线）
     pub macd: f64,
@@ -47,7 +49,7 @@ pub struct MACDOutput {
 /// - 快线周期：12
 /// - 慢线周期：26
 /// - 信号线周期：9
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MACD {
     /// 快速EMA
     fast_ema: EMA,
@@ -188,5 +190,35 @@ impl MACD {
     }
 }
 
+impl Indicator for MACD {
+    type Output = MACDOutput;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<MACDOutput> {
+        Some(self.update(kline.close))
+    }
+
+    fn is_ready(&self) -> bool {
+        MACD::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        MACD::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        1
+    }
+}
+
+impl From<MACDOutput> for IndicatorValue {
+    fn from(output: MACDOutput) -> Self {
+        IndicatorValue::from_fields(vec![
+            ("macd", output.macd),
+            ("signal", output.signal),
+            ("histogram", output.histogram),
+        ])
+    }
+}
+
 #[cfg(test)]
 mod tests;
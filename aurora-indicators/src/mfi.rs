@@ -48,11 +48,15 @@
 //! }
 //! ```
 
+use aurora_core::Kline;
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 /// MFI (Money Flow Index) 资金流量指数
 ///
 /// 成交量加权的RSI,衡量资金流入流出的强度
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MFI {
     /// 计算周期
     period: usize,
@@ -285,5 +289,25 @@ impl Default for MFI {
     }
 }
 
+impl Indicator for MFI {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.high, kline.low, kline.close, kline.volume)
+    }
+
+    fn is_ready(&self) -> bool {
+        MFI::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        MFI::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period + 1
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -16,6 +16,10 @@
 //!
 //! 通过成交量变化预测价格趋势。
 
+use aurora_core::Kline;
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
+
 /// OBV (On-Balance Volume) 能量潮指标
 ///
 /// OBV通过累积成交量来衡量买卖压力。
@@ -31,7 +35,7 @@
 /// - 确认价格趋势
 /// - 发现背离信号（价格创新高但OBV未创新高，或相反）
 /// - 衡量买卖力量
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OBV {
     /// 当前OBV值
     obv: f64,
@@ -118,5 +122,25 @@ impl Default for OBV {
     }
 }
 
+impl Indicator for OBV {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        Some(self.update(kline.close, kline.volume))
+    }
+
+    fn is_ready(&self) -> bool {
+        OBV::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        OBV::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        1
+    }
+}
+
 #[cfg(test)]
 mod tests;
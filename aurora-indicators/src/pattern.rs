@@ -0,0 +1,463 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! K线形态识别
+//!
+//! [`CandlePatterns`] 逐根读取K线，识别经典的K线形态并给出方向和强度，
+//! 可以作为策略的信号来源（[`PatternDirection::signal`]），也可以作为入场过滤条件。
+//!
+//! # 支持的形态
+//!
+//! | 形态 | K线数 | 方向 |
+//! |------|-------|------|
+//! | 十字星 (Doji) | 1 | 中性 |
+//! | 锤子线 (Hammer) | 1 | 看涨 |
+//! | 射击之星 (Shooting Star) | 1 | 看跌 |
+//! | 看涨/看跌吞没 (Engulfing) | 2 | 看涨/看跌 |
+//! | 内包线 (Inside Bar) | 2 | 中性 |
+//! | 外包线 (Outside Bar) | 2 | 随当前K线涨跌 |
+//! | 早晨之星/黄昏之星 (Morning/Evening Star) | 3 | 看涨/看跌 |
+//! | 红三兵/三只乌鸦 (Three White Soldiers/Black Crows) | 3 | 看涨/看跌 |
+//!
+//! 锤子线和射击之星要求出现在下跌/上涨之后（当前收盘价低于/高于上一根K线的收盘价）。
+//! 同一根K线可能同时满足多个形态，全部返回。
+//!
+//! # 强度
+//!
+//! 强度取值0到1，衡量形态的典型程度，例如吞没形态中前一根实体占当前实体的比例越小越强，
+//! 锤子线的下影线占整根K线的比例越大越强。策略可以用它设置过滤门槛。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_core::Kline;
+//! use aurora_indicators::{CandlePatterns, PatternDirection, PatternKind};
+//!
+//! let candle = |open: f64, high: f64, low: f64, close: f64| Kline {
+//!     timestamp: 0,
+//!     open,
+//!     high,
+//!     low,
+//!     close,
+//!     volume: 1.0,
+//!     symbol: None,
+//!     interval: None,
+//! };
+//!
+//! let mut patterns = CandlePatterns::new();
+//! patterns.update(&candle(105.0, 106.0, 99.0, 100.0));
+//! let detected = patterns.update(&candle(99.0, 107.0, 98.5, 106.5));
+//!
+//! let engulfing = detected
+//!     .iter()
+//!     .find(|p| p.kind == PatternKind::BullishEngulfing)
+//!     .unwrap();
+//! assert_eq!(engulfing.direction, PatternDirection::Bullish);
+//! assert!(engulfing.strength > 0.0 && engulfing.strength <= 1.0);
+//! ```
+
+use crate::Indicator;
+use aurora_core::{Kline, Signal};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// K线形态种类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PatternKind {
+    /// 十字星：实体极小，开盘价与收盘价几乎相同
+    Doji,
+    /// 锤子线：下跌后出现，下影线很长、上影线很短
+    Hammer,
+    /// 射击之星：上涨后出现，上影线很长、下影线很短
+    ShootingStar,
+    /// 看涨吞没：阳线实体完全覆盖前一根阴线实体
+    BullishEngulfing,
+    /// 看跌吞没：阴线实体完全覆盖前一根阳线实体
+    BearishEngulfing,
+    /// 内包线：高低点都在前一根K线范围内
+    InsideBar,
+    /// 外包线：高低点都超出前一根K线范围
+    OutsideBar,
+    /// 早晨之星：大阴线、小实体、收复阴线实体一半以上的阳线
+    MorningStar,
+    /// 黄昏之星：大阳线、小实体、跌破阳线实体一半以下的阴线
+    EveningStar,
+    /// 红三兵：三根依次抬高的阳线
+    ThreeWhiteSoldiers,
+    /// 三只乌鸦：三根依次降低的阴线
+    ThreeBlackCrows,
+}
+
+/// 形态方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PatternDirection {
+    /// 看涨
+    Bullish,
+    /// 看跌
+    Bearish,
+    /// 中性（只表示犹豫或波动收缩）
+    Neutral,
+}
+
+impl PatternDirection {
+    /// 对应的交易信号：看涨为买入，看跌为卖出，中性为持有
+    pub fn signal(&self) -> Signal {
+        match self {
+            PatternDirection::Bullish => Signal::Buy,
+            PatternDirection::Bearish => Signal::Sell,
+            PatternDirection::Neutral => Signal::Hold,
+        }
+    }
+}
+
+/// 识别出的形态
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Pattern {
+    /// 形态种类
+    pub kind: PatternKind,
+    /// 形态方向
+    pub direction: PatternDirection,
+    /// 强度（0到1）
+    pub strength: f64,
+}
+
+impl Pattern {
+    fn new(kind: PatternKind, direction: PatternDirection, strength: f64) -> Self {
+        Self {
+            kind,
+            direction,
+            strength: strength.clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// 形态识别所需的K线价格
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Candle {
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+}
+
+impl Candle {
+    fn body(&self) -> f64 {
+        (self.close - self.open).abs()
+    }
+
+    fn range(&self) -> f64 {
+        self.high - self.low
+    }
+
+    fn body_top(&self) -> f64 {
+        self.open.max(self.close)
+    }
+
+    fn body_bottom(&self) -> f64 {
+        self.open.min(self.close)
+    }
+
+    fn upper_shadow(&self) -> f64 {
+        self.high - self.body_top()
+    }
+
+    fn lower_shadow(&self) -> f64 {
+        self.body_bottom() - self.low
+    }
+
+    fn is_bullish(&self) -> bool {
+        self.close > self.open
+    }
+
+    fn is_bearish(&self) -> bool {
+        self.close < self.open
+    }
+
+    fn body_midpoint(&self) -> f64 {
+        (self.open + self.close) / 2.0
+    }
+}
+
+/// K线形态识别器
+///
+/// 保存最近3根K线，每次更新返回以当前K线结尾的全部形态。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CandlePatterns {
+    /// 十字星的实体占比上限
+    doji_ratio: f64,
+    /// 锤子线/射击之星的影线与实体之比下限
+    shadow_ratio: f64,
+    /// 最近3根K线，最新的在末尾
+    history: VecDeque<Candle>,
+}
+
+impl CandlePatterns {
+    /// 创建形态识别器
+    ///
+    /// 默认十字星实体不超过整根K线的10%，锤子线/射击之星的长影线至少为实体的2倍。
+    pub fn new() -> Self {
+        Self {
+            doji_ratio: 0.1,
+            shadow_ratio: 2.0,
+            history: VecDeque::with_capacity(3),
+        }
+    }
+
+    /// 设置十字星的实体占比上限
+    ///
+    /// # Panics
+    ///
+    /// 比例不在 (0, 1) 内时panic
+    pub fn with_doji_ratio(mut self, ratio: f64) -> Self {
+        assert!(ratio > 0.0 && ratio < 1.0, "十字星实体占比必须在0到1之间");
+        self.doji_ratio = ratio;
+        self
+    }
+
+    /// 设置锤子线/射击之星的影线与实体之比下限
+    ///
+    /// # Panics
+    ///
+    /// 比例不大于0时panic
+    pub fn with_shadow_ratio(mut self, ratio: f64) -> Self {
+        assert!(ratio > 0.0, "影线与实体之比必须大于0");
+        self.shadow_ratio = ratio;
+        self
+    }
+
+    /// 用一根K线更新，返回以这根K线结尾的全部形态
+    pub fn update(&mut self, kline: &Kline) -> Vec<Pattern> {
+        self.history.push_back(Candle {
+            open: kline.open,
+            high: kline.high,
+            low: kline.low,
+            close: kline.close,
+        });
+        if self.history.len() > 3 {
+            self.history.pop_front();
+        }
+
+        let mut patterns = Vec::new();
+        let n = self.history.len();
+        let current = self.history[n - 1];
+
+        self.single(&current, &mut patterns);
+        if n >= 2 {
+            let previous = self.history[n - 2];
+            self.single_in_context(&previous, &current, &mut patterns);
+            Self::double(&previous, &current, &mut patterns);
+        }
+        if n >= 3 {
+            Self::triple(&self.history[0], &self.history[1], &current, &mut patterns);
+        }
+        patterns
+    }
+
+    /// 单根K线形态
+    fn single(&self, candle: &Candle, patterns: &mut Vec<Pattern>) {
+        let range = candle.range();
+        if range <= 0.0 {
+            return;
+        }
+
+        let body_ratio = candle.body() / range;
+        if body_ratio <= self.doji_ratio {
+            patterns.push(Pattern::new(
+                PatternKind::Doji,
+                PatternDirection::Neutral,
+                1.0 - body_ratio / self.doji_ratio,
+            ));
+        }
+    }
+
+    /// 需要前一根K线判断趋势的单根K线形态
+    fn single_in_context(&self, previous: &Candle, candle: &Candle, patterns: &mut Vec<Pattern>) {
+        let range = candle.range();
+        if range <= 0.0 {
+            return;
+        }
+
+        let body = candle.body();
+        let upper = candle.upper_shadow();
+        let lower = candle.lower_shadow();
+
+        if candle.close < previous.close && lower >= self.shadow_ratio * body && upper <= body {
+            patterns.push(Pattern::new(
+                PatternKind::Hammer,
+                PatternDirection::Bullish,
+                lower / range,
+            ));
+        }
+        if candle.close > previous.close && upper >= self.shadow_ratio * body && lower <= body {
+            patterns.push(Pattern::new(
+                PatternKind::ShootingStar,
+                PatternDirection::Bearish,
+                upper / range,
+            ));
+        }
+    }
+
+    /// 两根K线形态
+    fn double(previous: &Candle, current: &Candle, patterns: &mut Vec<Pattern>) {
+        let (prev_body, body) = (previous.body(), current.body());
+
+        if previous.is_bearish()
+            && current.is_bullish()
+            && current.open <= previous.close
+            && current.close >= previous.open
+            && body > prev_body
+        {
+            patterns.push(Pattern::new(
+                PatternKind::BullishEngulfing,
+                PatternDirection::Bullish,
+                1.0 - prev_body / body,
+            ));
+        }
+        if previous.is_bullish()
+            && current.is_bearish()
+            && current.open >= previous.close
+            && current.close <= previous.open
+            && body > prev_body
+        {
+            patterns.push(Pattern::new(
+                PatternKind::BearishEngulfing,
+                PatternDirection::Bearish,
+                1.0 - prev_body / body,
+            ));
+        }
+
+        let (prev_range, range) = (previous.range(), current.range());
+        if prev_range <= 0.0 || range <= 0.0 {
+            return;
+        }
+
+        if current.high < previous.high && current.low > previous.low {
+            patterns.push(Pattern::new(
+                PatternKind::InsideBar,
+                PatternDirection::Neutral,
+                1.0 - range / prev_range,
+            ));
+        }
+        if current.high > previous.high && current.low < previous.low {
+            let direction = if current.is_bullish() {
+                PatternDirection::Bullish
+            } else if current.is_bearish() {
+                PatternDirection::Bearish
+            } else {
+                PatternDirection::Neutral
+            };
+            patterns.push(Pattern::new(
+                PatternKind::OutsideBar,
+                direction,
+                1.0 - prev_range / range,
+            ));
+        }
+    }
+
+    /// 三根K线形态
+    fn triple(first: &Candle, second: &Candle, third: &Candle, patterns: &mut Vec<Pattern>) {
+        // 第一根为实体占一半以上的大K线，第二根实体不超过第一根实体的30%
+        let is_star = first.range() > 0.0
+            && first.body() >= 0.5 * first.range()
+            && second.body() <= 0.3 * first.body();
+
+        if is_star && first.is_bearish() && third.is_bullish() && third.close > first.body_midpoint() {
+            patterns.push(Pattern::new(
+                PatternKind::MorningStar,
+                PatternDirection::Bullish,
+                (third.close - first.body_midpoint()) / (first.open - first.body_midpoint()),
+            ));
+        }
+        if is_star && first.is_bullish() && third.is_bearish() && third.close < first.body_midpoint() {
+            patterns.push(Pattern::new(
+                PatternKind::EveningStar,
+                PatternDirection::Bearish,
+                (first.body_midpoint() - third.close) / (first.body_midpoint() - first.open),
+            ));
+        }
+
+        let candles = [first, second, third];
+        let average_body_ratio = candles
+            .iter()
+            .map(|c| if c.range() > 0.0 { c.body() / c.range() } else { 0.0 })
+            .sum::<f64>()
+            / 3.0;
+
+        // 每根K线开盘于前一根实体之内，收盘创新高/新低，且收盘附近的影线不超过实体的30%
+        let soldiers = candles.iter().all(|c| c.is_bullish() && c.upper_shadow() <= 0.3 * c.body())
+            && candles.windows(2).all(|pair| {
+                let (prev, curr) = (pair[0], pair[1]);
+                curr.open > prev.open && curr.open <= prev.close && curr.close > prev.close
+            });
+        if soldiers {
+            patterns.push(Pattern::new(
+                PatternKind::ThreeWhiteSoldiers,
+                PatternDirection::Bullish,
+                average_body_ratio,
+            ));
+        }
+
+        let crows = candles.iter().all(|c| c.is_bearish() && c.lower_shadow() <= 0.3 * c.body())
+            && candles.windows(2).all(|pair| {
+                let (prev, curr) = (pair[0], pair[1]);
+                curr.open < prev.open && curr.open >= prev.close && curr.close < prev.close
+            });
+        if crows {
+            patterns.push(Pattern::new(
+                PatternKind::ThreeBlackCrows,
+                PatternDirection::Bearish,
+                average_body_ratio,
+            ));
+        }
+    }
+
+    /// 重置状态
+    pub fn reset(&mut self) {
+        self.history.clear();
+    }
+
+    /// 是否已读取过K线
+    pub fn is_ready(&self) -> bool {
+        !self.history.is_empty()
+    }
+}
+
+impl Default for CandlePatterns {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for CandlePatterns {
+    type Output = Vec<Pattern>;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<Vec<Pattern>> {
+        Some(self.update(kline))
+    }
+
+    fn is_ready(&self) -> bool {
+        CandlePatterns::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        CandlePatterns::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests;
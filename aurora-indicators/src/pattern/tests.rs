@@ -0,0 +1,213 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn candle(open: f64, high: f64, low: f64, close: f64) -> Kline {
+    Kline {
+        timestamp: 0,
+        open,
+        high,
+        low,
+        close,
+        volume: 1.0,
+        symbol: None,
+        interval: None,
+    }
+}
+
+/// 依次输入K线，返回最后一根K线上识别出的形态
+fn detect(klines: &[Kline]) -> Vec<Pattern> {
+    let mut patterns = CandlePatterns::new();
+    klines.iter().map(|k| patterns.update(k)).last().unwrap_or_default()
+}
+
+fn find(patterns: &[Pattern], kind: PatternKind) -> Option<Pattern> {
+    patterns.iter().find(|p| p.kind == kind).copied()
+}
+
+#[test]
+fn test_doji() {
+    let patterns = detect(&[candle(100.0, 105.0, 95.0, 100.2)]);
+    let doji = find(&patterns, PatternKind::Doji).unwrap();
+
+    assert_eq!(doji.direction, PatternDirection::Neutral);
+    // 实体占比 0.02，强度 1 - 0.02 / 0.1
+    assert!((doji.strength - 0.8).abs() < 1e-10);
+
+    // 实体过大不是十字星
+    assert!(find(&detect(&[candle(100.0, 105.0, 95.0, 103.0)]), PatternKind::Doji).is_none());
+    // 开高低收相同的K线不识别任何形态
+    assert!(detect(&[candle(100.0, 100.0, 100.0, 100.0)]).is_empty());
+}
+
+#[test]
+fn test_hammer_after_decline() {
+    // 实体1，下影线6，上影线0.5
+    let hammer = candle(100.0, 101.5, 94.0, 101.0);
+    let patterns = detect(&[candle(104.0, 105.0, 101.5, 102.0), hammer.clone()]);
+    let found = find(&patterns, PatternKind::Hammer).unwrap();
+
+    assert_eq!(found.direction, PatternDirection::Bullish);
+    assert!((found.strength - 6.0 / 7.5).abs() < 1e-10);
+
+    // 上涨之后同样形状的K线不是锤子线
+    let patterns = detect(&[candle(98.0, 99.0, 97.0, 98.5), hammer]);
+    assert!(find(&patterns, PatternKind::Hammer).is_none());
+}
+
+#[test]
+fn test_shooting_star_after_rise() {
+    // 实体1，上影线6，下影线0.5
+    let star = candle(101.0, 108.0, 99.5, 100.0);
+    let patterns = detect(&[candle(97.0, 99.0, 96.5, 98.0), star]);
+    let found = find(&patterns, PatternKind::ShootingStar).unwrap();
+
+    assert_eq!(found.direction, PatternDirection::Bearish);
+    assert_eq!(found.direction.signal(), Signal::Sell);
+    assert!(find(&patterns, PatternKind::Hammer).is_none());
+}
+
+#[test]
+fn test_shadow_ratio_configuration() {
+    // 下影线为实体的1.5倍：默认不是锤子线，比例放宽到1.5后是
+    let klines = [candle(104.0, 105.0, 101.5, 102.0), candle(100.0, 101.5, 98.5, 101.0)];
+    assert!(find(&detect(&klines), PatternKind::Hammer).is_none());
+
+    let mut patterns = CandlePatterns::new().with_shadow_ratio(1.5);
+    patterns.update(&klines[0]);
+    assert!(find(&patterns.update(&klines[1]), PatternKind::Hammer).is_some());
+}
+
+#[test]
+fn test_bullish_engulfing() {
+    let patterns = detect(&[candle(105.0, 106.0, 99.0, 100.0), candle(99.0, 107.0, 98.5, 106.5)]);
+    let found = find(&patterns, PatternKind::BullishEngulfing).unwrap();
+
+    assert_eq!(found.direction, PatternDirection::Bullish);
+    assert_eq!(found.direction.signal(), Signal::Buy);
+    // 前一根实体5，当前实体7.5
+    assert!((found.strength - (1.0 - 5.0 / 7.5)).abs() < 1e-10);
+    assert!(find(&patterns, PatternKind::BearishEngulfing).is_none());
+}
+
+#[test]
+fn test_bearish_engulfing() {
+    let patterns = detect(&[candle(100.0, 105.5, 99.5, 105.0), candle(106.0, 106.5, 98.0, 99.0)]);
+    let found = find(&patterns, PatternKind::BearishEngulfing).unwrap();
+    assert_eq!(found.direction, PatternDirection::Bearish);
+
+    // 实体没有完全覆盖时不算吞没
+    let patterns = detect(&[candle(100.0, 105.5, 99.5, 105.0), candle(104.0, 104.5, 98.0, 99.0)]);
+    assert!(find(&patterns, PatternKind::BearishEngulfing).is_none());
+}
+
+#[test]
+fn test_inside_and_outside_bars() {
+    let patterns = detect(&[candle(100.0, 110.0, 90.0, 105.0), candle(102.0, 105.0, 95.0, 103.0)]);
+    let inside = find(&patterns, PatternKind::InsideBar).unwrap();
+    assert_eq!(inside.direction, PatternDirection::Neutral);
+    assert!((inside.strength - 0.5).abs() < 1e-10);
+
+    let patterns = detect(&[candle(100.0, 105.0, 95.0, 103.0), candle(101.0, 110.0, 90.0, 96.0)]);
+    let outside = find(&patterns, PatternKind::OutsideBar).unwrap();
+    assert_eq!(outside.direction, PatternDirection::Bearish);
+    assert!((outside.strength - 0.5).abs() < 1e-10);
+}
+
+#[test]
+fn test_morning_and_evening_star() {
+    let morning = [
+        candle(110.0, 111.0, 99.0, 100.0),
+        candle(99.0, 100.0, 97.0, 98.0),
+        candle(99.0, 108.5, 98.5, 108.0),
+    ];
+    let found = find(&detect(&morning), PatternKind::MorningStar).unwrap();
+    assert_eq!(found.direction, PatternDirection::Bullish);
+    // 第一根实体中点105，第三根收盘108，收复 3 / 5
+    assert!((found.strength - 0.6).abs() < 1e-10);
+
+    let evening = [
+        candle(100.0, 111.0, 99.0, 110.0),
+        candle(111.0, 113.0, 110.0, 112.0),
+        candle(111.0, 111.5, 101.5, 102.0),
+    ];
+    let found = find(&detect(&evening), PatternKind::EveningStar).unwrap();
+    assert_eq!(found.direction, PatternDirection::Bearish);
+
+    // 第三根没有收复一半实体时不算早晨之星
+    let weak = [morning[0].clone(), morning[1].clone(), candle(99.0, 104.5, 98.5, 104.0)];
+    assert!(find(&detect(&weak), PatternKind::MorningStar).is_none());
+}
+
+#[test]
+fn test_three_white_soldiers_and_black_crows() {
+    let soldiers = [
+        candle(100.0, 103.2, 99.8, 103.0),
+        candle(102.0, 106.3, 101.8, 106.0),
+        candle(105.0, 109.2, 104.8, 109.0),
+    ];
+    let found = find(&detect(&soldiers), PatternKind::ThreeWhiteSoldiers).unwrap();
+    assert_eq!(found.direction, PatternDirection::Bullish);
+    assert!(found.strength > 0.8);
+
+    let crows = [
+        candle(109.0, 109.2, 105.8, 106.0),
+        candle(107.0, 107.2, 102.8, 103.0),
+        candle(104.0, 104.2, 99.8, 100.0),
+    ];
+    assert!(find(&detect(&crows), PatternKind::ThreeBlackCrows).is_some());
+
+    // 第二根高开于前一根实体之外时不算红三兵
+    let gap = [soldiers[0].clone(), candle(104.0, 107.2, 103.8, 107.0), soldiers[2].clone()];
+    assert!(find(&detect(&gap), PatternKind::ThreeWhiteSoldiers).is_none());
+}
+
+#[test]
+fn test_patterns_only_use_last_three_klines() {
+    let mut patterns = CandlePatterns::new();
+    for kline in [
+        candle(110.0, 111.0, 99.0, 100.0),
+        candle(99.0, 100.0, 97.0, 98.0),
+        candle(99.0, 108.5, 98.5, 108.0),
+    ] {
+        patterns.update(&kline);
+    }
+    assert_eq!(patterns.history.len(), 3);
+
+    // 早晨之星的第一根移出窗口后不再识别
+    let next = patterns.update(&candle(108.0, 109.0, 107.0, 108.5));
+    assert!(find(&next, PatternKind::MorningStar).is_none());
+    assert_eq!(patterns.history.len(), 3);
+}
+
+#[test]
+fn test_indicator_interface_and_reset() {
+    let mut patterns = CandlePatterns::default();
+    assert!(!Indicator::is_ready(&patterns));
+    assert_eq!(Indicator::warm_up_period(&patterns), 1);
+
+    let output = patterns.update_kline(&candle(100.0, 105.0, 95.0, 100.0));
+    assert!(output.is_some());
+    assert!(Indicator::is_ready(&patterns));
+
+    Indicator::reset(&mut patterns);
+    assert!(!Indicator::is_ready(&patterns));
+}
+
+#[test]
+#[should_panic(expected = "十字星实体占比必须在0到1之间")]
+fn test_invalid_doji_ratio() {
+    CandlePatterns::new().with_doji_ratio(1.5);
+}
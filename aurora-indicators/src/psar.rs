@@ -51,8 +51,12 @@
 //! }
 //! ```
 
+use aurora_core::Kline;
+use crate::{Indicator, IndicatorValue};
+use serde::{Deserialize, Serialize};
+
 /// Parabolic SAR 输出结构
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct PSAROutput {
     /// SAR 值
     pub sar: f64,
@@ -61,6 +65,7 @@ pub struct PSAROutput {
 }
 
 /// Parabolic SAR 指标结构
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PSAR {
     // 参数
     acceleration: f64,        // 加速因子起始值
@@ -213,6 +218,11 @@ impl PSAR {
         self.closes.clear();
         self.initialized = false;
     }
+
+    /// 是否已完成初始化
+    pub fn is_ready(&self) -> bool {
+        self.initialized
+    }
 }
 
 impl Default for PSAR {
@@ -225,5 +235,33 @@ impl Default for PSAR {
     }
 }
 
+impl Indicator for PSAR {
+    type Output = PSAROutput;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<PSAROutput> {
+        self.update(kline.high, kline.low, kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        PSAR::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        PSAR::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        2
+    }
+}
+
+impl From<PSAROutput> for IndicatorValue {
+    /// 趋势方向以 `trend` 字段表示：上升趋势为1，下降趋势为-1
+    fn from(output: PSAROutput) -> Self {
+        let trend = if output.is_uptrend { 1.0 } else { -1.0 };
+        IndicatorValue::from_fields(vec![("sar", output.sar), ("trend", trend)])
+    }
+}
+
 #[cfg(test)]
 mod tests;
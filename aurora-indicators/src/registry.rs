@@ -0,0 +1,380 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 指标注册表
+//!
+//! [`IndicatorRegistry`] 按名称和参数构造 [`BoxedIndicator`]，
+//! 供策略、Web API 和配置文件动态创建指标。内置指标及其参数（括号内为默认值）：
+//!
+//! | 名称 | 参数 |
+//! |------|------|
+//! | `ma` | `period` (20) |
+//! | `ema` | `period` (20) |
+//! | `rsi` | `period` (14) |
+//! | `bollinger` | `period` (20), `std_dev` (2.0) |
+//! | `macd` | `fast` (12), `slow` (26), `signal` (9) |
+//! | `atr` | `period` (14) |
+//! | `stochastic` | `k_period` (14), `d_period` (3) |
+//! | `obv` | 无 |
+//! | `adx` | `period` (14) |
+//! | `roc` | `period` (12) |
+//! | `cci` | `period` (20) |
+//! | `williams_r` | `period` (14) |
+//! | `stddev` | `period` (20) |
+//! | `keltner` | `period` (20), `multiplier` (2.0) |
+//! | `mfi` | `period` (14) |
+//! | `vwap` | `period` (0，累积模式) |
+//! | `psar` | `acceleration` (0.02), `max_acceleration` (0.2) |
+//! | `ichimoku` | `tenkan` (9), `kijun` (26), `senkou_b` (52) |
+//! | `cmf` | `period` (20) |
+//! | `adline` | 无 |
+//! | `wma` | `period` (20) |
+//! | `dema` | `period` (20) |
+//! | `tema` | `period` (20) |
+//! | `hma` | `period` (9) |
+//! | `kama` | `period` (10), `fast` (2), `slow` (30) |
+//! | `zlema` | `period` (20) |
+//! | `trix` | `period` (15) |
+//! | `aroon` | `period` (25) |
+//! | `donchian` | `period` (20) |
+//! | `supertrend` | `period` (10), `multiplier` (3.0) |
+//! | `zscore` | `period` (20) |
+//! | `linreg` | `period` (20) |
+//! | `autocorrelation` | `period` (20), `lag` (1) |
+//! | `hurst` | `period` (100) |
+//! | `volume_profile` | `period` (20), `buckets` (24), `value_area` (0.7), `session_minutes` (0，滚动窗口) |
+//!
+//! 参数非法时返回 [`IndicatorError`]，而不是像各指标的构造函数那样 panic。
+
+use crate::{
+    ADLine, ADX, ATR, Aroon, Autocorrelation, BollingerBands, BoxedIndicator, CCI, CMF, DEMA,
+    DonchianChannels, EMA, HMA, Hurst, Ichimoku, Indicator, KAMA, KeltnerChannels, LinearRegression,
+    MA, MACD, MFI, OBV, PSAR, ROC, RSI, StdDev, Stochastic, Supertrend, TEMA, TRIX, VWAP,
+    VolumeProfile, WMA, WilliamsR, ZLEMA, ZScore,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// 指标参数
+///
+/// 参数名到数值的映射，可直接从 JSON 或 TOML 的表反序列化。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_indicators::IndicatorParams;
+///
+/// let params = IndicatorParams::new().with("period", 14.0);
+/// assert_eq!(params.period("period", 20).unwrap(), 14);
+/// assert_eq!(params.period("other", 20).unwrap(), 20);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IndicatorParams {
+    values: BTreeMap<String, f64>,
+}
+
+impl IndicatorParams {
+    /// 创建空参数
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置参数
+    pub fn with(mut self, name: &str, value: f64) -> Self {
+        self.values.insert(name.to_string(), value);
+        self
+    }
+
+    /// 取参数值
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.values.get(name).copied()
+    }
+
+    /// 取浮点参数，未设置时使用默认值，非有限值返回错误
+    pub fn number(&self, name: &str, default: f64) -> Result<f64, IndicatorError> {
+        match self.get(name) {
+            None => Ok(default),
+            Some(value) if value.is_finite() => Ok(value),
+            Some(value) => Err(IndicatorError::invalid(name, format!("{} 不是有限数值", value))),
+        }
+    }
+
+    /// 取周期参数，未设置时使用默认值，必须为正整数
+    pub fn period(&self, name: &str, default: usize) -> Result<usize, IndicatorError> {
+        self.count(name, default).and_then(|value| {
+            if value == 0 {
+                Err(IndicatorError::invalid(name, "周期必须大于0".to_string()))
+            } else {
+                Ok(value)
+            }
+        })
+    }
+
+    /// 取非负整数参数，未设置时使用默认值
+    pub fn count(&self, name: &str, default: usize) -> Result<usize, IndicatorError> {
+        match self.get(name) {
+            None => Ok(default),
+            Some(value) if value >= 0.0 && value.fract() == 0.0 && value.is_finite() => {
+                Ok(value as usize)
+            }
+            Some(value) => Err(IndicatorError::invalid(name, format!("{} 不是非负整数", value))),
+        }
+    }
+}
+
+impl FromIterator<(String, f64)> for IndicatorParams {
+    fn from_iter<I: IntoIterator<Item = (String, f64)>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// 指标构造错误
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndicatorError {
+    /// 未注册的指标名称
+    UnknownIndicator(String),
+    /// 参数非法
+    InvalidParameter {
+        /// 参数名
+        name: String,
+        /// 原因
+        reason: String,
+    },
+}
+
+impl IndicatorError {
+    /// 创建参数非法错误
+    pub fn invalid(name: &str, reason: String) -> Self {
+        Self::InvalidParameter {
+            name: name.to_string(),
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for IndicatorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownIndicator(name) => write!(f, "未知指标: {}", name),
+            Self::InvalidParameter { name, reason } => write!(f, "参数 {} 非法: {}", name, reason),
+        }
+    }
+}
+
+impl std::error::Error for IndicatorError {}
+
+/// 指标构造函数
+pub type IndicatorFactory =
+    Box<dyn Fn(&IndicatorParams) -> Result<BoxedIndicator, IndicatorError> + Send + Sync>;
+
+/// 指标注册表
+///
+/// 名称不区分大小写。[`IndicatorRegistry::new`] 预先注册全部内置指标，
+/// 也可以通过 [`register`](Self::register) 注册自定义指标或覆盖内置指标。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_indicators::{IndicatorParams, IndicatorRegistry};
+///
+/// let registry = IndicatorRegistry::new();
+/// let params = IndicatorParams::new().with("fast", 5.0).with("slow", 10.0);
+/// let macd = registry.create("MACD", &params).unwrap();
+///
+/// assert_eq!(macd.warm_up_period(), 1);
+/// assert!(registry.create("unknown", &params).is_err());
+/// ```
+pub struct IndicatorRegistry {
+    factories: HashMap<String, IndicatorFactory>,
+}
+
+impl IndicatorRegistry {
+    /// 创建包含全部内置指标的注册表
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        registry.register_builtins();
+        registry
+    }
+
+    /// 创建空注册表
+    pub fn empty() -> Self {
+        Self {
+            factories: HashMap::new(),
+        }
+    }
+
+    /// 注册指标，同名指标会被覆盖
+    pub fn register<F>(&mut self, name: &str, factory: F)
+    where
+        F: Fn(&IndicatorParams) -> Result<BoxedIndicator, IndicatorError> + Send + Sync + 'static,
+    {
+        self.factories.insert(name.to_lowercase(), Box::new(factory));
+    }
+
+    /// 按名称和参数创建指标
+    pub fn create(&self, name: &str, params: &IndicatorParams) -> Result<BoxedIndicator, IndicatorError> {
+        let factory = self
+            .factories
+            .get(&name.to_lowercase())
+            .ok_or_else(|| IndicatorError::UnknownIndicator(name.to_string()))?;
+        factory(params)
+    }
+
+    /// 是否注册了给定名称
+    pub fn contains(&self, name: &str) -> bool {
+        self.factories.contains_key(&name.to_lowercase())
+    }
+
+    /// 已注册的指标名称（按字母排序）
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.factories.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    fn register_builtins(&mut self) {
+        self.register("ma", |p| Ok(MA::new(p.period("period", 20)?).boxed()));
+        self.register("ema", |p| Ok(EMA::new(p.period("period", 20)?).boxed()));
+        self.register("rsi", |p| Ok(RSI::new(p.period("period", 14)?).boxed()));
+        self.register("bollinger", |p| {
+            let std_dev = p.number("std_dev", 2.0)?;
+            if std_dev <= 0.0 {
+                return Err(IndicatorError::invalid("std_dev", "标准差倍数必须大于0".to_string()));
+            }
+            Ok(BollingerBands::new(p.period("period", 20)?, std_dev).boxed())
+        });
+        self.register("macd", |p| {
+            let fast = p.period("fast", 12)?;
+            let slow = p.period("slow", 26)?;
+            if fast >= slow {
+                return Err(IndicatorError::invalid("fast", "快线周期必须小于慢线周期".to_string()));
+            }
+            Ok(MACD::new(fast, slow, p.period("signal", 9)?).boxed())
+        });
+        self.register("atr", |p| Ok(ATR::new(p.period("period", 14)?).boxed()));
+        self.register("stochastic", |p| {
+            Ok(Stochastic::new(p.period("k_period", 14)?, p.period("d_period", 3)?).boxed())
+        });
+        self.register("obv", |_| Ok(OBV::new().boxed()));
+        self.register("adx", |p| Ok(ADX::new(p.period("period", 14)?).boxed()));
+        self.register("roc", |p| Ok(ROC::new(p.period("period", 12)?).boxed()));
+        self.register("cci", |p| Ok(CCI::new(p.period("period", 20)?).boxed()));
+        self.register("williams_r", |p| Ok(WilliamsR::new(p.period("period", 14)?).boxed()));
+        self.register("stddev", |p| Ok(StdDev::new(p.period("period", 20)?).boxed()));
+        self.register("keltner", |p| {
+            let multiplier = p.number("multiplier", 2.0)?;
+            if multiplier < 0.0 {
+                return Err(IndicatorError::invalid("multiplier", "ATR倍数不能为负数".to_string()));
+            }
+            Ok(KeltnerChannels::new(p.period("period", 20)?, multiplier).boxed())
+        });
+        self.register("mfi", |p| Ok(MFI::new(p.period("period", 14)?).boxed()));
+        self.register("vwap", |p| Ok(VWAP::new(p.count("period", 0)?).boxed()));
+        self.register("psar", |p| {
+            let acceleration = p.number("acceleration", 0.02)?;
+            let max_acceleration = p.number("max_acceleration", 0.2)?;
+            if acceleration <= 0.0 || max_acceleration < acceleration {
+                return Err(IndicatorError::invalid(
+                    "acceleration",
+                    "加速因子必须大于0且不超过最大值".to_string(),
+                ));
+            }
+            Ok(PSAR::new(acceleration, max_acceleration).boxed())
+        });
+        self.register("ichimoku", |p| {
+            Ok(Ichimoku::new(p.period("tenkan", 9)?, p.period("kijun", 26)?, p.period("senkou_b", 52)?).boxed())
+        });
+        self.register("cmf", |p| Ok(CMF::new(p.period("period", 20)?).boxed()));
+        self.register("adline", |_| Ok(ADLine::new().boxed()));
+        self.register("wma", |p| Ok(WMA::new(p.period("period", 20)?).boxed()));
+        self.register("dema", |p| Ok(DEMA::new(p.period("period", 20)?).boxed()));
+        self.register("tema", |p| Ok(TEMA::new(p.period("period", 20)?).boxed()));
+        self.register("hma", |p| Ok(HMA::new(p.period("period", 9)?).boxed()));
+        self.register("kama", |p| {
+            let fast = p.period("fast", 2)?;
+            let slow = p.period("slow", 30)?;
+            if fast >= slow {
+                return Err(IndicatorError::invalid("fast", "快速周期必须小于慢速周期".to_string()));
+            }
+            Ok(KAMA::new(p.period("period", 10)?, fast, slow).boxed())
+        });
+        self.register("zlema", |p| Ok(ZLEMA::new(p.period("period", 20)?).boxed()));
+        self.register("trix", |p| Ok(TRIX::new(p.period("period", 15)?).boxed()));
+        self.register("aroon", |p| Ok(Aroon::new(p.period("period", 25)?).boxed()));
+        self.register("donchian", |p| Ok(DonchianChannels::new(p.period("period", 20)?).boxed()));
+        self.register("supertrend", |p| {
+            let multiplier = p.number("multiplier", 3.0)?;
+            if multiplier <= 0.0 {
+                return Err(IndicatorError::invalid("multiplier", "ATR倍数必须大于0".to_string()));
+            }
+            Ok(Supertrend::new(p.period("period", 10)?, multiplier).boxed())
+        });
+        self.register("zscore", |p| Ok(ZScore::new(p.period("period", 20)?).boxed()));
+        self.register("linreg", |p| {
+            let period = p.period("period", 20)?;
+            if period < 2 {
+                return Err(IndicatorError::invalid("period", "线性回归周期必须大于1".to_string()));
+            }
+            Ok(LinearRegression::new(period).boxed())
+        });
+        self.register("autocorrelation", |p| {
+            let period = p.period("period", 20)?;
+            let lag = p.period("lag", 1)?;
+            if lag >= period {
+                return Err(IndicatorError::invalid("lag", "滞后期数必须小于周期".to_string()));
+            }
+            Ok(Autocorrelation::new(period, lag).boxed())
+        });
+        self.register("hurst", |p| {
+            let period = p.period("period", 100)?;
+            if period < 16 {
+                return Err(IndicatorError::invalid("period", "赫斯特指数周期不能小于16".to_string()));
+            }
+            Ok(Hurst::new(period).boxed())
+        });
+        self.register("volume_profile", |p| {
+            let buckets = p.period("buckets", 24)?;
+            let value_area = p.number("value_area", 0.7)?;
+            if value_area <= 0.0 || value_area > 1.0 {
+                return Err(IndicatorError::invalid("value_area", "价值区比例必须在(0, 1]内".to_string()));
+            }
+            let profile = match p.count("session_minutes", 0)? {
+                0 => VolumeProfile::new(p.period("period", 20)?, buckets),
+                minutes => VolumeProfile::session(minutes as i64 * 60_000, buckets),
+            };
+            Ok(profile.with_value_area(value_area).boxed())
+        });
+    }
+}
+
+impl Default for IndicatorRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for IndicatorRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IndicatorRegistry")
+            .field("indicators", &self.names())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests;
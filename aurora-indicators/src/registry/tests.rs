@@ -0,0 +1,112 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+/// 测试内置指标全部注册
+#[test]
+fn test_builtin_indicators_registered() {
+    let registry = IndicatorRegistry::new();
+
+    assert_eq!(registry.names().len(), 35);
+    for name in ["ma", "ema", "rsi", "macd", "adx", "ichimoku", "adline"] {
+        assert!(registry.contains(name), "缺少指标 {}", name);
+    }
+    assert!(IndicatorRegistry::empty().names().is_empty());
+}
+
+/// 测试名称不区分大小写并使用参数
+#[test]
+fn test_create_with_params() {
+    let registry = IndicatorRegistry::new();
+
+    let ma = registry.create("MA", &IndicatorParams::new().with("period", 5.0)).unwrap();
+    assert_eq!(ma.warm_up_period(), 5);
+
+    let stochastic = registry
+        .create("stochastic", &IndicatorParams::new().with("k_period", 5.0).with("d_period", 2.0))
+        .unwrap();
+    assert_eq!(stochastic.warm_up_period(), 6);
+
+    // 未设置的参数使用默认值
+    let adx = registry.create("adx", &IndicatorParams::new()).unwrap();
+    assert_eq!(adx.warm_up_period(), 15);
+
+    // 设置交易时段后按时段统计
+    let profile = registry
+        .create("volume_profile", &IndicatorParams::new().with("session_minutes", 60.0))
+        .unwrap();
+    assert_eq!(profile.warm_up_period(), 1);
+}
+
+/// 测试未知指标和非法参数
+#[test]
+fn test_create_errors() {
+    let registry = IndicatorRegistry::new();
+
+    assert_eq!(
+        registry.create("foo", &IndicatorParams::new()).unwrap_err(),
+        IndicatorError::UnknownIndicator("foo".to_string())
+    );
+
+    for (name, params) in [
+        ("ma", IndicatorParams::new().with("period", 0.0)),
+        ("ma", IndicatorParams::new().with("period", 2.5)),
+        ("rsi", IndicatorParams::new().with("period", -3.0)),
+        ("macd", IndicatorParams::new().with("fast", 30.0)),
+        ("bollinger", IndicatorParams::new().with("std_dev", 0.0)),
+        ("psar", IndicatorParams::new().with("acceleration", 0.5)),
+        ("ema", IndicatorParams::new().with("period", f64::NAN)),
+        ("linreg", IndicatorParams::new().with("period", 1.0)),
+        ("autocorrelation", IndicatorParams::new().with("lag", 20.0)),
+        ("hurst", IndicatorParams::new().with("period", 10.0)),
+        ("volume_profile", IndicatorParams::new().with("value_area", 0.0)),
+        ("volume_profile", IndicatorParams::new().with("buckets", 0.0)),
+    ] {
+        let error = registry.create(name, &params).unwrap_err();
+        assert!(
+            matches!(error, IndicatorError::InvalidParameter { .. }),
+            "{} 应拒绝参数 {:?}",
+            name,
+            params
+        );
+    }
+}
+
+/// 测试注册自定义指标
+#[test]
+fn test_register_custom_indicator() {
+    let mut registry = IndicatorRegistry::empty();
+    registry.register("fast_ma", |_| Ok(crate::MA::new(3).boxed()));
+
+    let indicator = registry.create("FAST_MA", &IndicatorParams::new()).unwrap();
+    assert_eq!(indicator.warm_up_period(), 3);
+}
+
+/// 测试参数从配置格式反序列化
+#[test]
+fn test_params_deserialize() {
+    let params: IndicatorParams = serde_json::from_str(r#"{"fast": 5, "slow": 10}"#).unwrap();
+
+    assert_eq!(params.period("fast", 12).unwrap(), 5);
+    assert_eq!(params.number("slow", 26.0).unwrap(), 10.0);
+    assert!(IndicatorRegistry::new().create("macd", &params).is_ok());
+}
+
+/// 测试错误信息
+#[test]
+fn test_error_display() {
+    let error = IndicatorError::invalid("period", "周期必须大于0".to_string());
+    assert!(error.to_string().contains("period"));
+    assert!(IndicatorError::UnknownIndicator("foo".to_string()).to_string().contains("foo"));
+}
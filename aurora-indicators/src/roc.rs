@@ -46,11 +46,15 @@
 //! assert!(result.is_some());
 //! ```
 
+use aurora_core::Kline;
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 /// ROC (Rate of Change) 变动率指标
 ///
 /// 计算当前价格相对于N个周期前价格的变化百分比
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ROC {
     /// 计算周期
     period: usize,
@@ -194,5 +198,25 @@ impl ROC {
     }
 }
 
+impl Indicator for ROC {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        ROC::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        ROC::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period + 1
+    }
+}
+
 #[cfg(test)]
 mod tests;
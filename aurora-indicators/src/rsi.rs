@@ -16,7 +16,9 @@
 //!
 //! RSI衡量价格变动的速度和幅度，用于判断超买超卖状态。
 
-use crate::EMA;
+use crate::{EMA, Indicator};
+use aurora_core::Kline;
+use serde::{Deserialize, Serialize};
 
 /// 相对强弱指数 (RSI) 指标
 ///
@@ -37,7 +39,7 @@ use crate::EMA;
 /// # 内存复杂度
 ///
 /// 空间复杂度为O(1)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RSI {
     /// RSI周期长度
     period: usize,
@@ -160,5 +162,25 @@ impl RSI {
     }
 }
 
+impl Indicator for RSI {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        RSI::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        RSI::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        2
+    }
+}
+
 #[cfg(test)]
 mod tests;
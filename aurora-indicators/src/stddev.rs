@@ -40,11 +40,15 @@
 //! let result = stddev.update(100.0);
 //! ```
 
+use aurora_core::Kline;
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 /// Standard Deviation 标准差指标
 ///
 /// 衡量价格数据的离散程度
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StdDev {
     /// 计算周期
     period: usize,
@@ -223,5 +227,25 @@ impl StdDev {
     }
 }
 
+impl Indicator for StdDev {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        StdDev::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        StdDev::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period
+    }
+}
+
 #[cfg(test)]
 mod tests;
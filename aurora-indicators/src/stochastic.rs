@@ -16,11 +16,13 @@
 //!
 //! 用于比较收盘价与价格区间的相对位置，判断超买超卖。
 
-use crate::MA;
+use crate::{Indicator, IndicatorValue, MA};
+use aurora_core::Kline;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 /// Stochastic指标输出
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct StochasticOutput {
     /// %K线 - 快速随机指标
     pub k: f64,
@@ -50,7 +52,7 @@ pub struct StochasticOutput {
 ///
 /// - %K > 80: 超买
 /// - %K < 20: 超卖
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Stochastic {
     /// %K周期
     k_period: usize,
@@ -206,5 +208,34 @@ impl Stochastic {
     }
 }
 
+impl Indicator for Stochastic {
+    type Output = StochasticOutput;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<StochasticOutput> {
+        self.update(kline.high, kline.low, kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        Stochastic::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        Stochastic::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.k_period + self.d_period - 1
+    }
+}
+
+impl From<StochasticOutput> for IndicatorValue {
+    fn from(output: StochasticOutput) -> Self {
+        IndicatorValue::from_fields(vec![
+            ("k", output.k),
+            ("d", output.d),
+        ])
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,249 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Supertrend (超级趋势) 指标
+//!
+//! Supertrend 以 (最高价 + 最低价) / 2 为中心、ATR的倍数为宽度构建上下轨，
+//! 上升趋势中跟随下轨、下降趋势中跟随上轨，收盘价穿越当前轨道时趋势翻转，
+//! 常用作趋势过滤和移动止损。
+//!
+//! # 计算公式
+//!
+//! 1. 基础上轨 = (最高价 + 最低价) / 2 + multiplier × ATR(period)
+//! 2. 基础下轨 = (最高价 + 最低价) / 2 - multiplier × ATR(period)
+//! 3. 最终上轨：基础上轨低于上一最终上轨，或上一收盘价高于上一最终上轨时取基础上轨，否则沿用上一最终上轨
+//! 4. 最终下轨：基础下轨高于上一最终下轨，或上一收盘价低于上一最终下轨时取基础下轨，否则沿用上一最终下轨
+//! 5. 上升趋势中收盘价跌破最终下轨则转为下降趋势；下降趋势中收盘价突破最终上轨则转为上升趋势
+//! 6. Supertrend = 上升趋势取最终下轨，下降趋势取最终上轨
+//!
+//! ATR 与 Supertrend 的原始定义及 TradingView `ta.supertrend` 一致，使用 Wilder 平滑：
+//! 前 `period` 个真实波幅（第一根K线取最高价 - 最低价）的简单平均作为初值，
+//! 之后 ATR = (前ATR × (period - 1) + TR) / period。
+//! 这与本库 [`ATR`](crate::ATR) 的EMA平滑不同，因此ATR就绪（第 `period` 根K线）后才输出，
+//! 第一个输出视为上升趋势。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::Supertrend;
+//!
+//! let mut st = Supertrend::new(3, 3.0);
+//!
+//! assert!(st.update(110.0, 90.0, 100.0).is_none());
+//! assert!(st.update(112.0, 96.0, 108.0).is_none());
+//! let output = st.update(115.0, 101.0, 112.0).unwrap();
+//! assert!(output.is_uptrend);
+//! assert!(output.value < 112.0);
+//! ```
+
+use aurora_core::Kline;
+use crate::{Indicator, IndicatorValue};
+use serde::{Deserialize, Serialize};
+
+/// Supertrend 输出结构
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SupertrendOutput {
+    /// Supertrend 值（上升趋势为下轨，下降趋势为上轨）
+    pub value: f64,
+    /// 当前趋势: true=上升, false=下降
+    pub is_uptrend: bool,
+}
+
+/// Supertrend 超级趋势指标
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Supertrend {
+    /// ATR周期
+    period: usize,
+    /// ATR倍数
+    multiplier: f64,
+    /// 预热期间累计的真实波幅
+    tr_sum: f64,
+    /// 已处理的K线数量
+    count: usize,
+    /// Wilder平滑的平均真实波幅
+    atr: Option<f64>,
+    /// 上一最终上轨
+    upper: Option<f64>,
+    /// 上一最终下轨
+    lower: Option<f64>,
+    /// 上一收盘价，用于计算真实波幅和调整轨道
+    prev_close: Option<f64>,
+    /// 当前趋势方向
+    is_uptrend: bool,
+}
+
+impl Supertrend {
+    /// 创建新的Supertrend指标
+    ///
+    /// # 参数
+    ///
+    /// * `period` - ATR周期，通常使用10
+    /// * `multiplier` - ATR倍数，通常使用3.0
+    ///
+    /// # Panics
+    ///
+    /// 当 `period` 为 0 或 `multiplier` 不大于0时会 panic
+    pub fn new(period: usize, multiplier: f64) -> Self {
+        assert!(period > 0, "Supertrend周期必须大于0");
+        assert!(multiplier > 0.0, "Supertrend倍数必须大于0");
+
+        Self {
+            period,
+            multiplier,
+            tr_sum: 0.0,
+            count: 0,
+            atr: None,
+            upper: None,
+            lower: None,
+            prev_close: None,
+            is_uptrend: true,
+        }
+    }
+
+    /// 更新指标并计算新的Supertrend值
+    ///
+    /// # 参数
+    ///
+    /// * `high` - 最高价
+    /// * `low` - 最低价
+    /// * `close` - 收盘价
+    ///
+    /// # 返回值
+    ///
+    /// 处理满 `period` 根K线后返回 `SupertrendOutput`，之前返回 `None`
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<SupertrendOutput> {
+        let Some(atr) = self.update_atr(high, low) else {
+            self.prev_close = Some(close);
+            return None;
+        };
+        let mid = (high + low) / 2.0;
+        let basic_upper = mid + self.multiplier * atr;
+        let basic_lower = mid - self.multiplier * atr;
+
+        let (upper, lower) = match (self.upper, self.lower, self.prev_close) {
+            (Some(prev_upper), Some(prev_lower), Some(prev_close)) => {
+                let upper = if basic_upper < prev_upper || prev_close > prev_upper {
+                    basic_upper
+                } else {
+                    prev_upper
+                };
+                let lower = if basic_lower > prev_lower || prev_close < prev_lower {
+                    basic_lower
+                } else {
+                    prev_lower
+                };
+
+                if self.is_uptrend && close < lower {
+                    self.is_uptrend = false;
+                } else if !self.is_uptrend && close > upper {
+                    self.is_uptrend = true;
+                }
+                (upper, lower)
+            }
+            _ => (basic_upper, basic_lower),
+        };
+
+        self.upper = Some(upper);
+        self.lower = Some(lower);
+        self.prev_close = Some(close);
+
+        Some(SupertrendOutput {
+            value: if self.is_uptrend { lower } else { upper },
+            is_uptrend: self.is_uptrend,
+        })
+    }
+
+    /// 当前ATR值，处理的K线不足 `period` 根时返回 `None`
+    pub fn atr(&self) -> Option<f64> {
+        self.atr
+    }
+
+    /// 用Wilder平滑更新ATR，预热期间返回 `None`
+    fn update_atr(&mut self, high: f64, low: f64) -> Option<f64> {
+        let tr = match self.prev_close {
+            Some(prev_close) => (high - low).max((high - prev_close).abs()).max((low - prev_close).abs()),
+            None => high - low,
+        };
+        self.count += 1;
+
+        let period = self.period as f64;
+        let atr = match self.atr {
+            Some(prev_atr) => (prev_atr * (period - 1.0) + tr) / period,
+            None => {
+                self.tr_sum += tr;
+                if self.count < self.period {
+                    return None;
+                }
+                self.tr_sum / period
+            }
+        };
+        self.atr = Some(atr);
+        Some(atr)
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.tr_sum = 0.0;
+        self.count = 0;
+        self.atr = None;
+        self.upper = None;
+        self.lower = None;
+        self.prev_close = None;
+        self.is_uptrend = true;
+    }
+
+    /// 检查是否已准备好输出结果
+    pub fn is_ready(&self) -> bool {
+        self.upper.is_some()
+    }
+}
+
+// 默认参数: 10周期, 3倍ATR
+impl Default for Supertrend {
+    fn default() -> Self {
+        Self::new(10, 3.0)
+    }
+}
+
+impl Indicator for Supertrend {
+    type Output = SupertrendOutput;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<SupertrendOutput> {
+        self.update(kline.high, kline.low, kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        Supertrend::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        Supertrend::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period
+    }
+}
+
+impl From<SupertrendOutput> for IndicatorValue {
+    fn from(output: SupertrendOutput) -> Self {
+        IndicatorValue::from_fields(vec![
+            ("supertrend", output.value),
+            ("trend", if output.is_uptrend { 1.0 } else { -1.0 }),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,163 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::test_data::{ATR_CLOSES, ATR_HIGHS, ATR_LOWS, ATR_WILDER_14};
+
+#[test]
+fn test_supertrend_new() {
+    let st = Supertrend::new(10, 3.0);
+    assert_eq!(st.multiplier, 3.0);
+    assert!(!st.is_ready());
+    assert_eq!(Supertrend::default().multiplier, 3.0);
+}
+
+#[test]
+#[should_panic(expected = "Supertrend周期必须大于0")]
+fn test_supertrend_zero_period() {
+    Supertrend::new(0, 3.0);
+}
+
+#[test]
+#[should_panic(expected = "Supertrend倍数必须大于0")]
+fn test_supertrend_zero_multiplier() {
+    Supertrend::new(10, 0.0);
+}
+
+/// 参考值：StockCharts ChartSchool ATR示例表（QQQ）公布的14日Wilder ATR
+#[test]
+fn test_supertrend_atr_matches_stockcharts() {
+    let mut st = Supertrend::new(14, 3.0);
+    let mut atrs = Vec::new();
+    for i in 0..ATR_CLOSES.len() {
+        if st.update(ATR_HIGHS[i], ATR_LOWS[i], ATR_CLOSES[i]).is_some() {
+            atrs.push(st.atr().unwrap());
+        }
+    }
+
+    assert_eq!(atrs.len(), ATR_WILDER_14.len());
+    for (atr, expected) in atrs.iter().zip(ATR_WILDER_14) {
+        assert!((atr - expected).abs() < 0.01, "{} != {}", atr, expected);
+    }
+}
+
+/// 参考值：用StockCharts公布的QQQ 14日ATR按Supertrend(14, 3)的轨道规则逐根推算。
+/// 第23根K线（48.43）跌破下轨48.765转为下降趋势，第29根K线（48.62）突破上轨48.565转回上升趋势。
+/// ATR只有两位小数，允许 3 × 0.01 的误差
+#[test]
+fn test_supertrend_reference_values() {
+    let mut st = Supertrend::new(14, 3.0);
+    let outputs: Vec<_> = (0..ATR_CLOSES.len())
+        .filter_map(|i| st.update(ATR_HIGHS[i], ATR_LOWS[i], ATR_CLOSES[i]))
+        .collect();
+
+    let expected = [
+        (48.28, true),
+        (48.28, true),
+        (48.56, true),
+        (48.765, true),
+        (48.765, true),
+        (48.765, true),
+        (48.765, true),
+        (48.765, true),
+        (48.765, true),
+        (51.01, false),
+        (50.41, false),
+        (48.565, false),
+        (48.565, false),
+        (48.565, false),
+        (48.565, false),
+        (44.26, true),
+        (44.30, true),
+    ];
+    assert_eq!(outputs.len(), expected.len());
+    for (output, (value, is_uptrend)) in outputs.iter().zip(expected) {
+        assert!((output.value - value).abs() < 0.03, "{:?} != {}", output, value);
+        assert_eq!(output.is_uptrend, is_uptrend);
+    }
+}
+
+/// 测试ATR预热期间不输出
+#[test]
+fn test_supertrend_waits_for_atr() {
+    let mut st = Supertrend::new(3, 1.0);
+    assert_eq!(st.update(10.0, 8.0, 9.0), None);
+    assert_eq!(st.update(12.0, 10.0, 11.0), None);
+    assert!(!st.is_ready());
+
+    // TR: 2, 3, 4，初始ATR = 3，基础下轨 = 9 - 3 = 6
+    let output = st.update(11.0, 7.0, 7.5).unwrap();
+    assert_eq!(st.atr(), Some(3.0));
+    assert_eq!(output.value, 6.0);
+    assert!(output.is_uptrend);
+    assert_eq!(Indicator::warm_up_period(&st), 3);
+}
+
+#[test]
+fn test_supertrend_lower_band_only_rises_in_uptrend() {
+    let mut st = Supertrend::new(5, 2.0);
+    let mut previous: Option<f64> = None;
+
+    for i in 0..30 {
+        let close = 100.0 + i as f64;
+        let Some(output) = st.update(close + 1.0, close - 1.0, close) else {
+            continue;
+        };
+        assert!(output.is_uptrend);
+        if let Some(previous) = previous {
+            assert!(output.value >= previous);
+        }
+        assert!(output.value < close);
+        previous = Some(output.value);
+    }
+}
+
+#[test]
+fn test_supertrend_flips_on_crash() {
+    let mut st = Supertrend::new(5, 2.0);
+    for i in 0..20 {
+        let close = 100.0 + i as f64;
+        st.update(close + 1.0, close - 1.0, close);
+    }
+
+    let output = st.update(90.0, 80.0, 81.0).unwrap();
+    assert!(!output.is_uptrend);
+    assert!(output.value > 81.0);
+}
+
+#[test]
+fn test_supertrend_indicator_value() {
+    let value = IndicatorValue::from(SupertrendOutput {
+        value: 95.0,
+        is_uptrend: false,
+    });
+    assert_eq!(value.primary(), 95.0);
+    assert_eq!(value.get("trend"), Some(-1.0));
+}
+
+#[test]
+fn test_supertrend_reset() {
+    let mut st = Supertrend::new(2, 1.0);
+    st.update(10.0, 8.0, 9.0);
+    st.update(11.0, 7.0, 7.0);
+    assert!(st.is_ready());
+
+    st.reset();
+    assert!(!st.is_ready());
+    assert!(st.is_uptrend);
+    assert_eq!(st.atr(), None);
+    assert_eq!(st.update(10.0, 8.0, 9.0), None);
+    // TR: 2, 2，初始ATR = 2，基础下轨 = 9 - 2 = 7
+    assert_eq!(st.update(10.0, 8.0, 9.0).unwrap().value, 7.0);
+}
@@ -0,0 +1,132 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 三重指数移动平均线 (Triple Exponential Moving Average) 指标
+//!
+//! TEMA 由 Patrick Mulloy 提出，在 [`DEMA`](crate::DEMA) 的基础上再做一次平滑修正，
+//! 进一步减小滞后。
+//!
+//! # 计算公式
+//!
+//! - EMA1 = EMA(价格, N)
+//! - EMA2 = EMA(EMA1, N)
+//! - EMA3 = EMA(EMA2, N)
+//! - TEMA = 3 × EMA1 - 3 × EMA2 + EMA3
+//!
+//! 与 [`EMA`] 一样以第一个价格作为初始值，从第一个数据点开始输出。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::TEMA;
+//!
+//! let mut tema = TEMA::new(3);
+//!
+//! assert_eq!(tema.update(1.0), 1.0);
+//! assert_eq!(tema.update(2.0), 1.875);
+//! assert_eq!(tema.update(3.0), 2.9375);
+//! ```
+
+use aurora_core::Kline;
+use crate::{EMA, Indicator};
+use serde::{Deserialize, Serialize};
+
+/// 三重指数移动平均线 (Triple Exponential Moving Average) 指标
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TEMA {
+    /// 价格的EMA
+    ema1: EMA,
+    /// EMA1的EMA
+    ema2: EMA,
+    /// EMA2的EMA
+    ema3: EMA,
+}
+
+impl TEMA {
+    /// 创建新的TEMA指标
+    ///
+    /// # 参数
+    ///
+    /// * `period` - EMA周期，必须大于0
+    ///
+    /// # Panics
+    ///
+    /// 如果周期为0，函数会panic
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "TEMA周期必须大于0");
+
+        Self {
+            ema1: EMA::new(period),
+            ema2: EMA::new(period),
+            ema3: EMA::new(period),
+        }
+    }
+
+    /// 更新指标并返回最新的TEMA值
+    ///
+    /// # 参数
+    ///
+    /// * `price` - 新的价格数据
+    pub fn update(&mut self, price: f64) -> f64 {
+        let ema1 = self.ema1.update(price);
+        let ema2 = self.ema2.update(ema1);
+        let ema3 = self.ema3.update(ema2);
+        3.0 * ema1 - 3.0 * ema2 + ema3
+    }
+
+    /// 获取当前的TEMA值
+    pub fn value(&self) -> Option<f64> {
+        Some(3.0 * self.ema1.value()? - 3.0 * self.ema2.value()? + self.ema3.value()?)
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.ema1.reset();
+        self.ema2.reset();
+        self.ema3.reset();
+    }
+
+    /// 获取周期设置
+    pub fn period(&self) -> usize {
+        self.ema1.period()
+    }
+
+    /// 检查指标是否已有数据
+    pub fn is_ready(&self) -> bool {
+        self.ema3.is_ready()
+    }
+}
+
+impl Indicator for TEMA {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        Some(self.update(kline.close))
+    }
+
+    fn is_ready(&self) -> bool {
+        TEMA::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        TEMA::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests;
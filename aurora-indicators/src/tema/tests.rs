@@ -0,0 +1,94 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::test_data::MOVAVG_CLOSES;
+use approx::assert_relative_eq;
+
+#[test]
+fn test_tema_new() {
+    let tema = TEMA::new(10);
+    assert_eq!(tema.period(), 10);
+    assert!(!tema.is_ready());
+    assert_eq!(tema.value(), None);
+}
+
+#[test]
+#[should_panic(expected = "TEMA周期必须大于0")]
+fn test_tema_zero_period() {
+    TEMA::new(0);
+}
+
+/// 参考值：StockCharts ChartSchool 移动平均线示例表（cs-movavg.xls）的30日收盘价的10日TEMA = 3 × EMA1 - 3 × EMA2 + EMA3，取最后8个值（保留四位小数）。
+/// EMA以第一个价格为初值，与公布的10日EMA的一致性见 `test_ema_matches_stockcharts`
+#[test]
+fn test_tema_reference_values() {
+    let mut tema = TEMA::new(10);
+    let outputs: Vec<f64> = MOVAVG_CLOSES.iter().map(|&price| tema.update(price)).collect();
+
+    let expected = [23.9419, 23.6533, 23.4118, 23.3520, 23.0190, 22.9906, 22.6621, 22.3480];
+    for (output, expected) in outputs[22..].iter().zip(expected) {
+        assert_relative_eq!(*output, expected, epsilon = 1e-4);
+    }
+}
+
+/// 周期3（α = 0.5）逐步计算：
+/// EMA1: 1, 1.5, 2.25, 3.125
+/// EMA2: 1, 1.25, 1.75, 2.4375
+/// EMA3: 1, 1.125, 1.4375, 1.9375
+/// TEMA: 1, 1.875, 2.9375, 4.0
+#[test]
+fn test_tema_worked_example() {
+    let mut tema = TEMA::new(3);
+    let outputs: Vec<f64> = [1.0, 2.0, 3.0, 4.0].iter().map(|&p| tema.update(p)).collect();
+
+    assert_eq!(outputs, vec![1.0, 1.875, 2.9375, 4.0]);
+    assert_eq!(tema.value(), Some(4.0));
+}
+
+#[test]
+fn test_tema_less_lag_than_dema() {
+    let mut tema = TEMA::new(10);
+    let mut dema = crate::DEMA::new(10);
+    let mut last = (0.0, 0.0);
+
+    // 加速上涨时，TEMA的滞后小于DEMA
+    for i in 0..30 {
+        let price = 100.0 + (i * i) as f64 * 0.1;
+        last = (tema.update(price), dema.update(price));
+    }
+
+    let price = 100.0 + (29 * 29) as f64 * 0.1;
+    assert!((price - last.0).abs() < (price - last.1).abs());
+}
+
+#[test]
+fn test_tema_constant_prices() {
+    let mut tema = TEMA::new(7);
+    for _ in 0..20 {
+        assert_relative_eq!(tema.update(50.0), 50.0, epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn test_tema_reset() {
+    let mut tema = TEMA::new(3);
+    tema.update(1.0);
+    tema.update(2.0);
+    assert!(tema.is_ready());
+
+    tema.reset();
+    assert!(!tema.is_ready());
+    assert_eq!(tema.update(5.0), 5.0);
+}
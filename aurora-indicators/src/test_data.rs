@@ -0,0 +1,53 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 参考值测试使用的公开示例数据
+//!
+//! 两组数据都来自 StockCharts ChartSchool 的示例表格，表格同时公布了
+//! 10日SMA/EMA和14日Wilder ATR，可以用来核对数据本身和各指标的基础计算。
+
+/// StockCharts ChartSchool "Moving Averages - Simple and Exponential" 示例表（cs-movavg.xls）的30日收盘价
+pub(crate) const MOVAVG_CLOSES: [f64; 30] = [
+    22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24, 22.29, 22.15, 22.39, 22.38, 22.61, 23.36,
+    24.05, 23.75, 23.83, 23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68, 23.10, 22.40, 22.17,
+];
+
+/// 同一表格公布的10日EMA（保留两位小数），从第10根K线开始，初值为前10日SMA
+pub(crate) const MOVAVG_EMA_10: [f64; 21] = [
+    22.22, 22.21, 22.24, 22.27, 22.33, 22.52, 22.80, 22.97, 23.13, 23.28, 23.34, 23.43, 23.51, 23.54, 23.47,
+    23.40, 23.39, 23.26, 23.23, 23.08, 22.92,
+];
+
+/// StockCharts ChartSchool "Average True Range (ATR)" 示例表（cs-atr.xls）的QQQ最高价
+pub(crate) const ATR_HIGHS: [f64; 30] = [
+    48.70, 48.72, 48.90, 48.87, 48.82, 49.05, 49.20, 49.35, 49.92, 50.19, 50.12, 49.66, 49.88, 50.19, 50.36,
+    50.57, 50.65, 50.43, 49.63, 50.33, 50.29, 50.17, 49.32, 48.50, 48.32, 46.80, 47.80, 48.39, 48.66, 48.79,
+];
+
+/// 同一表格的QQQ最低价
+pub(crate) const ATR_LOWS: [f64; 30] = [
+    47.79, 48.14, 48.39, 48.37, 48.24, 48.64, 48.94, 48.86, 49.50, 49.87, 49.20, 48.90, 49.43, 49.73, 49.26,
+    50.09, 50.30, 49.21, 48.98, 49.61, 49.20, 49.43, 48.08, 47.64, 41.55, 44.28, 47.31, 47.20, 47.90, 47.73,
+];
+
+/// 同一表格的QQQ收盘价
+pub(crate) const ATR_CLOSES: [f64; 30] = [
+    48.16, 48.61, 48.75, 48.63, 48.74, 49.03, 49.07, 49.32, 49.91, 50.13, 49.53, 49.50, 49.75, 50.03, 50.31,
+    50.52, 50.41, 49.34, 49.37, 50.23, 49.24, 49.93, 48.43, 48.18, 46.57, 45.41, 47.77, 47.72, 48.62, 47.85,
+];
+
+/// 同一表格公布的14日Wilder ATR（保留两位小数），从第14根K线开始
+pub(crate) const ATR_WILDER_14: [f64; 17] = [
+    0.56, 0.59, 0.59, 0.57, 0.62, 0.62, 0.64, 0.67, 0.69, 0.77, 0.78, 1.21, 1.30, 1.38, 1.37, 1.34, 1.32,
+];
@@ -0,0 +1,150 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! TRIX (三重指数平滑变动率) 指标
+//!
+//! TRIX 由 Jack Hutson 提出，计算三重EMA平滑后的价格的单周期变动率，
+//! 过滤掉短于周期的价格波动，用于判断趋势方向和动量。
+//!
+//! # 计算公式
+//!
+//! - EMA3 = EMA(EMA(EMA(价格, N), N), N)
+//! - TRIX = 100 × (EMA3(t) - EMA3(t-1)) / EMA3(t-1)
+//!
+//! 信号线通常取TRIX的9周期EMA，可通过 [`Indicator::then`] 串联 [`EMA`] 得到。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::TRIX;
+//!
+//! let mut trix = TRIX::new(15);
+//!
+//! assert_eq!(trix.update(100.0), None);
+//! assert!(trix.update(101.0).unwrap() > 0.0);
+//! ```
+
+use aurora_core::Kline;
+use crate::{EMA, Indicator};
+use serde::{Deserialize, Serialize};
+
+/// TRIX (三重指数平滑变动率) 指标
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TRIX {
+    /// 价格的EMA
+    ema1: EMA,
+    /// EMA1的EMA
+    ema2: EMA,
+    /// EMA2的EMA
+    ema3: EMA,
+    /// 上一次的三重EMA值
+    previous: Option<f64>,
+    /// 当前TRIX值
+    current: Option<f64>,
+}
+
+impl TRIX {
+    /// 创建新的TRIX指标
+    ///
+    /// # 参数
+    ///
+    /// * `period` - EMA周期，通常使用15
+    ///
+    /// # Panics
+    ///
+    /// 如果周期为0，函数会panic
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "TRIX周期必须大于0");
+
+        Self {
+            ema1: EMA::new(period),
+            ema2: EMA::new(period),
+            ema3: EMA::new(period),
+            previous: None,
+            current: None,
+        }
+    }
+
+    /// 更新指标并返回最新的TRIX值（百分比）
+    ///
+    /// # 参数
+    ///
+    /// * `price` - 新的价格数据
+    ///
+    /// # 返回值
+    ///
+    /// * `Some(f64)` - 从第二个数据点开始返回TRIX值，上一次的三重EMA为0时返回0
+    /// * `None` - 第一个数据点
+    pub fn update(&mut self, price: f64) -> Option<f64> {
+        let ema1 = self.ema1.update(price);
+        let ema2 = self.ema2.update(ema1);
+        let ema3 = self.ema3.update(ema2);
+
+        let previous = self.previous.replace(ema3)?;
+        let trix = if previous == 0.0 {
+            0.0
+        } else {
+            100.0 * (ema3 - previous) / previous
+        };
+        self.current = Some(trix);
+        Some(trix)
+    }
+
+    /// 获取当前的TRIX值
+    pub fn value(&self) -> Option<f64> {
+        self.current
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.ema1.reset();
+        self.ema2.reset();
+        self.ema3.reset();
+        self.previous = None;
+        self.current = None;
+    }
+
+    /// 获取周期设置
+    pub fn period(&self) -> usize {
+        self.ema1.period()
+    }
+
+    /// 检查指标是否已有输出
+    pub fn is_ready(&self) -> bool {
+        self.current.is_some()
+    }
+}
+
+impl Indicator for TRIX {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        TRIX::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        TRIX::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        2
+    }
+}
+
+#[cfg(test)]
+mod tests;
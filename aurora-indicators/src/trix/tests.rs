@@ -0,0 +1,118 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::test_data::MOVAVG_CLOSES;
+use approx::assert_relative_eq;
+
+#[test]
+fn test_trix_new() {
+    let trix = TRIX::new(15);
+    assert_eq!(trix.period(), 15);
+    assert!(!trix.is_ready());
+    assert_eq!(trix.value(), None);
+}
+
+#[test]
+#[should_panic(expected = "TRIX周期必须大于0")]
+fn test_trix_zero_period() {
+    TRIX::new(0);
+}
+
+/// 参考值：StockCharts ChartSchool 移动平均线示例表（cs-movavg.xls）的30日收盘价的5日TRIX，即三重EMA的单周期百分比变化，
+/// 上涨段之后的回落使其在第27根K线转负；取最后8个值（保留四位小数）。
+/// EMA以第一个价格为初值，与公布的10日EMA的一致性见 `test_ema_matches_stockcharts`
+#[test]
+fn test_trix_reference_values() {
+    let mut trix = TRIX::new(5);
+    let outputs: Vec<f64> = MOVAVG_CLOSES.iter().filter_map(|&price| trix.update(price)).collect();
+
+    assert_eq!(outputs.len(), 29);
+    let expected = [0.3977, 0.2201, 0.0469, -0.0458, -0.1914, -0.2421, -0.3536, -0.4785];
+    for (output, expected) in outputs[21..].iter().zip(expected) {
+        assert_relative_eq!(*output, expected, epsilon = 1e-4);
+    }
+}
+
+/// 周期3（α = 0.5）逐步计算：
+/// EMA3: 1, 1.125, 1.4375, 1.9375
+/// TRIX: -, 12.5, 27.7778, 34.7826
+#[test]
+fn test_trix_worked_example() {
+    let mut trix = TRIX::new(3);
+    assert_eq!(trix.update(1.0), None);
+    assert_relative_eq!(trix.update(2.0).unwrap(), 12.5, epsilon = 1e-10);
+    assert_relative_eq!(trix.update(3.0).unwrap(), 100.0 * 0.3125 / 1.125, epsilon = 1e-10);
+    assert_relative_eq!(trix.update(4.0).unwrap(), 100.0 * 0.5 / 1.4375, epsilon = 1e-10);
+}
+
+#[test]
+fn test_trix_sign_follows_trend() {
+    let mut rising = TRIX::new(5);
+    let mut falling = TRIX::new(5);
+    let mut last = (None, None);
+
+    for i in 0..30 {
+        last = (rising.update(100.0 + i as f64), falling.update(100.0 - i as f64));
+    }
+
+    assert!(last.0.unwrap() > 0.0);
+    assert!(last.1.unwrap() < 0.0);
+}
+
+#[test]
+fn test_trix_flat_prices() {
+    let mut trix = TRIX::new(5);
+    trix.update(100.0);
+    for _ in 0..10 {
+        assert_eq!(trix.update(100.0), Some(0.0));
+    }
+}
+
+#[test]
+fn test_trix_zero_previous() {
+    let mut trix = TRIX::new(3);
+    trix.update(0.0);
+    assert_eq!(trix.update(1.0), Some(0.0));
+}
+
+#[test]
+fn test_trix_signal_line_by_chaining() {
+    let mut trix = TRIX::new(3).then(EMA::new(3));
+    let kline = |close: f64| Kline {
+        timestamp: 0,
+        open: close,
+        high: close,
+        low: close,
+        close,
+        volume: 1.0,
+        symbol: None,
+        interval: None,
+    };
+
+    assert_eq!(trix.update_kline(&kline(1.0)), None);
+    assert_relative_eq!(trix.update_kline(&kline(2.0)).unwrap(), 12.5, epsilon = 1e-10);
+}
+
+#[test]
+fn test_trix_reset() {
+    let mut trix = TRIX::new(3);
+    trix.update(1.0);
+    trix.update(2.0);
+    assert!(trix.is_ready());
+
+    trix.reset();
+    assert!(!trix.is_ready());
+    assert_eq!(trix.update(1.0), None);
+}
@@ -0,0 +1,419 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Volume Profile - 成交量分布
+//!
+//! 成交量分布把一段时间内的成交量按价格层统计，显示成交集中在哪些价位，
+//! 常用于寻找支撑阻力和判断价格是否处于"公允"区间。
+//!
+//! # 计算方法
+//!
+//! 1. 把窗口内的最低价到最高价等分为N个价格层
+//! 2. 每根K线的成交量按其最高价到最低价的区间均匀分配到覆盖的价格层
+//! 3. 成交量最大的价格层的中点为控制点 (POC, Point of Control)
+//! 4. 从控制点开始，每次向成交量较大的一侧扩展一个价格层，
+//!    直到覆盖总成交量的70%（可调），得到价值区的上沿 (VAH) 和下沿 (VAL)
+//!
+//! # 窗口
+//!
+//! - **滚动窗口**: [`VolumeProfile::new`] 统计最近N根K线
+//! - **交易时段**: [`VolumeProfile::session`] 统计当前时段开始以来的K线，
+//!   时段按UTC时间戳对齐，新时段的第一根K线到来时重新开始统计
+//!
+//! 对整个数据文件做一次性分析时使用 [`VolumeProfileOutput::from_klines`]。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_core::Kline;
+//! use aurora_indicators::VolumeProfile;
+//!
+//! let kline = |low: f64, high: f64, volume: f64| Kline {
+//!     timestamp: 0,
+//!     open: low,
+//!     high,
+//!     low,
+//!     close: high,
+//!     volume,
+//!     symbol: None,
+//!     interval: None,
+//! };
+//!
+//! let mut profile = VolumeProfile::new(3, 4);
+//! profile.update(&kline(100.0, 104.0, 100.0));
+//! profile.update(&kline(101.0, 102.0, 300.0));
+//! let output = profile.update(&kline(100.0, 101.0, 50.0)).unwrap();
+//!
+//! // 101-102 价格层成交最多
+//! assert_eq!(output.point_of_control, 101.5);
+//! assert!(output.is_in_value_area(101.2));
+//! ```
+
+use aurora_core::Kline;
+use crate::{Indicator, IndicatorValue};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// 默认价值区比例
+const DEFAULT_VALUE_AREA: f64 = 0.7;
+
+/// 价格层
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PriceLevel {
+    /// 价格下沿
+    pub low: f64,
+    /// 价格上沿
+    pub high: f64,
+    /// 该价格层的成交量
+    pub volume: f64,
+}
+
+impl PriceLevel {
+    /// 价格层中点
+    pub fn mid(&self) -> f64 {
+        (self.low + self.high) / 2.0
+    }
+}
+
+/// Volume Profile 输出结构
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VolumeProfileOutput {
+    /// 控制点(成交量最大的价格层中点)
+    pub point_of_control: f64,
+    /// 价值区上沿
+    pub value_area_high: f64,
+    /// 价值区下沿
+    pub value_area_low: f64,
+    /// 窗口内的总成交量
+    pub total_volume: f64,
+    /// 从低到高排列的价格层
+    pub levels: Vec<PriceLevel>,
+}
+
+impl VolumeProfileOutput {
+    /// 对一组K线一次性计算成交量分布
+    ///
+    /// # 参数
+    ///
+    /// * `klines` - K线序列，通常是整个数据文件
+    /// * `bucket_count` - 价格层数量
+    /// * `value_area` - 价值区覆盖的成交量比例，取值(0, 1]
+    ///
+    /// # 返回值
+    ///
+    /// K线为空时返回 `None`
+    ///
+    /// # Panics
+    ///
+    /// 当 `bucket_count` 为 0 或 `value_area` 不在(0, 1]内时会 panic
+    pub fn from_klines(klines: &[Kline], bucket_count: usize, value_area: f64) -> Option<Self> {
+        assert!(bucket_count > 0, "价格层数量必须大于0");
+        assert!(value_area > 0.0 && value_area <= 1.0, "价值区比例必须在(0, 1]内");
+
+        let bars: Vec<ProfileBar> = klines.iter().map(ProfileBar::from).collect();
+        build_profile(bars.iter(), bucket_count, value_area)
+    }
+
+    /// 判断价格是否处于价值区内
+    pub fn is_in_value_area(&self, price: f64) -> bool {
+        price >= self.value_area_low && price <= self.value_area_high
+    }
+}
+
+/// 统计窗口
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProfileWindow {
+    /// 最近N根K线
+    Rolling(usize),
+    /// 交易时段，值为时段长度(毫秒)
+    Session(i64),
+}
+
+/// 参与统计的K线数据
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct ProfileBar {
+    high: f64,
+    low: f64,
+    volume: f64,
+}
+
+impl From<&Kline> for ProfileBar {
+    fn from(kline: &Kline) -> Self {
+        Self {
+            high: kline.high.max(kline.low),
+            low: kline.low.min(kline.high),
+            volume: kline.volume.max(0.0),
+        }
+    }
+}
+
+/// Volume Profile 成交量分布
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VolumeProfile {
+    /// 统计窗口
+    window: ProfileWindow,
+    /// 价格层数量
+    bucket_count: usize,
+    /// 价值区比例
+    value_area: f64,
+    /// 窗口内的K线
+    bars: VecDeque<ProfileBar>,
+    /// 当前交易时段编号
+    session_id: Option<i64>,
+}
+
+impl VolumeProfile {
+    /// 创建滚动窗口的成交量分布
+    ///
+    /// # 参数
+    ///
+    /// * `period` - 统计的K线数量
+    /// * `bucket_count` - 价格层数量
+    ///
+    /// # Panics
+    ///
+    /// 当 `period` 或 `bucket_count` 为 0 时会 panic
+    pub fn new(period: usize, bucket_count: usize) -> Self {
+        assert!(period > 0, "Volume Profile周期必须大于0");
+        Self::with_window(ProfileWindow::Rolling(period), bucket_count)
+    }
+
+    /// 创建按交易时段统计的成交量分布
+    ///
+    /// # 参数
+    ///
+    /// * `session_ms` - 时段长度(毫秒)，例如日内时段为 `86_400_000`
+    /// * `bucket_count` - 价格层数量
+    ///
+    /// # Panics
+    ///
+    /// 当 `session_ms` 不大于0或 `bucket_count` 为 0 时会 panic
+    pub fn session(session_ms: i64, bucket_count: usize) -> Self {
+        assert!(session_ms > 0, "交易时段长度必须大于0");
+        Self::with_window(ProfileWindow::Session(session_ms), bucket_count)
+    }
+
+    fn with_window(window: ProfileWindow, bucket_count: usize) -> Self {
+        assert!(bucket_count > 0, "价格层数量必须大于0");
+
+        Self {
+            window,
+            bucket_count,
+            value_area: DEFAULT_VALUE_AREA,
+            bars: VecDeque::new(),
+            session_id: None,
+        }
+    }
+
+    /// 设置价值区覆盖的成交量比例，默认0.7
+    ///
+    /// # Panics
+    ///
+    /// 当 `value_area` 不在(0, 1]内时会 panic
+    pub fn with_value_area(mut self, value_area: f64) -> Self {
+        assert!(value_area > 0.0 && value_area <= 1.0, "价值区比例必须在(0, 1]内");
+        self.value_area = value_area;
+        self
+    }
+
+    /// 更新指标并计算新的成交量分布
+    ///
+    /// # 返回值
+    ///
+    /// - `Some(VolumeProfileOutput)` - 滚动窗口累积了 period 根K线后，或时段模式下每根K线都返回
+    /// - `None` - 数据不足
+    pub fn update(&mut self, kline: &Kline) -> Option<VolumeProfileOutput> {
+        match self.window {
+            ProfileWindow::Rolling(period) => {
+                self.bars.push_back(ProfileBar::from(kline));
+                if self.bars.len() > period {
+                    self.bars.pop_front();
+                }
+            }
+            ProfileWindow::Session(session_ms) => {
+                let session_id = kline.timestamp.div_euclid(session_ms);
+                if self.session_id != Some(session_id) {
+                    self.bars.clear();
+                    self.session_id = Some(session_id);
+                }
+                self.bars.push_back(ProfileBar::from(kline));
+            }
+        }
+
+        self.value()
+    }
+
+    /// 获取当前的成交量分布
+    pub fn value(&self) -> Option<VolumeProfileOutput> {
+        if !self.is_ready() {
+            return None;
+        }
+
+        build_profile(self.bars.iter(), self.bucket_count, self.value_area)
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.bars.clear();
+        self.session_id = None;
+    }
+
+    /// 获取统计窗口
+    pub fn window(&self) -> ProfileWindow {
+        self.window
+    }
+
+    /// 获取价格层数量
+    pub fn bucket_count(&self) -> usize {
+        self.bucket_count
+    }
+
+    /// 获取价值区比例
+    pub fn value_area(&self) -> f64 {
+        self.value_area
+    }
+
+    /// 检查是否已准备好输出结果
+    pub fn is_ready(&self) -> bool {
+        match self.window {
+            ProfileWindow::Rolling(period) => self.bars.len() == period,
+            ProfileWindow::Session(_) => !self.bars.is_empty(),
+        }
+    }
+}
+
+/// 按价格层统计成交量，计算控制点和价值区
+fn build_profile<'a, I>(bars: I, bucket_count: usize, value_area: f64) -> Option<VolumeProfileOutput>
+where
+    I: Iterator<Item = &'a ProfileBar> + Clone,
+{
+    let low = bars.clone().map(|bar| bar.low).fold(f64::INFINITY, f64::min);
+    let high = bars.clone().map(|bar| bar.high).fold(f64::NEG_INFINITY, f64::max);
+    if !low.is_finite() || !high.is_finite() {
+        return None;
+    }
+
+    let total_volume: f64 = bars.clone().map(|bar| bar.volume).sum();
+
+    // 价格区间为0时只有一个价格层
+    if high == low {
+        return Some(VolumeProfileOutput {
+            point_of_control: low,
+            value_area_high: high,
+            value_area_low: low,
+            total_volume,
+            levels: vec![PriceLevel { low, high, volume: total_volume }],
+        });
+    }
+
+    let width = (high - low) / bucket_count as f64;
+    let mut levels: Vec<PriceLevel> = (0..bucket_count)
+        .map(|i| PriceLevel {
+            low: low + width * i as f64,
+            high: if i + 1 == bucket_count { high } else { low + width * (i + 1) as f64 },
+            volume: 0.0,
+        })
+        .collect();
+
+    for bar in bars {
+        if bar.high == bar.low {
+            // 无波动的K线全部计入所在价格层
+            let index = (((bar.low - low) / width) as usize).min(bucket_count - 1);
+            levels[index].volume += bar.volume;
+            continue;
+        }
+
+        let range = bar.high - bar.low;
+        for level in levels.iter_mut() {
+            let overlap = bar.high.min(level.high) - bar.low.max(level.low);
+            if overlap > 0.0 {
+                level.volume += bar.volume * overlap / range;
+            }
+        }
+    }
+
+    // 成交量相同时取价格较低的价格层
+    let poc = levels
+        .iter()
+        .enumerate()
+        .fold(0, |best, (i, level)| if level.volume > levels[best].volume { i } else { best });
+
+    // 从控制点向成交量较大的一侧逐层扩展
+    let target = total_volume * value_area;
+    let (mut lower, mut upper) = (poc, poc);
+    let mut covered = levels[poc].volume;
+    while covered < target && (lower > 0 || upper + 1 < bucket_count) {
+        let above = levels.get(upper + 1).map_or(f64::NEG_INFINITY, |level| level.volume);
+        let below = if lower > 0 { levels[lower - 1].volume } else { f64::NEG_INFINITY };
+        if above >= below {
+            upper += 1;
+            covered += above;
+        } else {
+            lower -= 1;
+            covered += below;
+        }
+    }
+
+    Some(VolumeProfileOutput {
+        point_of_control: levels[poc].mid(),
+        value_area_high: levels[upper].high,
+        value_area_low: levels[lower].low,
+        total_volume,
+        levels,
+    })
+}
+
+// 默认参数: 20根K线，24个价格层
+impl Default for VolumeProfile {
+    fn default() -> Self {
+        Self::new(20, 24)
+    }
+}
+
+impl Indicator for VolumeProfile {
+    type Output = VolumeProfileOutput;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<VolumeProfileOutput> {
+        self.update(kline)
+    }
+
+    fn is_ready(&self) -> bool {
+        VolumeProfile::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        VolumeProfile::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        match self.window {
+            ProfileWindow::Rolling(period) => period,
+            ProfileWindow::Session(_) => 1,
+        }
+    }
+}
+
+impl From<VolumeProfileOutput> for IndicatorValue {
+    fn from(output: VolumeProfileOutput) -> Self {
+        IndicatorValue::from_fields(vec![
+            ("poc", output.point_of_control),
+            ("value_area_high", output.value_area_high),
+            ("value_area_low", output.value_area_low),
+            ("total_volume", output.total_volume),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests;
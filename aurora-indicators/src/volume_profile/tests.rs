@@ -0,0 +1,186 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn kline(timestamp: i64, low: f64, high: f64, volume: f64) -> Kline {
+    Kline {
+        timestamp,
+        open: low,
+        high,
+        low,
+        close: high,
+        volume,
+        symbol: None,
+        interval: None,
+    }
+}
+
+#[test]
+fn test_volume_profile_new() {
+    let profile = VolumeProfile::new(20, 24);
+    assert_eq!(profile.window(), ProfileWindow::Rolling(20));
+    assert_eq!(profile.bucket_count(), 24);
+    assert_eq!(profile.value_area(), 0.7);
+    assert!(!profile.is_ready());
+
+    let session = VolumeProfile::session(86_400_000, 10).with_value_area(0.5);
+    assert_eq!(session.window(), ProfileWindow::Session(86_400_000));
+    assert_eq!(session.value_area(), 0.5);
+}
+
+#[test]
+#[should_panic(expected = "Volume Profile周期必须大于0")]
+fn test_volume_profile_zero_period() {
+    VolumeProfile::new(0, 10);
+}
+
+#[test]
+#[should_panic(expected = "价格层数量必须大于0")]
+fn test_volume_profile_zero_buckets() {
+    VolumeProfile::session(60_000, 0);
+}
+
+#[test]
+#[should_panic(expected = "价值区比例必须在(0, 1]内")]
+fn test_volume_profile_invalid_value_area() {
+    VolumeProfile::new(10, 10).with_value_area(1.5);
+}
+
+/// 参考值（按定义手工计算）：区间100-104分为4层
+#[test]
+fn test_volume_profile_distribution() {
+    let mut profile = VolumeProfile::new(3, 4);
+    assert!(profile.update(&kline(0, 100.0, 104.0, 100.0)).is_none());
+    assert!(profile.update(&kline(0, 101.0, 102.0, 300.0)).is_none());
+    let output = profile.update(&kline(0, 100.0, 101.0, 50.0)).unwrap();
+
+    let volumes: Vec<f64> = output.levels.iter().map(|level| level.volume).collect();
+    assert_eq!(volumes, vec![75.0, 325.0, 25.0, 25.0]);
+    assert_eq!(output.levels[0].low, 100.0);
+    assert_eq!(output.levels[3].high, 104.0);
+    assert_eq!(output.total_volume, 450.0);
+    assert_eq!(output.point_of_control, 101.5);
+
+    // 控制点层占 325/450，超过70%，价值区只有一层
+    assert_eq!(output.value_area_low, 101.0);
+    assert_eq!(output.value_area_high, 102.0);
+}
+
+/// 测试价值区向成交量较大的一侧扩展
+#[test]
+fn test_volume_profile_value_area_expansion() {
+    // 五层成交量: 10, 20, 40, 25, 5
+    let klines = vec![
+        kline(0, 100.0, 101.0, 10.0),
+        kline(0, 101.0, 102.0, 20.0),
+        kline(0, 102.0, 103.0, 40.0),
+        kline(0, 103.0, 104.0, 25.0),
+        kline(0, 104.0, 105.0, 5.0),
+    ];
+    let output = VolumeProfileOutput::from_klines(&klines, 5, 0.7).unwrap();
+
+    assert_eq!(output.point_of_control, 102.5);
+    // 40 -> 加上方25 = 65 -> 加下方20 = 85 >= 70
+    assert_eq!(output.value_area_low, 101.0);
+    assert_eq!(output.value_area_high, 104.0);
+    assert!(output.is_in_value_area(101.0));
+    assert!(!output.is_in_value_area(104.5));
+
+    // 比例为1时覆盖全部价格层
+    let full = VolumeProfileOutput::from_klines(&klines, 5, 1.0).unwrap();
+    assert_eq!(full.value_area_low, 100.0);
+    assert_eq!(full.value_area_high, 105.0);
+}
+
+/// 测试滚动窗口移出旧K线
+#[test]
+fn test_volume_profile_rolling_window() {
+    let mut profile = VolumeProfile::new(2, 2);
+    profile.update(&kline(0, 90.0, 91.0, 1000.0));
+    profile.update(&kline(1, 100.0, 101.0, 10.0));
+    let output = profile.update(&kline(2, 102.0, 103.0, 30.0)).unwrap();
+
+    assert_eq!(output.total_volume, 40.0);
+    assert_eq!(output.levels[0].low, 100.0);
+    assert_eq!(output.point_of_control, 102.25);
+}
+
+/// 测试交易时段切换时重新统计
+#[test]
+fn test_volume_profile_session_reset() {
+    let hour = 3_600_000;
+    let mut profile = VolumeProfile::session(hour, 4);
+
+    let first = profile.update(&kline(0, 100.0, 101.0, 10.0)).unwrap();
+    assert_eq!(first.total_volume, 10.0);
+    let second = profile.update(&kline(hour / 2, 101.0, 102.0, 20.0)).unwrap();
+    assert_eq!(second.total_volume, 30.0);
+
+    // 新时段的第一根K线
+    let next = profile.update(&kline(hour, 110.0, 112.0, 5.0)).unwrap();
+    assert_eq!(next.total_volume, 5.0);
+    assert_eq!(next.value_area_low, 110.0);
+    assert_eq!(Indicator::warm_up_period(&profile), 1);
+}
+
+/// 测试价格没有波动
+#[test]
+fn test_volume_profile_flat_prices() {
+    let klines = vec![kline(0, 100.0, 100.0, 10.0), kline(1, 100.0, 100.0, 20.0)];
+    let output = VolumeProfileOutput::from_klines(&klines, 10, 0.7).unwrap();
+
+    assert_eq!(output.levels.len(), 1);
+    assert_eq!(output.point_of_control, 100.0);
+    assert_eq!(output.value_area_high, 100.0);
+    assert_eq!(output.value_area_low, 100.0);
+    assert_eq!(output.total_volume, 30.0);
+
+    assert!(VolumeProfileOutput::from_klines(&[], 10, 0.7).is_none());
+}
+
+/// 测试无波动的K线计入所在价格层
+#[test]
+fn test_volume_profile_point_bar() {
+    let klines = vec![kline(0, 100.0, 104.0, 4.0), kline(1, 103.5, 103.5, 10.0)];
+    let output = VolumeProfileOutput::from_klines(&klines, 4, 0.7).unwrap();
+
+    assert_eq!(output.levels[3].volume, 11.0);
+    assert_eq!(output.point_of_control, 103.5);
+}
+
+#[test]
+fn test_volume_profile_reset() {
+    let mut profile = VolumeProfile::new(2, 4);
+    profile.update(&kline(0, 100.0, 101.0, 10.0));
+    profile.update(&kline(1, 100.0, 101.0, 10.0));
+    assert!(profile.is_ready());
+
+    profile.reset();
+    assert!(!profile.is_ready());
+    assert!(profile.update(&kline(2, 100.0, 101.0, 10.0)).is_none());
+}
+
+#[test]
+fn test_volume_profile_indicator_value() {
+    let mut profile = VolumeProfile::new(3, 4);
+    profile.update(&kline(0, 100.0, 104.0, 100.0));
+    profile.update(&kline(0, 101.0, 102.0, 300.0));
+    let value = IndicatorValue::from(profile.update(&kline(0, 100.0, 101.0, 50.0)).unwrap());
+
+    assert_eq!(value.primary(), 101.5);
+    assert_eq!(value.get("value_area_high"), Some(102.0));
+    assert_eq!(value.get("value_area_low"), Some(101.0));
+    assert_eq!(value.get("total_volume"), Some(450.0));
+}
@@ -49,11 +49,15 @@
 //! }
 //! ```
 
+use aurora_core::Kline;
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 /// VWAP (Volume Weighted Average Price) 成交量加权平均价
 ///
 /// 计算成交量加权的平均价格
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VWAP {
     /// 计算周期(0表示累积模式,不限周期)
     period: usize,
@@ -264,5 +268,25 @@ impl VWAP {
     }
 }
 
+impl Indicator for VWAP {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.high, kline.low, kline.close, kline.volume)
+    }
+
+    fn is_ready(&self) -> bool {
+        VWAP::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        VWAP::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        1
+    }
+}
+
 #[cfg(test)]
 mod tests;
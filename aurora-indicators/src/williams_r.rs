@@ -43,11 +43,15 @@
 //! let result = wr.update(110.0, 90.0, 100.0);
 //! ```
 
+use aurora_core::Kline;
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 /// Williams %R 威廉指标
 ///
 /// 衡量收盘价在N周期内高低区间中的相对位置,值域为0到-100
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WilliamsR {
     /// 计算周期
     period: usize,
@@ -229,5 +233,25 @@ impl WilliamsR {
     }
 }
 
+impl Indicator for WilliamsR {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.high, kline.low, kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        WilliamsR::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        WilliamsR::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,146 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 加权移动平均线 (Weighted Moving Average) 指标
+//!
+//! 对窗口内的价格按时间线性加权，越新的价格权重越大，比SMA更快地响应价格变化。
+//!
+//! # 计算公式
+//!
+//! WMA = (1×P(t-N+1) + 2×P(t-N+2) + ... + N×P(t)) / (1 + 2 + ... + N)
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::WMA;
+//!
+//! let mut wma = WMA::new(3);
+//!
+//! assert_eq!(wma.update(1.0), None);
+//! assert_eq!(wma.update(2.0), None);
+//! // (1×1 + 2×2 + 3×3) / 6
+//! assert_eq!(wma.update(3.0), Some(14.0 / 6.0));
+//! ```
+
+use aurora_core::Kline;
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// 加权移动平均线 (Weighted Moving Average) 指标
+///
+/// 第i个价格（从窗口最旧的价格开始数，i从1开始）的权重为i。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WMA {
+    /// 周期长度
+    period: usize,
+    /// 最近N个价格的滑动窗口
+    values: VecDeque<f64>,
+    /// 权重之和 N×(N+1)/2
+    weight_sum: f64,
+}
+
+impl WMA {
+    /// 创建新的WMA指标
+    ///
+    /// # 参数
+    ///
+    /// * `period` - 周期，必须大于0
+    ///
+    /// # Panics
+    ///
+    /// 如果周期为0，函数会panic
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "WMA周期必须大于0");
+
+        Self {
+            period,
+            values: VecDeque::with_capacity(period),
+            weight_sum: (period * (period + 1)) as f64 / 2.0,
+        }
+    }
+
+    /// 更新指标并返回最新的WMA值
+    ///
+    /// # 参数
+    ///
+    /// * `price` - 新的价格数据
+    ///
+    /// # 返回值
+    ///
+    /// * `Some(f64)` - 已有 period 个数据点时返回WMA值
+    /// * `None` - 数据点不足
+    pub fn update(&mut self, price: f64) -> Option<f64> {
+        self.values.push_back(price);
+        if self.values.len() > self.period {
+            self.values.pop_front();
+        }
+
+        self.value()
+    }
+
+    /// 获取当前的WMA值（如果可用）
+    pub fn value(&self) -> Option<f64> {
+        if !self.is_ready() {
+            return None;
+        }
+
+        // 每次重新加权求和，避免增量更新的累积误差
+        let weighted: f64 = self
+            .values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (i + 1) as f64 * value)
+            .sum();
+        Some(weighted / self.weight_sum)
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.values.clear();
+    }
+
+    /// 获取周期设置
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// 检查指标是否已准备好（有足够的数据）
+    pub fn is_ready(&self) -> bool {
+        self.values.len() == self.period
+    }
+}
+
+impl Indicator for WMA {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        WMA::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        WMA::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests;
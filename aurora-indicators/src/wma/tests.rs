@@ -0,0 +1,113 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::test_data::MOVAVG_CLOSES;
+use approx::assert_relative_eq;
+
+#[test]
+fn test_wma_new() {
+    let wma = WMA::new(5);
+    assert_eq!(wma.period(), 5);
+    assert_eq!(wma.weight_sum, 15.0);
+    assert!(!wma.is_ready());
+}
+
+#[test]
+#[should_panic(expected = "WMA周期必须大于0")]
+fn test_wma_zero_period() {
+    WMA::new(0);
+}
+
+#[test]
+fn test_wma_insufficient_data() {
+    let mut wma = WMA::new(4);
+    for price in [10.0, 11.0, 12.0] {
+        assert_eq!(wma.update(price), None);
+    }
+    assert!(wma.update(13.0).is_some());
+}
+
+/// 参考值：StockCharts ChartSchool 移动平均线示例表（cs-movavg.xls）的30日收盘价的10日WMA，
+/// 按权重1到10（最新价格权重最大）、除以55逐窗口计算，取最后8个值（保留四位小数）
+#[test]
+fn test_wma_reference_values() {
+    let mut wma = WMA::new(10);
+    let outputs: Vec<f64> = MOVAVG_CLOSES.iter().filter_map(|&price| wma.update(price)).collect();
+
+    assert_eq!(outputs.len(), 21);
+    let expected = [23.7569, 23.6729, 23.5620, 23.4976, 23.3282, 23.2545, 23.0669, 22.8656];
+    for (output, expected) in outputs[13..].iter().zip(expected) {
+        assert_relative_eq!(*output, expected, epsilon = 1e-4);
+    }
+}
+
+/// 5日WMA逐项计算：
+/// 价格 90, 89, 88, 85, 82 → (90×1 + 89×2 + 88×3 + 85×4 + 82×5) / 15 = 85.6
+#[test]
+fn test_wma_worked_example() {
+    let mut wma = WMA::new(5);
+    let mut result = None;
+    for price in [90.0, 89.0, 88.0, 85.0, 82.0] {
+        result = wma.update(price);
+    }
+    assert_relative_eq!(result.unwrap(), 1282.0 / 15.0, epsilon = 1e-10);
+}
+
+#[test]
+fn test_wma_sliding_window() {
+    let mut wma = WMA::new(3);
+    let outputs: Vec<_> = [1.0, 2.0, 3.0, 4.0, 5.0].iter().map(|&p| wma.update(p)).collect();
+
+    assert_eq!(outputs[2], Some(14.0 / 6.0));
+    // (2×1 + 3×2 + 4×3) / 6
+    assert_eq!(outputs[3], Some(20.0 / 6.0));
+    // (3×1 + 4×2 + 5×3) / 6
+    assert_eq!(outputs[4], Some(26.0 / 6.0));
+}
+
+#[test]
+fn test_wma_weights_recent_prices_more() {
+    let mut wma = WMA::new(5);
+    let mut ma = crate::MA::new(5);
+    let mut last = (None, None);
+    for price in [100.0, 101.0, 102.0, 103.0, 110.0] {
+        last = (wma.update(price), ma.update(price));
+    }
+    // 最新价格跳涨时，WMA比SMA更接近最新价格
+    assert!(last.0.unwrap() > last.1.unwrap());
+}
+
+#[test]
+fn test_wma_constant_prices() {
+    let mut wma = WMA::new(10);
+    let mut result = None;
+    for _ in 0..10 {
+        result = wma.update(42.0);
+    }
+    assert_relative_eq!(result.unwrap(), 42.0, epsilon = 1e-10);
+}
+
+#[test]
+fn test_wma_reset() {
+    let mut wma = WMA::new(2);
+    wma.update(1.0);
+    wma.update(2.0);
+    assert!(wma.is_ready());
+
+    wma.reset();
+    assert!(!wma.is_ready());
+    assert_eq!(wma.value(), None);
+    assert_eq!(wma.update(3.0), None);
+}
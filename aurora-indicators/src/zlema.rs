@@ -0,0 +1,148 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 零滞后指数移动平均线 (Zero Lag Exponential Moving Average) 指标
+//!
+//! ZLEMA 由 John Ehlers 和 Ric Way 提出，先用当前价格减去滞后价格的差值修正输入，
+//! 再做EMA平滑，以减小EMA的滞后。
+//!
+//! # 计算公式
+//!
+//! - lag = (N - 1) / 2（向下取整）
+//! - 修正价格 = 2 × P(t) - P(t - lag)
+//! - ZLEMA = EMA(修正价格, N)
+//!
+//! 需要 lag + 1 个数据点后才开始输出。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::ZLEMA;
+//!
+//! let mut zlema = ZLEMA::new(5); // lag = 2
+//!
+//! assert_eq!(zlema.update(1.0), None);
+//! assert_eq!(zlema.update(2.0), None);
+//! assert_eq!(zlema.update(3.0), Some(5.0)); // 2×3 - 1
+//! ```
+
+use aurora_core::Kline;
+use crate::{EMA, Indicator};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// 零滞后指数移动平均线 (Zero Lag Exponential Moving Average) 指标
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZLEMA {
+    /// 滞后长度 (N - 1) / 2
+    lag: usize,
+    /// 最近 lag + 1 个价格
+    prices: VecDeque<f64>,
+    /// 修正价格的EMA
+    ema: EMA,
+}
+
+impl ZLEMA {
+    /// 创建新的ZLEMA指标
+    ///
+    /// # 参数
+    ///
+    /// * `period` - EMA周期，必须大于0
+    ///
+    /// # Panics
+    ///
+    /// 如果周期为0，函数会panic
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "ZLEMA周期必须大于0");
+
+        let lag = (period - 1) / 2;
+        Self {
+            lag,
+            prices: VecDeque::with_capacity(lag + 1),
+            ema: EMA::new(period),
+        }
+    }
+
+    /// 更新指标并返回最新的ZLEMA值
+    ///
+    /// # 参数
+    ///
+    /// * `price` - 新的价格数据
+    ///
+    /// # 返回值
+    ///
+    /// * `Some(f64)` - 已有 lag + 1 个数据点时返回ZLEMA值
+    /// * `None` - 数据点不足
+    pub fn update(&mut self, price: f64) -> Option<f64> {
+        self.prices.push_back(price);
+        if self.prices.len() > self.lag + 1 {
+            self.prices.pop_front();
+        }
+        if self.prices.len() <= self.lag {
+            return None;
+        }
+
+        let lagged = self.prices[0];
+        Some(self.ema.update(2.0 * price - lagged))
+    }
+
+    /// 获取当前的ZLEMA值
+    pub fn value(&self) -> Option<f64> {
+        self.ema.value()
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.prices.clear();
+        self.ema.reset();
+    }
+
+    /// 获取周期设置
+    pub fn period(&self) -> usize {
+        self.ema.period()
+    }
+
+    /// 获取滞后长度
+    pub fn lag(&self) -> usize {
+        self.lag
+    }
+
+    /// 检查指标是否已有输出
+    pub fn is_ready(&self) -> bool {
+        self.ema.is_ready()
+    }
+}
+
+impl Indicator for ZLEMA {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        ZLEMA::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        ZLEMA::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.lag + 1
+    }
+}
+
+#[cfg(test)]
+mod tests;
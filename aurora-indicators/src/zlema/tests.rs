@@ -0,0 +1,97 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::test_data::MOVAVG_CLOSES;
+use approx::assert_relative_eq;
+
+#[test]
+fn test_zlema_new() {
+    let zlema = ZLEMA::new(10);
+    assert_eq!(zlema.period(), 10);
+    assert_eq!(zlema.lag(), 4);
+    assert!(!zlema.is_ready());
+}
+
+#[test]
+#[should_panic(expected = "ZLEMA周期必须大于0")]
+fn test_zlema_zero_period() {
+    ZLEMA::new(0);
+}
+
+/// 参考值：StockCharts ChartSchool 移动平均线示例表（cs-movavg.xls）的30日收盘价的10日ZLEMA，
+/// lag = 4，对 2 × 价格 - 4个周期前的价格 求10日EMA，取最后8个值（保留四位小数）。
+/// EMA以第一个价格为初值，与公布的10日EMA的一致性见 `test_ema_matches_stockcharts`
+#[test]
+fn test_zlema_reference_values() {
+    let mut zlema = ZLEMA::new(10);
+    let outputs: Vec<f64> = MOVAVG_CLOSES.iter().filter_map(|&price| zlema.update(price)).collect();
+
+    assert_eq!(outputs.len(), 26);
+    let expected = [23.7897, 23.6007, 23.3787, 23.2717, 22.9878, 22.9918, 22.7569, 22.4393];
+    for (output, expected) in outputs[18..].iter().zip(expected) {
+        assert_relative_eq!(*output, expected, epsilon = 1e-4);
+    }
+}
+
+/// 周期5（lag = 2，α = 1/3）逐步计算：
+/// 修正价格: 5, 6, 7
+/// ZLEMA: 5, 5.3333, 5.8889
+#[test]
+fn test_zlema_worked_example() {
+    let mut zlema = ZLEMA::new(5);
+    let outputs: Vec<_> = [1.0, 2.0, 3.0, 4.0, 5.0].iter().map(|&p| zlema.update(p)).collect();
+
+    assert_eq!(outputs[0], None);
+    assert_eq!(outputs[1], None);
+    assert_eq!(outputs[2], Some(5.0));
+    assert_relative_eq!(outputs[3].unwrap(), 16.0 / 3.0, epsilon = 1e-10);
+    assert_relative_eq!(outputs[4].unwrap(), 53.0 / 9.0, epsilon = 1e-10);
+}
+
+#[test]
+fn test_zlema_period_one_equals_price() {
+    let mut zlema = ZLEMA::new(1);
+    assert_eq!(zlema.lag(), 0);
+    for price in [3.0, 7.0, 5.0] {
+        assert_eq!(zlema.update(price), Some(price));
+    }
+}
+
+#[test]
+fn test_zlema_less_lag_than_ema() {
+    let mut zlema = ZLEMA::new(10);
+    let mut ema = EMA::new(10);
+    let mut last = (None, 0.0);
+
+    for i in 0..50 {
+        let price = 100.0 + i as f64;
+        last = (zlema.update(price), ema.update(price));
+    }
+
+    let price = 149.0;
+    assert!((price - last.0.unwrap()).abs() < (price - last.1).abs());
+}
+
+#[test]
+fn test_zlema_reset() {
+    let mut zlema = ZLEMA::new(3);
+    zlema.update(1.0);
+    zlema.update(2.0);
+    assert!(zlema.is_ready());
+
+    zlema.reset();
+    assert!(!zlema.is_ready());
+    assert_eq!(zlema.update(1.0), None);
+}
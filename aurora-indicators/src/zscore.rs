@@ -0,0 +1,142 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Z-Score - 标准分数
+//!
+//! Z-Score 衡量当前价格偏离N周期均值多少个标准差，是均值回归策略最常用的入场依据。
+//!
+//! # 计算公式
+//!
+//! Z = (价格 - μ) / σ
+//!
+//! 其中 μ 和 σ 为包含当前价格在内的N周期均值和总体标准差（与 [`StdDev`](crate::StdDev) 一致）。
+//! σ 为0时 Z 为0。
+//!
+//! # 使用场景
+//!
+//! - **均值回归**: Z 低于 -2 买入、高于 2 卖出，回到0附近平仓
+//! - **配对交易**: 对两个资产的价差计算 Z-Score
+//!
+//! # 示例
+//!
+//! ```rust
+//! use aurora_indicators::ZScore;
+//!
+//! let mut zscore = ZScore::new(5);
+//! for price in [1.0, 2.0, 3.0, 4.0] {
+//!     assert_eq!(zscore.update(price), None);
+//! }
+//!
+//! // μ = 3, σ = √2
+//! let z = zscore.update(5.0).unwrap();
+//! assert!((z - 2.0_f64.sqrt()).abs() < 1e-9);
+//! ```
+
+use aurora_core::Kline;
+use crate::Indicator;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Z-Score 标准分数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZScore {
+    /// 计算周期
+    period: usize,
+    /// 价格历史数据
+    prices: VecDeque<f64>,
+}
+
+impl ZScore {
+    /// 创建新的Z-Score指标实例
+    ///
+    /// # 参数
+    ///
+    /// * `period` - 计算周期,通常使用20
+    ///
+    /// # Panics
+    ///
+    /// 当 `period` 为 0 时会 panic
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "Z-Score周期必须大于0");
+
+        Self {
+            period,
+            prices: VecDeque::with_capacity(period),
+        }
+    }
+
+    /// 更新指标并计算新的Z-Score
+    ///
+    /// # 返回值
+    ///
+    /// - `Some(f64)` - 累积了 period 个数据点后返回Z-Score
+    /// - `None` - 数据不足
+    pub fn update(&mut self, price: f64) -> Option<f64> {
+        self.prices.push_back(price);
+        if self.prices.len() > self.period {
+            self.prices.pop_front();
+        }
+        if !self.is_ready() {
+            return None;
+        }
+
+        let n = self.period as f64;
+        let mean = self.prices.iter().sum::<f64>() / n;
+        let variance = self.prices.iter().map(|p| (p - mean).powi(2)).sum::<f64>() / n;
+        let std_dev = variance.sqrt();
+
+        if std_dev == 0.0 {
+            return Some(0.0);
+        }
+        Some((price - mean) / std_dev)
+    }
+
+    /// 重置指标状态
+    pub fn reset(&mut self) {
+        self.prices.clear();
+    }
+
+    /// 获取周期设置
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// 检查是否已准备好输出结果
+    pub fn is_ready(&self) -> bool {
+        self.prices.len() == self.period
+    }
+}
+
+impl Indicator for ZScore {
+    type Output = f64;
+
+    fn update_kline(&mut self, kline: &Kline) -> Option<f64> {
+        self.update(kline.close)
+    }
+
+    fn is_ready(&self) -> bool {
+        ZScore::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        ZScore::reset(self);
+    }
+
+    fn warm_up_period(&self) -> usize {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests;
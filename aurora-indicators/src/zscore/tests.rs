@@ -0,0 +1,67 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use approx::assert_relative_eq;
+
+#[test]
+#[should_panic(expected = "Z-Score周期必须大于0")]
+fn test_zscore_zero_period() {
+    ZScore::new(0);
+}
+
+/// 参考值（按定义手工计算）：窗口 [1, 2, 3, 4, 5]，μ=3，σ=√2
+#[test]
+fn test_zscore_values() {
+    let mut zscore = ZScore::new(5);
+    assert_eq!(zscore.period(), 5);
+    for price in [1.0, 2.0, 3.0, 4.0] {
+        assert!(zscore.update(price).is_none());
+    }
+    assert_relative_eq!(zscore.update(5.0).unwrap(), 1.414_213_562_373_095, epsilon = 1e-9);
+
+    // 窗口 [2, 3, 4, 5, 0]，μ=2.8，σ=√2.96
+    assert_relative_eq!(zscore.update(0.0).unwrap(), -2.8 / 2.96_f64.sqrt(), epsilon = 1e-9);
+}
+
+#[test]
+fn test_zscore_constant_prices() {
+    let mut zscore = ZScore::new(3);
+    zscore.update(10.0);
+    zscore.update(10.0);
+    assert_eq!(zscore.update(10.0), Some(0.0));
+}
+
+#[test]
+fn test_zscore_symmetry() {
+    let mut up = ZScore::new(4);
+    let mut down = ZScore::new(4);
+    let mut last = (0.0, 0.0);
+    for price in [10.0, 12.0, 11.0, 15.0] {
+        last = (up.update(price).unwrap_or(0.0), down.update(-price).unwrap_or(0.0));
+    }
+    assert_relative_eq!(last.0, -last.1, epsilon = 1e-12);
+    assert!(last.0 > 0.0);
+}
+
+#[test]
+fn test_zscore_reset() {
+    let mut zscore = ZScore::new(2);
+    zscore.update(1.0);
+    zscore.update(2.0);
+    assert!(zscore.is_ready());
+
+    zscore.reset();
+    assert!(!zscore.is_ready());
+    assert!(zscore.update(1.0).is_none());
+}
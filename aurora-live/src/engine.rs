@@ -1,61 +1,118 @@
 use crate::paper_trader::PaperTrader;
 use anyhow::{Result, anyhow};
-use aurora_core::{MarketEvent, Signal, Strategy};
-use aurora_portfolio::Portfolio;
-use aurora_strategy::MACrossoverStrategy;
+use aurora_core::{
+    Clock, DataSource, Kline, MarketEvent, OrderRejection, RunMode, Signal, SignalEvent, SimulatedClock, Strategy,
+    StrategyContext, StrategySnapshot, SystemClock, TimerSchedule,
+};
+use aurora_portfolio::{ExecutionPrices, Portfolio, Trade};
+use aurora_config::StrategyConfig;
+use aurora_strategy::StrategyRegistry;
 use futures_util::{SinkExt, StreamExt};
 use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 /// 运行实时模拟交易
+///
+/// 策略由 [`StrategyRegistry`] 按 `strategy_config` 的类型和参数创建，
+/// 策略类型未注册或参数非法时返回错误。
 pub async fn run_live_trading(
     symbol: &str,
     interval: &str,
-    strategy_name: &str,
-    short_period: usize,
-    long_period: usize,
+    strategy_config: &StrategyConfig,
     initial_cash: f64,
+    checkpoint: Option<&str>,
 ) -> Result<()> {
     // 创建策略
-    let strategy = match strategy_name {
-        "ma-crossover" => MACrossoverStrategy::new(short_period, long_period),
-        _ => return Err(anyhow!("不支持的策略: {}", strategy_name)),
-    };
+    let strategy = StrategyRegistry::new()
+        .from_config(strategy_config)
+        .map_err(|e| anyhow!("创建策略 {} 失败: {}", strategy_config.name, e))?;
 
     info!(
-        "初始化实时交易引擎，策略: {}, 参数: {}:{}, 交易对: {}",
-        strategy_name, short_period, long_period, symbol
+        "初始化实时交易引擎，策略: {} ({}), 参数: {:?}, 交易对: {}",
+        strategy_config.name, strategy_config.strategy_type, strategy_config.parameters, symbol
     );
 
     // 创建实时引擎并运行
     let mut engine = LiveEngine::new(strategy, initial_cash);
+    if let Some(path) = checkpoint {
+        engine = engine.with_checkpoint(path);
+    }
     engine.run(symbol, interval).await?;
 
     Ok(())
 }
 
+/// 定期状态报告的间隔（毫秒）
+const STATUS_INTERVAL_MS: i64 = 300_000;
+
+/// 没有市场数据时检查定时器的最长间隔（毫秒）
+const TIMER_HEARTBEAT_MS: i64 = 1_000;
+
 /// 实时交易引擎
-pub struct LiveEngine {
-    strategy: MACrossoverStrategy,
+///
+/// 默认使用 [`SystemClock`] 计时，测试时可通过 [`with_clock`](Self::with_clock)
+/// 替换为模拟时钟。
+///
+/// 通过 [`with_checkpoint`](Self::with_checkpoint) 设置检查点文件后，
+/// 引擎启动时从文件恢复策略状态，并在每根完成的K线和停止时保存最新状态。
+///
+/// 策略声明了定时器时，引擎在每个市场事件到达时检查到期的定时器，
+/// 连接实时数据时还会按心跳定期检查，因此定时器不依赖K线收盘也能按时触发，
+/// 与回测中逐事件触发的行为一致。定时器信号按最近一次行情价格执行。
+///
+/// 策略类型默认为 `Box<dyn Strategy>`，用于运行 [`StrategyRegistry`] 按配置创建的策略。
+pub struct LiveEngine<S: Strategy = Box<dyn Strategy>> {
+    strategy: S,
     paper_trader: PaperTrader,
-    last_status_time: std::time::Instant,
+    clock: Arc<dyn Clock>,
+    last_status_time: i64,
+    timer: Option<TimerSchedule>,
+    last_prices: Option<ExecutionPrices>,
+    checkpoint: Option<PathBuf>,
 }
 
-impl LiveEngine {
+impl<S: Strategy> LiveEngine<S> {
     /// 创建新的实时引擎
-    pub fn new(strategy: MACrossoverStrategy, initial_cash: f64) -> Self {
+    pub fn new(strategy: S, initial_cash: f64) -> Self {
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock::new());
         Self {
             strategy,
             paper_trader: PaperTrader::new(initial_cash),
-            last_status_time: std::time::Instant::now(),
+            last_status_time: clock.now(),
+            clock,
+            timer: None,
+            last_prices: None,
+            checkpoint: None,
         }
     }
 
+    /// 设置时钟
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.last_status_time = clock.now();
+        self.clock = clock;
+        self
+    }
+
+    /// 设置策略状态检查点文件
+    pub fn with_checkpoint<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.checkpoint = Some(path.as_ref().to_path_buf());
+        self
+    }
+
     /// 运行实时引擎
     pub async fn run(&mut self, symbol: &str, interval: &str) -> Result<()> {
         let stream_name = format!("{}@kline_{}", symbol.to_lowercase(), interval);
 
+        let context = StrategyContext::new(RunMode::Live, self.paper_trader.get_cash())
+            .with_symbol(symbol)
+            .with_interval(interval)
+            .with_start_time(self.clock.now());
+        self.start_strategy(&context);
+        self.restore_checkpoint();
+
         // 尝试多个 Binance WebSocket 端点
         let endpoints = [
             "wss://stream.binance.com:9443",
@@ -93,9 +150,82 @@ impl LiveEngine {
             }
         }
 
+        self.strategy.on_finish();
+        self.save_checkpoint();
         Ok(())
     }
 
+    /// 从给定数据源运行引擎
+    ///
+    /// 与 [`run`](Self::run) 的交易流程相同，但市场数据来自任意 [`DataSource`]，
+    /// 例如回放录制数据的 `HistoricalReplaySource`，便于离线验证实时交易流程。
+    /// 数据源的通道关闭后引擎停止。
+    ///
+    /// 回放时引擎改用 [`SimulatedClock`]，从第一个事件的时间开始，并随每个事件的时间戳推进，
+    /// 策略的开始时间和定时器都按数据中的时间计算，而不是墙上时间。
+    /// K线之外的事件按事件本身的价格成交：报价和深度快照买入用卖一价、卖出用买一价，
+    /// 其他事件使用参考价格；无法确定价格的事件产生的信号被忽略。
+    pub async fn run_with_source<D: DataSource + Send>(
+        &mut self,
+        source: &mut D,
+        symbol: &str,
+        interval: &str,
+    ) -> Result<()> {
+        let mut receiver = source.start().await?;
+        let mut next = receiver.recv().await;
+
+        let clock = Arc::new(SimulatedClock::new(
+            next.as_ref().map_or_else(|| self.clock.now(), MarketEvent::timestamp),
+        ));
+        self.clock = clock.clone();
+        self.last_status_time = clock.now();
+
+        let context = StrategyContext::new(RunMode::Live, self.paper_trader.get_cash())
+            .with_symbol(symbol)
+            .with_interval(interval)
+            .with_start_time(clock.now());
+        self.start_strategy(&context);
+        self.restore_checkpoint();
+
+        while let Some(event) = next {
+            clock.advance_to(event.timestamp());
+            match event {
+                MarketEvent::Kline(kline) => self.process_kline(kline).await,
+                other => self.process_event(&other).await,
+            }
+            next = receiver.recv().await;
+        }
+
+        info!("数据源已结束");
+        self.strategy.on_finish();
+        self.save_checkpoint();
+        Ok(())
+    }
+
+    /// 处理K线之外的市场事件
+    async fn process_event(&mut self, event: &MarketEvent) {
+        let prices = execution_prices(event);
+
+        // 先检查挂单和止损止盈是否被这次行情触发
+        if let Some(prices) = &prices {
+            self.last_prices = Some(*prices);
+            let results = self.paper_trader.on_prices(prices, event.timestamp()).await;
+            for result in results {
+                self.notify_execution(result);
+            }
+        }
+
+        self.poll_timers().await;
+
+        let Some(signal_event) = self.strategy.on_market_event(event) else {
+            return;
+        };
+        match prices {
+            Some(prices) => self.execute_signal(&signal_event, &prices).await,
+            None => warn!("无法从市场事件确定成交价格，忽略信号 {:?}", signal_event.signal),
+        }
+    }
+
     /// 连接WebSocket并进行交易
     async fn connect_and_trade(&mut self, url: &str) -> Result<()> {
         let (ws_stream, _) = connect_async(url).await?;
@@ -103,7 +233,26 @@ impl LiveEngine {
 
         let (mut write, mut read) = ws_stream.split();
 
-        while let Some(message) = read.next().await {
+        // 心跳间隔不超过定时器间隔，行情稀疏时定时器也能按时触发
+        let heartbeat_ms = self
+            .timer
+            .as_ref()
+            .map_or(TIMER_HEARTBEAT_MS, |timer| timer.interval().min(TIMER_HEARTBEAT_MS));
+        let mut heartbeat = tokio::time::interval(tokio::time::Duration::from_millis(heartbeat_ms as u64));
+        heartbeat.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            let message = tokio::select! {
+                message = read.next() => message,
+                _ = heartbeat.tick(), if self.timer.is_some() => {
+                    self.poll_timers().await;
+                    continue;
+                }
+            };
+            let Some(message) = message else {
+                break;
+            };
+
             match message {
                 Ok(Message::Text(text)) => {
                     debug!("收到K线消息");
@@ -113,9 +262,10 @@ impl LiveEngine {
                     }
 
                     // 定期打印账户状态（每5分钟）
-                    if self.last_status_time.elapsed().as_secs() >= 300 {
+                    let now = self.clock.now();
+                    if now - self.last_status_time >= STATUS_INTERVAL_MS {
                         self.print_periodic_status().await;
-                        self.last_status_time = std::time::Instant::now();
+                        self.last_status_time = now;
                     }
                 }
                 Ok(Message::Close(_)) => {
@@ -152,7 +302,7 @@ impl LiveEngine {
                 return Ok(());
             }
 
-            let kline = aurora_core::Kline {
+            let kline = Kline {
                 timestamp: kline_data["t"].as_i64().unwrap_or(0),
                 open: kline_data["o"]
                     .as_str()
@@ -179,52 +329,128 @@ impl LiveEngine {
                     .unwrap_or("0")
                     .parse()
                     .unwrap_or(0.0),
+                symbol: kline_data["s"].as_str().map(str::to_string),
+                interval: kline_data["i"].as_str().map(str::to_string),
             };
 
+            self.process_kline(kline).await;
+        }
+
+        Ok(())
+    }
+
+    /// 处理一根完成的K线
+    async fn process_kline(&mut self, kline: Kline) {
+        info!(
+            "📊 收到完成K线: 时间={}, 收盘价={:.2}, 成交量={:.2}",
+            kline.timestamp, kline.close, kline.volume
+        );
+
+        // 先检查挂单和止损止盈是否被这根K线触发
+        let bar_prices = ExecutionPrices::at(kline.close)
+            .with_open(kline.open)
+            .with_range(kline.high, kline.low);
+        self.last_prices = Some(bar_prices);
+        let results = self.paper_trader.on_prices(&bar_prices, kline.timestamp).await;
+        for result in results {
+            self.notify_execution(result);
+        }
+
+        // 触发到期的定时器，定时器信号按这根K线的收盘价执行
+        self.poll_timers().await;
+
+        // 让策略处理K线数据
+        let market_event = MarketEvent::Kline(kline.clone());
+        if let Some(signal_event) = self.strategy.on_market_event(&market_event) {
             info!(
-                "📊 收到完成K线: 时间={}, 收盘价={:.2}, 成交量={:.2}",
-                kline.timestamp, kline.close, kline.volume
+                "🚨 策略信号: {:?} at price {:.2}",
+                signal_event.signal, signal_event.price
             );
 
-            // 让策略处理K线数据
-            let market_event = MarketEvent::Kline(kline.clone());
-            if let Some(signal_event) = self.strategy.on_market_event(&market_event) {
-                info!(
-                    "🚨 策略信号: {:?} at price {:.2}",
-                    signal_event.signal, signal_event.price
-                );
-
-                // 处理交易信号
-                match signal_event.signal {
-                    Signal::Buy => {
-                        if let Err(e) = self
-                            .paper_trader
-                            .execute_paper_buy(signal_event.price, signal_event.timestamp)
-                            .await
-                        {
-                            error!("执行买入失败: {}", e);
-                        }
-                    }
-                    Signal::Sell => {
-                        if let Err(e) = self
-                            .paper_trader
-                            .execute_paper_sell(signal_event.price, signal_event.timestamp)
-                            .await
-                        {
-                            error!("执行卖出失败: {}", e);
-                        }
-                    }
-                    Signal::Hold => {
-                        // 不执行任何操作
-                    }
-                }
+            // 按订单意图处理交易信号，与回测一样按这根K线的价格成交
+            self.execute_signal(&signal_event, &bar_prices).await;
 
-                // 在有交易信号时立即打印状态
-                self.paper_trader.print_status(kline.close);
+            // 在有交易信号时立即打印状态
+            self.paper_trader.print_status(kline.close);
+        }
+
+        self.save_checkpoint();
+    }
+
+    /// 触发到期的定时器，定时器信号按最近一次行情价格执行
+    async fn poll_timers(&mut self) {
+        let due_timers = self
+            .timer
+            .as_mut()
+            .map(|timer| timer.poll(self.clock.as_ref()))
+            .unwrap_or_default();
+        for tick in due_timers {
+            let Some(signal_event) = self.strategy.on_timer(tick) else {
+                continue;
+            };
+            match self.last_prices {
+                Some(prices) => self.execute_signal(&signal_event, &prices).await,
+                None => warn!("尚未收到行情，忽略定时器信号 {:?}", signal_event.signal),
             }
         }
+    }
 
-        Ok(())
+    /// 通知策略开始运行，并按策略声明的间隔创建定时器
+    fn start_strategy(&mut self, context: &StrategyContext) {
+        self.strategy.on_start(context);
+        self.timer = self.strategy.timer_interval().map(TimerSchedule::new);
+    }
+
+    /// 从检查点文件恢复策略状态
+    ///
+    /// 文件不存在时从头开始；文件损坏或与当前策略不兼容时记录警告并从头开始。
+    fn restore_checkpoint(&mut self) {
+        let Some(path) = &self.checkpoint else {
+            return;
+        };
+        if !path.exists() {
+            info!("检查点文件 {} 不存在，策略从初始状态开始", path.display());
+            return;
+        }
+
+        match StrategySnapshot::load(path).and_then(|snapshot| self.strategy.restore(&snapshot)) {
+            Ok(()) => info!("已从检查点 {} 恢复策略状态", path.display()),
+            Err(e) => warn!("无法从检查点 {} 恢复策略状态: {}", path.display(), e),
+        }
+    }
+
+    /// 保存策略状态到检查点文件
+    fn save_checkpoint(&self) {
+        let Some(path) = &self.checkpoint else {
+            return;
+        };
+
+        match self.strategy.snapshot().and_then(|snapshot| snapshot.save(path)) {
+            Ok(()) => debug!("策略状态已保存到 {}", path.display()),
+            Err(e) => warn!("保存检查点 {} 失败: {}", path.display(), e),
+        }
+    }
+
+    /// 执行交易信号并把结果通知策略
+    async fn execute_signal(&mut self, signal_event: &SignalEvent, prices: &ExecutionPrices) {
+        if signal_event.signal == Signal::Hold {
+            return;
+        }
+        let result = self.paper_trader.execute_signal(signal_event, prices).await;
+        if let Some(result) = result.transpose() {
+            self.notify_execution(result);
+        }
+    }
+
+    /// 把订单执行结果通知策略
+    fn notify_execution(&mut self, result: std::result::Result<Trade, OrderRejection>) {
+        match result {
+            Ok(trade) => self.strategy.on_order_filled(&trade),
+            Err(rejection) => {
+                error!("订单被拒绝: {}", rejection);
+                self.strategy.on_order_rejected(&rejection);
+            }
+        }
     }
 
     /// 定期打印账户状态
@@ -246,9 +472,31 @@ impl LiveEngine {
     }
 }
 
+/// 根据市场事件确定成交价格
+///
+/// K线按收盘价成交并用最高价和最低价检查止损止盈；报价和深度快照买入用卖一价、卖出用买一价；
+/// 其他事件使用参考价格（成交价或标记价格）。
+fn execution_prices(event: &MarketEvent) -> Option<ExecutionPrices> {
+    match event {
+        MarketEvent::Kline(kline) => Some(
+            ExecutionPrices::at(kline.close)
+                .with_open(kline.open)
+                .with_range(kline.high, kline.low),
+        ),
+        MarketEvent::Quote(quote) => Some(ExecutionPrices::new(quote.ask_price, quote.bid_price)),
+        MarketEvent::DepthSnapshot(depth) => {
+            let ask = depth.best_ask()?.price;
+            let bid = depth.best_bid()?.price;
+            Some(ExecutionPrices::new(ask, bid))
+        }
+        _ => event.reference_price().map(ExecutionPrices::at),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aurora_strategy::MACrossoverStrategy;
 
     #[test]
     fn test_live_engine_creation() {
@@ -323,4 +571,190 @@ mod tests {
         // 未完成的K线不应该触发任何交易
         assert_eq!(engine.paper_trader.portfolio().get_trades().len(), 0);
     }
+
+    #[tokio::test]
+    async fn test_checkpoint_save_and_restore() {
+        let path = std::env::temp_dir().join(format!("aurora_live_checkpoint_{}.json", std::process::id()));
+        let mut engine = LiveEngine::new(MACrossoverStrategy::new(2, 3), 10000.0).with_checkpoint(&path);
+
+        for (i, close) in ["100.0", "102.0", "104.0"].iter().enumerate() {
+            let message = format!(
+                r#"{{"k": {{"t": {}, "s": "BTCUSDT", "i": "1m", "o": "{close}", "c": "{close}", "h": "{close}", "l": "{close}", "v": "1.0", "x": true}}}}"#,
+                i * 60_000
+            );
+            engine.process_kline_message(&message).await.unwrap();
+        }
+        assert!(path.exists());
+
+        // 重启后从检查点恢复均线状态
+        let mut restarted = LiveEngine::new(MACrossoverStrategy::new(2, 3), 10000.0).with_checkpoint(&path);
+        restarted.restore_checkpoint();
+        std::fs::remove_file(&path).ok();
+
+        assert!(restarted.strategy.is_ready());
+        assert_eq!(restarted.strategy.short_ma_value(), engine.strategy.short_ma_value());
+        assert_eq!(restarted.strategy.long_ma_value(), engine.strategy.long_ma_value());
+    }
+
+    #[tokio::test]
+    async fn test_run_with_replay_source() {
+        use aurora_data::HistoricalReplaySource;
+
+        // 先下跌再上涨，短期均线上穿长期均线产生买入
+        let closes = [100.0, 98.0, 96.0, 94.0, 97.0, 101.0, 105.0];
+        let klines = closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| Kline {
+                timestamp: 1640995200000 + i as i64 * 60_000,
+                open: *close,
+                high: *close,
+                low: *close,
+                close: *close,
+                volume: 1.0,
+                symbol: None,
+                interval: None,
+            })
+            .collect();
+        let mut source = HistoricalReplaySource::from_klines(klines);
+
+        let mut engine = LiveEngine::new(MACrossoverStrategy::new(2, 3), 10000.0);
+        engine.run_with_source(&mut source, "BTCUSDT", "1m").await.unwrap();
+
+        assert!(engine.strategy.is_ready());
+        assert_eq!(engine.paper_trader.portfolio().get_trades().len(), 1);
+        assert!(engine.paper_trader.get_position() > 0.0);
+    }
+
+    /// 记录开始时间和定时器触发时刻，收到报价时买入的测试策略
+    #[derive(Default)]
+    struct ReplayProbe {
+        start_time: Option<i64>,
+        ticks: Vec<i64>,
+    }
+
+    impl Strategy for ReplayProbe {
+        fn on_market_event(&mut self, event: &MarketEvent) -> Option<SignalEvent> {
+            match event {
+                // 信号价格故意与报价不同，成交价应来自报价本身
+                MarketEvent::Quote(quote) => Some(SignalEvent::new(Signal::Buy, 1.0, quote.timestamp)),
+                _ => None,
+            }
+        }
+
+        fn on_start(&mut self, context: &StrategyContext) {
+            self.start_time = context.start_time;
+        }
+
+        fn timer_interval(&self) -> Option<i64> {
+            Some(60_000)
+        }
+
+        fn on_timer(&mut self, timestamp: i64) -> Option<SignalEvent> {
+            self.ticks.push(timestamp);
+            None
+        }
+    }
+
+    fn flat_kline(timestamp: i64, close: f64) -> Kline {
+        Kline {
+            timestamp,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: 1.0,
+            symbol: None,
+            interval: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_advances_simulated_clock() {
+        use aurora_data::HistoricalReplaySource;
+
+        let start = 1640995200000;
+        let klines = (0..4).map(|i| flat_kline(start + i * 60_000, 100.0)).collect();
+        let mut source = HistoricalReplaySource::from_klines(klines);
+
+        let mut engine = LiveEngine::new(ReplayProbe::default(), 10000.0);
+        engine.run_with_source(&mut source, "BTCUSDT", "1m").await.unwrap();
+
+        // 开始时间和定时器都按数据中的时间，而不是墙上时间
+        assert_eq!(engine.strategy.start_time, Some(start));
+        assert_eq!(engine.strategy.ticks, vec![start + 60_000, start + 120_000, start + 180_000]);
+        assert_eq!(engine.clock.now(), start + 180_000);
+    }
+
+    #[tokio::test]
+    async fn test_replay_quote_executes_at_ask() {
+        use aurora_core::Quote;
+        use aurora_data::HistoricalReplaySource;
+
+        let start = 1640995200000;
+        let events = vec![
+            MarketEvent::Kline(flat_kline(start, 100.0)),
+            MarketEvent::Quote(Quote {
+                symbol: "BTCUSDT".to_string(),
+                timestamp: start + 1_000,
+                bid_price: 99.5,
+                bid_quantity: 1.0,
+                ask_price: 100.5,
+                ask_quantity: 1.0,
+            }),
+        ];
+        let mut source = HistoricalReplaySource::from_events(events);
+
+        let mut engine = LiveEngine::new(ReplayProbe::default(), 10000.0);
+        engine.run_with_source(&mut source, "BTCUSDT", "1m").await.unwrap();
+
+        let trades = engine.paper_trader.portfolio().get_trades();
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].price, 100.5);
+        assert_eq!(trades[0].timestamp, start + 1_000);
+    }
+
+    #[tokio::test]
+    async fn test_timers_fire_between_klines() {
+        use aurora_core::TradeTick;
+        use aurora_data::HistoricalReplaySource;
+
+        // 1小时K线之间每分钟一笔成交，1分钟定时器随成交逐次触发而不是等到下一根K线
+        let start = 1640995200000;
+        let mut events = vec![MarketEvent::Kline(flat_kline(start, 100.0))];
+        events.extend((1..=3).map(|i| {
+            MarketEvent::Trade(TradeTick {
+                symbol: "BTCUSDT".to_string(),
+                timestamp: start + i * 60_000,
+                price: 100.0,
+                quantity: 0.1,
+                is_buyer_maker: false,
+                trade_id: None,
+            })
+        }));
+        let mut source = HistoricalReplaySource::from_events(events);
+
+        let mut engine = LiveEngine::new(ReplayProbe::default(), 10000.0);
+        engine.run_with_source(&mut source, "BTCUSDT", "1h").await.unwrap();
+
+        assert_eq!(engine.strategy.ticks, vec![start + 60_000, start + 120_000, start + 180_000]);
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_polls_timers_without_market_data() {
+        let start = 1640995200000;
+        let clock = Arc::new(SimulatedClock::new(start));
+        let mut engine = LiveEngine::new(ReplayProbe::default(), 10000.0).with_clock(clock.clone());
+        engine.start_strategy(&StrategyContext::new(RunMode::Live, 10000.0));
+
+        engine.poll_timers().await;
+        clock.advance_to(start + 60_000);
+        engine.poll_timers().await;
+        clock.advance_to(start + 90_000);
+        engine.poll_timers().await;
+        clock.advance_to(start + 120_000);
+        engine.poll_timers().await;
+
+        assert_eq!(engine.strategy.ticks, vec![start + 60_000, start + 120_000]);
+    }
 }
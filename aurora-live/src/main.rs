@@ -1,11 +1,9 @@
 use anyhow::{Context, Result};
-use aurora_config::Config;
+use aurora_config::{Config, StrategyConfig};
+use aurora_live::engine;
 use clap::Parser;
 use tracing::{error, info};
 
-mod engine;
-mod paper_trader;
-
 #[derive(Parser)]
 #[command(name = "aurora-live")]
 #[command(about = "Aurora项目的实时模拟交易引擎")]
@@ -37,6 +35,10 @@ struct Cli {
     /// K线时间间隔
     #[arg(short, long)]
     interval: Option<String>,
+
+    /// 策略状态检查点文件，重启时从中恢复策略状态
+    #[arg(long)]
+    checkpoint: Option<String>,
 }
 
 #[tokio::main]
@@ -77,32 +79,18 @@ async fn run_with_config(config_path: &str) -> Result<()> {
         .find(|s| s.enabled)
         .context("配置文件中没有启用的策略")?;
 
-    // 从策略参数中提取short和long
-    let short = strategy
-        .parameters
-        .get("short")
-        .and_then(|p| p.as_usize())
-        .unwrap_or(10);
-
-    let long = strategy
-        .parameters
-        .get("long")
-        .and_then(|p| p.as_usize())
-        .unwrap_or(30);
-
     info!(
-        "启动实时模拟交易: 交易对={}, 策略={}, 参数={}:{}",
-        live_config.symbol, strategy.name, short, long
+        "启动实时模拟交易: 交易对={}, 策略={} ({})",
+        live_config.symbol, strategy.name, strategy.strategy_type
     );
 
     // 运行实时交易
     match engine::run_live_trading(
         &live_config.symbol,
         &live_config.interval,
-        &strategy.strategy_type,
-        short,
-        long,
+        strategy,
         config.portfolio.initial_cash,
+        live_config.checkpoint.as_deref(),
     )
     .await
     {
@@ -125,22 +113,24 @@ async fn run_with_cli_args(cli: Cli) -> Result<()> {
     // 验证必需参数
     let symbol = cli.symbol.context("缺少必需参数: --symbol")?;
 
-    let strategy_name = cli
-        .strategy_name
-        .unwrap_or_else(|| "ma-crossover".to_string());
-    let short = cli.short.unwrap_or(10);
-    let long = cli.long.unwrap_or(30);
+    let strategy_config = StrategyConfig::from_cli(cli.strategy_name, cli.short, cli.long);
     let initial_cash = cli.initial_cash.unwrap_or(10000.0);
     let interval = cli.interval.unwrap_or_else(|| "1m".to_string());
 
     info!(
-        "启动实时模拟交易: 交易对={}, 策略={}, 参数={}:{}",
-        symbol, strategy_name, short, long
+        "启动实时模拟交易: 交易对={}, 策略={}, 参数={:?}",
+        symbol, strategy_config.strategy_type, strategy_config.parameters
     );
 
     // 运行实时交易
-    match engine::run_live_trading(&symbol, &interval, &strategy_name, short, long, initial_cash)
-        .await
+    match engine::run_live_trading(
+        &symbol,
+        &interval,
+        &strategy_config,
+        initial_cash,
+        cli.checkpoint.as_deref(),
+    )
+    .await
     {
         Ok(_) => {
             info!("实时交易结束");
@@ -198,6 +188,8 @@ mod tests {
             "50000.0",
             "--interval",
             "5m",
+            "--checkpoint",
+            "state.json",
         ];
 
         let cli = Cli::try_parse_from(args).unwrap();
@@ -210,6 +202,7 @@ mod tests {
         assert_eq!(cli.long, Some(20));
         assert_eq!(cli.initial_cash, Some(50000.0));
         assert_eq!(cli.interval, Some("5m".to_string()));
+        assert_eq!(cli.checkpoint, Some("state.json".to_string()));
     }
 
     #[test]
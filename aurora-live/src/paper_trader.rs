@@ -3,7 +3,8 @@
 //! 为实时环境提供模拟交易功能，使用统一的投资组合管理接口
 
 use anyhow::Result;
-use aurora_portfolio::{BasePortfolio, Portfolio};
+use aurora_core::{OrderRejection, SignalEvent};
+use aurora_portfolio::{BasePortfolio, ExecutionPrices, IntentExecutor, Portfolio, Trade};
 use tracing::{debug, info};
 
 /// 模拟交易者
@@ -12,6 +13,7 @@ use tracing::{debug, info};
 #[derive(Debug)]
 pub struct PaperTrader {
     portfolio: BasePortfolio,
+    executor: IntentExecutor,
 }
 
 impl PaperTrader {
@@ -21,6 +23,7 @@ impl PaperTrader {
 
         Self {
             portfolio: BasePortfolio::new(initial_cash),
+            executor: IntentExecutor::new(),
         }
     }
 
@@ -70,6 +73,74 @@ impl PaperTrader {
         }
     }
 
+    /// 执行策略信号
+    ///
+    /// 按信号携带的订单意图下单：市价单立即成交，
+    /// 限价单和止损触发单挂起，在后续行情中由 [`on_prices`](Self::on_prices) 检查。
+    ///
+    /// # 返回值
+    ///
+    /// 立即成交时返回交易记录，挂起时返回 `None`，被拒绝时返回拒绝通知
+    pub async fn execute_signal(
+        &mut self,
+        signal_event: &SignalEvent,
+        prices: &ExecutionPrices,
+    ) -> std::result::Result<Option<Trade>, OrderRejection> {
+        let result = self
+            .executor
+            .submit(&mut self.portfolio, signal_event, prices)
+            .await;
+        match &result {
+            Ok(Some(trade)) => self.report_trade(trade),
+            Ok(None) if self.executor.has_pending_order() => {
+                info!("📝 挂单等待触发: {:?}", signal_event.signal);
+            }
+            Ok(None) => {}
+            Err(rejection) => debug!("模拟下单失败: {}", rejection),
+        }
+        result
+    }
+
+    /// 处理行情更新，检查挂单和止损止盈是否被触发
+    ///
+    /// # 返回值
+    ///
+    /// 本次行情触发的每一笔订单的执行结果
+    pub async fn on_prices(
+        &mut self,
+        prices: &ExecutionPrices,
+        timestamp: i64,
+    ) -> Vec<std::result::Result<Trade, OrderRejection>> {
+        let results = self
+            .executor
+            .on_prices(&mut self.portfolio, prices, timestamp)
+            .await;
+        for result in &results {
+            match result {
+                Ok(trade) => self.report_trade(trade),
+                Err(rejection) => debug!("挂单或止损止盈执行失败: {}", rejection),
+            }
+        }
+        results
+    }
+
+    /// 记录成交日志并发送通知
+    fn report_trade(&self, trade: &Trade) {
+        let (icon, action) = if trade.is_buy() {
+            ("📈", "买入")
+        } else {
+            ("📉", "卖出")
+        };
+        info!(
+            "{} 模拟{}成功: 价格={:.2}, 数量={:.6}, 总价值={:.2}",
+            icon, action, trade.price, trade.quantity, trade.value
+        );
+        self.send_notification(&format!(
+            "模拟{} {:.6} @ {:.2}",
+            action, trade.quantity, trade.price
+        ));
+    }
+
     /// 更新权益记录
     pub fn update_equity(&mut self, timestamp: i64, current_price: f64) {
         self.portfolio.update_equity(timestamp, current_price);
@@ -274,4 +345,32 @@ mod tests {
         let total_equity = trader.get_total_equity(51000.0);
         assert!(total_equity > 0.0);
     }
+
+    #[tokio::test]
+    async fn test_execute_signal_with_intent() {
+        use aurora_core::{OrderIntent, Signal};
+
+        let mut trader = PaperTrader::new(10000.0);
+
+        // 用20%的权益挂限价买单，并设置止损
+        let signal = SignalEvent::new(Signal::Buy, 100.0, 1640995200000)
+            .with_intent(OrderIntent::limit(95.0).with_weight(0.2).with_stop_loss(90.0));
+        let result = trader
+            .execute_signal(&signal, &ExecutionPrices::at(100.0))
+            .await
+            .unwrap();
+        assert!(result.is_none());
+        assert_eq!(trader.get_position(), 0.0);
+
+        // 价格回落到限价，挂单成交
+        let bar = ExecutionPrices::at(96.0).with_range(99.0, 94.0);
+        assert!(trader.on_prices(&bar, 1640995260000).await[0].is_ok());
+        assert!((trader.get_position() - 2000.0 / 95.0).abs() < 1e-9);
+
+        // 跌破止损价，自动平仓
+        let bar = ExecutionPrices::at(89.0).with_range(93.0, 88.0);
+        assert!(trader.on_prices(&bar, 1640995320000).await[0].is_ok());
+        assert_eq!(trader.get_position(), 0.0);
+        assert_eq!(trader.portfolio().get_trades().len(), 2);
+    }
 }
@@ -75,6 +75,8 @@ async fn test_strategy_signal_integration() {
             low: 48000.0,
             close: 50000.0,
             volume: 100.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 1640995260000,
@@ -83,6 +85,8 @@ async fn test_strategy_signal_integration() {
             low: 49000.0,
             close: 51000.0,
             volume: 120.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 1640995320000,
@@ -91,6 +95,8 @@ async fn test_strategy_signal_integration() {
             low: 50000.0,
             close: 52000.0,
             volume: 110.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 1640995380000,
@@ -99,6 +105,8 @@ async fn test_strategy_signal_integration() {
             low: 51000.0,
             close: 53000.0,
             volume: 130.0,
+            symbol: None,
+            interval: None,
         },
         Kline {
             timestamp: 1640995440000,
@@ -107,6 +115,8 @@ async fn test_strategy_signal_integration() {
             low: 52000.0,
             close: 54000.0,
             volume: 125.0,
+            symbol: None,
+            interval: None,
         },
     ];
 
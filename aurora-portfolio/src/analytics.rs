@@ -14,7 +14,7 @@
 
 //! 投资组合分析和业绩指标计算模块
 
-use crate::trade::Trade;
+use aurora_core::Trade;
 use serde::{Deserialize, Serialize};
 
 /// 权益曲线数据点
@@ -719,7 +719,7 @@ impl PerformanceMetrics {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::trade::Trade;
+    use aurora_core::Trade;
 
     #[test]
     fn test_equity_point_creation() {
@@ -19,9 +19,9 @@
 
 use anyhow::Result;
 use async_trait::async_trait;
+use aurora_core::Trade;
 
 use crate::order::{Order, OrderStatus};
-use crate::trade::Trade;
 
 /// 经纪商统一接口
 ///
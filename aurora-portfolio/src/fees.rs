@@ -16,7 +16,11 @@
 //!
 //! 提供多种手续费和滑点计算方式,用于更真实地模拟交易成本。
 //! 支持固定值、百分比、以及基于成交量和波动率的动态模型。
+//!
+//! 成交价、成交金额和手续费按 [`Amount`](aurora_core::Amount) 计算后再转换为 `f64`,
+//! 启用 `decimal` 特性时百分比费率不会引入二进制浮点误差。
 
+use aurora_core::{amount_to_f64, to_amount};
 use serde::{Deserialize, Serialize};
 
 /// 手续费模型
@@ -178,11 +182,11 @@ impl TradeCostCalculator {
     ) -> TradeCost {
         // 计算滑点(买入时滑点为正,价格上涨)
         let slippage = self.calculate_slippage(price, quantity, volume, volatility);
-        let executed_price = price + slippage;
+        let executed_price = to_amount(price) + to_amount(slippage);
 
         // 计算手续费
-        let trade_value = executed_price * quantity;
-        let fee = self.calculate_fee(trade_value, is_maker);
+        let trade_value = executed_price * to_amount(quantity);
+        let fee = to_amount(self.calculate_fee(amount_to_f64(trade_value), is_maker));
 
         // 买入总成本 = 成交金额 + 手续费
         let total_cost = trade_value + fee;
@@ -190,9 +194,9 @@ impl TradeCostCalculator {
         TradeCost {
             original_price: price,
             slippage,
-            executed_price,
-            fee,
-            total_cost,
+            executed_price: amount_to_f64(executed_price),
+            fee: amount_to_f64(fee),
+            total_cost: amount_to_f64(total_cost),
         }
     }
 
@@ -219,11 +223,11 @@ impl TradeCostCalculator {
     ) -> TradeCost {
         // 计算滑点(卖出时滑点为负,价格下跌)
         let slippage = self.calculate_slippage(price, quantity, volume, volatility);
-        let executed_price = price - slippage;
+        let executed_price = to_amount(price) - to_amount(slippage);
 
         // 计算手续费
-        let trade_value = executed_price * quantity;
-        let fee = self.calculate_fee(trade_value, is_maker);
+        let trade_value = executed_price * to_amount(quantity);
+        let fee = to_amount(self.calculate_fee(amount_to_f64(trade_value), is_maker));
 
         // 卖出总收益 = 成交金额 - 手续费
         let total_cost = -(trade_value - fee); // 负数表示收入
@@ -231,9 +235,9 @@ impl TradeCostCalculator {
         TradeCost {
             original_price: price,
             slippage,
-            executed_price,
-            fee,
-            total_cost,
+            executed_price: amount_to_f64(executed_price),
+            fee: amount_to_f64(fee),
+            total_cost: amount_to_f64(total_cost),
         }
     }
 
@@ -247,7 +251,7 @@ impl TradeCostCalculator {
     ) -> f64 {
         match &self.slippage_model {
             SlippageModel::Fixed(amount) => *amount,
-            SlippageModel::Percentage(pct) => price * pct / 100.0,
+            SlippageModel::Percentage(pct) => percent_of(price, *pct),
             SlippageModel::VolumeBased {
                 base_slippage,
                 volume_coefficient,
@@ -300,17 +304,17 @@ impl TradeCostCalculator {
     fn calculate_fee(&self, trade_value: f64, is_maker: bool) -> f64 {
         match &self.fee_model {
             FeeModel::Fixed(amount) => *amount,
-            FeeModel::Percentage(pct) => trade_value * pct / 100.0,
+            FeeModel::Percentage(pct) => percent_of(trade_value, *pct),
             FeeModel::Tiered(tiers) => {
                 // 找到适用的费率档位
                 for (threshold, rate) in tiers {
                     if trade_value <= *threshold {
-                        return trade_value * rate / 100.0;
+                        return percent_of(trade_value, *rate);
                     }
                 }
                 // 如果没有找到匹配的档位,使用最后一个
                 if let Some((_, rate)) = tiers.last() {
-                    return trade_value * rate / 100.0;
+                    return percent_of(trade_value, *rate);
                 }
                 0.0
             }
@@ -319,7 +323,7 @@ impl TradeCostCalculator {
                 taker_fee,
             } => {
                 let rate = if is_maker { maker_fee } else { taker_fee };
-                trade_value * rate / 100.0
+                percent_of(trade_value, *rate)
             }
             FeeModel::None => 0.0,
         }
@@ -336,6 +340,11 @@ impl TradeCostCalculator {
     }
 }
 
+/// 计算 `value` 的 `pct`%
+fn percent_of(value: f64, pct: f64) -> f64 {
+    amount_to_f64(to_amount(value) * to_amount(pct) / to_amount(100.0))
+}
+
 impl Default for TradeCostCalculator {
     fn default() -> Self {
         Self {
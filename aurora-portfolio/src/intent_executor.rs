@@ -0,0 +1,346 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 订单意图执行器
+//!
+//! [`IntentExecutor`] 把策略信号中的 [`OrderIntent`] 转换为对 [`Portfolio`] 的操作，
+//! 供回测引擎和实时引擎共用：
+//!
+//! - 按固定数量或权益比例计算下单规模
+//! - 市价单立即成交，未触发的限价单和止损触发单挂起，
+//!   在后续行情中检查触发条件
+//! - 买入成交后记录意图中的止损价和止盈价，价格触及时自动平仓
+//! - 投资组合拒绝的订单转换为 [`OrderRejection`]，便于引擎通知策略
+//!
+//! 执行器同一时间只保留一个挂单，新信号会替换尚未成交的旧挂单。
+
+use aurora_core::{OrderIntent, OrderKind, OrderRejection, OrderSize, Signal, SignalEvent, Trade};
+use tracing::{debug, info};
+
+use crate::portfolio::Portfolio;
+
+/// 一次行情更新中可用于成交的价格
+///
+/// `buy` 和 `sell` 是当前可立即成交的买入价和卖出价，
+/// `high` 和 `low` 是本次更新覆盖的价格区间（K线的最高价和最低价），
+/// 用于判断挂单和止损止盈是否被触发。
+/// `open` 是区间的开盘价，价格跳空越过触发价时按开盘价成交，
+/// 未设置时按当前对应方向的买卖价计算。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExecutionPrices {
+    /// 当前买入价
+    pub buy: f64,
+    /// 当前卖出价
+    pub sell: f64,
+    /// 区间最高价
+    pub high: f64,
+    /// 区间最低价
+    pub low: f64,
+    /// 区间开盘价
+    pub open: Option<f64>,
+}
+
+impl ExecutionPrices {
+    /// 以单一价格创建（买卖价和价格区间相同）
+    pub fn at(price: f64) -> Self {
+        Self::new(price, price)
+    }
+
+    /// 以买入价和卖出价创建，价格区间取两者之间
+    pub fn new(buy: f64, sell: f64) -> Self {
+        Self {
+            buy,
+            sell,
+            high: buy.max(sell),
+            low: buy.min(sell),
+            open: None,
+        }
+    }
+
+    /// 设置价格区间，区间会扩展到包含当前买卖价和开盘价
+    pub fn with_range(mut self, high: f64, low: f64) -> Self {
+        let open = self.open.unwrap_or(self.buy);
+        self.high = high.max(self.buy).max(self.sell).max(open);
+        self.low = low.min(self.buy).min(self.sell).min(open);
+        self
+    }
+
+    /// 设置区间开盘价，区间会扩展到包含开盘价
+    pub fn with_open(mut self, open: f64) -> Self {
+        self.open = Some(open);
+        self.high = self.high.max(open);
+        self.low = self.low.min(open);
+        self
+    }
+
+    /// 跳空时的成交价：开盘价，未设置时取对应方向的当前价
+    fn gap_price(&self, is_buy: bool) -> f64 {
+        self.open.unwrap_or(if is_buy { self.buy } else { self.sell })
+    }
+}
+
+/// 尚未触发的挂单
+#[derive(Debug, Clone)]
+struct PendingOrder {
+    signal: Signal,
+    intent: OrderIntent,
+}
+
+/// 订单意图执行器
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::{OrderIntent, Signal, SignalEvent};
+/// use aurora_portfolio::{BasePortfolio, ExecutionPrices, IntentExecutor, Portfolio};
+///
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// let mut portfolio = BasePortfolio::new(10000.0);
+/// let mut executor = IntentExecutor::new();
+///
+/// // 用30%的权益买入，并设置止损
+/// let signal = SignalEvent::new(Signal::Buy, 100.0, 1000)
+///     .with_intent(OrderIntent::market().with_weight(0.3).with_stop_loss(90.0));
+/// let trade = executor
+///     .submit(&mut portfolio, &signal, &ExecutionPrices::at(100.0))
+///     .await?
+///     .unwrap();
+/// assert_eq!(trade.quantity, 30.0);
+///
+/// // 价格跌破止损价时自动平仓
+/// let prices = ExecutionPrices::at(92.0).with_range(95.0, 88.0);
+/// let results = executor.on_prices(&mut portfolio, &prices, 2000).await;
+/// assert_eq!(results.len(), 1);
+/// assert_eq!(results[0].as_ref().unwrap().price, 90.0);
+/// assert_eq!(portfolio.get_position(), 0.0);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct IntentExecutor {
+    pending: Option<PendingOrder>,
+    stop_loss: Option<f64>,
+    take_profit: Option<f64>,
+}
+
+impl IntentExecutor {
+    /// 创建执行器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 是否存在尚未触发的挂单
+    pub fn has_pending_order(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// 撤销尚未触发的挂单
+    pub fn cancel_pending_order(&mut self) {
+        self.pending = None;
+    }
+
+    /// 当前持仓的止损价
+    pub fn stop_loss(&self) -> Option<f64> {
+        self.stop_loss
+    }
+
+    /// 当前持仓的止盈价
+    pub fn take_profit(&self) -> Option<f64> {
+        self.take_profit
+    }
+
+    /// 提交策略信号
+    ///
+    /// 不带订单意图的信号按市价执行，规模由投资组合决定。
+    /// 限价单和止损触发单在当前价格满足条件时立即成交，否则挂起等待后续行情。
+    ///
+    /// # 返回值
+    ///
+    /// 立即成交时返回交易记录，挂起或持有信号返回 `None`，
+    /// 投资组合拒绝成交时返回 [`OrderRejection`]
+    pub async fn submit<P: Portfolio + ?Sized>(
+        &mut self,
+        portfolio: &mut P,
+        signal_event: &SignalEvent,
+        prices: &ExecutionPrices,
+    ) -> Result<Option<Trade>, OrderRejection> {
+        if signal_event.signal == Signal::Hold {
+            return Ok(None);
+        }
+
+        let intent = signal_event.intent.clone().unwrap_or_default();
+        if let Some(confidence) = intent.confidence {
+            debug!("信号置信度: {:.2}", confidence);
+        }
+
+        // 新信号替换尚未成交的旧挂单
+        self.pending = None;
+
+        let is_buy = signal_event.signal == Signal::Buy;
+        let current = if is_buy { prices.buy } else { prices.sell };
+        let triggered = match intent.kind {
+            OrderKind::Market => true,
+            OrderKind::Limit(limit) => {
+                if is_buy { current <= limit } else { current >= limit }
+            }
+            OrderKind::Stop(stop) => {
+                if is_buy { current >= stop } else { current <= stop }
+            }
+        };
+
+        if !triggered {
+            debug!("挂单等待触发: {:?} {:?}", signal_event.signal, intent.kind);
+            self.pending = Some(PendingOrder {
+                signal: signal_event.signal.clone(),
+                intent,
+            });
+            return Ok(None);
+        }
+
+        self.fill(portfolio, &signal_event.signal, &intent, current, signal_event.timestamp)
+            .await
+            .map(Some)
+    }
+
+    /// 处理一次行情更新
+    ///
+    /// 先检查持仓的止损止盈，再检查挂单是否被触发。
+    /// 开盘价尚未越过触发价时按触发价成交；开盘价已经跳空越过触发价时按开盘价成交，
+    /// 止损因此可能以比止损价更差的价格成交。
+    ///
+    /// # 返回值
+    ///
+    /// 本次更新中每一笔触发订单的执行结果（成交记录或拒绝通知）
+    pub async fn on_prices<P: Portfolio + ?Sized>(
+        &mut self,
+        portfolio: &mut P,
+        prices: &ExecutionPrices,
+        timestamp: i64,
+    ) -> Vec<Result<Trade, OrderRejection>> {
+        let mut results = Vec::new();
+
+        if portfolio.get_position() > 0.0 {
+            let open = prices.gap_price(false);
+            let exit_price = match (self.stop_loss, self.take_profit) {
+                (Some(stop), _) if prices.low <= stop => {
+                    info!("触发止损价 {:.2}", stop);
+                    Some(stop.min(open))
+                }
+                (_, Some(target)) if prices.high >= target => {
+                    info!("触发止盈价 {:.2}", target);
+                    Some(target.max(open))
+                }
+                _ => None,
+            };
+            if let Some(price) = exit_price {
+                let result = portfolio
+                    .execute_sell(price, timestamp)
+                    .await
+                    .map_err(|e| rejection(Signal::Sell, price, timestamp, e));
+                if result.is_ok() {
+                    self.clear_protective_levels();
+                }
+                results.push(result);
+            }
+        } else {
+            self.clear_protective_levels();
+        }
+
+        let Some(pending) = self.pending.clone() else {
+            return results;
+        };
+
+        let is_buy = pending.signal == Signal::Buy;
+        let open = prices.gap_price(is_buy);
+        let fill_price = match pending.intent.kind {
+            OrderKind::Market => Some(if is_buy { prices.buy } else { prices.sell }),
+            OrderKind::Limit(limit) if is_buy && prices.low <= limit => Some(limit.min(open)),
+            OrderKind::Limit(limit) if !is_buy && prices.high >= limit => Some(limit.max(open)),
+            OrderKind::Stop(stop) if is_buy && prices.high >= stop => Some(stop.max(open)),
+            OrderKind::Stop(stop) if !is_buy && prices.low <= stop => Some(stop.min(open)),
+            _ => None,
+        };
+
+        if let Some(price) = fill_price {
+            self.pending = None;
+            debug!("挂单触发: {:?} {:?} @ {:.2}", pending.signal, pending.intent.kind, price);
+            results.push(
+                self.fill(portfolio, &pending.signal, &pending.intent, price, timestamp)
+                    .await,
+            );
+        }
+
+        results
+    }
+
+    /// 按订单意图的规模成交
+    async fn fill<P: Portfolio + ?Sized>(
+        &mut self,
+        portfolio: &mut P,
+        signal: &Signal,
+        intent: &OrderIntent,
+        price: f64,
+        timestamp: i64,
+    ) -> Result<Trade, OrderRejection> {
+        let reject = |e| rejection(signal.clone(), price, timestamp, e);
+        let quantity = intent.size.map(|size| match size {
+            OrderSize::Quantity(quantity) => quantity,
+            OrderSize::Weight(weight) => weight * portfolio.get_total_equity(price) / price,
+        });
+
+        if *signal == Signal::Buy {
+            let trade = match quantity {
+                Some(quantity) => portfolio.execute_buy_quantity(price, quantity, timestamp).await,
+                None => portfolio.execute_buy(price, timestamp).await,
+            }
+            .map_err(reject)?;
+            if intent.stop_loss.is_some() {
+                self.stop_loss = intent.stop_loss;
+            }
+            if intent.take_profit.is_some() {
+                self.take_profit = intent.take_profit;
+            }
+            Ok(trade)
+        } else {
+            let trade = match quantity {
+                Some(quantity) => portfolio.execute_sell_quantity(price, quantity, timestamp).await,
+                None => portfolio.execute_sell(price, timestamp).await,
+            }
+            .map_err(reject)?;
+            if portfolio.get_position() <= 0.0 {
+                self.clear_protective_levels();
+            }
+            Ok(trade)
+        }
+    }
+
+    fn clear_protective_levels(&mut self) {
+        self.stop_loss = None;
+        self.take_profit = None;
+    }
+}
+
+/// 把投资组合返回的错误转换为拒绝通知
+fn rejection(signal: Signal, price: f64, timestamp: i64, error: anyhow::Error) -> OrderRejection {
+    OrderRejection {
+        signal,
+        price,
+        timestamp,
+        reason: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests;
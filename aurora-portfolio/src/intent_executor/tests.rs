@@ -0,0 +1,255 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 订单意图执行器的单元测试
+
+use super::*;
+use crate::portfolio::BasePortfolio;
+
+fn buy(intent: Option<OrderIntent>) -> SignalEvent {
+    SignalEvent {
+        signal: Signal::Buy,
+        price: 100.0,
+        timestamp: 1000,
+        intent,
+    }
+}
+
+fn sell(intent: Option<OrderIntent>) -> SignalEvent {
+    SignalEvent {
+        signal: Signal::Sell,
+        price: 100.0,
+        timestamp: 1000,
+        intent,
+    }
+}
+
+#[tokio::test]
+async fn test_signal_without_intent_goes_all_in() {
+    let mut portfolio = BasePortfolio::new(10000.0);
+    let mut executor = IntentExecutor::new();
+
+    let trade = executor
+        .submit(&mut portfolio, &buy(None), &ExecutionPrices::at(100.0))
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(trade.quantity, 100.0);
+    assert_eq!(portfolio.get_cash(), 0.0);
+}
+
+#[tokio::test]
+async fn test_quantity_and_weight_sizing() {
+    let mut portfolio = BasePortfolio::new(10000.0);
+    let mut executor = IntentExecutor::new();
+    let prices = ExecutionPrices::at(100.0);
+
+    let trade = executor
+        .submit(&mut portfolio, &buy(Some(OrderIntent::market().with_quantity(5.0))), &prices)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(trade.quantity, 5.0);
+
+    let trade = executor
+        .submit(&mut portfolio, &buy(Some(OrderIntent::market().with_weight(0.25))), &prices)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(trade.quantity, 25.0);
+    assert_eq!(portfolio.get_position(), 30.0);
+
+    // 按数量部分卖出
+    let trade = executor
+        .submit(&mut portfolio, &sell(Some(OrderIntent::market().with_quantity(10.0))), &prices)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(trade.quantity, 10.0);
+    assert_eq!(portfolio.get_position(), 20.0);
+}
+
+#[tokio::test]
+async fn test_limit_buy_waits_for_price() {
+    let mut portfolio = BasePortfolio::new(10000.0);
+    let mut executor = IntentExecutor::new();
+
+    let signal = buy(Some(OrderIntent::limit(95.0).with_quantity(10.0)));
+    let result = executor
+        .submit(&mut portfolio, &signal, &ExecutionPrices::at(100.0))
+        .await
+        .unwrap();
+    assert!(result.is_none());
+    assert!(executor.has_pending_order());
+
+    // 区间未触及限价
+    let trades = executor
+        .on_prices(&mut portfolio, &ExecutionPrices::at(98.0).with_range(99.0, 96.0), 2000)
+        .await;
+    assert!(trades.is_empty());
+
+    // 最低价触及限价，按限价成交
+    let trades = executor
+        .on_prices(&mut portfolio, &ExecutionPrices::at(97.0).with_range(98.0, 94.0), 3000)
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(trades.len(), 1);
+    assert_eq!(trades[0].price, 95.0);
+    assert_eq!(trades[0].quantity, 10.0);
+    assert!(!executor.has_pending_order());
+}
+
+#[tokio::test]
+async fn test_stop_buy_and_new_signal_replaces_pending() {
+    let mut portfolio = BasePortfolio::new(10000.0);
+    let mut executor = IntentExecutor::new();
+    let prices = ExecutionPrices::at(100.0);
+
+    executor
+        .submit(&mut portfolio, &buy(Some(OrderIntent::stop(110.0))), &prices)
+        .await
+        .unwrap();
+    assert!(executor.has_pending_order());
+
+    // 新的买卖信号替换尚未触发的挂单
+    executor
+        .submit(&mut portfolio, &buy(Some(OrderIntent::stop(105.0).with_quantity(1.0))), &prices)
+        .await
+        .unwrap();
+
+    // 跳空高开越过触发价，按开盘价成交
+    let trades = executor
+        .on_prices(
+            &mut portfolio,
+            &ExecutionPrices::at(108.0).with_range(109.0, 107.5).with_open(107.0),
+            2000,
+        )
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(trades.len(), 1);
+    assert_eq!(trades[0].price, 107.0);
+    assert_eq!(trades[0].quantity, 1.0);
+}
+
+#[tokio::test]
+async fn test_take_profit_closes_position() {
+    let mut portfolio = BasePortfolio::new(10000.0);
+    let mut executor = IntentExecutor::new();
+
+    let intent = OrderIntent::market().with_weight(0.5).with_stop_loss(90.0).with_take_profit(120.0);
+    executor
+        .submit(&mut portfolio, &buy(Some(intent)), &ExecutionPrices::at(100.0))
+        .await
+        .unwrap();
+    assert_eq!(executor.stop_loss(), Some(90.0));
+    assert_eq!(executor.take_profit(), Some(120.0));
+
+    let trades = executor
+        .on_prices(&mut portfolio, &ExecutionPrices::at(118.0).with_range(121.0, 110.0), 2000)
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(trades.len(), 1);
+    assert!(!trades[0].is_buy());
+    assert_eq!(trades[0].price, 120.0);
+    assert_eq!(portfolio.get_position(), 0.0);
+    assert_eq!(executor.take_profit(), None);
+}
+
+#[tokio::test]
+async fn test_gap_through_protective_levels_fills_at_open() {
+    let mut portfolio = BasePortfolio::new(10000.0);
+    let mut executor = IntentExecutor::new();
+
+    // 跳空低开越过止损价，按更差的开盘价成交而不是止损价
+    let intent = OrderIntent::market().with_quantity(10.0).with_stop_loss(95.0);
+    executor
+        .submit(&mut portfolio, &buy(Some(intent)), &ExecutionPrices::at(100.0))
+        .await
+        .unwrap();
+    let trades = executor
+        .on_prices(&mut portfolio, &ExecutionPrices::at(91.0).with_range(93.0, 89.0).with_open(90.0), 2000)
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(trades.len(), 1);
+    assert_eq!(trades[0].price, 90.0);
+    assert_eq!(portfolio.get_position(), 0.0);
+
+    // 跳空高开越过止盈价，按开盘价成交
+    let intent = OrderIntent::market().with_quantity(10.0).with_take_profit(110.0);
+    executor
+        .submit(&mut portfolio, &buy(Some(intent)), &ExecutionPrices::at(100.0))
+        .await
+        .unwrap();
+    let trades = executor
+        .on_prices(&mut portfolio, &ExecutionPrices::at(113.0).with_range(115.0, 111.0).with_open(112.0), 3000)
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(trades.len(), 1);
+    assert_eq!(trades[0].price, 112.0);
+
+    // 开盘价未越过止损价时仍按止损价成交
+    let intent = OrderIntent::market().with_quantity(10.0).with_stop_loss(95.0);
+    executor
+        .submit(&mut portfolio, &buy(Some(intent)), &ExecutionPrices::at(100.0))
+        .await
+        .unwrap();
+    let trades = executor
+        .on_prices(&mut portfolio, &ExecutionPrices::at(94.0).with_range(99.0, 93.0).with_open(98.0), 4000)
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(trades[0].price, 95.0);
+}
+
+#[tokio::test]
+async fn test_hold_signal_does_nothing() {
+    let mut portfolio = BasePortfolio::new(10000.0);
+    let mut executor = IntentExecutor::new();
+    let signal = SignalEvent::new(Signal::Hold, 100.0, 1000);
+
+    let result = executor
+        .submit(&mut portfolio, &signal, &ExecutionPrices::at(100.0))
+        .await
+        .unwrap();
+    assert!(result.is_none());
+    assert!(portfolio.get_trades().is_empty());
+}
+
+#[tokio::test]
+async fn test_rejected_order_reports_reason() {
+    let mut portfolio = BasePortfolio::new(10000.0);
+    let mut executor = IntentExecutor::new();
+
+    // 无持仓时卖出被拒绝
+    let rejection = executor
+        .submit(&mut portfolio, &sell(None), &ExecutionPrices::at(100.0))
+        .await
+        .unwrap_err();
+
+    assert_eq!(rejection.signal, Signal::Sell);
+    assert_eq!(rejection.price, 100.0);
+    assert!(rejection.reason.contains("无持仓"));
+}
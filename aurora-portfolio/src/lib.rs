@@ -6,6 +6,7 @@
 //! # 主要功能
 //!
 //! - **交易执行**: 买入/卖出操作的统一接口,支持多种订单类型
+//! - **订单意图**: 按数量或权益比例下单,支持限价、止损触发单和止损止盈价
 //! - **权益管理**: 实时计算总权益、现金余额、持仓价值
 //! - **风险控制**: 最大回撤、止损止盈、连续亏损限制等多层风险管理
 //! - **仓位管理**: 固定金额、固定比例、Kelly准则、金字塔加仓等多种策略
@@ -14,6 +15,11 @@
 //! - **订单簿模拟**: 完整的订单簿和撮合引擎实现
 //! - **交易成本**: 支持多种手续费和滑点模型
 //!
+//! # 特性
+//!
+//! - `decimal`: 现金、持仓和手续费以十进制定点数记账（见 [`aurora_core::Amount`]），
+//!   消除长时间回测中的浮点零头，余额可与交易所对账单逐位核对
+//!
 //! # 使用示例
 //!
 //! ## 基础交易
@@ -127,21 +133,22 @@
 mod analytics;
 mod broker;
 mod fees;
+mod intent_executor;
 mod order;
 mod order_book;
 mod paper_broker;
 mod portfolio;
 mod position_manager;
 mod risk_manager;
-mod trade;
 
 pub use analytics::{EquityPoint, PerformanceMetrics, PortfolioAnalytics};
+pub use aurora_core::{Instrument, InstrumentError, Trade, TradeBuilder, TradeSide};
 pub use broker::Broker;
 pub use fees::{FeeModel, SlippageModel, TradeCost, TradeCostCalculator};
+pub use intent_executor::{ExecutionPrices, IntentExecutor};
 pub use order::{Order, OrderSide, OrderStatus, OrderType};
 pub use order_book::{MatchingEngine, OrderBook};
 pub use paper_broker::PaperBroker;
 pub use portfolio::{BasePortfolio, Portfolio};
 pub use position_manager::{PositionManager, PositionSizingStrategy};
 pub use risk_manager::{RiskCheckResult, RiskManager, RiskRules};
-pub use trade::{Trade, TradeBuilder, TradeSide};
@@ -110,6 +110,10 @@ pub struct Order {
     
     /// 备注信息
     pub note: Option<String>,
+
+    /// 过期时间戳(Unix毫秒),到达该时间仍未成交的订单会被撤销
+    #[serde(default)]
+    pub expires_at: Option<i64>,
 }
 
 impl Order {
@@ -153,6 +157,7 @@ impl Order {
             executed_price: None,
             executed_at: None,
             note: None,
+            expires_at: None,
         }
     }
 
@@ -240,6 +245,27 @@ impl Order {
         self.note = Some(note);
         self
     }
+
+    /// 设置过期时间戳(Unix毫秒)
+    pub fn with_expiry(mut self, expires_at: i64) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// 检查订单在给定时间是否已过期
+    ///
+    /// 只有尚未成交或撤销的订单会过期。
+    pub fn is_expired(&self, now: i64) -> bool {
+        matches!(self.status, OrderStatus::Pending | OrderStatus::Triggered)
+            && self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+
+    /// 将订单标记为已过期
+    pub fn expire(&mut self) {
+        if self.status == OrderStatus::Pending || self.status == OrderStatus::Triggered {
+            self.status = OrderStatus::Expired;
+        }
+    }
 }
 
 #[cfg(test)]
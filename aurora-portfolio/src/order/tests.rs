@@ -261,3 +261,21 @@ fn test_order_status_equality() {
     assert_eq!(OrderStatus::Executed, OrderStatus::Executed);
     assert_ne!(OrderStatus::Pending, OrderStatus::Executed);
 }
+
+#[test]
+fn test_order_expiry() {
+    let mut order = Order::new(OrderType::Limit(100.0), OrderSide::Buy, 1.0, 1000)
+        .with_expiry(5000);
+
+    assert!(!order.is_expired(4999));
+    assert!(order.is_expired(5000));
+
+    order.expire();
+    assert_eq!(order.status, OrderStatus::Expired);
+    // 已过期的订单不会再次过期,也不会被触发
+    assert!(!order.is_expired(6000));
+    assert!(!order.should_trigger(90.0));
+
+    let order = Order::new(OrderType::Limit(100.0), OrderSide::Buy, 1.0, 1000);
+    assert!(!order.is_expired(i64::MAX));
+}
@@ -19,9 +19,10 @@
 
 use std::collections::{BTreeMap, HashMap};
 use anyhow::{Result, anyhow};
+use aurora_core::{Instrument, InstrumentError, Trade, TradeBuilder, TradeSide};
+use tracing::debug;
 
 use crate::order::{Order, OrderType};
-use crate::trade::{Trade, TradeSide, TradeBuilder};
 
 /// 订单簿
 ///
@@ -101,9 +102,9 @@ impl OrderBook {
             OrderType::Limit(price) => {
                 let price_key = OrderedFloat(*price);
                 if order.is_buy() {
-                    self.bids.entry(price_key).or_insert_with(Vec::new).push(order.clone());
+                    self.bids.entry(price_key).or_default().push(order.clone());
                 } else {
-                    self.asks.entry(price_key).or_insert_with(Vec::new).push(order.clone());
+                    self.asks.entry(price_key).or_default().push(order.clone());
                 }
             }
             OrderType::StopLoss(_) | OrderType::TakeProfit(_) => {
@@ -160,6 +161,28 @@ impl OrderBook {
         Ok(order)
     }
 
+    /// 撤销在给定时间已过期的订单
+    ///
+    /// # 返回值
+    ///
+    /// 返回被撤销的订单,状态为 `Expired`
+    pub fn expire_orders(&mut self, now: i64) -> Result<Vec<Order>> {
+        let expired_ids: Vec<String> = self
+            .order_index
+            .values()
+            .filter(|order| order.is_expired(now))
+            .map(|order| order.id.clone())
+            .collect();
+
+        let mut expired = Vec::with_capacity(expired_ids.len());
+        for order_id in expired_ids {
+            let mut order = self.cancel_order(&order_id)?;
+            order.expire();
+            expired.push(order);
+        }
+        Ok(expired)
+    }
+
     /// 获取订单
     ///
     /// # 参数
@@ -239,6 +262,8 @@ pub struct MatchingEngine {
     order_books: HashMap<String, OrderBook>,
     /// 各交易对的当前价格
     current_prices: HashMap<String, f64>,
+    /// 各交易对的交易品种规则
+    instruments: HashMap<String, Instrument>,
 }
 
 impl MatchingEngine {
@@ -247,9 +272,85 @@ impl MatchingEngine {
         Self {
             order_books: HashMap::new(),
             current_prices: HashMap::new(),
+            instruments: HashMap::new(),
         }
     }
 
+    /// 注册交易品种规则
+    ///
+    /// 注册后，提交到 `instrument.symbol` 的订单会先按规则取整价格和数量，
+    /// 不满足最小数量或最小名义金额的订单会被拒绝。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use aurora_core::Instrument;
+    /// use aurora_portfolio::{MatchingEngine, Order, OrderSide, OrderType};
+    ///
+    /// let mut engine = MatchingEngine::new();
+    /// engine.register_instrument(
+    ///     Instrument::new("BTC/USDT", "BTC", "USDT").with_tick_size(0.5).with_step_size(0.01),
+    /// );
+    ///
+    /// let order = Order::new(OrderType::Limit(100.3), OrderSide::Buy, 1.234, 0);
+    /// let order_id = order.id.clone();
+    /// engine.submit_order("BTC/USDT", order).unwrap();
+    ///
+    /// let stored = engine.get_order("BTC/USDT", &order_id).unwrap();
+    /// assert_eq!(stored.order_type, OrderType::Limit(100.5));
+    /// assert_eq!(stored.quantity, 1.23);
+    /// ```
+    pub fn register_instrument(&mut self, instrument: Instrument) {
+        self.instruments.insert(instrument.symbol.clone(), instrument);
+    }
+
+    /// 获取交易对的交易品种规则
+    pub fn get_instrument(&self, symbol: &str) -> Option<&Instrument> {
+        self.instruments.get(symbol)
+    }
+
+    /// 按交易品种规则取整并校验订单
+    ///
+    /// 市价单使用当前市场价格校验最小名义金额；尚无市场价格时只校验数量。
+    fn normalize_order(&self, symbol: &str, mut order: Order) -> Result<Order> {
+        let Some(instrument) = self.instruments.get(symbol) else {
+            return Ok(order);
+        };
+
+        order.order_type = match order.order_type {
+            OrderType::Market => OrderType::Market,
+            OrderType::Limit(price) => OrderType::Limit(instrument.round_price(price)),
+            OrderType::StopLoss(price) => OrderType::StopLoss(instrument.round_price(price)),
+            OrderType::TakeProfit(price) => OrderType::TakeProfit(instrument.round_price(price)),
+        };
+        order.quantity = instrument.round_quantity(order.quantity);
+
+        let reference_price = match order.order_type {
+            OrderType::Market => self
+                .current_prices
+                .get(symbol)
+                .map(|price| instrument.round_price(*price)),
+            OrderType::Limit(price) | OrderType::StopLoss(price) | OrderType::TakeProfit(price) => {
+                Some(price)
+            }
+        };
+
+        let check = match reference_price {
+            Some(price) => instrument.validate_order(price, order.quantity),
+            None if order.quantity <= 0.0 => Err(InstrumentError::InvalidQuantity(order.quantity)),
+            None if order.quantity < instrument.min_quantity => {
+                Err(InstrumentError::QuantityBelowMinimum {
+                    quantity: order.quantity,
+                    min_quantity: instrument.min_quantity,
+                })
+            }
+            None => Ok(()),
+        };
+        check.map_err(|e| anyhow!("订单不满足 {} 的交易规则: {}", symbol, e))?;
+
+        Ok(order)
+    }
+
     /// 获取或创建订单簿
     fn get_or_create_order_book(&mut self, symbol: &str) -> &mut OrderBook {
         self.order_books
@@ -268,13 +369,19 @@ impl MatchingEngine {
     ///
     /// 对于市价单,立即返回交易记录;对于其他类型订单,返回 None
     pub fn submit_order(&mut self, symbol: &str, order: Order) -> Result<Option<Trade>> {
+        let order = self.normalize_order(symbol, order)?;
+
         match order.order_type {
             OrderType::Market => {
                 // 市价单立即执行
                 let current_price = self.current_prices.get(symbol)
                     .ok_or_else(|| anyhow!("交易对 {} 的市场价格未设置", symbol))?;
                 
-                let trade = self.execute_market_order(symbol, order, *current_price)?;
+                let current_price = match self.instruments.get(symbol) {
+                    Some(instrument) => instrument.round_price(*current_price),
+                    None => *current_price,
+                };
+                let trade = self.execute_market_order(symbol, order, current_price)?;
                 Ok(Some(trade))
             }
             _ => {
@@ -313,7 +420,7 @@ impl MatchingEngine {
     ///
     /// # 返回值
     ///
-    /// 返回被触发并执行的交易列表
+    /// 返回被触发并执行的交易列表。撮合前先撤销在 `timestamp` 时已过期的订单
     pub fn update_price(&mut self, symbol: &str, price: f64, timestamp: i64) -> Result<Vec<Trade>> {
         // 更新当前价格
         self.current_prices.insert(symbol.to_string(), price);
@@ -323,6 +430,10 @@ impl MatchingEngine {
             .entry(symbol.to_string())
             .or_insert_with(|| OrderBook::new(symbol.to_string()));
 
+        for order in order_book.expire_orders(timestamp)? {
+            debug!("订单已过期: {}", order.id);
+        }
+
         let mut trades = Vec::new();
 
         // 检查并触发限价单
@@ -421,6 +532,15 @@ impl MatchingEngine {
         Ok(trades)
     }
 
+    /// 撤销所有交易对中在给定时间已过期的订单
+    pub fn expire_orders(&mut self, now: i64) -> Result<Vec<Order>> {
+        let mut expired = Vec::new();
+        for order_book in self.order_books.values_mut() {
+            expired.extend(order_book.expire_orders(now)?);
+        }
+        Ok(expired)
+    }
+
     /// 取消订单
     pub fn cancel_order(&mut self, symbol: &str, order_id: &str) -> Result<Order> {
         let order_book = self.order_books.get_mut(symbol)
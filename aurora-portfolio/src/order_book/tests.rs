@@ -14,6 +14,7 @@
 
 use super::*;
 use crate::order::{OrderSide, OrderType};
+use aurora_core::Instrument;
 
 #[test]
 fn test_order_book_add_limit_order() {
@@ -257,3 +258,61 @@ fn test_matching_engine_cancel_order() {
     assert!(engine.cancel_order("BTC/USDT", &order_id).is_ok());
     assert_eq!(engine.get_open_orders(Some("BTC/USDT")).len(), 0);
 }
+
+#[test]
+fn test_matching_engine_rounds_orders_with_instrument() {
+    let mut engine = MatchingEngine::new();
+    engine.register_instrument(
+        Instrument::new("BTC/USDT", "BTC", "USDT")
+            .with_tick_size(0.1)
+            .with_step_size(0.001),
+    );
+    engine.update_price("BTC/USDT", 50000.04, 1000).unwrap();
+
+    let order = Order::new(OrderType::Market, OrderSide::Buy, 0.12345, 1001);
+    let trade = engine.submit_order("BTC/USDT", order).unwrap().unwrap();
+
+    assert_eq!(trade.price, 50000.0);
+    assert_eq!(trade.quantity, 0.123);
+}
+
+#[test]
+fn test_matching_engine_rejects_orders_below_minimums() {
+    let mut engine = MatchingEngine::new();
+    engine.register_instrument(
+        Instrument::new("BTC/USDT", "BTC", "USDT")
+            .with_step_size(0.001)
+            .with_min_quantity(0.01)
+            .with_min_notional(100.0),
+    );
+
+    // 尚无市场价格时，市价单只校验数量
+    let order = Order::new(OrderType::Market, OrderSide::Buy, 0.005, 1000);
+    assert!(engine.submit_order("BTC/USDT", order).is_err());
+
+    // 限价单按限价校验名义金额：0.01 * 5000 = 50 < 100
+    let order = Order::new(OrderType::Limit(5000.0), OrderSide::Buy, 0.01, 1000);
+    assert!(engine.submit_order("BTC/USDT", order).is_err());
+    assert!(engine.get_open_orders(Some("BTC/USDT")).is_empty());
+
+    // 其他交易对不受影响
+    let order = Order::new(OrderType::Limit(5000.0), OrderSide::Buy, 0.01, 1000);
+    assert!(engine.submit_order("ETH/USDT", order).is_ok());
+}
+
+#[test]
+fn test_matching_engine_expires_orders_before_matching() {
+    let mut engine = MatchingEngine::new();
+    engine.update_price("BTC/USDT", 110.0, 1000).unwrap();
+
+    let expiring = Order::new(OrderType::Limit(100.0), OrderSide::Buy, 1.0, 1000).with_expiry(2000);
+    let lasting = Order::new(OrderType::Limit(100.0), OrderSide::Buy, 2.0, 1000);
+    engine.submit_order("BTC/USDT", expiring).unwrap();
+    engine.submit_order("BTC/USDT", lasting).unwrap();
+
+    // 价格在过期之后才触及限价,只有未过期的订单成交
+    let trades = engine.update_price("BTC/USDT", 99.0, 2000).unwrap();
+    assert_eq!(trades.len(), 1);
+    assert_eq!(trades[0].quantity, 2.0);
+    assert!(engine.get_open_orders(None).is_empty());
+}
@@ -18,19 +18,21 @@
 //! 用于回测和策略验证,无需连接真实交易所。
 
 use std::collections::HashMap;
+use std::sync::Arc;
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
+use aurora_core::{AMOUNT_ZERO, Amount, Clock, Instrument, Trade, amount_to_f64, to_amount};
 
 use crate::broker::Broker;
 use crate::order::{Order, OrderStatus};
 use crate::order_book::MatchingEngine;
 use crate::fees::{TradeCostCalculator, FeeModel, SlippageModel};
-use crate::trade::Trade;
 
 /// 模拟交易经纪商
 ///
 /// 提供与真实经纪商相同的接口,但所有交易都在内存中模拟执行。
 /// 支持完整的订单簿模拟、手续费和滑点计算。
+/// 余额和持仓以 [`Amount`] 记账,启用 `decimal` 特性后可精确对账。
 ///
 /// # 示例
 ///
@@ -67,13 +69,15 @@ pub struct PaperBroker {
     /// 交易成本计算器
     cost_calculator: TradeCostCalculator,
     /// 账户余额: 资产符号 -> 余额
-    balances: HashMap<String, f64>,
+    balances: HashMap<String, Amount>,
     /// 持仓信息: 交易对符号 -> 持仓数量
-    positions: HashMap<String, f64>,
+    positions: HashMap<String, Amount>,
     /// 交易历史
     trade_history: Vec<Trade>,
     /// 是否启用手续费和滑点
     enable_costs: bool,
+    /// 时钟(可选,用于判断订单过期)
+    clock: Option<Arc<dyn Clock>>,
 }
 
 impl PaperBroker {
@@ -88,6 +92,7 @@ impl PaperBroker {
             positions: HashMap::new(),
             trade_history: Vec::new(),
             enable_costs: true,
+            clock: None,
         }
     }
 
@@ -98,10 +103,20 @@ impl PaperBroker {
     /// * `asset` - 资产符号,如 "USDT", "BTC"
     /// * `amount` - 余额数量
     pub fn with_balance(mut self, asset: &str, amount: f64) -> Self {
-        self.balances.insert(asset.to_string(), amount);
+        self.balances.insert(asset.to_string(), to_amount(amount));
         self
     }
 
+    /// 获取精确的账户余额
+    pub fn balance_amount(&self, asset: &str) -> Amount {
+        self.balances.get(asset).copied().unwrap_or(AMOUNT_ZERO)
+    }
+
+    /// 获取精确的持仓数量
+    pub fn position_amount(&self, symbol: &str) -> Amount {
+        self.positions.get(symbol).copied().unwrap_or(AMOUNT_ZERO)
+    }
+
     /// 设置手续费模型
     pub fn with_fee_model(mut self, fee_model: FeeModel) -> Self {
         let slippage_model = self.cost_calculator.slippage_model().clone();
@@ -116,19 +131,69 @@ impl PaperBroker {
         self
     }
 
+    /// 设置交易品种规则
+    ///
+    /// 规则按 `instrument.symbol` 注册到撮合引擎，
+    /// 提交到该交易对的订单会按 tick size 和 step size 取整，
+    /// 并校验最小数量和最小名义金额。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use aurora_core::Instrument;
+    /// use aurora_portfolio::PaperBroker;
+    ///
+    /// let broker = PaperBroker::new()
+    ///     .with_balance("USDT", 10000.0)
+    ///     .with_instrument(
+    ///         Instrument::new("BTC/USDT", "BTC", "USDT")
+    ///             .with_tick_size(0.01)
+    ///             .with_step_size(0.00001)
+    ///             .with_min_notional(5.0),
+    ///     );
+    /// ```
+    pub fn with_instrument(mut self, instrument: Instrument) -> Self {
+        self.matching_engine.register_instrument(instrument);
+        self
+    }
+
     /// 启用或禁用手续费和滑点
     pub fn set_enable_costs(mut self, enable: bool) -> Self {
         self.enable_costs = enable;
         self
     }
 
+    /// 设置时钟
+    ///
+    /// 设置后,提交时已过期的订单会被拒绝,
+    /// 并可通过 [`expire_orders`](Self::expire_orders) 按时钟撤销过期挂单。
+    /// 价格更新时始终按更新的时间戳撤销过期挂单。
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// 按时钟撤销所有已过期的挂单
+    ///
+    /// 未设置时钟时不做任何处理。
+    ///
+    /// # 返回值
+    ///
+    /// 返回被撤销的订单
+    pub fn expire_orders(&mut self) -> Result<Vec<Order>> {
+        match self.clock {
+            Some(ref clock) => self.matching_engine.expire_orders(clock.now()),
+            None => Ok(Vec::new()),
+        }
+    }
+
     /// 检查并更新余额(买入)
     fn check_and_update_balance_buy(
         &mut self,
         quote_asset: &str,
-        cost: f64,
+        cost: Amount,
     ) -> Result<()> {
-        let balance = self.balances.get(quote_asset).copied().unwrap_or(0.0);
+        let balance = self.balance_amount(quote_asset);
         if balance < cost {
             return Err(anyhow!(
                 "余额不足: 需要 {} {},当前余额 {}",
@@ -146,11 +211,11 @@ impl PaperBroker {
         &mut self,
         symbol: &str,
         quote_asset: &str,
-        quantity: f64,
-        proceeds: f64,
+        quantity: Amount,
+        proceeds: Amount,
     ) -> Result<()> {
         // 检查持仓
-        let position = self.positions.get(symbol).copied().unwrap_or(0.0);
+        let position = self.position_amount(symbol);
         if position < quantity {
             return Err(anyhow!(
                 "持仓不足: 需要 {} {},当前持仓 {}",
@@ -164,15 +229,15 @@ impl PaperBroker {
         self.positions.insert(symbol.to_string(), position - quantity);
 
         // 更新余额
-        let balance = self.balances.get(quote_asset).copied().unwrap_or(0.0);
+        let balance = self.balance_amount(quote_asset);
         self.balances.insert(quote_asset.to_string(), balance + proceeds);
 
         Ok(())
     }
 
     /// 更新持仓(买入)
-    fn update_position_buy(&mut self, symbol: &str, quantity: f64) {
-        let position = self.positions.get(symbol).copied().unwrap_or(0.0);
+    fn update_position_buy(&mut self, symbol: &str, quantity: Amount) {
+        let position = self.position_amount(symbol);
         self.positions.insert(symbol.to_string(), position + quantity);
     }
 
@@ -219,34 +284,24 @@ impl PaperBroker {
             // 更新交易记录中的价格和手续费
             trade.price = cost.executed_price;
             trade.fee = Some(cost.fee);
+        }
 
-            // 更新余额和持仓
-            if is_buy {
-                self.check_and_update_balance_buy(&quote_asset, cost.total_cost.abs())?;
-                self.update_position_buy(symbol, trade.quantity);
-            } else {
-                let proceeds = cost.total_cost.abs();
-                self.update_balance_and_position_sell(
-                    symbol,
-                    &quote_asset,
-                    trade.quantity,
-                    proceeds,
-                )?;
-            }
+        // 由成交价、数量和手续费重新计算记账金额,避免累计 f64 误差
+        let quantity = to_amount(trade.quantity);
+        let trade_value = to_amount(trade.price) * quantity;
+        let fee = to_amount(trade.fee.unwrap_or(0.0));
+
+        // 更新余额和持仓
+        if is_buy {
+            self.check_and_update_balance_buy(&quote_asset, trade_value + fee)?;
+            self.update_position_buy(symbol, quantity);
         } else {
-            // 不启用成本时,直接更新余额和持仓
-            let trade_value = trade.price * trade.quantity;
-            if is_buy {
-                self.check_and_update_balance_buy(&quote_asset, trade_value)?;
-                self.update_position_buy(symbol, trade.quantity);
-            } else {
-                self.update_balance_and_position_sell(
-                    symbol,
-                    &quote_asset,
-                    trade.quantity,
-                    trade_value,
-                )?;
-            }
+            self.update_balance_and_position_sell(
+                symbol,
+                &quote_asset,
+                quantity,
+                trade_value - fee,
+            )?;
         }
 
         Ok(trade)
@@ -259,6 +314,10 @@ impl Broker for PaperBroker {
         let order_id = order.id.clone();
         let is_buy = order.is_buy();
 
+        if self.clock.as_ref().is_some_and(|clock| order.is_expired(clock.now())) {
+            return Err(anyhow!("订单已过期: {}", order_id));
+        }
+
         // 提交订单到撮合引擎
         let trade_opt = self.matching_engine.submit_order(symbol, order)?;
 
@@ -317,7 +376,7 @@ impl Broker for PaperBroker {
         // 对所有触发的交易应用成本
         let mut processed_trades = Vec::new();
         for trade in trades.drain(..) {
-            let is_buy = trade.side == crate::trade::TradeSide::Buy;
+            let is_buy = trade.side == aurora_core::TradeSide::Buy;
             let processed_trade = self.execute_trade_with_costs(symbol, trade, is_buy)?;
             self.trade_history.push(processed_trade.clone());
             processed_trades.push(processed_trade);
@@ -332,11 +391,11 @@ impl Broker for PaperBroker {
     }
 
     async fn get_balance(&self, asset: &str) -> Result<f64> {
-        Ok(self.balances.get(asset).copied().unwrap_or(0.0))
+        Ok(amount_to_f64(self.balance_amount(asset)))
     }
 
     async fn get_position(&self, symbol: &str) -> Result<f64> {
-        Ok(self.positions.get(symbol).copied().unwrap_or(0.0))
+        Ok(amount_to_f64(self.position_amount(symbol)))
     }
 }
 
@@ -256,3 +256,74 @@ async fn test_paper_broker_multiple_symbols() {
     let balance = broker.get_balance("USDT").await.unwrap();
     assert_eq!(balance, 12000.0); // 20000 - 5000 - 3000
 }
+
+#[tokio::test]
+async fn test_paper_broker_applies_instrument_rules() {
+    let mut broker = PaperBroker::new()
+        .with_balance("USDT", 10000.0)
+        .set_enable_costs(false)
+        .with_instrument(
+            aurora_core::Instrument::new("BTC/USDT", "BTC", "USDT")
+                .with_tick_size(0.01)
+                .with_step_size(0.001)
+                .with_min_notional(10.0),
+        );
+
+    broker.update_market_price("BTC/USDT", 50000.0, 1000).await.unwrap();
+
+    // 数量向下取整到步长
+    let order = Order::new(OrderType::Market, OrderSide::Buy, 0.1009, 1001);
+    broker.submit_order("BTC/USDT", order).await.unwrap();
+    assert_eq!(broker.get_position("BTC/USDT").await.unwrap(), 0.1);
+
+    // 名义金额不足的订单被拒绝，余额不变
+    let balance_before = broker.get_balance("USDT").await.unwrap();
+    let order = Order::new(OrderType::Market, OrderSide::Buy, 0.0001, 1002);
+    assert!(broker.submit_order("BTC/USDT", order).await.is_err());
+    assert_eq!(broker.get_balance("USDT").await.unwrap(), balance_before);
+}
+
+#[cfg(feature = "decimal")]
+#[tokio::test]
+async fn test_decimal_balance_reconciles() {
+    let mut broker = PaperBroker::new()
+        .with_balance("USDT", 100.0)
+        .with_fee_model(FeeModel::Percentage(0.1))
+        .with_slippage_model(SlippageModel::None);
+
+    broker.update_market_price("BTC/USDT", 0.3, 1000).await.unwrap();
+    for i in 0..10 {
+        let order = Order::new(OrderType::Market, OrderSide::Buy, 10.0, 1001 + i);
+        broker.submit_order("BTC/USDT", order).await.unwrap();
+    }
+
+    // 10 笔 3 USDT 的买入，每笔手续费 0.003 USDT
+    assert_eq!(broker.balance_amount("USDT"), aurora_core::to_amount(69.97));
+    assert_eq!(broker.position_amount("BTC/USDT"), aurora_core::to_amount(100.0));
+}
+
+#[tokio::test]
+async fn test_paper_broker_expires_orders_by_clock() {
+    use aurora_core::SimulatedClock;
+    use std::sync::Arc;
+
+    let clock = Arc::new(SimulatedClock::new(1000));
+    let mut broker = PaperBroker::new()
+        .with_balance("USDT", 10000.0)
+        .set_enable_costs(false)
+        .with_clock(clock.clone());
+    broker.update_market_price("BTC/USDT", 110.0, 1000).await.unwrap();
+
+    let order = Order::new(OrderType::Limit(100.0), OrderSide::Buy, 1.0, 1000).with_expiry(3000);
+    let order_id = broker.submit_order("BTC/USDT", order).await.unwrap();
+
+    clock.advance_to(3000);
+    let expired = broker.expire_orders().unwrap();
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].id, order_id);
+    assert!(broker.get_open_orders(None).await.unwrap().is_empty());
+
+    // 提交时已过期的订单被拒绝
+    let order = Order::new(OrderType::Limit(100.0), OrderSide::Buy, 1.0, 3000).with_expiry(2000);
+    assert!(broker.submit_order("BTC/USDT", order).await.is_err());
+}
@@ -16,12 +16,12 @@
 
 use anyhow::Result;
 use async_trait::async_trait;
+use aurora_core::{AMOUNT_ZERO, Amount, Instrument, Trade, amount_to_f64, to_amount};
 use tracing::{debug, info, warn};
 
 use crate::analytics::{EquityPoint, PerformanceMetrics, PortfolioAnalytics};
 use crate::position_manager::PositionManager;
 use crate::risk_manager::{RiskCheckResult, RiskManager};
-use crate::trade::Trade;
 
 /// 投资组合管理统一接口
 ///
@@ -53,6 +53,28 @@ pub trait Portfolio: Send + Sync {
     /// 成功时返回交易记录，失败时返回错误信息
     async fn execute_sell(&mut self, price: f64, timestamp: i64) -> Result<Trade>;
 
+    /// 按指定数量买入
+    ///
+    /// 数量超过可用现金能买入的上限时按上限成交。
+    ///
+    /// # 参数
+    ///
+    /// * `price` - 买入价格
+    /// * `quantity` - 买入数量
+    /// * `timestamp` - 交易时间戳
+    async fn execute_buy_quantity(&mut self, price: f64, quantity: f64, timestamp: i64) -> Result<Trade>;
+
+    /// 按指定数量卖出
+    ///
+    /// 数量超过当前持仓时卖出全部持仓。
+    ///
+    /// # 参数
+    ///
+    /// * `price` - 卖出价格
+    /// * `quantity` - 卖出数量
+    /// * `timestamp` - 交易时间戳
+    async fn execute_sell_quantity(&mut self, price: f64, quantity: f64, timestamp: i64) -> Result<Trade>;
+
     /// 获取总权益
     ///
     /// # 参数
@@ -86,18 +108,57 @@ pub trait Portfolio: Send + Sync {
 
     /// 计算业绩指标
     fn calculate_performance(&self, time_period_days: f64) -> PerformanceMetrics;
+
+    /// 获取交易品种规则（如果已设置）
+    ///
+    /// 默认实现返回 None。
+    fn instrument(&self) -> Option<&Instrument> {
+        None
+    }
+
+    /// 设置交易品种规则
+    ///
+    /// 默认实现返回错误，表示该投资组合不支持品种规则。
+    fn set_instrument(&mut self, instrument: Instrument) -> Result<()> {
+        Err(anyhow::anyhow!("投资组合不支持交易品种规则: {}", instrument.symbol))
+    }
+
+    /// 获取风险管理器的可变引用（如果存在）
+    ///
+    /// 默认实现返回 None，回测引擎据此跳过止损止盈设置。
+    fn get_risk_manager_mut(&mut self) -> Option<&mut RiskManager> {
+        None
+    }
+
+    /// 设置风险管理器，替换原有的风险管理器
+    ///
+    /// 默认实现返回错误，表示该投资组合不支持风险管理。
+    fn set_risk_manager(&mut self, _risk_manager: RiskManager) -> Result<()> {
+        Err(anyhow::anyhow!("投资组合不支持风险管理"))
+    }
+
+    /// 设置仓位管理器，替换原有的仓位管理器
+    ///
+    /// 默认实现返回错误，表示该投资组合不支持仓位管理。
+    fn set_position_manager(&mut self, _position_manager: PositionManager) -> Result<()> {
+        Err(anyhow::anyhow!("投资组合不支持仓位管理"))
+    }
 }
 
 /// 基础投资组合实现
 ///
 /// 提供投资组合管理的标准实现，适用于大多数场景。
 /// 支持可选的风险管理和仓位管理功能。
+///
+/// 现金和持仓以 [`Amount`] 记账，启用 `decimal` 特性后成交金额按十进制精确累计，
+/// 可通过 [`cash_amount`](Self::cash_amount) 和 [`position_amount`](Self::position_amount)
+/// 读取未经 `f64` 转换的精确余额。
 #[derive(Debug, Clone)]
 pub struct BasePortfolio {
     /// 现金余额
-    cash: f64,
+    cash: Amount,
     /// 持仓数量
-    position: f64,
+    position: Amount,
     /// 初始权益
     initial_equity: f64,
     /// 交易记录
@@ -114,6 +175,8 @@ pub struct BasePortfolio {
     entry_price: Option<f64>,
     /// 上次警告的回撤值（用于限制日志输出频率）
     last_warned_drawdown: f64,
+    /// 交易品种规则（可选，设置后按其取整和校验订单）
+    instrument: Option<Instrument>,
 }
 
 impl BasePortfolio {
@@ -134,8 +197,8 @@ impl BasePortfolio {
     /// ```
     pub fn new(initial_cash: f64) -> Self {
         Self {
-            cash: initial_cash,
-            position: 0.0,
+            cash: to_amount(initial_cash),
+            position: AMOUNT_ZERO,
             initial_equity: initial_cash,
             trades: Vec::new(),
             equity_curve: Vec::new(),
@@ -144,6 +207,7 @@ impl BasePortfolio {
             position_manager: None,
             entry_price: None,
             last_warned_drawdown: 0.0,
+            instrument: None,
         }
     }
 
@@ -189,6 +253,44 @@ impl BasePortfolio {
         self
     }
 
+    /// 设置交易品种规则
+    ///
+    /// 设置后，成交价格取整到 tick size，成交数量向下取整到 step size，
+    /// 不满足最小数量或最小名义金额的订单会被拒绝。
+    ///
+    /// # 参数
+    ///
+    /// * `instrument` - 交易品种规则
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use aurora_core::Instrument;
+    /// use aurora_portfolio::{BasePortfolio, Portfolio};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> anyhow::Result<()> {
+    /// let instrument = Instrument::new("BTCUSDT", "BTC", "USDT")
+    ///     .with_tick_size(0.01)
+    ///     .with_step_size(0.001);
+    /// let mut portfolio = BasePortfolio::new(10000.0).with_instrument(instrument);
+    ///
+    /// let trade = portfolio.execute_buy(30000.004, 1640995200000).await?;
+    /// assert_eq!(trade.price, 30000.0);
+    /// assert_eq!(trade.quantity, 0.333);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_instrument(mut self, instrument: Instrument) -> Self {
+        self.instrument = Some(instrument);
+        self
+    }
+
+    /// 获取交易品种规则（如果已设置）
+    pub fn instrument(&self) -> Option<&Instrument> {
+        self.instrument.as_ref()
+    }
+
     /// 获取风险管理器的可变引用（如果存在）
     ///
     /// # 返回值
@@ -198,6 +300,16 @@ impl BasePortfolio {
         self.risk_manager.as_mut()
     }
 
+    /// 获取精确的现金余额
+    pub fn cash_amount(&self) -> Amount {
+        self.cash
+    }
+
+    /// 获取精确的持仓数量
+    pub fn position_amount(&self) -> Amount {
+        self.position
+    }
+
     /// 检查是否可以买入
     ///
     /// # 参数
@@ -208,12 +320,12 @@ impl BasePortfolio {
     ///
     /// 如果现金足够买入至少最小单位，返回true
     fn can_buy(&self, price: f64) -> bool {
-        self.cash > price * 0.001 // 最小买入单位
+        self.get_cash() > price * 0.001 // 最小买入单位
     }
 
     /// 检查是否可以卖出
     fn can_sell(&self) -> bool {
-        self.position > 0.0
+        self.position > AMOUNT_ZERO
     }
 
     /// 计算买入数量
@@ -230,16 +342,17 @@ impl BasePortfolio {
         };
         
         // 如果有仓位管理器，使用它计算应使用的资金
+        let cash = self.get_cash();
         let position_value = if let Some(ref pm) = self.position_manager {
             pm.calculate_position_size(total_equity, current_profit)
-                .unwrap_or(self.cash)
+                .unwrap_or(cash)
         } else {
             // 默认全仓
-            self.cash
+            cash
         };
         
         // 确保不超过可用现金
-        let position_value = position_value.min(self.cash);
+        let position_value = position_value.min(cash);
         position_value / price
     }
 
@@ -247,24 +360,28 @@ impl BasePortfolio {
     ///
     /// 默认卖出全部持仓
     fn calculate_sell_quantity(&self) -> f64 {
-        self.position
+        self.get_position()
     }
 
-    /// 验证交易参数
-    fn validate_trade_params(&self, price: f64, timestamp: i64) -> Result<()> {
-        if price <= 0.0 {
-            return Err(anyhow::anyhow!("价格必须大于0"));
-        }
-        if timestamp < 0 {
-            return Err(anyhow::anyhow!("时间戳不能为负数"));
+    /// 按交易品种规则取整并校验成交价格和数量
+    ///
+    /// 未设置交易品种时原样返回。
+    fn normalize_fill(&self, price: f64, quantity: f64) -> Result<(f64, f64)> {
+        match self.instrument {
+            Some(ref instrument) => instrument
+                .normalize_order(price, quantity)
+                .map_err(|e| anyhow::anyhow!("订单不满足交易规则: {}", e)),
+            None => Ok((price, quantity)),
         }
-        Ok(())
     }
-}
 
-#[async_trait]
-impl Portfolio for BasePortfolio {
-    async fn execute_buy(&mut self, price: f64, timestamp: i64) -> Result<Trade> {
+    /// 买入前的参数校验和风险检查
+    ///
+    /// # 返回值
+    ///
+    /// 按交易规则取整后的买入价格。先取整价格再计算数量，
+    /// 保证取整后的成交金额不超过可用现金
+    fn prepare_buy(&mut self, price: f64, timestamp: i64) -> Result<f64> {
         self.validate_trade_params(price, timestamp)?;
 
         if !self.can_buy(price) {
@@ -290,11 +407,20 @@ impl Portfolio for BasePortfolio {
             }
         }
 
-        let quantity = self.calculate_buy_quantity(price);
-        let value = quantity * price;
+        Ok(self
+            .instrument
+            .as_ref()
+            .map_or(price, |instrument| instrument.round_price(price)))
+    }
+
+    /// 按给定价格和数量完成买入，更新持仓、现金和交易记录
+    fn fill_buy(&mut self, price: f64, quantity: f64, timestamp: i64) -> Result<Trade> {
+        let (price, quantity) = self.normalize_fill(price, quantity)?;
+        let quantity = self.affordable_quantity(price, quantity)?;
+        let value = to_amount(quantity) * to_amount(price);
 
         // 更新持仓和现金
-        self.position += quantity;
+        self.position += to_amount(quantity);
         self.cash -= value;
         
         // 记录入场价格（用于止损止盈）
@@ -316,7 +442,31 @@ impl Portfolio for BasePortfolio {
         Ok(trade)
     }
 
-    async fn execute_sell(&mut self, price: f64, timestamp: i64) -> Result<Trade> {
+    /// 把买入数量减到可用现金实际能买入的数量
+    ///
+    /// 按现金计算的数量经过浮点运算可能产生末位舍入，使成交金额略超出现金。
+    /// 这时逐步减小数量直到成交金额不超过现金，设置了品种规则时再按数量步长向下取整，
+    /// 保证扣除的现金等于增加的持仓价值。现金不足以买入任何数量时返回错误。
+    fn affordable_quantity(&self, price: f64, quantity: f64) -> Result<f64> {
+        let price_amount = to_amount(price);
+        let affordable = |quantity: f64| to_amount(quantity) * price_amount <= self.cash;
+        if affordable(quantity) {
+            return Ok(quantity);
+        }
+
+        let mut reduced = quantity.min(self.get_cash() / price);
+        while reduced > 0.0 && !affordable(reduced) {
+            reduced = reduced.next_down();
+        }
+        if reduced <= 0.0 {
+            return Err(anyhow::anyhow!("现金不足，无法买入"));
+        }
+        let (_, reduced) = self.normalize_fill(price, reduced)?;
+        Ok(reduced)
+    }
+
+    /// 卖出前的参数校验和风险检查
+    fn prepare_sell(&mut self, price: f64, timestamp: i64) -> Result<()> {
         self.validate_trade_params(price, timestamp)?;
 
         if !self.can_sell() {
@@ -348,8 +498,13 @@ impl Portfolio for BasePortfolio {
             }
         }
 
-        let quantity = self.calculate_sell_quantity();
-        let value = quantity * price;
+        Ok(())
+    }
+
+    /// 按给定价格和数量完成卖出，更新持仓、现金和交易记录
+    fn fill_sell(&mut self, price: f64, quantity: f64, timestamp: i64) -> Result<Trade> {
+        let (price, quantity) = self.normalize_fill(price, quantity)?;
+        let value = to_amount(quantity) * to_amount(price);
         
         // 计算本次交易的盈亏（用于风险管理器记录）
         let is_profitable = if let Some(entry) = self.entry_price {
@@ -360,10 +515,17 @@ impl Portfolio for BasePortfolio {
 
         // 更新持仓和现金
         self.cash += value;
-        self.position = 0.0; // 全部卖出
-        
-        // 清除入场价格
-        self.entry_price = None;
+        self.position -= to_amount(quantity);
+
+        // 按步长取整后可能留下无法交易的零头，全部卖出时视为平仓
+        if self.position <= to_amount(f64::EPSILON) {
+            self.position = AMOUNT_ZERO;
+        }
+
+        // 平仓后清除入场价格，部分卖出时保留
+        if self.position == AMOUNT_ZERO {
+            self.entry_price = None;
+        }
         
         // 记录交易结果到风险管理器
         if let Some(ref mut risk_mgr) = self.risk_manager {
@@ -386,16 +548,61 @@ impl Portfolio for BasePortfolio {
         Ok(trade)
     }
 
+    /// 验证交易参数
+    fn validate_trade_params(&self, price: f64, timestamp: i64) -> Result<()> {
+        if price <= 0.0 {
+            return Err(anyhow::anyhow!("价格必须大于0"));
+        }
+        if timestamp < 0 {
+            return Err(anyhow::anyhow!("时间戳不能为负数"));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Portfolio for BasePortfolio {
+    async fn execute_buy(&mut self, price: f64, timestamp: i64) -> Result<Trade> {
+        let price = self.prepare_buy(price, timestamp)?;
+        let quantity = self.calculate_buy_quantity(price);
+        self.fill_buy(price, quantity, timestamp)
+    }
+
+    async fn execute_sell(&mut self, price: f64, timestamp: i64) -> Result<Trade> {
+        self.prepare_sell(price, timestamp)?;
+        let quantity = self.calculate_sell_quantity();
+        self.fill_sell(price, quantity, timestamp)
+    }
+
+    async fn execute_buy_quantity(&mut self, price: f64, quantity: f64, timestamp: i64) -> Result<Trade> {
+        if quantity <= 0.0 {
+            return Err(anyhow::anyhow!("买入数量必须大于0"));
+        }
+        let price = self.prepare_buy(price, timestamp)?;
+        // 不超过可用现金能买入的数量
+        let quantity = quantity.min(self.get_cash() / price);
+        self.fill_buy(price, quantity, timestamp)
+    }
+
+    async fn execute_sell_quantity(&mut self, price: f64, quantity: f64, timestamp: i64) -> Result<Trade> {
+        if quantity <= 0.0 {
+            return Err(anyhow::anyhow!("卖出数量必须大于0"));
+        }
+        self.prepare_sell(price, timestamp)?;
+        let quantity = quantity.min(self.get_position());
+        self.fill_sell(price, quantity, timestamp)
+    }
+
     fn get_total_equity(&self, current_price: f64) -> f64 {
-        self.cash + (self.position * current_price)
+        amount_to_f64(self.cash + self.position * to_amount(current_price))
     }
 
     fn get_cash(&self) -> f64 {
-        self.cash
+        amount_to_f64(self.cash)
     }
 
     fn get_position(&self) -> f64 {
-        self.position
+        amount_to_f64(self.position)
     }
 
     fn get_trades(&self) -> &[Trade] {
@@ -453,6 +660,29 @@ impl Portfolio for BasePortfolio {
             time_period_days,
         )
     }
+
+    fn instrument(&self) -> Option<&Instrument> {
+        self.instrument.as_ref()
+    }
+
+    fn set_instrument(&mut self, instrument: Instrument) -> Result<()> {
+        self.instrument = Some(instrument);
+        Ok(())
+    }
+
+    fn get_risk_manager_mut(&mut self) -> Option<&mut RiskManager> {
+        self.risk_manager.as_mut()
+    }
+
+    fn set_risk_manager(&mut self, risk_manager: RiskManager) -> Result<()> {
+        self.risk_manager = Some(risk_manager);
+        Ok(())
+    }
+
+    fn set_position_manager(&mut self, position_manager: PositionManager) -> Result<()> {
+        self.position_manager = Some(position_manager);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -16,6 +16,7 @@
 
 use super::*;
 use crate::{PositionManager, PositionSizingStrategy, RiskManager, RiskRules, TradeSide};
+use aurora_core::amount_to_f64;
 
 // === 基础功能测试 ===
 
@@ -206,3 +207,117 @@ async fn test_kelly_criterion_strategy() {
     // Kelly建议的仓位应该是合理的（不会是全仓）
     assert!(portfolio.get_cash() > 1000.0);
 }
+
+#[tokio::test]
+async fn test_instrument_rounds_buy_and_sell() {
+    let instrument = aurora_core::Instrument::new("BTCUSDT", "BTC", "USDT")
+        .with_tick_size(0.1)
+        .with_step_size(0.01);
+    let mut portfolio = BasePortfolio::new(1000.0).with_instrument(instrument);
+
+    // 价格取整到 0.1，数量向下取整到 0.01
+    let buy = portfolio.execute_buy(33.333, 1000).await.unwrap();
+    assert_eq!(buy.price, 33.3);
+    assert_eq!(buy.quantity, 30.03);
+    assert!(portfolio.get_cash() >= 0.0);
+
+    let sell = portfolio.execute_sell(40.04, 2000).await.unwrap();
+    assert_eq!(sell.price, 40.0);
+    assert_eq!(sell.quantity, 30.03);
+    assert_eq!(portfolio.get_position(), 0.0);
+}
+
+#[tokio::test]
+async fn test_instrument_rejects_small_orders() {
+    let instrument = aurora_core::Instrument::new("BTCUSDT", "BTC", "USDT")
+        .with_step_size(0.001)
+        .with_min_notional(50.0);
+    let mut portfolio = BasePortfolio::new(40.0).with_instrument(instrument);
+
+    // 全部现金也不足最小名义金额，订单被拒绝且状态不变
+    let result = portfolio.execute_buy(100.0, 1000).await;
+    assert!(result.is_err());
+    assert_eq!(portfolio.get_cash(), 40.0);
+    assert_eq!(portfolio.get_position(), 0.0);
+    assert!(portfolio.get_trades().is_empty());
+}
+
+#[tokio::test]
+async fn test_execute_quantity_orders() {
+    let mut portfolio = BasePortfolio::new(10000.0);
+
+    let buy = portfolio.execute_buy_quantity(100.0, 30.0, 1000).await.unwrap();
+    assert_eq!(buy.quantity, 30.0);
+    assert_eq!(portfolio.get_cash(), 7000.0);
+
+    // 部分卖出后仍然持仓
+    let sell = portfolio.execute_sell_quantity(110.0, 10.0, 2000).await.unwrap();
+    assert_eq!(sell.quantity, 10.0);
+    assert_eq!(portfolio.get_position(), 20.0);
+
+    // 超过持仓的卖出数量按全部持仓成交
+    let sell = portfolio.execute_sell_quantity(110.0, 100.0, 3000).await.unwrap();
+    assert_eq!(sell.quantity, 20.0);
+    assert_eq!(portfolio.get_position(), 0.0);
+}
+
+#[tokio::test]
+async fn test_execute_buy_quantity_capped_by_cash() {
+    let mut portfolio = BasePortfolio::new(1000.0);
+
+    let buy = portfolio.execute_buy_quantity(100.0, 50.0, 1000).await.unwrap();
+    assert_eq!(buy.quantity, 10.0);
+    assert_eq!(portfolio.get_cash(), 0.0);
+
+    assert!(portfolio.execute_sell_quantity(100.0, 0.0, 2000).await.is_err());
+}
+
+#[tokio::test]
+async fn test_buy_value_never_exceeds_cash() {
+    use aurora_core::to_amount;
+
+    // 1000 / 29.97 在 f64 下再乘回价格得到 1000.0000000000001
+    for execute_all in [true, false] {
+        let mut portfolio = BasePortfolio::new(1000.0);
+        let trade = if execute_all {
+            portfolio.execute_buy(29.97, 1000).await.unwrap()
+        } else {
+            portfolio.execute_buy_quantity(29.97, 1000.0 / 29.97, 1000).await.unwrap()
+        };
+
+        // 持仓价值与扣除的现金一致，不会凭空多出价值
+        let value = to_amount(trade.quantity) * to_amount(trade.price);
+        assert!(value <= to_amount(1000.0));
+        assert_eq!(portfolio.cash_amount(), to_amount(1000.0) - value);
+        assert!(portfolio.get_total_equity(29.97) <= 1000.0);
+    }
+}
+
+#[tokio::test]
+async fn test_exact_amount_accessors() {
+    let mut portfolio = BasePortfolio::new(1000.0);
+    portfolio.execute_buy_quantity(3.0, 0.1, 1000).await.unwrap();
+
+    assert_eq!(amount_to_f64(portfolio.cash_amount()), portfolio.get_cash());
+    assert_eq!(amount_to_f64(portfolio.position_amount()), portfolio.get_position());
+}
+
+#[cfg(feature = "decimal")]
+#[tokio::test]
+async fn test_decimal_ledger_has_no_dust() {
+    use aurora_core::{AMOUNT_ZERO, to_amount};
+
+    let mut portfolio = BasePortfolio::new(1.0);
+
+    for i in 0..3 {
+        portfolio.execute_buy_quantity(3.0, 0.1, 1000 + i).await.unwrap();
+    }
+
+    // f64 记账结果为 0.09999999999999987
+    assert_eq!(portfolio.cash_amount(), to_amount(0.1));
+    assert_eq!(portfolio.position_amount(), to_amount(0.3));
+
+    portfolio.execute_sell(3.0, 2000).await.unwrap();
+    assert_eq!(portfolio.cash_amount(), to_amount(1.0));
+    assert_eq!(portfolio.position_amount(), AMOUNT_ZERO);
+}
@@ -17,7 +17,9 @@
 //! 提供投资组合级别的风险控制功能,包括最大回撤限制、
 //! 连续亏损限制等,用于保护账户资金安全。
 
+use aurora_core::Clock;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use tracing::{error, info, warn};
 
 /// 风险控制规则
@@ -180,6 +182,29 @@ impl RiskCheckResult {
 /// 风险管理器
 ///
 /// 负责执行风险检查,跟踪交易状态,并根据规则判断是否应停止交易。
+///
+/// 设置时钟后,每次风险检查都会按时钟判断是否进入新的自然日(UTC),
+/// 进入新的一天时自动重置单日统计,并解除因单日亏损触发的交易停止。
+/// 未设置时钟时需要调用方手动调用 [`reset_daily_stats`](Self::reset_daily_stats)。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::{MILLIS_PER_DAY, SimulatedClock};
+/// use aurora_portfolio::{RiskManager, RiskRules};
+/// use std::sync::Arc;
+///
+/// let clock = Arc::new(SimulatedClock::new(0));
+/// let rules = RiskRules::new().with_max_daily_loss(5.0);
+/// let mut manager = RiskManager::new(rules, 10000.0).with_clock(clock.clone());
+///
+/// // 当天亏损6%,停止交易
+/// assert!(!manager.check_risk(9400.0, 0.0, 100.0).is_pass());
+///
+/// // 第二天以当前权益为起点重新计算
+/// clock.advance_to(MILLIS_PER_DAY);
+/// assert!(manager.check_risk(9400.0, 0.0, 100.0).is_pass());
+/// ```
 #[derive(Debug, Clone)]
 pub struct RiskManager {
     /// 风险控制规则
@@ -202,6 +227,15 @@ pub struct RiskManager {
 
     /// 入场价格(用于计算止损止盈)
     entry_price: Option<f64>,
+
+    /// 时钟(可选,设置后按自然日自动重置单日统计)
+    clock: Option<Arc<dyn Clock>>,
+
+    /// 单日统计所属的自然日
+    current_day: Option<i64>,
+
+    /// 交易停止是否由单日亏损触发
+    stopped_by_daily_loss: bool,
 }
 
 impl RiskManager {
@@ -220,6 +254,38 @@ impl RiskManager {
             trading_stopped: false,
             stop_reason: None,
             entry_price: None,
+            clock: None,
+            current_day: None,
+            stopped_by_daily_loss: false,
+        }
+    }
+
+    /// 设置时钟
+    ///
+    /// 回测传入由事件时间驱动的 [`SimulatedClock`](aurora_core::SimulatedClock),
+    /// 实时交易传入 [`SystemClock`](aurora_core::SystemClock)。
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.current_day = Some(clock.day());
+        self.clock = Some(clock);
+        self
+    }
+
+    /// 按时钟检查是否进入新的自然日,是则重置单日统计
+    fn roll_daily_stats(&mut self, current_equity: f64) {
+        let Some(day) = self.clock.as_ref().map(|clock| clock.day()) else {
+            return;
+        };
+        if self.current_day.is_some_and(|current| current >= day) {
+            return;
+        }
+
+        self.current_day = Some(day);
+        self.reset_daily_stats(current_equity);
+        if self.stopped_by_daily_loss {
+            info!("进入新的交易日,解除单日亏损限制");
+            self.trading_stopped = false;
+            self.stop_reason = None;
+            self.stopped_by_daily_loss = false;
         }
     }
 
@@ -240,6 +306,8 @@ impl RiskManager {
         current_drawdown: f64,
         current_price: f64,
     ) -> RiskCheckResult {
+        self.roll_daily_stats(current_equity);
+
         // 如果已经停止交易,直接返回
         if self.trading_stopped {
             return RiskCheckResult::MaxDrawdownReached(
@@ -271,6 +339,7 @@ impl RiskManager {
                 );
                 error!("{}", msg);
                 self.stop_trading(msg.clone());
+                self.stopped_by_daily_loss = true;
                 return RiskCheckResult::MaxDailyLossReached(msg);
             }
         }
@@ -421,6 +490,7 @@ impl RiskManager {
     pub fn resume_trading(&mut self) {
         self.trading_stopped = false;
         self.stop_reason = None;
+        self.stopped_by_daily_loss = false;
         self.consecutive_losses = 0;
         warn!("风险管理器已恢复交易,请确认风险已解除");
     }
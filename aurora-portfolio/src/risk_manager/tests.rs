@@ -267,3 +267,44 @@ fn test_update_rules() {
 
     assert_eq!(manager.get_rules().max_drawdown_pct, Some(20.0));
 }
+
+#[test]
+fn test_clock_rolls_daily_stats() {
+    use aurora_core::{MILLIS_PER_DAY, SimulatedClock};
+    use std::sync::Arc;
+
+    let clock = Arc::new(SimulatedClock::new(MILLIS_PER_DAY / 2));
+    let rules = RiskRules::new().with_max_daily_loss(5.0);
+    let mut manager = RiskManager::new(rules, 10000.0).with_clock(clock.clone());
+
+    // 同一天内不重置,亏损6%触发
+    clock.advance_to(MILLIS_PER_DAY - 1);
+    assert!(matches!(
+        manager.check_risk(9400.0, 0.0, 100.0),
+        RiskCheckResult::MaxDailyLossReached(_)
+    ));
+    assert!(manager.should_stop_trading());
+
+    // 进入新的一天,以当前权益为起点并恢复交易
+    clock.advance_to(MILLIS_PER_DAY);
+    assert!(manager.check_risk(9400.0, 0.0, 100.0).is_pass());
+    assert!(!manager.should_stop_trading());
+    assert!(!manager.check_risk(8900.0, 0.0, 100.0).is_pass());
+}
+
+#[test]
+fn test_new_day_keeps_drawdown_stop() {
+    use aurora_core::{MILLIS_PER_DAY, SimulatedClock};
+    use std::sync::Arc;
+
+    let clock = Arc::new(SimulatedClock::new(0));
+    let rules = RiskRules::new().with_max_drawdown(10.0);
+    let mut manager = RiskManager::new(rules, 10000.0).with_clock(clock.clone());
+
+    assert!(!manager.check_risk(8500.0, 15.0, 100.0).is_pass());
+
+    // 最大回撤触发的停止不会因为跨日而解除
+    clock.advance_to(MILLIS_PER_DAY * 2);
+    assert!(!manager.check_risk(8500.0, 0.0, 100.0).is_pass());
+    assert!(manager.should_stop_trading());
+}
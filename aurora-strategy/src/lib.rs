@@ -7,7 +7,13 @@
 //!
 //! - **策略接口抽象化**: 通过 `Strategy` trait 提供统一的策略执行接口
 //! - **移动平均线策略**: 实现了双均线交叉买卖信号生成
+//! - **RSI均值回归**: `RsiReversionStrategy` 在超卖时买入、RSI恢复或时间止损时卖出，可选EMA/ADX趋势过滤
+//! - **挤压突破**: `SqueezeBreakoutStrategy` 在布林带收缩进肯特纳通道后按动量方向突破入场，ATR止损止盈
+//! - **趋势跟踪**: `TrendFollowingStrategy` 组合EMA/MACD交叉、ADX趋势强度过滤和PSAR跟踪离场
 //! - **信号生成**: 基于技术指标产生买入、卖出或持有信号
+//! - **形态过滤**: `PatternFilter` 只在出现看涨K线形态时放行被包装策略的买入信号
+//! - **多周期**: `MultiTimeframe` 把基础K线聚合为订阅的高周期K线，一起交给策略
+//! - **策略注册表**: `StrategyRegistry` 按配置中的策略类型和参数创建策略，并校验参数
 //! - **状态管理**: 维护策略运行时的内部状态
 //!
 //! ## 使用示例
@@ -27,6 +33,8 @@
 //!     low: 49500.0,
 //!     close: 50200.0,
 //!     volume: 100.0,
+//!     symbol: None,
+//!     interval: None,
 //! };
 //!
 //! // 执行策略
@@ -42,8 +50,11 @@
 //!
 //! 1. 实现 `aurora_core::Strategy` trait
 //! 2. 在 `on_market_event` 方法中实现策略逻辑
-//! 3. 维护必要的内部状态（如技术指标）
+//! 3. 维护必要的内部状态（如技术指标），交叉、阈值等判断可直接使用
+//!    `aurora_indicators` 的事件检测器（`CrossAbove`、`Threshold` 等）
 //! 4. 根据市场数据生成相应的交易信号事件
+//! 5. 需要在实时交易中断点续跑时，实现 `snapshot` 和 `restore` 导出与恢复内部状态
+//! 6. 需要通过配置文件或 Web API 按名称创建时，用 `StrategyRegistry::register` 注册构造函数
 //!
 //! ## 性能考虑
 //!
@@ -51,8 +62,29 @@
 //! - 使用滑动窗口算法来维护技术指标状态
 //! - 合理设置指标周期，避免过度拟合
 
-use aurora_core::{MarketEvent, Signal, SignalEvent, Strategy};
-use aurora_indicators::MA;
+mod multi_timeframe;
+mod pattern_filter;
+mod registry;
+mod rsi_reversion;
+mod squeeze_breakout;
+mod trend_following;
+
+pub use multi_timeframe::{MultiTimeframe, MultiTimeframeStrategy, TimeframeBars};
+pub use pattern_filter::PatternFilter;
+pub use registry::{StrategyError, StrategyFactory, StrategyParams, StrategyRegistry};
+pub use rsi_reversion::RsiReversionStrategy;
+pub use squeeze_breakout::SqueezeBreakoutStrategy;
+pub use trend_following::TrendFollowingStrategy;
+
+use aurora_core::{MarketEvent, Signal, SignalEvent, SnapshotError, Strategy, StrategySnapshot};
+use aurora_indicators::{CrossAbove, CrossBelow, MA};
+use serde::{Deserialize, Serialize};
+
+/// MA交叉策略的快照名称
+const MA_CROSSOVER_NAME: &str = "ma-crossover";
+
+/// Buy & Hold 策略的快照名称
+const BUY_AND_HOLD_NAME: &str = "buy-and-hold";
 
 /// 移动平均线交叉策略
 ///
@@ -79,7 +111,7 @@ use aurora_indicators::MA;
 ///
 /// 策略维护以下内部状态：
 /// - 两个MA指标实例
-/// - 前一次的MA值，用于检测交叉点
+/// - 金叉和死叉检测器，保存上一次的均线值用于检测交叉点
 ///
 /// ## 示例
 ///
@@ -97,6 +129,8 @@ use aurora_indicators::MA;
 ///     low: 95.0,
 ///     close: 102.0,
 ///     volume: 1000.0,
+///     symbol: None,
+///     interval: None,
 /// };
 ///
 /// let event = MarketEvent::Kline(kline);
@@ -108,7 +142,7 @@ use aurora_indicators::MA;
 ///     }
 /// }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MACrossoverStrategy {
     /// 短期移动平均线指标
     /// 周期较短，对价格变化更敏感，用于捕捉短期趋势
@@ -118,13 +152,11 @@ pub struct MACrossoverStrategy {
     /// 周期较长，反映长期趋势，提供趋势方向参考
     long_ma: MA,
 
-    /// 上一次短期MA值，用于判断交叉
-    /// 保存历史值以便检测从一个状态到另一个状态的转变
-    prev_short_value: Option<f64>,
+    /// 金叉检测器：短期MA从下方或相等位置穿过长期MA到上方
+    golden_cross: CrossAbove,
 
-    /// 上一次长期MA值，用于判断交叉
-    /// 保存历史值以便检测从一个状态到另一个状态的转变
-    prev_long_value: Option<f64>,
+    /// 死叉检测器：短期MA从上方或相等位置穿过长期MA到下方
+    death_cross: CrossBelow,
 }
 
 impl MACrossoverStrategy {
@@ -164,8 +196,8 @@ impl MACrossoverStrategy {
         Self {
             short_ma: MA::new(short_period),
             long_ma: MA::new(long_period),
-            prev_short_value: None,
-            prev_long_value: None,
+            golden_cross: CrossAbove::new(),
+            death_cross: CrossBelow::new(),
         }
     }
 
@@ -261,10 +293,7 @@ impl MACrossoverStrategy {
     /// assert!(!strategy.is_ready()); // 初始状态未准备好
     /// ```
     pub fn is_ready(&self) -> bool {
-        self.short_ma.is_ready()
-            && self.long_ma.is_ready()
-            && self.prev_short_value.is_some()
-            && self.prev_long_value.is_some()
+        self.short_ma.is_ready() && self.long_ma.is_ready() && self.golden_cross.is_ready()
     }
 
     /// 重置策略状态
@@ -290,50 +319,8 @@ impl MACrossoverStrategy {
     pub fn reset(&mut self) {
         self.short_ma.reset();
         self.long_ma.reset();
-        self.prev_short_value = None;
-        self.prev_long_value = None;
-    }
-
-    /// 检测MA交叉信号
-    ///
-    /// 这是策略的核心逻辑，通过比较当前和历史的MA值来检测交叉。
-    ///
-    /// # 参数
-    ///
-    /// * `current_short` - 当前短期MA值
-    /// * `current_long` - 当前长期MA值
-    ///
-    /// # 返回值
-    ///
-    /// * `Signal::Buy` - 检测到金叉（短期MA向上穿越长期MA）
-    /// * `Signal::Sell` - 检测到死叉（短期MA向下穿越长期MA）
-    /// * `Signal::Hold` - 无交叉或无足够历史数据
-    ///
-    /// # 算法逻辑
-    ///
-    /// 1. 检查是否有历史MA值用于比较
-    /// 2. 金叉检测：prev_short <= prev_long && current_short > current_long
-    /// 3. 死叉检测：prev_short >= prev_long && current_short < current_long
-    /// 4. 其他情况返回Hold信号
-    ///
-    /// # 注意事项
-    ///
-    /// 交叉检测使用 <= 和 >= 比较以确保在MA值相等时也能正确检测到交叉。
-    /// 这种设计避免了在MA值非常接近时错过交叉信号。
-    fn detect_crossover(&self, current_short: f64, current_long: f64) -> Signal {
-        if let (Some(prev_short), Some(prev_long)) = (self.prev_short_value, self.prev_long_value) {
-            // 金叉：短期MA从下方或相等位置穿过长期MA到上方
-            if prev_short <= prev_long && current_short > current_long {
-                return Signal::Buy;
-            }
-            // 死叉：短期MA从上方或相等位置穿过长期MA到下方
-            else if prev_short >= prev_long && current_short < current_long {
-                return Signal::Sell;
-            }
-        }
-
-        // 无交叉或无历史数据
-        Signal::Hold
+        self.golden_cross.reset();
+        self.death_cross.reset();
     }
 }
 
@@ -355,9 +342,8 @@ impl Strategy for MACrossoverStrategy {
     ///
     /// 1. 解析市场事件，提取K线数据
     /// 2. 使用收盘价更新两个移动平均线指标
-    /// 3. 如果两个MA都有有效值，进行交叉检测
-    /// 4. 更新历史MA值用于下次比较
-    /// 5. 只在产生Buy或Sell信号时返回SignalEvent
+    /// 3. 如果两个MA都有有效值，由金叉和死叉检测器进行交叉检测
+    /// 4. 只在产生Buy或Sell信号时返回SignalEvent
     ///
     /// # 设计考虑
     ///
@@ -377,12 +363,17 @@ impl Strategy for MACrossoverStrategy {
                 // 只有当两个MA都有值时才进行交叉检测
                 // 这确保了我们有足够的数据来计算可靠的移动平均线
                 if let (Some(current_short), Some(current_long)) = (short_value, long_value) {
-                    let signal = self.detect_crossover(current_short, current_long);
+                    // 两个检测器都必须更新，以保存本次的均线值用于下次比较
+                    let golden = self.golden_cross.update(current_short, current_long);
+                    let death = self.death_cross.update(current_short, current_long);
 
-                    // 更新前一个时间点的值，这对下次检测交叉至关重要
-                    // 必须在信号检测后更新，确保时序正确
-                    self.prev_short_value = Some(current_short);
-                    self.prev_long_value = Some(current_long);
+                    let signal = if golden {
+                        Signal::Buy
+                    } else if death {
+                        Signal::Sell
+                    } else {
+                        Signal::Hold
+                    };
 
                     // 只在非Hold信号时返回SignalEvent
                     // 这减少了不必要的事件传播，提高了系统效率
@@ -391,23 +382,39 @@ impl Strategy for MACrossoverStrategy {
                             signal,
                             price: kline.close,
                             timestamp: kline.timestamp,
+                            intent: None,
                         });
                     }
-                } else {
-                    // 即使无法进行交叉检测，也要更新可用的MA值
-                    // 这确保了策略状态的连续性，为后续计算做准备
-                    if let Some(val) = short_value {
-                        self.prev_short_value = Some(val);
-                    }
-                    if let Some(val) = long_value {
-                        self.prev_long_value = Some(val);
-                    }
                 }
 
                 // 默认返回None，表示无信号产生
                 None
             }
+            // 均线交叉只基于K线收盘价，逐笔和盘口事件不参与计算
+            _ => None,
+        }
+    }
+
+    /// 导出均线窗口和上一次的均线值
+    fn snapshot(&self) -> Result<StrategySnapshot, SnapshotError> {
+        StrategySnapshot::new(MA_CROSSOVER_NAME, self)
+    }
+
+    /// 从快照恢复均线窗口，快照的均线周期必须与当前策略一致
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> Result<(), SnapshotError> {
+        let state: Self = snapshot.state(MA_CROSSOVER_NAME)?;
+        if state.short_period() != self.short_period() || state.long_period() != self.long_period() {
+            return Err(SnapshotError::Incompatible(format!(
+                "快照周期为 {}:{}，当前策略为 {}:{}",
+                state.short_period(),
+                state.long_period(),
+                self.short_period(),
+                self.long_period()
+            )));
         }
+
+        *self = state;
+        Ok(())
     }
 }
 
@@ -418,7 +425,7 @@ impl Strategy for MACrossoverStrategy {
 ///
 /// ## 策略原理
 ///
-/// - **首次信号**: 在接收到第一个K线数据时产生买入信号
+/// - **首次信号**: 在接收到第一个带价格的市场事件时产生买入信号
 /// - **持有**: 之后一直持有，不产生任何交易信号
 ///
 /// ## 适用场景
@@ -441,6 +448,8 @@ impl Strategy for MACrossoverStrategy {
 ///     low: 95.0,
 ///     close: 102.0,
 ///     volume: 1000.0,
+///     symbol: None,
+///     interval: None,
 /// };
 ///
 /// let event = MarketEvent::Kline(kline);
@@ -453,7 +462,7 @@ impl Strategy for MACrossoverStrategy {
 /// // 之后不再产生任何信号
 /// assert!(strategy.on_market_event(&event).is_none());
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BuyAndHoldStrategy {
     /// 是否已经买入
     /// true表示已经产生过买入信号，不再产生新信号
@@ -541,22 +550,33 @@ impl Strategy for BuyAndHoldStrategy {
     ///
     /// # 设计考虑
     ///
-    /// 使用收盘价作为买入价格，与实际交易场景更接近
+    /// 使用事件的参考价格作为买入价格（K线为收盘价，成交为成交价，报价为中间价），
+    /// 因此无论回测数据是K线还是逐笔数据，基准都能在第一个有价格的事件上建仓
     fn on_market_event(&mut self, event: &MarketEvent) -> Option<SignalEvent> {
-        match event {
-            MarketEvent::Kline(kline) => {
-                if !self.has_bought {
-                    // 首次接收到数据时买入
-                    self.has_bought = true;
-                    return Some(SignalEvent {
-                        signal: Signal::Buy,
-                        price: kline.close,
-                        timestamp: kline.timestamp,
-                    });
-                }
-                // 已买入后持有，不产生任何信号
-                None
-            }
+        if self.has_bought {
+            // 已买入后持有，不产生任何信号
+            return None;
         }
+
+        // 首次接收到带价格的数据时买入
+        let price = event.reference_price()?;
+        self.has_bought = true;
+        Some(SignalEvent {
+            signal: Signal::Buy,
+            price,
+            timestamp: event.timestamp(),
+            intent: None,
+        })
+    }
+
+    /// 导出是否已经买入
+    fn snapshot(&self) -> Result<StrategySnapshot, SnapshotError> {
+        StrategySnapshot::new(BUY_AND_HOLD_NAME, self)
+    }
+
+    /// 从快照恢复是否已经买入，恢复后不会重复买入
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> Result<(), SnapshotError> {
+        *self = snapshot.state(BUY_AND_HOLD_NAME)?;
+        Ok(())
     }
 }
@@ -0,0 +1,284 @@
+//! 多周期策略
+//!
+//! [`MultiTimeframe`] 包装实现了 [`MultiTimeframeStrategy`] 的策略，
+//! 用 [`Resampler`] 把输入的基础K线聚合为订阅的高周期K线，
+//! 每根基础K线都把基础K线、本次完成的高周期K线和正在形成的高周期K线一起交给策略，
+//! 从而在同一个数据流上实现"4小时定趋势、15分钟找入场"之类的逻辑。
+
+use aurora_core::{
+    Kline, MarketEvent, OrderRejection, Resampler, SignalEvent, SnapshotError, Strategy,
+    StrategyContext, StrategySnapshot, Timeframe, Trade,
+};
+use serde::{Deserialize, Serialize};
+
+/// 多周期策略包装器的快照名称
+const MULTI_TIMEFRAME_NAME: &str = "multi-timeframe";
+
+/// 一根基础K线对应的多周期数据
+#[derive(Debug, Clone, Copy)]
+pub struct TimeframeBars<'a> {
+    /// 基础K线
+    base: &'a Kline,
+    /// 各订阅周期的重采样器，包含正在形成的高周期K线
+    resamplers: &'a [Resampler],
+    /// 各订阅周期本次完成的高周期K线，与重采样器一一对应
+    completed: &'a [Vec<Kline>],
+}
+
+impl<'a> TimeframeBars<'a> {
+    /// 基础K线
+    pub fn base(&self) -> &'a Kline {
+        self.base
+    }
+
+    /// 订阅的周期，按订阅顺序排列
+    pub fn timeframes(&self) -> impl Iterator<Item = &'a Timeframe> {
+        self.resamplers.iter().map(Resampler::timeframe)
+    }
+
+    /// 指定周期在这根基础K线上完成的K线，通常为零根或一根
+    ///
+    /// 未订阅的周期返回空切片。
+    pub fn completed(&self, timeframe: &str) -> &'a [Kline] {
+        self.position(timeframe)
+            .map(|index| self.completed[index].as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// 指定周期正在形成的K线，已包含这根基础K线
+    ///
+    /// 高周期K线刚好在这根基础K线上完成，或未订阅该周期时返回 `None`。
+    pub fn partial(&self, timeframe: &str) -> Option<&'a Kline> {
+        self.position(timeframe)
+            .and_then(|index| self.resamplers[index].partial())
+    }
+
+    fn position(&self, timeframe: &str) -> Option<usize> {
+        self.resamplers
+            .iter()
+            .position(|resampler| resampler.timeframe().name() == timeframe)
+    }
+}
+
+/// 多周期策略接口
+///
+/// 与 [`Strategy`] 的区别是每根基础K线收到的是 [`TimeframeBars`]，
+/// 生命周期回调的含义相同，默认不做任何处理。
+pub trait MultiTimeframeStrategy: Send + Sync {
+    /// 处理一根基础K线及其对应的高周期K线，可能产生交易信号
+    fn on_bars(&mut self, bars: &TimeframeBars) -> Option<SignalEvent>;
+
+    /// 开始运行时调用
+    fn on_start(&mut self, _context: &StrategyContext) {}
+
+    /// 订单成交后调用
+    fn on_order_filled(&mut self, _trade: &Trade) {}
+
+    /// 订单被拒绝后调用
+    fn on_order_rejected(&mut self, _rejection: &OrderRejection) {}
+
+    /// 定时器间隔（毫秒），默认不启用定时器
+    fn timer_interval(&self) -> Option<i64> {
+        None
+    }
+
+    /// 定时器触发时调用，可以返回交易信号
+    fn on_timer(&mut self, _timestamp: i64) -> Option<SignalEvent> {
+        None
+    }
+
+    /// 数据结束或引擎停止时调用
+    fn on_finish(&mut self) {}
+
+    /// 导出策略状态快照，默认返回 [`SnapshotError::Unsupported`]
+    ///
+    /// 包装器会把这个快照和各周期正在形成的K线一起保存。
+    fn snapshot(&self) -> Result<StrategySnapshot, SnapshotError> {
+        Err(SnapshotError::Unsupported)
+    }
+
+    /// 从快照恢复策略状态，默认返回 [`SnapshotError::Unsupported`]
+    fn restore(&mut self, _snapshot: &StrategySnapshot) -> Result<(), SnapshotError> {
+        Err(SnapshotError::Unsupported)
+    }
+}
+
+/// 多周期策略包装器的快照内容
+#[derive(Serialize, Deserialize)]
+struct MultiTimeframeState {
+    /// 各订阅周期的重采样器
+    resamplers: Vec<Resampler>,
+    /// 内部策略的快照
+    inner: StrategySnapshot,
+}
+
+/// 多周期策略包装器
+///
+/// 输入应为单一交易对、单一周期的K线，其他市场事件被忽略。
+/// 包装后的策略实现 [`Strategy`]，可以直接交给回测引擎和实时引擎。
+/// 定时器转发给内部策略；快照包含内部策略的快照和各周期正在形成的K线，
+/// 内部策略不支持快照时包装器同样不支持。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::{Kline, MarketEvent, Signal, SignalEvent, Strategy};
+/// use aurora_strategy::{MultiTimeframe, MultiTimeframeStrategy, TimeframeBars};
+///
+/// /// 1小时K线收阳后，在15分钟K线收阳时买入
+/// struct TrendEntry {
+///     uptrend: bool,
+/// }
+///
+/// impl MultiTimeframeStrategy for TrendEntry {
+///     fn on_bars(&mut self, bars: &TimeframeBars) -> Option<SignalEvent> {
+///         if let Some(hour) = bars.completed("1h").last() {
+///             self.uptrend = hour.close > hour.open;
+///         }
+///         let base = bars.base();
+///         (self.uptrend && base.close > base.open).then(|| SignalEvent {
+///             signal: Signal::Buy,
+///             price: base.close,
+///             timestamp: base.timestamp,
+///             intent: None,
+///         })
+///     }
+/// }
+///
+/// let mut strategy = MultiTimeframe::new(TrendEntry { uptrend: false }, &["1h"]);
+///
+/// let mut signals = 0;
+/// for i in 0..8 {
+///     let kline = Kline {
+///         timestamp: i * 15 * 60_000,
+///         open: 100.0 + i as f64,
+///         high: 102.0 + i as f64,
+///         low: 99.0 + i as f64,
+///         close: 101.0 + i as f64,
+///         volume: 10.0,
+///         symbol: None,
+///         interval: Some("15m".to_string()),
+///     };
+///     signals += strategy.on_market_event(&MarketEvent::Kline(kline)).is_some() as usize;
+/// }
+///
+/// // 第一根1小时K线在第4根15分钟K线上完成，之后每根都满足条件
+/// assert_eq!(signals, 5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultiTimeframe<S> {
+    /// 被包装的策略
+    inner: S,
+    /// 各订阅周期的重采样器
+    resamplers: Vec<Resampler>,
+}
+
+impl<S: MultiTimeframeStrategy> MultiTimeframe<S> {
+    /// 包装策略并订阅若干高周期
+    ///
+    /// # 参数
+    ///
+    /// * `inner` - 多周期策略
+    /// * `timeframes` - 订阅的周期，如 `["1h", "4h"]`
+    ///
+    /// # Panics
+    ///
+    /// 周期不受支持或重复订阅时panic
+    pub fn new(inner: S, timeframes: &[&str]) -> Self {
+        let mut resamplers: Vec<Resampler> = Vec::with_capacity(timeframes.len());
+        for &name in timeframes {
+            let timeframe =
+                Timeframe::parse(name).unwrap_or_else(|| panic!("不支持的K线周期: {}", name));
+            assert!(
+                resamplers.iter().all(|r| r.timeframe() != &timeframe),
+                "重复订阅K线周期: {}",
+                name
+            );
+            resamplers.push(Resampler::new(timeframe));
+        }
+
+        Self { inner, resamplers }
+    }
+
+    /// 被包装的策略
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// 订阅的周期
+    pub fn timeframes(&self) -> Vec<&Timeframe> {
+        self.resamplers.iter().map(Resampler::timeframe).collect()
+    }
+}
+
+impl<S: MultiTimeframeStrategy> Strategy for MultiTimeframe<S> {
+    fn on_market_event(&mut self, event: &MarketEvent) -> Option<SignalEvent> {
+        let MarketEvent::Kline(kline) = event else {
+            return None;
+        };
+
+        let completed: Vec<Vec<Kline>> = self
+            .resamplers
+            .iter_mut()
+            .map(|resampler| resampler.update(kline))
+            .collect();
+        let bars = TimeframeBars {
+            base: kline,
+            resamplers: &self.resamplers,
+            completed: &completed,
+        };
+
+        self.inner.on_bars(&bars)
+    }
+
+    fn on_start(&mut self, context: &StrategyContext) {
+        self.inner.on_start(context);
+    }
+
+    fn on_order_filled(&mut self, trade: &Trade) {
+        self.inner.on_order_filled(trade);
+    }
+
+    fn on_order_rejected(&mut self, rejection: &OrderRejection) {
+        self.inner.on_order_rejected(rejection);
+    }
+
+    fn timer_interval(&self) -> Option<i64> {
+        self.inner.timer_interval()
+    }
+
+    fn on_timer(&mut self, timestamp: i64) -> Option<SignalEvent> {
+        self.inner.on_timer(timestamp)
+    }
+
+    fn on_finish(&mut self) {
+        self.inner.on_finish();
+    }
+
+    fn snapshot(&self) -> Result<StrategySnapshot, SnapshotError> {
+        let state = MultiTimeframeState {
+            resamplers: self.resamplers.clone(),
+            inner: self.inner.snapshot()?,
+        };
+        StrategySnapshot::new(MULTI_TIMEFRAME_NAME, &state)
+    }
+
+    /// 从快照恢复，快照订阅的周期必须与当前包装器一致
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> Result<(), SnapshotError> {
+        let state: MultiTimeframeState = snapshot.state(MULTI_TIMEFRAME_NAME)?;
+        let names = |resamplers: &[Resampler]| -> Vec<String> {
+            resamplers.iter().map(|r| r.timeframe().name().to_string()).collect()
+        };
+        if names(&state.resamplers) != names(&self.resamplers) {
+            return Err(SnapshotError::Incompatible(format!(
+                "快照订阅周期为 {:?}，当前策略为 {:?}",
+                names(&state.resamplers),
+                names(&self.resamplers)
+            )));
+        }
+
+        self.inner.restore(&state.inner)?;
+        self.resamplers = state.resamplers;
+        Ok(())
+    }
+}
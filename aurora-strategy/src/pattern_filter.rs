@@ -0,0 +1,157 @@
+//! K线形态过滤
+//!
+//! [`PatternFilter`] 包装任意策略，只有在最近出现看涨K线形态时才放行买入信号，
+//! 用于减少被包装策略在没有价格行为确认时的入场。
+
+use aurora_core::{
+    MarketEvent, OrderRejection, Signal, SignalEvent, SnapshotError, Strategy, StrategyContext,
+    StrategySnapshot, Trade,
+};
+use aurora_indicators::{CandlePatterns, PatternDirection};
+
+/// K线形态过滤器
+///
+/// 每根K线都用 [`CandlePatterns`] 识别形态，被包装策略产生买入信号时，
+/// 只有最近 `lookback` 根K线（含当前）内出现过强度不低于 `min_strength` 的看涨形态才放行。
+/// 卖出信号不受影响，以免阻止平仓。
+///
+/// 快照和恢复直接委托给被包装的策略，形态窗口在恢复后重新积累。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_core::{Kline, MarketEvent, Strategy};
+/// use aurora_strategy::{MACrossoverStrategy, PatternFilter};
+///
+/// let mut strategy = PatternFilter::new(MACrossoverStrategy::new(5, 20))
+///     .with_lookback(3)
+///     .with_min_strength(0.3);
+///
+/// let kline = Kline {
+///     timestamp: 1640995200000,
+///     open: 100.0,
+///     high: 101.0,
+///     low: 99.0,
+///     close: 100.5,
+///     volume: 10.0,
+///     symbol: None,
+///     interval: None,
+/// };
+/// assert!(strategy.on_market_event(&MarketEvent::Kline(kline)).is_none());
+/// assert_eq!(strategy.inner().short_period(), 5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PatternFilter<S> {
+    /// 被包装的策略
+    inner: S,
+    /// K线形态识别器
+    patterns: CandlePatterns,
+    /// 看涨形态的有效K线数
+    lookback: usize,
+    /// 形态强度下限
+    min_strength: f64,
+    /// 距最近一次看涨形态经过的K线数
+    bars_since_bullish: Option<usize>,
+}
+
+impl<S: Strategy> PatternFilter<S> {
+    /// 包装策略，默认只认可当前K线上任意强度的看涨形态
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            patterns: CandlePatterns::new(),
+            lookback: 1,
+            min_strength: 0.0,
+            bars_since_bullish: None,
+        }
+    }
+
+    /// 设置看涨形态的有效K线数
+    ///
+    /// # Panics
+    ///
+    /// 回看长度为0时panic
+    pub fn with_lookback(mut self, lookback: usize) -> Self {
+        assert!(lookback > 0, "回看长度必须大于0");
+        self.lookback = lookback;
+        self
+    }
+
+    /// 设置形态强度下限（0到1）
+    pub fn with_min_strength(mut self, min_strength: f64) -> Self {
+        self.min_strength = min_strength;
+        self
+    }
+
+    /// 使用自定义参数的形态识别器
+    pub fn with_patterns(mut self, patterns: CandlePatterns) -> Self {
+        self.patterns = patterns;
+        self
+    }
+
+    /// 被包装的策略
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// 当前是否处于看涨形态的有效期内
+    pub fn is_confirmed(&self) -> bool {
+        self.bars_since_bullish.is_some_and(|bars| bars < self.lookback)
+    }
+
+    /// 过滤没有形态确认的买入信号
+    fn filter(&self, signal: Option<SignalEvent>) -> Option<SignalEvent> {
+        signal.filter(|event| event.signal != Signal::Buy || self.is_confirmed())
+    }
+}
+
+impl<S: Strategy> Strategy for PatternFilter<S> {
+    fn on_market_event(&mut self, event: &MarketEvent) -> Option<SignalEvent> {
+        if let MarketEvent::Kline(kline) = event {
+            let bullish = self.patterns.update(kline).iter().any(|pattern| {
+                pattern.direction == PatternDirection::Bullish && pattern.strength >= self.min_strength
+            });
+            self.bars_since_bullish = if bullish {
+                Some(0)
+            } else {
+                self.bars_since_bullish.map(|bars| bars + 1)
+            };
+        }
+
+        let signal = self.inner.on_market_event(event);
+        self.filter(signal)
+    }
+
+    fn on_start(&mut self, context: &StrategyContext) {
+        self.inner.on_start(context);
+    }
+
+    fn on_order_filled(&mut self, trade: &Trade) {
+        self.inner.on_order_filled(trade);
+    }
+
+    fn on_order_rejected(&mut self, rejection: &OrderRejection) {
+        self.inner.on_order_rejected(rejection);
+    }
+
+    fn timer_interval(&self) -> Option<i64> {
+        self.inner.timer_interval()
+    }
+
+    fn on_timer(&mut self, timestamp: i64) -> Option<SignalEvent> {
+        let signal = self.inner.on_timer(timestamp);
+        self.filter(signal)
+    }
+
+    fn on_finish(&mut self) {
+        self.inner.on_finish();
+    }
+
+    fn snapshot(&self) -> Result<StrategySnapshot, SnapshotError> {
+        self.inner.snapshot()
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> Result<(), SnapshotError> {
+        self.inner.restore(snapshot)
+    }
+}
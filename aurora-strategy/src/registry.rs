@@ -0,0 +1,594 @@
+//! 策略注册表
+//!
+//! [`StrategyRegistry`] 按 [`StrategyConfig`] 的 `strategy_type` 和 `parameters`
+//! 构造 `Box<dyn Strategy>`，回测、实时交易和 Web API 都通过它创建策略。
+//! 内置策略及其参数（括号内为默认值）：
+//!
+//! | 名称 | 参数 |
+//! |------|------|
+//! | `ma-crossover` | `short` / `short_period` (10), `long` / `long_period` (30) |
+//! | `buy-and-hold` | 无 |
+//! | `rsi-reversion` | `period` (14), `oversold` (30), `overbought` (70), `exit_level` (未设置时为超买线), `max_holding` (0，不启用时间止损), `trend_filter` (`none`，可选 `ema` / `adx`), `ema_period` (200), `adx_period` (14), `adx_threshold` (25) |
+//! | `squeeze-breakout` | `bb_period` (20), `bb_std_dev` (2.0), `kc_period` (20), `kc_multiplier` (1.5), `min_squeeze` (1), `momentum` (`macd`，可选 `roc`), `macd_fast` (12), `macd_slow` (26), `macd_signal` (9), `roc_period` (12), `atr_period` (14), `stop_atr` (2.0), `target_atr` (0，不设止盈), `trailing` (true) |
+//! | `trend-following` | `entry` (`ema`，可选 `macd`), `ema_fast` (10), `ema_slow` (30), `macd_fast` (12), `macd_slow` (26), `macd_signal` (9), `cross_lookback` (5), `adx_filter` (true), `adx_period` (14), `psar_acceleration` (0.02), `psar_max_acceleration` (0.2) |
+//!
+//! 斜杠分隔的是同一参数的别名，只能设置其中一个。
+//! 未设置的参数取默认值。与各策略的构造函数 panic 不同，注册表对以下问题返回 [`StrategyError`]：
+//! 未注册的策略、策略不认识的参数（通常是拼写错误）以及类型或取值非法的参数。
+
+use crate::{
+    BuyAndHoldStrategy, MACrossoverStrategy, RsiReversionStrategy, SqueezeBreakoutStrategy,
+    TrendFollowingStrategy,
+};
+use aurora_config::{StrategyConfig, StrategyParameter};
+use aurora_core::Strategy;
+use std::collections::HashMap;
+use std::fmt;
+
+/// 策略参数
+///
+/// 参数名到 [`StrategyParameter`] 的映射，通常取自 [`StrategyConfig::parameters`]。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_config::StrategyParameter;
+/// use aurora_strategy::StrategyParams;
+///
+/// let params = StrategyParams::new().with("short", StrategyParameter::Integer(5));
+/// assert_eq!(params.period("short", 10).unwrap(), 5);
+/// assert_eq!(params.period("long", 30).unwrap(), 30);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StrategyParams {
+    values: HashMap<String, StrategyParameter>,
+}
+
+impl StrategyParams {
+    /// 创建空参数
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置参数
+    pub fn with(mut self, name: &str, value: StrategyParameter) -> Self {
+        self.values.insert(name.to_string(), value);
+        self
+    }
+
+    /// 取参数值
+    pub fn get(&self, name: &str) -> Option<&StrategyParameter> {
+        self.values.get(name)
+    }
+
+    /// 是否设置了参数
+    pub fn contains(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    /// 已设置的参数名（按字母排序）
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.values.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// 在同一参数的多个别名中选出已设置的那个
+    ///
+    /// 都未设置时返回第一个别名，同时设置了多个别名时返回错误。
+    pub fn alias<'a>(&self, names: &[&'a str]) -> Result<&'a str, StrategyError> {
+        let set: Vec<&str> = names.iter().copied().filter(|name| self.contains(name)).collect();
+        match set.as_slice() {
+            [] => Ok(names[0]),
+            [name] => Ok(name),
+            _ => Err(StrategyError::invalid(
+                set[1],
+                format!("与 {} 是同一参数，只能设置其中一个", set[0]),
+            )),
+        }
+    }
+
+    /// 取浮点参数，未设置时使用默认值，整数也可以作为浮点数
+    pub fn number(&self, name: &str, default: f64) -> Result<f64, StrategyError> {
+        match self.get(name) {
+            None => Ok(default),
+            Some(StrategyParameter::Integer(value)) => Ok(*value as f64),
+            Some(StrategyParameter::Float(value)) if value.is_finite() => Ok(*value),
+            Some(other) => Err(StrategyError::invalid(name, format!("{} 不是有限数值", describe(other)))),
+        }
+    }
+
+    /// 取周期参数，未设置时使用默认值，必须为正整数
+    pub fn period(&self, name: &str, default: usize) -> Result<usize, StrategyError> {
+        self.count(name, default).and_then(|value| {
+            if value == 0 {
+                Err(StrategyError::invalid(name, "周期必须大于0".to_string()))
+            } else {
+                Ok(value)
+            }
+        })
+    }
+
+    /// 取非负整数参数，未设置时使用默认值
+    ///
+    /// 小数部分为0的浮点数（例如 JSON 中的 `20.0`）同样接受。
+    pub fn count(&self, name: &str, default: usize) -> Result<usize, StrategyError> {
+        match self.get(name) {
+            None => Ok(default),
+            Some(StrategyParameter::Integer(value)) if *value >= 0 => Ok(*value as usize),
+            Some(StrategyParameter::Float(value))
+                if *value >= 0.0 && value.fract() == 0.0 && value.is_finite() =>
+            {
+                Ok(*value as usize)
+            }
+            Some(other) => Err(StrategyError::invalid(name, format!("{} 不是非负整数", describe(other)))),
+        }
+    }
+
+    /// 取布尔参数，未设置时使用默认值
+    pub fn flag(&self, name: &str, default: bool) -> Result<bool, StrategyError> {
+        match self.get(name) {
+            None => Ok(default),
+            Some(StrategyParameter::Bool(value)) => Ok(*value),
+            Some(other) => Err(StrategyError::invalid(name, format!("{} 不是布尔值", describe(other)))),
+        }
+    }
+
+    /// 取枚举型字符串参数，未设置时使用默认值，不区分大小写
+    ///
+    /// 返回 `options` 中匹配的那一项。
+    pub fn choice<'a>(&self, name: &str, options: &[&'a str], default: &'a str) -> Result<&'a str, StrategyError> {
+        let value = match self.get(name) {
+            None => return Ok(default),
+            Some(StrategyParameter::String(value)) => value,
+            Some(other) => {
+                return Err(StrategyError::invalid(name, format!("{} 不是字符串", describe(other))));
+            }
+        };
+        options
+            .iter()
+            .copied()
+            .find(|option| option.eq_ignore_ascii_case(value))
+            .ok_or_else(|| {
+                StrategyError::invalid(name, format!("{} 不是可选值 {} 之一", value, options.join(", ")))
+            })
+    }
+}
+
+impl From<HashMap<String, StrategyParameter>> for StrategyParams {
+    fn from(values: HashMap<String, StrategyParameter>) -> Self {
+        Self { values }
+    }
+}
+
+impl FromIterator<(String, StrategyParameter)> for StrategyParams {
+    fn from_iter<I: IntoIterator<Item = (String, StrategyParameter)>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// 错误信息中展示的参数值
+fn describe(value: &StrategyParameter) -> String {
+    match value {
+        StrategyParameter::Integer(v) => v.to_string(),
+        StrategyParameter::Float(v) => v.to_string(),
+        StrategyParameter::String(v) => format!("\"{}\"", v),
+        StrategyParameter::Bool(v) => v.to_string(),
+    }
+}
+
+/// 策略构造错误
+#[derive(Debug, Clone, PartialEq)]
+pub enum StrategyError {
+    /// 未注册的策略名称
+    UnknownStrategy {
+        /// 策略名称
+        name: String,
+        /// 已注册的策略名称
+        available: Vec<String>,
+    },
+    /// 策略不支持的参数
+    UnknownParameter {
+        /// 策略名称
+        strategy: String,
+        /// 参数名
+        name: String,
+        /// 策略支持的参数名
+        expected: Vec<String>,
+    },
+    /// 参数非法
+    InvalidParameter {
+        /// 参数名
+        name: String,
+        /// 原因
+        reason: String,
+    },
+}
+
+impl StrategyError {
+    /// 创建参数非法错误
+    pub fn invalid(name: &str, reason: String) -> Self {
+        Self::InvalidParameter {
+            name: name.to_string(),
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for StrategyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownStrategy { name, available } => {
+                write!(f, "未知策略: {}，可用策略: {}", name, available.join(", "))
+            }
+            Self::UnknownParameter { strategy, name, expected } if expected.is_empty() => {
+                write!(f, "策略 {} 不接受参数，但设置了 {}", strategy, name)
+            }
+            Self::UnknownParameter { strategy, name, expected } => write!(
+                f,
+                "策略 {} 不支持参数 {}，可用参数: {}",
+                strategy,
+                name,
+                expected.join(", ")
+            ),
+            Self::InvalidParameter { name, reason } => write!(f, "参数 {} 非法: {}", name, reason),
+        }
+    }
+}
+
+impl std::error::Error for StrategyError {}
+
+/// 策略构造函数
+pub type StrategyFactory =
+    Box<dyn Fn(&StrategyParams) -> Result<Box<dyn Strategy>, StrategyError> + Send + Sync>;
+
+/// 已注册的策略
+struct Entry {
+    /// 策略接受的参数名，包括别名
+    parameters: Vec<String>,
+    /// 构造函数
+    factory: StrategyFactory,
+}
+
+/// 策略注册表
+///
+/// 名称不区分大小写。[`StrategyRegistry::new`] 预先注册全部内置策略，
+/// 也可以通过 [`register`](Self::register) 注册自定义策略或覆盖内置策略。
+///
+/// # 示例
+///
+/// ```rust
+/// use aurora_config::{StrategyConfig, StrategyParameter};
+/// use aurora_strategy::StrategyRegistry;
+/// use std::collections::HashMap;
+///
+/// let registry = StrategyRegistry::new();
+/// let config = StrategyConfig {
+///     name: "双均线".to_string(),
+///     strategy_type: "ma-crossover".to_string(),
+///     parameters: HashMap::from([
+///         ("short".to_string(), StrategyParameter::Integer(5)),
+///         ("long".to_string(), StrategyParameter::Integer(20)),
+///     ]),
+///     enabled: true,
+/// };
+/// assert!(registry.from_config(&config).is_ok());
+///
+/// // 短期周期不小于长期周期时返回错误而不是 panic
+/// let mut invalid = config.clone();
+/// invalid.parameters.insert("short".to_string(), StrategyParameter::Integer(30));
+/// let error = registry.from_config(&invalid).err().unwrap();
+/// assert!(error.to_string().contains("short"));
+/// ```
+pub struct StrategyRegistry {
+    entries: HashMap<String, Entry>,
+}
+
+impl StrategyRegistry {
+    /// 创建包含全部内置策略的注册表
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        registry.register_builtins();
+        registry
+    }
+
+    /// 创建空注册表
+    pub fn empty() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// 注册策略，同名策略会被覆盖
+    ///
+    /// # 参数
+    ///
+    /// * `name` - 策略名称，即配置中的 `strategy_type`
+    /// * `parameters` - 策略接受的参数名，设置了其他参数时 [`create`](Self::create) 返回错误
+    /// * `factory` - 构造函数
+    pub fn register<F>(&mut self, name: &str, parameters: &[&str], factory: F)
+    where
+        F: Fn(&StrategyParams) -> Result<Box<dyn Strategy>, StrategyError> + Send + Sync + 'static,
+    {
+        self.entries.insert(
+            name.to_lowercase(),
+            Entry {
+                parameters: parameters.iter().map(|p| p.to_string()).collect(),
+                factory: Box::new(factory),
+            },
+        );
+    }
+
+    /// 按名称和参数创建策略
+    pub fn create(&self, name: &str, params: &StrategyParams) -> Result<Box<dyn Strategy>, StrategyError> {
+        let entry = self
+            .entries
+            .get(&name.to_lowercase())
+            .ok_or_else(|| StrategyError::UnknownStrategy {
+                name: name.to_string(),
+                available: self.names().into_iter().map(String::from).collect(),
+            })?;
+
+        if let Some(unknown) = params
+            .names()
+            .into_iter()
+            .find(|param| !entry.parameters.iter().any(|p| p == param))
+        {
+            return Err(StrategyError::UnknownParameter {
+                strategy: name.to_string(),
+                name: unknown.to_string(),
+                expected: entry.parameters.clone(),
+            });
+        }
+
+        (entry.factory)(params)
+    }
+
+    /// 按策略配置创建策略，不检查 `enabled`
+    pub fn from_config(&self, config: &StrategyConfig) -> Result<Box<dyn Strategy>, StrategyError> {
+        let params = StrategyParams::from(config.parameters.clone());
+        self.create(&config.strategy_type, &params)
+    }
+
+    /// 是否注册了给定名称
+    pub fn contains(&self, name: &str) -> bool {
+        self.entries.contains_key(&name.to_lowercase())
+    }
+
+    /// 已注册的策略名称（按字母排序）
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.entries.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// 策略接受的参数名，未注册时返回 `None`
+    pub fn parameters(&self, name: &str) -> Option<&[String]> {
+        self.entries
+            .get(&name.to_lowercase())
+            .map(|entry| entry.parameters.as_slice())
+    }
+
+    fn register_builtins(&mut self) {
+        self.register(
+            "ma-crossover",
+            &["short", "short_period", "long", "long_period"],
+            |p| {
+                let short_name = p.alias(&["short", "short_period"])?;
+                let long_name = p.alias(&["long", "long_period"])?;
+                let short = p.period(short_name, 10)?;
+                let long = p.period(long_name, 30)?;
+                if short >= long {
+                    return Err(StrategyError::invalid(
+                        short_name,
+                        format!("短期周期 {} 必须小于长期周期 {}", short, long),
+                    ));
+                }
+                Ok(Box::new(MACrossoverStrategy::new(short, long)))
+            },
+        );
+        self.register("buy-and-hold", &[], |_| Ok(Box::new(BuyAndHoldStrategy::new())));
+        self.register(
+            "rsi-reversion",
+            &[
+                "period",
+                "oversold",
+                "overbought",
+                "exit_level",
+                "max_holding",
+                "trend_filter",
+                "ema_period",
+                "adx_period",
+                "adx_threshold",
+            ],
+            |p| {
+                let period = p.period("period", 14)?;
+                let oversold = p.number("oversold", 30.0)?;
+                let overbought = p.number("overbought", 70.0)?;
+                if !(0.0 < oversold && oversold < overbought && overbought < 100.0) {
+                    return Err(StrategyError::invalid(
+                        "oversold",
+                        format!("超卖线 {} 和超买线 {} 必须满足 0 < 超卖线 < 超买线 < 100", oversold, overbought),
+                    ));
+                }
+
+                let mut strategy = RsiReversionStrategy::new(period, oversold, overbought);
+                if p.contains("exit_level") {
+                    let level = p.number("exit_level", overbought)?;
+                    if !(oversold < level && level <= overbought) {
+                        return Err(StrategyError::invalid(
+                            "exit_level",
+                            format!("平仓水平 {} 必须高于超卖线 {} 且不高于超买线 {}", level, oversold, overbought),
+                        ));
+                    }
+                    strategy = strategy.with_exit_level(level);
+                }
+                let max_holding = p.count("max_holding", 0)?;
+                if max_holding > 0 {
+                    strategy = strategy.with_max_holding(max_holding);
+                }
+                match p.choice("trend_filter", &["none", "ema", "adx"], "none")? {
+                    "ema" => strategy = strategy.with_ema_filter(p.period("ema_period", 200)?),
+                    "adx" => {
+                        let threshold = p.number("adx_threshold", 25.0)?;
+                        if !(0.0..=100.0).contains(&threshold) {
+                            return Err(StrategyError::invalid(
+                                "adx_threshold",
+                                format!("ADX阈值 {} 必须在0到100之间", threshold),
+                            ));
+                        }
+                        strategy = strategy.with_adx_filter(p.period("adx_period", 14)?, threshold);
+                    }
+                    _ => {}
+                }
+                Ok(Box::new(strategy))
+            },
+        );
+        self.register(
+            "squeeze-breakout",
+            &[
+                "bb_period",
+                "bb_std_dev",
+                "kc_period",
+                "kc_multiplier",
+                "min_squeeze",
+                "momentum",
+                "macd_fast",
+                "macd_slow",
+                "macd_signal",
+                "roc_period",
+                "atr_period",
+                "stop_atr",
+                "target_atr",
+                "trailing",
+            ],
+            |p| {
+                let positive = |name: &str, default: f64| {
+                    let value = p.number(name, default)?;
+                    if value > 0.0 {
+                        Ok(value)
+                    } else {
+                        Err(StrategyError::invalid(name, format!("{} 必须为正数", value)))
+                    }
+                };
+
+                let mut strategy = SqueezeBreakoutStrategy::new(
+                    p.period("bb_period", 20)?,
+                    positive("bb_std_dev", 2.0)?,
+                    p.period("kc_period", 20)?,
+                    positive("kc_multiplier", 1.5)?,
+                )
+                .with_min_squeeze(p.period("min_squeeze", 1)?)
+                .with_trailing_stop(p.flag("trailing", true)?);
+
+                strategy = match p.choice("momentum", &["macd", "roc"], "macd")? {
+                    "roc" => strategy.with_roc(p.period("roc_period", 12)?),
+                    _ => {
+                        let fast = p.period("macd_fast", 12)?;
+                        let slow = p.period("macd_slow", 26)?;
+                        if fast >= slow {
+                            return Err(StrategyError::invalid(
+                                "macd_fast",
+                                format!("MACD快线周期 {} 必须小于慢线周期 {}", fast, slow),
+                            ));
+                        }
+                        strategy.with_macd(fast, slow, p.period("macd_signal", 9)?)
+                    }
+                };
+
+                let target = p.number("target_atr", 0.0)?;
+                if target < 0.0 {
+                    return Err(StrategyError::invalid(
+                        "target_atr",
+                        format!("{} 不能为负数，0表示不设止盈", target),
+                    ));
+                }
+                let target = (target > 0.0).then_some(target);
+                Ok(Box::new(strategy.with_atr_exits(
+                    p.period("atr_period", 14)?,
+                    positive("stop_atr", 2.0)?,
+                    target,
+                )))
+            },
+        );
+        self.register(
+            "trend-following",
+            &[
+                "entry",
+                "ema_fast",
+                "ema_slow",
+                "macd_fast",
+                "macd_slow",
+                "macd_signal",
+                "cross_lookback",
+                "adx_filter",
+                "adx_period",
+                "psar_acceleration",
+                "psar_max_acceleration",
+            ],
+            |p| {
+                let ordered = |fast_name: &str, fast: usize, slow: usize| {
+                    if fast < slow {
+                        Ok(())
+                    } else {
+                        Err(StrategyError::invalid(
+                            fast_name,
+                            format!("快线周期 {} 必须小于慢线周期 {}", fast, slow),
+                        ))
+                    }
+                };
+
+                let mut strategy = match p.choice("entry", &["ema", "macd"], "ema")? {
+                    "macd" => {
+                        let fast = p.period("macd_fast", 12)?;
+                        let slow = p.period("macd_slow", 26)?;
+                        ordered("macd_fast", fast, slow)?;
+                        TrendFollowingStrategy::with_macd_entry(fast, slow, p.period("macd_signal", 9)?)
+                    }
+                    _ => {
+                        let fast = p.period("ema_fast", 10)?;
+                        let slow = p.period("ema_slow", 30)?;
+                        ordered("ema_fast", fast, slow)?;
+                        TrendFollowingStrategy::new(fast, slow)
+                    }
+                };
+
+                strategy = strategy.with_cross_lookback(p.period("cross_lookback", 5)?);
+                strategy = if p.flag("adx_filter", true)? {
+                    strategy.with_adx_period(p.period("adx_period", 14)?)
+                } else {
+                    strategy.without_adx_filter()
+                };
+
+                let acceleration = p.number("psar_acceleration", 0.02)?;
+                let max_acceleration = p.number("psar_max_acceleration", 0.2)?;
+                if !(0.0 < acceleration && acceleration <= max_acceleration && max_acceleration <= 1.0) {
+                    return Err(StrategyError::invalid(
+                        "psar_acceleration",
+                        format!(
+                            "加速因子起始值 {} 和最大值 {} 必须满足 0 < 起始值 <= 最大值 <= 1",
+                            acceleration, max_acceleration
+                        ),
+                    ));
+                }
+                Ok(Box::new(strategy.with_psar(acceleration, max_acceleration)))
+            },
+        );
+    }
+}
+
+impl Default for StrategyRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for StrategyRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StrategyRegistry")
+            .field("strategies", &self.names())
+            .finish()
+    }
+}
@@ -0,0 +1,366 @@
+//! RSI均值回归策略
+//!
+//! [`RsiReversionStrategy`] 在RSI跌入超卖区时买入，等待价格回归后卖出，
+//! 可选用EMA或ADX过滤趋势环境，并支持按持仓K线数的时间止损。
+
+use aurora_core::{
+    MarketEvent, OrderRejection, Signal, SignalEvent, SnapshotError, Strategy, StrategySnapshot, Trade,
+};
+use aurora_indicators::{ADX, EMA, RSI};
+use serde::{Deserialize, Serialize};
+
+/// RSI均值回归策略的快照名称
+const RSI_REVERSION_NAME: &str = "rsi-reversion";
+
+/// 趋势过滤器
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum TrendFilter {
+    /// 不过滤
+    None,
+    /// 收盘价高于EMA时才允许买入，即只在上升趋势中逢低买入
+    Ema(EMA),
+    /// ADX低于阈值时才允许买入，即只在没有强趋势的震荡行情中交易
+    Adx {
+        /// ADX指标
+        adx: ADX,
+        /// ADX阈值
+        threshold: f64,
+    },
+}
+
+/// RSI均值回归策略
+///
+/// 价格短期超跌后往往会向均值回归，策略据此在RSI进入超卖区时买入，
+/// 在RSI恢复后卖出。只做多：买入信号开仓，卖出信号平仓。
+///
+/// ## 策略规则
+///
+/// - **入场**: 空仓时RSI低于超卖线，且趋势过滤器放行 → 买入信号
+/// - **RSI恢复**: 持仓时RSI达到平仓水平 → 卖出信号。平仓水平默认为超买线，
+///   可以用 [`with_exit_level`](Self::with_exit_level) 设为更低的水平（如50）以便更早离场
+/// - **时间止损**: 设置了最长持仓K线数时，持仓达到该数量仍未恢复 → 卖出信号
+/// - **重新入场**: 平仓后RSI需要先回到超卖线之上，才会再次产生买入信号，
+///   避免时间止损后在同一段超卖行情中反复买入
+///
+/// ## 趋势过滤
+///
+/// - [`with_ema_filter`](Self::with_ema_filter): 收盘价高于EMA时才买入
+/// - [`with_adx_filter`](Self::with_adx_filter): ADX低于阈值时才买入
+///
+/// 过滤器只影响入场，不会阻止平仓。过滤指标尚未就绪时不买入。
+/// RSI积累满 `period` 次涨跌之前同样不交易。
+///
+/// 持仓期间记录入场以来的K线数，供时间止损使用。
+/// 止损等外部卖出成交后按平仓处理，同样要等RSI回到超卖线之上才会再次买入；
+/// 买入被拒绝时视为从未入场，仍在超卖区的下一根K线会再次买入。
+///
+/// ## 示例
+///
+/// ```rust
+/// use aurora_core::{Kline, MarketEvent, Signal, Strategy};
+/// use aurora_strategy::RsiReversionStrategy;
+///
+/// let mut strategy = RsiReversionStrategy::new(3, 30.0, 70.0)
+///     .with_exit_level(50.0)
+///     .with_max_holding(10);
+///
+/// let mut signals = Vec::new();
+/// for (i, close) in [100.0, 99.0, 97.0, 94.0, 90.0, 93.0, 97.0].into_iter().enumerate() {
+///     let kline = Kline {
+///         timestamp: 1640995200000 + i as i64 * 60_000,
+///         open: close,
+///         high: close + 1.0,
+///         low: close - 1.0,
+///         close,
+///         volume: 10.0,
+///         symbol: None,
+///         interval: None,
+///     };
+///     if let Some(event) = strategy.on_market_event(&MarketEvent::Kline(kline)) {
+///         signals.push(event.signal);
+///     }
+/// }
+///
+/// // 连续下跌后超卖买入，反弹使RSI回到50以上后卖出
+/// assert_eq!(signals, vec![Signal::Buy, Signal::Sell]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RsiReversionStrategy {
+    /// RSI指标
+    rsi: RSI,
+    /// 超卖线，RSI低于此值时买入
+    oversold: f64,
+    /// 超买线，未设置平仓水平时RSI达到此值卖出
+    overbought: f64,
+    /// RSI恢复平仓水平，为 `None` 时使用超买线
+    exit_level: Option<f64>,
+    /// 时间止损的最长持仓K线数
+    max_holding: Option<usize>,
+    /// 趋势过滤器
+    trend_filter: TrendFilter,
+    /// 已持仓的K线数，空仓时为 `None`
+    bars_held: Option<usize>,
+    /// 平仓后RSI是否已回到超卖线之上，允许再次入场
+    armed: bool,
+    /// 距离RSI积累满一个周期的涨跌还需的K线数
+    warmup: usize,
+}
+
+impl RsiReversionStrategy {
+    /// 创建RSI均值回归策略，默认RSI达到超买线时平仓，没有趋势过滤和时间止损
+    ///
+    /// # 参数
+    ///
+    /// * `period` - RSI周期，常用14
+    /// * `oversold` - 超卖线，常用30
+    /// * `overbought` - 超买线，常用70
+    ///
+    /// # Panics
+    ///
+    /// 周期为0，或不满足 `0 < oversold < overbought < 100` 时panic
+    pub fn new(period: usize, oversold: f64, overbought: f64) -> Self {
+        assert!(period > 0, "RSI周期必须大于0");
+        assert!(
+            0.0 < oversold && oversold < overbought && overbought < 100.0,
+            "RSI阈值必须满足 0 < 超卖线 < 超买线 < 100"
+        );
+
+        Self {
+            rsi: RSI::new(period),
+            oversold,
+            overbought,
+            exit_level: None,
+            max_holding: None,
+            trend_filter: TrendFilter::None,
+            bars_held: None,
+            armed: true,
+            warmup: period,
+        }
+    }
+
+    /// 设置RSI恢复平仓水平，例如50表示RSI回到中性区即平仓
+    ///
+    /// # Panics
+    ///
+    /// 平仓水平不在超卖线（不含）和超买线（含）之间时panic
+    pub fn with_exit_level(mut self, level: f64) -> Self {
+        assert!(
+            self.oversold < level && level <= self.overbought,
+            "平仓水平必须高于超卖线且不高于超买线"
+        );
+        self.exit_level = Some(level);
+        self
+    }
+
+    /// 设置时间止损：持仓 `bars` 根K线后仍未平仓则卖出
+    ///
+    /// # Panics
+    ///
+    /// K线数为0时panic
+    pub fn with_max_holding(mut self, bars: usize) -> Self {
+        assert!(bars > 0, "最长持仓K线数必须大于0");
+        self.max_holding = Some(bars);
+        self
+    }
+
+    /// 使用EMA趋势过滤：收盘价高于EMA时才买入
+    ///
+    /// # Panics
+    ///
+    /// 周期为0时panic
+    pub fn with_ema_filter(mut self, period: usize) -> Self {
+        assert!(period > 0, "EMA周期必须大于0");
+        self.trend_filter = TrendFilter::Ema(EMA::new(period));
+        self
+    }
+
+    /// 使用ADX趋势过滤：ADX低于 `threshold` 时才买入
+    ///
+    /// # Panics
+    ///
+    /// 周期为0或阈值不在0到100之间时panic
+    pub fn with_adx_filter(mut self, period: usize, threshold: f64) -> Self {
+        assert!(period > 0, "ADX周期必须大于0");
+        assert!(
+            (0.0..=100.0).contains(&threshold),
+            "ADX阈值必须在0到100之间"
+        );
+        self.trend_filter = TrendFilter::Adx {
+            adx: ADX::new(period),
+            threshold,
+        };
+        self
+    }
+
+    /// RSI周期
+    pub fn period(&self) -> usize {
+        self.rsi.period()
+    }
+
+    /// 超卖线
+    pub fn oversold(&self) -> f64 {
+        self.oversold
+    }
+
+    /// 超买线
+    pub fn overbought(&self) -> f64 {
+        self.overbought
+    }
+
+    /// 实际使用的平仓水平
+    pub fn exit_level(&self) -> f64 {
+        self.exit_level.unwrap_or(self.overbought)
+    }
+
+    /// 时间止损的最长持仓K线数
+    pub fn max_holding(&self) -> Option<usize> {
+        self.max_holding
+    }
+
+    /// 当前RSI值
+    pub fn rsi_value(&self) -> Option<f64> {
+        self.rsi.value()
+    }
+
+    /// 已持仓的K线数，空仓时返回 `None`
+    pub fn bars_held(&self) -> Option<usize> {
+        self.bars_held
+    }
+
+    /// 重置指标和持仓状态
+    pub fn reset(&mut self) {
+        self.rsi.reset();
+        match &mut self.trend_filter {
+            TrendFilter::None => {}
+            TrendFilter::Ema(ema) => ema.reset(),
+            TrendFilter::Adx { adx, .. } => adx.reset(),
+        }
+        self.bars_held = None;
+        self.armed = true;
+        self.warmup = self.rsi.period();
+    }
+
+    /// 用K线更新趋势过滤器，返回是否允许买入
+    fn update_filter(&mut self, high: f64, low: f64, close: f64) -> bool {
+        match &mut self.trend_filter {
+            TrendFilter::None => true,
+            TrendFilter::Ema(ema) => {
+                let ready = ema.is_ready();
+                let value = ema.update(close);
+                // 本根K线之前EMA已就绪，才认为均线可用
+                ready && close > value
+            }
+            TrendFilter::Adx { adx, threshold } => adx
+                .update(high, low, close)
+                .filter(|_| adx.is_ready())
+                .is_some_and(|output| output.adx < *threshold),
+        }
+    }
+
+    /// 参数是否与另一个策略一致，用于校验快照
+    fn same_parameters(&self, other: &Self) -> bool {
+        let filter_matches = match (&self.trend_filter, &other.trend_filter) {
+            (TrendFilter::None, TrendFilter::None) => true,
+            (TrendFilter::Ema(a), TrendFilter::Ema(b)) => a.period() == b.period(),
+            (
+                TrendFilter::Adx { adx: a, threshold: x },
+                TrendFilter::Adx { adx: b, threshold: y },
+            ) => a.period() == b.period() && x == y,
+            _ => false,
+        };
+        self.period() == other.period()
+            && self.oversold == other.oversold
+            && self.overbought == other.overbought
+            && self.exit_level == other.exit_level
+            && self.max_holding == other.max_holding
+            && filter_matches
+    }
+}
+
+impl Strategy for RsiReversionStrategy {
+    /// 用收盘价更新RSI，按持仓状态判断入场或平仓
+    fn on_market_event(&mut self, event: &MarketEvent) -> Option<SignalEvent> {
+        let MarketEvent::Kline(kline) = event else {
+            return None;
+        };
+
+        // 过滤器每根K线都要更新，即使当前持仓也是如此
+        let trend_ok = self.update_filter(kline.high, kline.low, kline.close);
+        let rsi = self.rsi.update(kline.close)?;
+        // RSI的平均涨跌幅从第一次涨跌就开始输出，积累满一个周期的涨跌后才交易
+        if self.warmup > 0 {
+            self.warmup -= 1;
+            if self.warmup > 0 {
+                return None;
+            }
+        }
+
+        if let Some(held) = self.bars_held.as_mut() {
+            *held += 1;
+            let recovered = rsi >= self.exit_level.unwrap_or(self.overbought);
+            let timed_out = self.max_holding.is_some_and(|max| *held >= max);
+            if recovered || timed_out {
+                self.bars_held = None;
+                self.armed = rsi >= self.oversold;
+                return Some(SignalEvent::new(Signal::Sell, kline.close, kline.timestamp));
+            }
+            return None;
+        }
+
+        if rsi >= self.oversold {
+            self.armed = true;
+            return None;
+        }
+
+        if self.armed && trend_ok {
+            self.bars_held = Some(0);
+            self.armed = false;
+            return Some(SignalEvent::new(Signal::Buy, kline.close, kline.timestamp));
+        }
+
+        None
+    }
+
+    /// 持仓期间出现卖出成交（例如止损或手动平仓）时回到空仓
+    ///
+    /// 平仓发生在策略之外，当前可能仍处于同一段超卖区，
+    /// 需要等RSI回到超卖线之上才会再次买入。
+    fn on_order_filled(&mut self, trade: &Trade) {
+        if trade.is_sell() && self.bars_held.is_some() {
+            self.bars_held = None;
+            self.armed = false;
+        }
+    }
+
+    /// 买入被拒绝时回到空仓，仍处于超卖区时下一根K线可以再次尝试
+    fn on_order_rejected(&mut self, rejection: &OrderRejection) {
+        if rejection.signal == Signal::Buy {
+            self.bars_held = None;
+            self.armed = true;
+        }
+    }
+
+    /// 导出指标和持仓状态
+    fn snapshot(&self) -> Result<StrategySnapshot, SnapshotError> {
+        StrategySnapshot::new(RSI_REVERSION_NAME, self)
+    }
+
+    /// 从快照恢复，快照的参数必须与当前策略一致
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> Result<(), SnapshotError> {
+        let state: Self = snapshot.state(RSI_REVERSION_NAME)?;
+        if !self.same_parameters(&state) {
+            return Err(SnapshotError::Incompatible(format!(
+                "快照参数为 RSI({}) {}/{}，与当前策略 RSI({}) {}/{} 的参数不一致",
+                state.period(),
+                state.oversold,
+                state.overbought,
+                self.period(),
+                self.oversold,
+                self.overbought
+            )));
+        }
+
+        *self = state;
+        Ok(())
+    }
+}
@@ -0,0 +1,389 @@
+//! 波动率挤压突破策略
+//!
+//! [`SqueezeBreakoutStrategy`] 在布林带收缩进肯特纳通道（挤压）后等待波动率释放，
+//! 按动量确认的方向入场，用ATR设置止损和止盈。
+
+use aurora_core::{
+    MarketEvent, OrderRejection, Signal, SignalEvent, SnapshotError, Strategy, StrategySnapshot, Trade,
+};
+use aurora_indicators::{ATR, BollingerBands, KeltnerChannels, MACD, ROC};
+use serde::{Deserialize, Serialize};
+
+/// 挤压突破策略的快照名称
+const SQUEEZE_BREAKOUT_NAME: &str = "squeeze-breakout";
+
+/// 动量确认指标
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum Momentum {
+    /// MACD柱状图
+    Macd(MACD),
+    /// 变化率
+    Roc(ROC),
+}
+
+impl Momentum {
+    /// 用收盘价更新，返回就绪后的动量值
+    fn update(&mut self, close: f64) -> Option<f64> {
+        match self {
+            Momentum::Macd(macd) => {
+                let output = macd.update(close);
+                macd.is_ready().then_some(output.histogram)
+            }
+            Momentum::Roc(roc) => roc.update(close),
+        }
+    }
+
+    fn reset(&mut self) {
+        match self {
+            Momentum::Macd(macd) => macd.reset(),
+            Momentum::Roc(roc) => roc.reset(),
+        }
+    }
+}
+
+/// 策略参数，快照恢复时用于校验
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Settings {
+    /// 布林带周期
+    bollinger_period: usize,
+    /// 布林带标准差倍数
+    bollinger_std_dev: f64,
+    /// 肯特纳通道周期
+    keltner_period: usize,
+    /// 肯特纳通道ATR倍数
+    keltner_multiplier: f64,
+    /// MACD的快线、慢线和信号线周期，使用ROC时为 `None`
+    macd_periods: Option<(usize, usize, usize)>,
+    /// ROC周期，使用MACD时为 `None`
+    roc_period: Option<usize>,
+    /// 止损止盈使用的ATR周期
+    atr_period: usize,
+    /// 止损距离的ATR倍数
+    stop_multiplier: f64,
+    /// 止盈距离的ATR倍数
+    target_multiplier: Option<f64>,
+    /// 是否使用跟踪止损
+    trailing: bool,
+    /// 挤压至少持续的K线数
+    min_squeeze: usize,
+}
+
+/// 持仓的止损止盈状态
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Position {
+    /// 当前止损价
+    stop: f64,
+    /// 止盈价
+    target: Option<f64>,
+    /// 入场以来的最高收盘价
+    highest_close: f64,
+}
+
+/// 波动率挤压突破策略
+///
+/// 布林带的宽度随标准差变化，肯特纳通道的宽度随ATR变化。当布林带整体收缩到肯特纳通道之内时，
+/// 市场处于低波动的"挤压"状态，挤压结束往往伴随方向性的突破。
+/// 只做多：买入信号开仓，卖出信号平仓。
+///
+/// ## 策略规则
+///
+/// - **挤压**: 布林带上轨低于肯特纳上轨，且下轨高于肯特纳下轨
+/// - **入场**: 空仓时，持续至少 `min_squeeze` 根K线的挤压在本根K线结束，
+///   收盘价高于布林带中轨且动量为正 → 买入信号。向下突破不开仓
+/// - **止损**: 入场价下方 `stop_multiplier` 倍ATR；启用跟踪止损时，
+///   止损价随入场以来的最高收盘价上移，始终保持在其下方 `stop_multiplier` 倍ATR，只升不降
+/// - **止盈**: 设置了止盈倍数时，入场价上方 `target_multiplier` 倍ATR
+///
+/// 止损和止盈按收盘价判断。动量默认使用MACD(12, 26, 9)柱状图，
+/// 可以用 [`with_roc`](Self::with_roc) 改用变化率。
+/// 所有指标就绪之前不交易。
+/// 发出买入信号后记下止损价、止盈价和入场以来的最高收盘价，之后每根K线据此判断离场；
+/// 仓位被执行层平掉或买入被拒绝时丢弃这些价格，重新等待下一次挤压结束。
+///
+/// ## 示例
+///
+/// ```rust
+/// use aurora_core::{Kline, MarketEvent, Strategy};
+/// use aurora_strategy::SqueezeBreakoutStrategy;
+///
+/// let mut strategy = SqueezeBreakoutStrategy::new(20, 2.0, 20, 1.5)
+///     .with_roc(12)
+///     .with_atr_exits(14, 2.0, Some(3.0))
+///     .with_min_squeeze(5);
+///
+/// let kline = Kline {
+///     timestamp: 1640995200000,
+///     open: 100.0,
+///     high: 101.0,
+///     low: 99.0,
+///     close: 100.5,
+///     volume: 10.0,
+///     symbol: None,
+///     interval: None,
+/// };
+/// assert!(strategy.on_market_event(&MarketEvent::Kline(kline)).is_none());
+/// assert!(!strategy.is_squeezed());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SqueezeBreakoutStrategy {
+    /// 策略参数
+    settings: Settings,
+    /// 布林带
+    bollinger: BollingerBands,
+    /// 肯特纳通道
+    keltner: KeltnerChannels,
+    /// 动量确认指标
+    momentum: Momentum,
+    /// 止损止盈使用的ATR
+    atr: ATR,
+    /// 连续处于挤压状态的K线数
+    squeeze_bars: usize,
+    /// 当前持仓，空仓时为 `None`
+    position: Option<Position>,
+}
+
+impl SqueezeBreakoutStrategy {
+    /// 创建挤压突破策略
+    ///
+    /// 默认使用MACD(12, 26, 9)柱状图确认动量，ATR(14)的2倍跟踪止损，不设止盈，
+    /// 挤压至少持续1根K线。
+    ///
+    /// # 参数
+    ///
+    /// * `bollinger_period` - 布林带周期，常用20
+    /// * `bollinger_std_dev` - 布林带标准差倍数，常用2.0
+    /// * `keltner_period` - 肯特纳通道周期，常用20
+    /// * `keltner_multiplier` - 肯特纳通道ATR倍数，常用1.5
+    ///
+    /// # Panics
+    ///
+    /// 周期为0或倍数不为正数时panic
+    pub fn new(
+        bollinger_period: usize,
+        bollinger_std_dev: f64,
+        keltner_period: usize,
+        keltner_multiplier: f64,
+    ) -> Self {
+        assert!(
+            bollinger_period > 0 && keltner_period > 0,
+            "布林带和肯特纳通道周期必须大于0"
+        );
+        assert!(
+            bollinger_std_dev > 0.0 && keltner_multiplier > 0.0,
+            "布林带标准差倍数和肯特纳通道ATR倍数必须为正数"
+        );
+
+        let settings = Settings {
+            bollinger_period,
+            bollinger_std_dev,
+            keltner_period,
+            keltner_multiplier,
+            macd_periods: Some((12, 26, 9)),
+            roc_period: None,
+            atr_period: 14,
+            stop_multiplier: 2.0,
+            target_multiplier: None,
+            trailing: true,
+            min_squeeze: 1,
+        };
+
+        Self {
+            bollinger: BollingerBands::new(bollinger_period, bollinger_std_dev),
+            keltner: KeltnerChannels::new(keltner_period, keltner_multiplier),
+            momentum: Momentum::Macd(MACD::new(12, 26, 9)),
+            atr: ATR::new(settings.atr_period),
+            settings,
+            squeeze_bars: 0,
+            position: None,
+        }
+    }
+
+    /// 使用MACD柱状图确认动量
+    ///
+    /// # Panics
+    ///
+    /// 周期为0或快线周期不小于慢线周期时panic
+    pub fn with_macd(mut self, fast_period: usize, slow_period: usize, signal_period: usize) -> Self {
+        assert!(fast_period < slow_period, "MACD快线周期必须小于慢线周期");
+        self.momentum = Momentum::Macd(MACD::new(fast_period, slow_period, signal_period));
+        self.settings.macd_periods = Some((fast_period, slow_period, signal_period));
+        self.settings.roc_period = None;
+        self
+    }
+
+    /// 使用变化率（ROC）确认动量
+    ///
+    /// # Panics
+    ///
+    /// 周期为0时panic
+    pub fn with_roc(mut self, period: usize) -> Self {
+        self.momentum = Momentum::Roc(ROC::new(period));
+        self.settings.macd_periods = None;
+        self.settings.roc_period = Some(period);
+        self
+    }
+
+    /// 设置ATR止损和止盈
+    ///
+    /// # 参数
+    ///
+    /// * `period` - ATR周期
+    /// * `stop_multiplier` - 止损距离的ATR倍数
+    /// * `target_multiplier` - 止盈距离的ATR倍数，`None` 表示不设止盈
+    ///
+    /// # Panics
+    ///
+    /// 周期为0或倍数不为正数时panic
+    pub fn with_atr_exits(mut self, period: usize, stop_multiplier: f64, target_multiplier: Option<f64>) -> Self {
+        assert!(period > 0, "ATR周期必须大于0");
+        assert!(
+            stop_multiplier > 0.0 && target_multiplier.is_none_or(|m| m > 0.0),
+            "止损和止盈的ATR倍数必须为正数"
+        );
+        self.atr = ATR::new(period);
+        self.settings.atr_period = period;
+        self.settings.stop_multiplier = stop_multiplier;
+        self.settings.target_multiplier = target_multiplier;
+        self
+    }
+
+    /// 设置止损是否随最高收盘价上移，默认启用
+    pub fn with_trailing_stop(mut self, trailing: bool) -> Self {
+        self.settings.trailing = trailing;
+        self
+    }
+
+    /// 设置挤压至少持续的K线数，更短的挤压结束时不入场
+    ///
+    /// # Panics
+    ///
+    /// K线数为0时panic
+    pub fn with_min_squeeze(mut self, bars: usize) -> Self {
+        assert!(bars > 0, "挤压持续K线数必须大于0");
+        self.settings.min_squeeze = bars;
+        self
+    }
+
+    /// 当前是否处于挤压状态
+    pub fn is_squeezed(&self) -> bool {
+        self.squeeze_bars > 0
+    }
+
+    /// 连续处于挤压状态的K线数
+    pub fn squeeze_bars(&self) -> usize {
+        self.squeeze_bars
+    }
+
+    /// 是否持仓
+    pub fn is_holding(&self) -> bool {
+        self.position.is_some()
+    }
+
+    /// 当前止损价，空仓时返回 `None`
+    pub fn stop_price(&self) -> Option<f64> {
+        self.position.map(|p| p.stop)
+    }
+
+    /// 当前止盈价，空仓或未设置止盈时返回 `None`
+    pub fn target_price(&self) -> Option<f64> {
+        self.position.and_then(|p| p.target)
+    }
+
+    /// 重置指标和持仓状态
+    pub fn reset(&mut self) {
+        self.bollinger.reset();
+        self.keltner.reset();
+        self.momentum.reset();
+        self.atr.reset();
+        self.squeeze_bars = 0;
+        self.position = None;
+    }
+}
+
+impl Strategy for SqueezeBreakoutStrategy {
+    /// 更新各指标，持仓时检查止损止盈，空仓时检查挤压是否向上释放
+    fn on_market_event(&mut self, event: &MarketEvent) -> Option<SignalEvent> {
+        let MarketEvent::Kline(kline) = event else {
+            return None;
+        };
+
+        // 所有指标每根K线都要更新，避免持仓期间指标中断
+        let bands = self.bollinger.update(kline.close);
+        let channel = self.keltner.update(kline.high, kline.low, kline.close);
+        let momentum = self.momentum.update(kline.close);
+        let atr = self.atr.update(kline.high, kline.low, kline.close);
+
+        let (Some(bands), Some(channel), Some(momentum), Some(atr)) = (bands, channel, momentum, atr)
+        else {
+            return None;
+        };
+        if !(self.bollinger.is_ready() && self.keltner.is_ready() && self.atr.is_ready()) {
+            return None;
+        }
+
+        let squeezed = bands.upper < channel.upper && bands.lower > channel.lower;
+        let released = !squeezed && self.squeeze_bars >= self.settings.min_squeeze;
+        self.squeeze_bars = if squeezed { self.squeeze_bars + 1 } else { 0 };
+
+        let settings = &self.settings;
+        if let Some(position) = self.position.as_mut() {
+            position.highest_close = position.highest_close.max(kline.close);
+            if settings.trailing {
+                let trailing_stop = position.highest_close - settings.stop_multiplier * atr;
+                position.stop = position.stop.max(trailing_stop);
+            }
+
+            let stopped = kline.close <= position.stop;
+            let reached = position.target.is_some_and(|target| kline.close >= target);
+            if stopped || reached {
+                self.position = None;
+                return Some(SignalEvent::new(Signal::Sell, kline.close, kline.timestamp));
+            }
+            return None;
+        }
+
+        if released && momentum > 0.0 && kline.close > bands.middle {
+            self.position = Some(Position {
+                stop: kline.close - settings.stop_multiplier * atr,
+                target: settings.target_multiplier.map(|m| kline.close + m * atr),
+                highest_close: kline.close,
+            });
+            return Some(SignalEvent::new(Signal::Buy, kline.close, kline.timestamp));
+        }
+
+        None
+    }
+
+    /// 持仓期间出现卖出成交（例如止损或手动平仓）时回到空仓，不再跟踪原来的止损止盈
+    fn on_order_filled(&mut self, trade: &Trade) {
+        if trade.is_sell() {
+            self.position = None;
+        }
+    }
+
+    /// 买入被拒绝时回到空仓
+    fn on_order_rejected(&mut self, rejection: &OrderRejection) {
+        if rejection.signal == Signal::Buy {
+            self.position = None;
+        }
+    }
+
+    /// 导出指标和持仓状态
+    fn snapshot(&self) -> Result<StrategySnapshot, SnapshotError> {
+        StrategySnapshot::new(SQUEEZE_BREAKOUT_NAME, self)
+    }
+
+    /// 从快照恢复，快照的参数必须与当前策略一致
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> Result<(), SnapshotError> {
+        let state: Self = snapshot.state(SQUEEZE_BREAKOUT_NAME)?;
+        if state.settings != self.settings {
+            return Err(SnapshotError::Incompatible(format!(
+                "快照参数为 {:?}，当前策略为 {:?}",
+                state.settings, self.settings
+            )));
+        }
+
+        *self = state;
+        Ok(())
+    }
+}
@@ -0,0 +1,377 @@
+//! 趋势跟踪策略
+//!
+//! [`TrendFollowingStrategy`] 组合多个指标：EMA或MACD交叉给出入场时机，
+//! ADX判断趋势是否足够强，抛物线转向指标（PSAR）作为跟踪止损离场。
+
+use aurora_core::{
+    MarketEvent, OrderRejection, Signal, SignalEvent, SnapshotError, Strategy, StrategySnapshot, Trade,
+};
+use aurora_indicators::{ADX, CrossAbove, EMA, MACD, PSAR};
+use serde::{Deserialize, Serialize};
+
+/// 趋势跟踪策略的快照名称
+const TREND_FOLLOWING_NAME: &str = "trend-following";
+
+/// 默认的交叉有效K线数
+const DEFAULT_CROSS_LOOKBACK: usize = 5;
+
+/// 入场交叉信号
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum Entry {
+    /// 快速EMA上穿慢速EMA
+    Ema {
+        /// 快速EMA
+        fast: EMA,
+        /// 慢速EMA
+        slow: EMA,
+    },
+    /// MACD线上穿信号线
+    Macd(MACD),
+}
+
+impl Entry {
+    /// 用收盘价更新，返回就绪后参与交叉比较的两条线
+    fn update(&mut self, close: f64) -> Option<(f64, f64)> {
+        match self {
+            Entry::Ema { fast, slow } => {
+                let fast_value = fast.update(close);
+                let slow_value = slow.update(close);
+                slow.is_ready().then_some((fast_value, slow_value))
+            }
+            Entry::Macd(macd) => {
+                let output = macd.update(close);
+                macd.is_ready().then_some((output.macd, output.signal))
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        match self {
+            Entry::Ema { fast, slow } => {
+                fast.reset();
+                slow.reset();
+            }
+            Entry::Macd(macd) => macd.reset(),
+        }
+    }
+}
+
+/// 策略参数，快照恢复时用于校验
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Settings {
+    /// 快速和慢速EMA周期，使用MACD时为 `None`
+    ema_periods: Option<(usize, usize)>,
+    /// MACD的快线、慢线和信号线周期，使用EMA时为 `None`
+    macd_periods: Option<(usize, usize, usize)>,
+    /// 交叉发生后仍可入场的K线数
+    cross_lookback: usize,
+    /// ADX周期，不使用趋势强度过滤时为 `None`
+    adx_period: Option<usize>,
+    /// PSAR加速因子起始值
+    psar_acceleration: f64,
+    /// PSAR加速因子最大值
+    psar_max_acceleration: f64,
+}
+
+/// 趋势跟踪策略
+///
+/// 单一均线交叉在震荡行情中容易反复止损，这个策略在交叉之外加入两层确认：
+/// ADX确认趋势足够强，PSAR确认价格处于上升趋势，并由PSAR翻转给出离场点。
+/// 只做多：买入信号开仓，卖出信号平仓。
+///
+/// ## 策略规则
+///
+/// - **入场**: 空仓时，最近 `cross_lookback` 根K线内发生过向上交叉
+///   （默认快速EMA上穿慢速EMA，也可以用MACD线上穿信号线），
+///   [`ADX::is_strong_trend`] 为真，且PSAR处于上升趋势 → 买入信号
+/// - **离场**: 持仓时PSAR翻转为下降趋势 → 卖出信号。PSAR随价格上涨逐步加速上移，
+///   相当于跟踪止损
+///
+/// 趋势强度过滤可以用 [`without_adx_filter`](Self::without_adx_filter) 关闭。
+/// 默认参数为EMA(10, 30)、ADX(14)、PSAR(0.02, 0.2)，与 [`MACrossoverStrategy`](crate::MACrossoverStrategy)
+/// 的默认周期一致，便于把它作为均线交叉的对照。
+/// 所有指标就绪之前不交易。
+/// 持仓状态只有持有和空仓两种：仓位被执行层平掉或买入被拒绝时回到空仓，
+/// 交叉检测不受影响，回看期内的交叉满足条件时可以再次入场。
+///
+/// ## 示例
+///
+/// ```rust
+/// use aurora_core::{Kline, MarketEvent, Signal, Strategy};
+/// use aurora_strategy::TrendFollowingStrategy;
+///
+/// let mut strategy = TrendFollowingStrategy::new(3, 8)
+///     .with_adx_period(5)
+///     .with_psar(0.02, 0.2);
+///
+/// // 先下跌再持续上涨，最后急跌
+/// let closes = (0..15)
+///     .map(|i| 130.0 - i as f64)
+///     .chain((0..25).map(|i| 116.0 + i as f64 * 2.0))
+///     .chain([150.0, 140.0]);
+///
+/// let mut signals = Vec::new();
+/// for (i, close) in closes.enumerate() {
+///     let kline = Kline {
+///         timestamp: 1640995200000 + i as i64 * 60_000,
+///         open: close,
+///         high: close + 1.0,
+///         low: close - 1.0,
+///         close,
+///         volume: 10.0,
+///         symbol: None,
+///         interval: None,
+///     };
+///     if let Some(event) = strategy.on_market_event(&MarketEvent::Kline(kline)) {
+///         signals.push(event.signal);
+///     }
+/// }
+///
+/// assert_eq!(signals, vec![Signal::Buy, Signal::Sell]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrendFollowingStrategy {
+    /// 策略参数
+    settings: Settings,
+    /// 入场交叉的两条线
+    entry: Entry,
+    /// 向上交叉检测器
+    cross: CrossAbove,
+    /// 趋势强度过滤
+    adx: Option<ADX>,
+    /// 抛物线转向指标
+    psar: PSAR,
+    /// 是否持仓
+    holding: bool,
+}
+
+impl TrendFollowingStrategy {
+    /// 创建以EMA交叉入场的趋势跟踪策略
+    ///
+    /// 默认交叉后5根K线内可以入场，使用ADX(14)过滤和PSAR(0.02, 0.2)离场。
+    ///
+    /// # 参数
+    ///
+    /// * `fast_period` - 快速EMA周期
+    /// * `slow_period` - 慢速EMA周期，必须大于快速周期
+    ///
+    /// # Panics
+    ///
+    /// 周期为0或快速周期不小于慢速周期时panic
+    pub fn new(fast_period: usize, slow_period: usize) -> Self {
+        assert!(fast_period > 0, "EMA周期必须大于0");
+        assert!(fast_period < slow_period, "快速EMA周期必须小于慢速EMA周期");
+
+        Self::with_entry(
+            Entry::Ema {
+                fast: EMA::new(fast_period),
+                slow: EMA::new(slow_period),
+            },
+            Some((fast_period, slow_period)),
+            None,
+        )
+    }
+
+    /// 创建以MACD线上穿信号线入场的趋势跟踪策略
+    ///
+    /// 其余默认参数与 [`new`](Self::new) 相同。
+    ///
+    /// # 参数
+    ///
+    /// * `fast_period` - MACD快线周期
+    /// * `slow_period` - MACD慢线周期，必须大于快线周期
+    /// * `signal_period` - 信号线周期
+    ///
+    /// # Panics
+    ///
+    /// 周期为0或快线周期不小于慢线周期时panic
+    pub fn with_macd_entry(fast_period: usize, slow_period: usize, signal_period: usize) -> Self {
+        assert!(fast_period > 0, "MACD周期必须大于0");
+        assert!(fast_period < slow_period, "MACD快线周期必须小于慢线周期");
+
+        Self::with_entry(
+            Entry::Macd(MACD::new(fast_period, slow_period, signal_period)),
+            None,
+            Some((fast_period, slow_period, signal_period)),
+        )
+    }
+
+    /// 以给定的入场指标和默认的交叉、ADX和PSAR参数创建策略
+    fn with_entry(
+        entry: Entry,
+        ema_periods: Option<(usize, usize)>,
+        macd_periods: Option<(usize, usize, usize)>,
+    ) -> Self {
+        Self {
+            settings: Settings {
+                ema_periods,
+                macd_periods,
+                cross_lookback: DEFAULT_CROSS_LOOKBACK,
+                adx_period: Some(14),
+                psar_acceleration: 0.02,
+                psar_max_acceleration: 0.2,
+            },
+            entry,
+            cross: CrossAbove::new().with_lookback(DEFAULT_CROSS_LOOKBACK),
+            adx: Some(ADX::new(14)),
+            psar: PSAR::new(0.02, 0.2),
+            holding: false,
+        }
+    }
+
+    /// 改用MACD线上穿信号线入场
+    ///
+    /// # Panics
+    ///
+    /// 周期为0或快线周期不小于慢线周期时panic
+    pub fn with_macd(mut self, fast_period: usize, slow_period: usize, signal_period: usize) -> Self {
+        assert!(fast_period > 0, "MACD周期必须大于0");
+        assert!(fast_period < slow_period, "MACD快线周期必须小于慢线周期");
+        self.entry = Entry::Macd(MACD::new(fast_period, slow_period, signal_period));
+        self.settings.ema_periods = None;
+        self.settings.macd_periods = Some((fast_period, slow_period, signal_period));
+        self
+    }
+
+    /// 设置交叉的有效K线数：交叉发生后的 `lookback` 根K线内（含交叉当根）满足其他条件即可入场
+    ///
+    /// 趋势强度往往在交叉之后才上升，适当放宽可以避免错过入场。
+    ///
+    /// # Panics
+    ///
+    /// K线数为0时panic
+    pub fn with_cross_lookback(mut self, lookback: usize) -> Self {
+        self.cross = CrossAbove::new().with_lookback(lookback);
+        self.settings.cross_lookback = lookback;
+        self
+    }
+
+    /// 设置ADX周期
+    ///
+    /// # Panics
+    ///
+    /// 周期为0时panic
+    pub fn with_adx_period(mut self, period: usize) -> Self {
+        self.adx = Some(ADX::new(period));
+        self.settings.adx_period = Some(period);
+        self
+    }
+
+    /// 关闭ADX趋势强度过滤
+    pub fn without_adx_filter(mut self) -> Self {
+        self.adx = None;
+        self.settings.adx_period = None;
+        self
+    }
+
+    /// 设置PSAR加速因子
+    ///
+    /// # Panics
+    ///
+    /// 不满足 `0 < acceleration <= max_acceleration <= 1` 时panic
+    pub fn with_psar(mut self, acceleration: f64, max_acceleration: f64) -> Self {
+        assert!(
+            0.0 < acceleration && acceleration <= max_acceleration && max_acceleration <= 1.0,
+            "PSAR加速因子必须满足 0 < 起始值 <= 最大值 <= 1"
+        );
+        self.psar = PSAR::new(acceleration, max_acceleration);
+        self.settings.psar_acceleration = acceleration;
+        self.settings.psar_max_acceleration = max_acceleration;
+        self
+    }
+
+    /// 是否持仓
+    pub fn is_holding(&self) -> bool {
+        self.holding
+    }
+
+    /// 是否启用了ADX趋势强度过滤
+    pub fn has_adx_filter(&self) -> bool {
+        self.adx.is_some()
+    }
+
+    /// 重置指标和持仓状态
+    pub fn reset(&mut self) {
+        self.entry.reset();
+        self.cross.reset();
+        if let Some(adx) = self.adx.as_mut() {
+            adx.reset();
+        }
+        self.psar.reset();
+        self.holding = false;
+    }
+}
+
+impl Strategy for TrendFollowingStrategy {
+    /// 更新各指标，持仓时等待PSAR翻转，空仓时检查交叉、趋势强度和PSAR方向
+    fn on_market_event(&mut self, event: &MarketEvent) -> Option<SignalEvent> {
+        let MarketEvent::Kline(kline) = event else {
+            return None;
+        };
+
+        // 所有指标每根K线都要更新，交叉检测器也要持续记录两条线的位置
+        let crossed = self
+            .entry
+            .update(kline.close)
+            .is_some_and(|(line, reference)| self.cross.update(line, reference));
+        let strong_trend = match self.adx.as_mut() {
+            Some(adx) => {
+                adx.update(kline.high, kline.low, kline.close);
+                adx.is_ready() && adx.is_strong_trend()
+            }
+            None => true,
+        };
+        let uptrend = self.psar.update(kline.high, kline.low, kline.close)?.is_uptrend;
+
+        if self.holding {
+            if !uptrend {
+                self.holding = false;
+                return Some(SignalEvent::new(Signal::Sell, kline.close, kline.timestamp));
+            }
+            return None;
+        }
+
+        if crossed && strong_trend && uptrend {
+            self.holding = true;
+            return Some(SignalEvent::new(Signal::Buy, kline.close, kline.timestamp));
+        }
+
+        None
+    }
+
+    /// 持仓期间出现卖出成交（例如止损或手动平仓）时回到空仓
+    ///
+    /// 只清除持仓状态，交叉检测器继续记录两条线的位置：
+    /// 平仓后紧接着出现的交叉照常识别，仍在回看期内的交叉满足条件时也可以再次入场。
+    fn on_order_filled(&mut self, trade: &Trade) {
+        if trade.is_sell() && self.holding {
+            self.holding = false;
+        }
+    }
+
+    /// 买入被拒绝时回到空仓
+    fn on_order_rejected(&mut self, rejection: &OrderRejection) {
+        if rejection.signal == Signal::Buy {
+            self.holding = false;
+        }
+    }
+
+    /// 导出指标和持仓状态
+    fn snapshot(&self) -> Result<StrategySnapshot, SnapshotError> {
+        StrategySnapshot::new(TREND_FOLLOWING_NAME, self)
+    }
+
+    /// 从快照恢复，快照的参数必须与当前策略一致
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> Result<(), SnapshotError> {
+        let state: Self = snapshot.state(TREND_FOLLOWING_NAME)?;
+        if state.settings != self.settings {
+            return Err(SnapshotError::Incompatible(format!(
+                "快照参数为 {:?}，当前策略为 {:?}",
+                state.settings, self.settings
+            )));
+        }
+
+        *self = state;
+        Ok(())
+    }
+}
@@ -1,7 +1,13 @@
 //! 策略模块集成测试
 
-use aurora_core::{Kline, MarketEvent, Signal, Strategy};
-use aurora_strategy::{BuyAndHoldStrategy, MACrossoverStrategy};
+use aurora_core::{Kline, MarketEvent, Signal, SnapshotError, Strategy, StrategySnapshot};
+use aurora_config::{StrategyConfig, StrategyParameter};
+use aurora_strategy::{
+    BuyAndHoldStrategy, MACrossoverStrategy, MultiTimeframe, MultiTimeframeStrategy, PatternFilter,
+    RsiReversionStrategy, SqueezeBreakoutStrategy, StrategyError, StrategyParams, StrategyRegistry, TimeframeBars,
+    TrendFollowingStrategy,
+};
+use std::collections::HashMap;
 
 /// 测试MA交叉策略的基本功能
 #[test]
@@ -276,6 +282,8 @@ fn create_test_kline(close_price: f64, timestamp: i64) -> Kline {
         low: close_price * 0.98,
         close: close_price,
         volume: 1000.0,
+        symbol: None,
+        interval: None,
     }
 }
 
@@ -371,3 +379,948 @@ fn test_buy_and_hold_clone() {
     let strategy2 = strategy1.clone();
     assert!(strategy2.has_bought());
 }
+
+/// 测试MA交叉策略的快照恢复后与原策略产生相同信号
+#[test]
+fn test_ma_crossover_snapshot_restore() {
+    let prices = [100.0, 98.0, 96.0, 95.0, 97.0, 101.0, 105.0, 103.0, 99.0, 94.0, 90.0];
+    let mut original = MACrossoverStrategy::new(2, 5);
+    for (i, price) in prices[..6].iter().enumerate() {
+        original.on_market_event(&MarketEvent::Kline(create_test_kline(*price, i as i64)));
+    }
+
+    // 经过 JSON 往返，模拟写入检查点文件后重启
+    let json = original.snapshot().unwrap().to_json().unwrap();
+    let mut restored = MACrossoverStrategy::new(2, 5);
+    restored.restore(&StrategySnapshot::from_json(&json).unwrap()).unwrap();
+    assert_eq!(restored.short_ma_value(), original.short_ma_value());
+    assert_eq!(restored.long_ma_value(), original.long_ma_value());
+
+    for (i, price) in prices[6..].iter().enumerate() {
+        let event = MarketEvent::Kline(create_test_kline(*price, 6 + i as i64));
+        let expected = original.on_market_event(&event).map(|e| e.signal);
+        assert_eq!(restored.on_market_event(&event).map(|e| e.signal), expected);
+    }
+}
+
+/// 测试快照与策略不匹配时拒绝恢复
+#[test]
+fn test_snapshot_restore_rejects_mismatch() {
+    let snapshot = MACrossoverStrategy::new(2, 5).snapshot().unwrap();
+
+    let mut different_periods = MACrossoverStrategy::new(3, 10);
+    assert!(matches!(
+        different_periods.restore(&snapshot),
+        Err(SnapshotError::Incompatible(_))
+    ));
+
+    let mut buy_and_hold = BuyAndHoldStrategy::new();
+    assert!(matches!(
+        buy_and_hold.restore(&snapshot),
+        Err(SnapshotError::StrategyMismatch { .. })
+    ));
+}
+
+/// 测试 Buy & Hold 策略的快照恢复
+#[test]
+fn test_buy_and_hold_snapshot_restore() {
+    let mut strategy = BuyAndHoldStrategy::new();
+    strategy.on_market_event(&MarketEvent::Kline(create_test_kline(100.0, 1640995200000)));
+
+    let mut restored = BuyAndHoldStrategy::new();
+    restored.restore(&strategy.snapshot().unwrap()).unwrap();
+    assert!(restored.has_bought());
+}
+
+/// 每根K线都产生同一信号的测试策略
+struct AlwaysSignal(Signal);
+
+impl Strategy for AlwaysSignal {
+    fn on_market_event(&mut self, event: &MarketEvent) -> Option<aurora_core::SignalEvent> {
+        Some(aurora_core::SignalEvent {
+            signal: self.0.clone(),
+            price: event.reference_price()?,
+            timestamp: event.timestamp(),
+            intent: None,
+        })
+    }
+}
+
+fn create_candle(open: f64, high: f64, low: f64, close: f64, timestamp: i64) -> MarketEvent {
+    MarketEvent::Kline(Kline {
+        timestamp,
+        open,
+        high,
+        low,
+        close,
+        volume: 1000.0,
+        symbol: None,
+        interval: None,
+    })
+}
+
+/// 测试形态过滤器只在看涨形态后放行买入
+#[test]
+fn test_pattern_filter_requires_bullish_pattern() {
+    let mut strategy = PatternFilter::new(AlwaysSignal(Signal::Buy)).with_lookback(2);
+
+    // 普通阴线：没有看涨形态，买入被过滤
+    let bearish = create_candle(105.0, 106.0, 99.0, 100.0, 1640995200000);
+    assert!(strategy.on_market_event(&bearish).is_none());
+
+    // 看涨吞没：放行
+    let engulfing = create_candle(99.0, 107.0, 98.5, 106.5, 1640995260000);
+    assert_eq!(strategy.on_market_event(&engulfing).map(|e| e.signal), Some(Signal::Buy));
+    assert!(strategy.is_confirmed());
+
+    // 回看长度内仍然有效，之后失效
+    let plain = create_candle(106.5, 107.0, 105.0, 106.8, 1640995320000);
+    assert!(strategy.on_market_event(&plain).is_some());
+    let plain = create_candle(106.8, 107.5, 106.0, 107.2, 1640995380000);
+    assert!(strategy.on_market_event(&plain).is_none());
+    assert!(!strategy.is_confirmed());
+}
+
+/// 测试卖出信号不受形态过滤影响
+#[test]
+fn test_pattern_filter_passes_sell_signals() {
+    let mut strategy = PatternFilter::new(AlwaysSignal(Signal::Sell));
+    let event = create_candle(105.0, 106.0, 99.0, 100.0, 1640995200000);
+
+    assert_eq!(strategy.on_market_event(&event).map(|e| e.signal), Some(Signal::Sell));
+}
+
+/// 测试形态强度下限
+#[test]
+fn test_pattern_filter_min_strength() {
+    let mut strict = PatternFilter::new(AlwaysSignal(Signal::Buy)).with_min_strength(0.5);
+    let mut loose = PatternFilter::new(AlwaysSignal(Signal::Buy)).with_min_strength(0.3);
+
+    let klines = [
+        create_candle(105.0, 106.0, 99.0, 100.0, 1640995200000),
+        // 看涨吞没，强度 1 - 5 / 6 ≈ 0.17
+        create_candle(99.0, 106.0, 98.5, 105.0, 1640995260000),
+        // 看涨外包线，强度 1 - 7.5 / 12 = 0.375
+        create_candle(99.0, 110.0, 98.0, 109.0, 1640995320000),
+    ];
+
+    let strict_signals: Vec<bool> = klines.iter().map(|k| strict.on_market_event(k).is_some()).collect();
+    let loose_signals: Vec<bool> = klines.iter().map(|k| loose.on_market_event(k).is_some()).collect();
+
+    assert_eq!(strict_signals, vec![false, false, false]);
+    assert_eq!(loose_signals, vec![false, false, true]);
+}
+
+/// 测试快照委托给被包装的策略
+#[test]
+fn test_pattern_filter_snapshot_delegates() {
+    let strategy = PatternFilter::new(BuyAndHoldStrategy::new());
+    let snapshot = strategy.snapshot().unwrap();
+
+    assert_eq!(snapshot.strategy, "buy-and-hold");
+    assert_eq!(
+        PatternFilter::new(AlwaysSignal(Signal::Buy)).snapshot(),
+        Err(SnapshotError::Unsupported)
+    );
+}
+
+/// 记录每根基础K线收到的多周期数据
+#[derive(Default)]
+struct TimeframeRecorder {
+    /// 每根基础K线上完成的 (1h, 4h) K线数量
+    completed: Vec<(usize, usize)>,
+    /// 完成的4小时K线
+    four_hour: Vec<Kline>,
+    /// 每根基础K线上正在形成的1小时K线的成交量
+    partial_volume: Vec<Option<f64>>,
+}
+
+impl MultiTimeframeStrategy for TimeframeRecorder {
+    fn on_bars(&mut self, bars: &TimeframeBars) -> Option<aurora_core::SignalEvent> {
+        assert_eq!(bars.timeframes().map(|t| t.name()).collect::<Vec<_>>(), vec!["1h", "4h"]);
+        assert!(bars.completed("1d").is_empty());
+
+        self.completed
+            .push((bars.completed("1h").len(), bars.completed("4h").len()));
+        self.four_hour.extend_from_slice(bars.completed("4h"));
+        self.partial_volume.push(bars.partial("1h").map(|bar| bar.volume));
+        None
+    }
+}
+
+fn create_15m_kline(index: i64) -> MarketEvent {
+    let mut kline = create_test_kline(100.0 + index as f64, 1_704_067_200_000 + index * 15 * 60_000);
+    kline.interval = Some("15m".to_string());
+    MarketEvent::Kline(kline)
+}
+
+/// 测试多周期包装器按周期聚合基础K线
+#[test]
+fn test_multi_timeframe_resamples_base_bars() {
+    let mut strategy = MultiTimeframe::new(TimeframeRecorder::default(), &["1h", "4h"]);
+    assert_eq!(strategy.timeframes().len(), 2);
+
+    // 8小时的15分钟K线
+    for i in 0..32 {
+        assert!(strategy.on_market_event(&create_15m_kline(i)).is_none());
+    }
+
+    let recorder = strategy.inner();
+    let hourly: usize = recorder.completed.iter().map(|(hour, _)| hour).sum();
+    assert_eq!(hourly, 8);
+    assert_eq!(recorder.completed[3], (1, 0));
+    assert_eq!(recorder.completed[15], (1, 1));
+
+    // 4小时K线由16根15分钟K线组成
+    assert_eq!(recorder.four_hour.len(), 2);
+    assert_eq!(recorder.four_hour[0].timestamp, 1_704_067_200_000);
+    assert_eq!(recorder.four_hour[0].close, 115.0);
+    assert_eq!(recorder.four_hour[1].open, 116.0 * 0.99);
+    assert_eq!(recorder.four_hour[0].interval.as_deref(), Some("4h"));
+
+    // 正在形成的1小时K线逐根累计成交量，完成时为空
+    assert_eq!(
+        &recorder.partial_volume[..4],
+        &[Some(1000.0), Some(2000.0), Some(3000.0), None]
+    );
+}
+
+/// 记录完成的1小时K线并按定时器发出卖出信号的多周期策略，支持快照
+#[derive(Default, serde::Serialize, serde::Deserialize)]
+struct HourlyBars {
+    /// 完成的1小时K线的 (开盘价, 收盘价, 成交量)
+    hours: Vec<(f64, f64, f64)>,
+}
+
+impl MultiTimeframeStrategy for HourlyBars {
+    fn on_bars(&mut self, bars: &TimeframeBars) -> Option<aurora_core::SignalEvent> {
+        self.hours
+            .extend(bars.completed("1h").iter().map(|bar| (bar.open, bar.close, bar.volume)));
+        None
+    }
+
+    fn timer_interval(&self) -> Option<i64> {
+        Some(60_000)
+    }
+
+    fn on_timer(&mut self, timestamp: i64) -> Option<aurora_core::SignalEvent> {
+        Some(aurora_core::SignalEvent::new(Signal::Sell, 0.0, timestamp))
+    }
+
+    fn snapshot(&self) -> Result<StrategySnapshot, SnapshotError> {
+        StrategySnapshot::new("hourly-bars", self)
+    }
+
+    fn restore(&mut self, snapshot: &StrategySnapshot) -> Result<(), SnapshotError> {
+        *self = snapshot.state("hourly-bars")?;
+        Ok(())
+    }
+}
+
+/// 测试多周期包装器转发定时器
+#[test]
+fn test_multi_timeframe_forwards_timer() {
+    let mut strategy = MultiTimeframe::new(HourlyBars::default(), &["1h"]);
+    assert_eq!(strategy.timer_interval(), Some(60_000));
+    assert_eq!(strategy.on_timer(120_000).map(|s| s.signal), Some(Signal::Sell));
+
+    let recorder = MultiTimeframe::new(TimeframeRecorder::default(), &["1h", "4h"]);
+    assert_eq!(recorder.timer_interval(), None);
+}
+
+/// 测试多周期包装器的快照同时保存正在形成的高周期K线
+#[test]
+fn test_multi_timeframe_snapshot_restore() {
+    let mut original = MultiTimeframe::new(HourlyBars::default(), &["1h"]);
+    // 第一根1小时K线只形成了一半
+    for i in 0..2 {
+        original.on_market_event(&create_15m_kline(i));
+    }
+
+    let snapshot = original.snapshot().unwrap();
+    assert_eq!(snapshot.strategy, "multi-timeframe");
+    let mut restored = MultiTimeframe::new(HourlyBars::default(), &["1h"]);
+    restored.restore(&snapshot).unwrap();
+
+    for i in 2..8 {
+        original.on_market_event(&create_15m_kline(i));
+        restored.on_market_event(&create_15m_kline(i));
+    }
+
+    // 恢复后的第一根1小时K线包含快照之前的基础K线
+    assert_eq!(restored.inner().hours, original.inner().hours);
+    assert_eq!(restored.inner().hours.len(), 2);
+    assert_eq!(restored.inner().hours[0], (100.0 * 0.99, 103.0, 4000.0));
+
+    // 订阅周期不同的快照不兼容，且不改变状态
+    let mut mismatched = MultiTimeframe::new(HourlyBars::default(), &["4h"]);
+    assert!(matches!(mismatched.restore(&snapshot), Err(SnapshotError::Incompatible(_))));
+    assert!(mismatched.inner().hours.is_empty());
+
+    // 内部策略不支持快照时包装器也不支持
+    let recorder = MultiTimeframe::new(TimeframeRecorder::default(), &["1h", "4h"]);
+    assert_eq!(recorder.snapshot(), Err(SnapshotError::Unsupported));
+}
+
+/// 测试不支持的周期
+#[test]
+#[should_panic(expected = "不支持的K线周期")]
+fn test_multi_timeframe_rejects_unknown_timeframe() {
+    MultiTimeframe::new(TimeframeRecorder::default(), &["7m"]);
+}
+
+/// 创建策略配置
+fn strategy_config(strategy_type: &str, parameters: &[(&str, StrategyParameter)]) -> StrategyConfig {
+    StrategyConfig {
+        name: strategy_type.to_string(),
+        strategy_type: strategy_type.to_string(),
+        parameters: parameters
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect::<HashMap<_, _>>(),
+        enabled: true,
+    }
+}
+
+/// 测试注册表创建的策略与直接构造的策略行为一致
+#[test]
+fn test_registry_creates_builtin_strategies() {
+    let registry = StrategyRegistry::new();
+    assert_eq!(registry.names(), vec!["buy-and-hold", "ma-crossover", "rsi-reversion", "squeeze-breakout", "trend-following"]);
+
+    // 两种参数拼写都可以使用
+    for (short, long) in [("short", "long"), ("short_period", "long_period")] {
+        let config = strategy_config(
+            "MA-Crossover",
+            &[(short, StrategyParameter::Integer(2)), (long, StrategyParameter::Integer(3))],
+        );
+        let mut created = registry.from_config(&config).unwrap();
+        let mut direct = MACrossoverStrategy::new(2, 3);
+
+        for (i, price) in [100.0, 99.0, 98.0, 101.0, 103.0, 99.0, 95.0].into_iter().enumerate() {
+            let event = MarketEvent::Kline(create_test_kline(price, i as i64));
+            let expected = direct.on_market_event(&event).map(|s| s.signal);
+            assert_eq!(created.on_market_event(&event).map(|s| s.signal), expected);
+        }
+    }
+
+    let mut benchmark = registry.from_config(&strategy_config("buy-and-hold", &[])).unwrap();
+    assert!(benchmark.on_market_event(&MarketEvent::Kline(create_test_kline(100.0, 0))).is_some());
+}
+
+/// 测试注册表对配置错误返回可读的错误
+#[test]
+fn test_registry_reports_invalid_config() {
+    let registry = StrategyRegistry::new();
+
+    let error = registry.from_config(&strategy_config("rsi-oversold", &[])).err().unwrap();
+    assert!(matches!(error, StrategyError::UnknownStrategy { .. }));
+    assert!(error.to_string().contains("ma-crossover"));
+
+    // 拼写错误的参数名
+    let config = strategy_config("ma-crossover", &[("shrot", StrategyParameter::Integer(5))]);
+    let error = registry.from_config(&config).err().unwrap();
+    assert!(matches!(error, StrategyError::UnknownParameter { ref name, .. } if name == "shrot"));
+
+    // 同一参数的两个别名
+    let config = strategy_config(
+        "ma-crossover",
+        &[("short", StrategyParameter::Integer(5)), ("short_period", StrategyParameter::Integer(5))],
+    );
+    assert!(registry.from_config(&config).is_err());
+
+    // 类型和取值非法
+    for value in [
+        StrategyParameter::String("5".to_string()),
+        StrategyParameter::Integer(0),
+        StrategyParameter::Float(5.5),
+        StrategyParameter::Integer(30),
+    ] {
+        let config = strategy_config("ma-crossover", &[("short", value)]);
+        let error = registry.from_config(&config).err().unwrap();
+        assert!(matches!(error, StrategyError::InvalidParameter { ref name, .. } if name == "short"));
+    }
+
+    let config = strategy_config("buy-and-hold", &[("short", StrategyParameter::Integer(5))]);
+    assert!(registry.from_config(&config).is_err());
+}
+
+/// 测试注册自定义策略和参数读取
+#[test]
+fn test_registry_custom_strategy() {
+    let mut registry = StrategyRegistry::empty();
+    assert!(!registry.contains("ma-crossover"));
+
+    registry.register("Fast-MA", &["period", "mode", "filter"], |p| {
+        let period = p.period("period", 3)?;
+        let mode = p.choice("mode", &["fast", "slow"], "fast")?;
+        let filter = p.flag("filter", false)?;
+        let long = if mode == "fast" { period + 1 } else { period * 2 };
+        if filter {
+            Ok(Box::new(PatternFilter::new(MACrossoverStrategy::new(period, long))))
+        } else {
+            Ok(Box::new(MACrossoverStrategy::new(period, long)))
+        }
+    });
+    assert!(registry.contains("fast-ma"));
+    assert_eq!(registry.parameters("FAST-MA").unwrap().len(), 3);
+
+    let params = StrategyParams::new()
+        .with("mode", StrategyParameter::String("SLOW".to_string()))
+        .with("filter", StrategyParameter::Bool(true));
+    assert!(registry.create("fast-ma", &params).is_ok());
+
+    let params = StrategyParams::new().with("mode", StrategyParameter::String("medium".to_string()));
+    assert!(registry.create("fast-ma", &params).is_err());
+
+    let params = StrategyParams::new().with("period", StrategyParameter::Float(4.0));
+    assert_eq!(params.number("period", 0.0).unwrap(), 4.0);
+    assert!(registry.create("fast-ma", &params).is_ok());
+}
+
+/// 依次把收盘价交给策略，返回每根K线上产生的信号
+fn run_closes<S: Strategy>(strategy: &mut S, closes: &[f64]) -> Vec<Option<Signal>> {
+    closes
+        .iter()
+        .enumerate()
+        .map(|(i, &close)| {
+            let event = MarketEvent::Kline(create_test_kline(close, 1640995200000 + i as i64 * 60000));
+            strategy.on_market_event(&event).map(|s| s.signal)
+        })
+        .collect()
+}
+
+/// 测试RSI均值回归策略在超卖时买入、RSI恢复后卖出
+#[test]
+fn test_rsi_reversion_buys_oversold_and_exits_on_recovery() {
+    let closes = [100.0, 99.0, 97.0, 94.0, 90.0, 93.0, 97.0, 101.0, 104.0];
+
+    // 默认等到超买线才平仓
+    let mut strategy = RsiReversionStrategy::new(3, 30.0, 70.0);
+    let signals = run_closes(&mut strategy, &closes);
+    assert_eq!(signals[3], Some(Signal::Buy));
+    assert_eq!(signals[6], Some(Signal::Sell));
+    assert_eq!(signals.iter().flatten().count(), 2);
+    assert_eq!(strategy.bars_held(), None);
+
+    // 较低的平仓水平在第一次反弹时就离场
+    let mut strategy = RsiReversionStrategy::new(3, 30.0, 70.0).with_exit_level(45.0);
+    let signals = run_closes(&mut strategy, &closes);
+    assert_eq!(signals[3], Some(Signal::Buy));
+    assert_eq!(signals[5], Some(Signal::Sell));
+    assert_eq!(strategy.exit_level(), 45.0);
+}
+
+/// 测试时间止损，以及平仓后需要RSI回到超卖线之上才能再次买入
+#[test]
+fn test_rsi_reversion_time_stop() {
+    let mut closes = vec![100.0, 99.0, 97.0];
+    closes.extend((0..6).map(|i| 94.0 - i as f64 * 3.0));
+
+    let mut strategy = RsiReversionStrategy::new(3, 30.0, 70.0).with_max_holding(2);
+    let signals = run_closes(&mut strategy, &closes);
+    let buy_at = signals.iter().position(|s| *s == Some(Signal::Buy)).unwrap();
+    assert_eq!(signals[buy_at + 2], Some(Signal::Sell));
+
+    // 持续下跌RSI一直处于超卖区，时间止损后不再买入
+    assert_eq!(signals.iter().flatten().count(), 2);
+    assert!(signals[buy_at + 3..].iter().all(Option::is_none));
+}
+
+/// 测试EMA趋势过滤只在收盘价高于均线时买入
+#[test]
+fn test_rsi_reversion_ema_filter() {
+    // 长期上涨后的短暂回调：收盘价仍高于慢速EMA
+    let mut closes: Vec<f64> = (0..30).map(|i| 100.0 + i as f64 * 2.0).collect();
+    closes.extend([150.0, 147.0, 145.0]);
+
+    let mut filtered = RsiReversionStrategy::new(3, 30.0, 70.0).with_ema_filter(20);
+    assert!(run_closes(&mut filtered, &closes).contains(&Some(Signal::Buy)));
+
+    // 一路下跌时收盘价低于EMA，不逆势买入
+    let falling: Vec<f64> = (0..30).map(|i| 200.0 - i as f64 * 2.0).collect();
+    let mut filtered = RsiReversionStrategy::new(3, 30.0, 70.0).with_ema_filter(20);
+    assert!(run_closes(&mut filtered, &falling).iter().all(Option::is_none));
+
+    let mut unfiltered = RsiReversionStrategy::new(3, 30.0, 70.0);
+    assert!(run_closes(&mut unfiltered, &falling).contains(&Some(Signal::Buy)));
+}
+
+/// 测试ADX趋势过滤在强趋势中不买入，在震荡行情中放行
+#[test]
+fn test_rsi_reversion_adx_filter() {
+    // 持续下跌，ADX一直处于强趋势区
+    let falling: Vec<f64> = (0..40).map(|i| 200.0 - i as f64 * 2.0).collect();
+    let mut filtered = RsiReversionStrategy::new(3, 30.0, 70.0).with_adx_filter(5, 25.0);
+    assert!(run_closes(&mut filtered, &falling).iter().all(Option::is_none));
+
+    // 横盘震荡后的急跌，ADX仍然较低
+    let mut ranging: Vec<f64> = (0..30).map(|i| if i % 2 == 0 { 100.0 } else { 104.0 }).collect();
+    ranging.extend([101.0, 98.0, 95.0]);
+    let mut filtered = RsiReversionStrategy::new(3, 30.0, 70.0).with_adx_filter(5, 25.0);
+    assert!(run_closes(&mut filtered, &ranging).contains(&Some(Signal::Buy)));
+}
+
+/// 测试买入被拒绝后回到空仓
+#[test]
+fn test_rsi_reversion_buy_rejected() {
+    let mut strategy = RsiReversionStrategy::new(3, 30.0, 70.0);
+    let signals = run_closes(&mut strategy, &[100.0, 99.0, 97.0, 94.0]);
+    assert_eq!(signals[3], Some(Signal::Buy));
+    assert_eq!(strategy.bars_held(), Some(0));
+
+    strategy.on_order_rejected(&aurora_core::OrderRejection {
+        signal: Signal::Buy,
+        price: 94.0,
+        timestamp: 1640995380000,
+        reason: "资金不足".to_string(),
+    });
+    assert_eq!(strategy.bars_held(), None);
+
+    // 仍在超卖区，下一根K线重新买入
+    let event = MarketEvent::Kline(create_test_kline(90.0, 1640995440000));
+    assert_eq!(strategy.on_market_event(&event).map(|s| s.signal), Some(Signal::Buy));
+}
+
+/// 测试策略之外的卖出成交会让策略回到空仓
+#[test]
+fn test_rsi_reversion_external_sell_fill() {
+    let mut strategy = RsiReversionStrategy::new(3, 30.0, 70.0);
+    let signals = run_closes(&mut strategy, &[100.0, 99.0, 97.0, 94.0]);
+    assert_eq!(signals[3], Some(Signal::Buy));
+
+    // 自己的买入成交不改变持仓状态
+    strategy.on_order_filled(&aurora_core::Trade::new_buy(94.0, 1.0, 1640995380000));
+    assert_eq!(strategy.bars_held(), Some(0));
+
+    // 止损卖出后不再在RSI恢复时发出卖出信号，同一段超卖区也不重新买入
+    strategy.on_order_filled(&aurora_core::Trade::new_sell(93.0, 1.0, 1640995400000));
+    assert_eq!(strategy.bars_held(), None);
+    let signals: Vec<_> = [90.0, 93.0, 97.0, 101.0, 104.0]
+        .iter()
+        .enumerate()
+        .map(|(i, &close)| {
+            let event = MarketEvent::Kline(create_test_kline(close, 1640995440000 + i as i64 * 60000));
+            strategy.on_market_event(&event).map(|s| s.signal)
+        })
+        .collect();
+    assert!(signals.iter().all(Option::is_none));
+}
+
+/// 测试RSI均值回归策略的快照恢复
+#[test]
+fn test_rsi_reversion_snapshot_restore() {
+    let closes = [100.0, 99.0, 97.0, 94.0, 90.0, 93.0];
+    let mut original = RsiReversionStrategy::new(3, 30.0, 70.0).with_max_holding(5);
+    run_closes(&mut original, &closes[..5]);
+    assert_eq!(original.bars_held(), Some(1));
+
+    let snapshot = original.snapshot().unwrap();
+    let mut restored = RsiReversionStrategy::new(3, 30.0, 70.0).with_max_holding(5);
+    restored.restore(&snapshot).unwrap();
+    assert_eq!(restored.bars_held(), Some(1));
+    assert_eq!(restored.rsi_value(), original.rsi_value());
+
+    let event = MarketEvent::Kline(create_test_kline(closes[5], 1640995500000));
+    assert_eq!(
+        restored.on_market_event(&event).map(|s| s.signal),
+        original.on_market_event(&event).map(|s| s.signal)
+    );
+
+    let mut mismatched = RsiReversionStrategy::new(3, 25.0, 70.0);
+    assert!(matches!(mismatched.restore(&snapshot), Err(SnapshotError::Incompatible(_))));
+}
+
+/// 测试RSI阈值非法时panic
+#[test]
+#[should_panic(expected = "RSI阈值")]
+fn test_rsi_reversion_invalid_thresholds() {
+    RsiReversionStrategy::new(14, 70.0, 30.0);
+}
+
+/// 测试通过注册表按配置创建RSI均值回归策略
+#[test]
+fn test_registry_creates_rsi_reversion() {
+    let registry = StrategyRegistry::new();
+    let closes = [100.0, 99.0, 97.0, 94.0, 90.0, 93.0, 97.0, 101.0, 104.0];
+
+    let config = strategy_config(
+        "rsi-reversion",
+        &[
+            ("period", StrategyParameter::Integer(3)),
+            ("exit_level", StrategyParameter::Float(50.0)),
+            ("max_holding", StrategyParameter::Integer(10)),
+        ],
+    );
+    let mut created = registry.from_config(&config).unwrap();
+    let mut direct = RsiReversionStrategy::new(3, 30.0, 70.0)
+        .with_exit_level(50.0)
+        .with_max_holding(10);
+    assert_eq!(run_closes(&mut created, &closes), run_closes(&mut direct, &closes));
+
+    let config = strategy_config(
+        "rsi-reversion",
+        &[
+            ("trend_filter", StrategyParameter::String("ADX".to_string())),
+            ("adx_period", StrategyParameter::Integer(7)),
+        ],
+    );
+    assert!(registry.from_config(&config).is_ok());
+
+    // 阈值顺序颠倒、平仓水平越界、未知过滤器和ADX阈值越界都返回错误
+    let adx = || ("trend_filter", StrategyParameter::String("adx".to_string()));
+    for parameters in [
+        vec![("oversold", StrategyParameter::Float(80.0))],
+        vec![("exit_level", StrategyParameter::Float(20.0))],
+        vec![("trend_filter", StrategyParameter::String("sma".to_string()))],
+        vec![adx(), ("adx_threshold", StrategyParameter::Float(120.0))],
+    ] {
+        let error = registry.from_config(&strategy_config("rsi-reversion", &parameters)).err().unwrap();
+        assert!(matches!(error, StrategyError::InvalidParameter { .. }), "{}", error);
+    }
+}
+
+/// 挤压突破测试数据：20根收盘价几乎不变但振幅较大的K线，之后按给定收盘价运行
+///
+/// 每根K线的最高价和最低价为收盘价上下各1。
+fn squeeze_then(closes: &[f64]) -> Vec<MarketEvent> {
+    (0..20)
+        .map(|i| if i % 2 == 0 { 100.0 } else { 100.2 })
+        .chain(closes.iter().copied())
+        .enumerate()
+        .map(|(i, close)| create_candle(close, close + 1.0, close - 1.0, close, 1640995200000 + i as i64 * 60000))
+        .collect()
+}
+
+/// 依次处理事件，返回每个事件上产生的信号
+fn run_events<S: Strategy>(strategy: &mut S, events: &[MarketEvent]) -> Vec<Option<Signal>> {
+    events
+        .iter()
+        .map(|event| strategy.on_market_event(event).map(|s| s.signal))
+        .collect()
+}
+
+fn squeeze_strategy() -> SqueezeBreakoutStrategy {
+    SqueezeBreakoutStrategy::new(5, 2.0, 5, 1.5)
+        .with_roc(3)
+        .with_atr_exits(5, 2.0, None)
+}
+
+/// 测试挤压向上释放时买入，价格回落触发ATR止损后卖出
+#[test]
+fn test_squeeze_breakout_enters_on_release_and_stops_out() {
+    let events = squeeze_then(&[102.0, 105.0, 109.0, 112.0, 110.0, 104.0, 98.0]);
+    let mut strategy = squeeze_strategy();
+
+    let signals = run_events(&mut strategy, &events[..20]);
+    assert!(signals.iter().all(Option::is_none));
+    assert!(strategy.is_squeezed());
+
+    let signals = run_events(&mut strategy, &events[20..]);
+    let buy_at = signals.iter().position(|s| *s == Some(Signal::Buy)).unwrap();
+    let sell_at = signals.iter().position(|s| *s == Some(Signal::Sell)).unwrap();
+    assert!(buy_at < sell_at);
+    assert_eq!(signals.iter().flatten().count(), 2);
+    assert!(!strategy.is_holding());
+}
+
+/// 测试向下突破不开仓
+#[test]
+fn test_squeeze_breakout_ignores_downside_release() {
+    let events = squeeze_then(&[98.0, 95.0, 91.0, 88.0, 84.0]);
+    let mut strategy = squeeze_strategy();
+    assert!(run_events(&mut strategy, &events).iter().all(Option::is_none));
+    assert!(!strategy.is_squeezed());
+}
+
+/// 测试挤压持续时间不足时不入场
+#[test]
+fn test_squeeze_breakout_min_squeeze() {
+    let events = squeeze_then(&[102.0, 105.0, 109.0]);
+    let mut strategy = squeeze_strategy().with_min_squeeze(100);
+    assert!(run_events(&mut strategy, &events).iter().all(Option::is_none));
+}
+
+/// 测试跟踪止损只升不降，以及ATR止盈
+#[test]
+fn test_squeeze_breakout_trailing_stop_and_target() {
+    let events = squeeze_then(&[102.0, 105.0, 109.0, 112.0, 116.0]);
+
+    let mut strategy = squeeze_strategy();
+    // 第一根上涨K线仍在挤压中，第二根释放挤压时买入
+    let signals = run_events(&mut strategy, &events[..22]);
+    assert_eq!(signals[21], Some(Signal::Buy));
+    let initial_stop = strategy.stop_price().unwrap();
+    run_events(&mut strategy, &events[22..]);
+    assert!(strategy.is_holding());
+    assert!(strategy.stop_price().unwrap() > initial_stop);
+
+    // 固定止损不随价格上移
+    let mut fixed = squeeze_strategy().with_trailing_stop(false);
+    run_events(&mut fixed, &events);
+    assert_eq!(fixed.stop_price(), Some(initial_stop));
+
+    // 止盈设为1倍ATR时很快离场
+    let mut target = SqueezeBreakoutStrategy::new(5, 2.0, 5, 1.5)
+        .with_roc(3)
+        .with_atr_exits(5, 2.0, Some(1.0));
+    let signals = run_events(&mut target, &events);
+    assert_eq!(signals[21], Some(Signal::Buy));
+    assert!(target.target_price().is_none());
+    assert!(signals[22..].contains(&Some(Signal::Sell)));
+}
+
+/// 测试策略之外的卖出成交会清除持仓和止损价
+#[test]
+fn test_squeeze_breakout_external_sell_fill() {
+    let events = squeeze_then(&[102.0, 105.0, 109.0, 112.0, 110.0, 104.0, 98.0]);
+    let mut strategy = squeeze_strategy();
+    let signals = run_events(&mut strategy, &events[..22]);
+    assert_eq!(signals[21], Some(Signal::Buy));
+
+    strategy.on_order_filled(&aurora_core::Trade::new_buy(105.0, 1.0, 1640996460000));
+    assert!(strategy.is_holding());
+
+    strategy.on_order_filled(&aurora_core::Trade::new_sell(105.0, 1.0, 1640996470000));
+    assert!(!strategy.is_holding());
+    assert_eq!(strategy.stop_price(), None);
+
+    // 之后价格跌破原止损价也不会再发出卖出信号
+    assert!(!run_events(&mut strategy, &events[22..]).contains(&Some(Signal::Sell)));
+}
+
+/// 测试默认的MACD柱状图动量确认
+#[test]
+fn test_squeeze_breakout_macd_momentum() {
+    let mut quiet: Vec<f64> = (0..20).map(|i| if i % 2 == 0 { 100.0 } else { 100.2 }).collect();
+    quiet.extend([102.0, 105.0, 109.0, 112.0]);
+    let events = squeeze_then(&quiet);
+
+    let mut strategy = SqueezeBreakoutStrategy::new(5, 2.0, 5, 1.5).with_macd(3, 6, 2);
+    assert!(run_events(&mut strategy, &events).contains(&Some(Signal::Buy)));
+}
+
+/// 测试挤压突破策略的快照恢复
+#[test]
+fn test_squeeze_breakout_snapshot_restore() {
+    let events = squeeze_then(&[102.0, 105.0, 109.0, 112.0, 110.0, 104.0, 98.0]);
+    let mut original = squeeze_strategy();
+    run_events(&mut original, &events[..22]);
+    assert!(original.is_holding());
+
+    let snapshot = original.snapshot().unwrap();
+    let mut restored = squeeze_strategy();
+    restored.restore(&snapshot).unwrap();
+    assert_eq!(restored.stop_price(), original.stop_price());
+    assert_eq!(run_events(&mut restored, &events[22..]), run_events(&mut original, &events[22..]));
+
+    let mut mismatched = squeeze_strategy().with_min_squeeze(3);
+    assert!(matches!(mismatched.restore(&snapshot), Err(SnapshotError::Incompatible(_))));
+}
+
+/// 测试通过注册表按配置创建挤压突破策略
+#[test]
+fn test_registry_creates_squeeze_breakout() {
+    let registry = StrategyRegistry::new();
+    let events = squeeze_then(&[102.0, 105.0, 109.0, 112.0, 110.0, 104.0, 98.0]);
+
+    let config = strategy_config(
+        "squeeze-breakout",
+        &[
+            ("bb_period", StrategyParameter::Integer(5)),
+            ("kc_period", StrategyParameter::Integer(5)),
+            ("momentum", StrategyParameter::String("roc".to_string())),
+            ("roc_period", StrategyParameter::Integer(3)),
+            ("atr_period", StrategyParameter::Integer(5)),
+        ],
+    );
+    let mut created = registry.from_config(&config).unwrap();
+    let mut direct = squeeze_strategy();
+    assert_eq!(run_events(&mut created, &events), run_events(&mut direct, &events));
+
+    for parameters in [
+        vec![("kc_multiplier", StrategyParameter::Float(0.0))],
+        vec![("stop_atr", StrategyParameter::Float(-1.0))],
+        vec![("target_atr", StrategyParameter::Float(-2.0))],
+        vec![("macd_fast", StrategyParameter::Integer(30))],
+        vec![("momentum", StrategyParameter::String("rsi".to_string()))],
+        vec![("trailing", StrategyParameter::Integer(1))],
+    ] {
+        let error = registry.from_config(&strategy_config("squeeze-breakout", &parameters)).err().unwrap();
+        assert!(matches!(error, StrategyError::InvalidParameter { .. }), "{}", error);
+    }
+}
+
+/// 趋势跟踪测试数据：先下跌，再持续上涨，最后急跌
+fn trend_closes() -> Vec<f64> {
+    (0..15)
+        .map(|i| 130.0 - i as f64)
+        .chain((0..25).map(|i| 116.0 + i as f64 * 2.0))
+        .chain([150.0, 140.0, 130.0])
+        .collect()
+}
+
+/// 震荡测试数据：围绕100来回摆动，均线反复交叉
+fn choppy_closes() -> Vec<f64> {
+    (0..80).map(|i| 100.0 + 4.0 * ((i as f64) * 2.1).sin()).collect()
+}
+
+/// 测试趋势跟踪策略在强趋势中买入，PSAR翻转后卖出
+#[test]
+fn test_trend_following_enters_strong_trend_and_exits_on_psar() {
+    let closes = trend_closes();
+    let mut strategy = TrendFollowingStrategy::new(3, 8).with_adx_period(5);
+    let signals = run_closes(&mut strategy, &closes);
+
+    let buy_at = signals.iter().position(|s| *s == Some(Signal::Buy)).unwrap();
+    let sell_at = signals.iter().position(|s| *s == Some(Signal::Sell)).unwrap();
+    assert!(buy_at > 15 && buy_at < sell_at);
+    // 上涨阶段PSAR不翻转，直到急跌才离场
+    assert!(sell_at >= 40);
+    assert_eq!(signals.iter().flatten().count(), 2);
+    assert!(!strategy.is_holding());
+}
+
+/// 测试策略之外的卖出成交会让策略回到空仓
+#[test]
+fn test_trend_following_external_sell_fill() {
+    let events: Vec<MarketEvent> = trend_closes()
+        .iter()
+        .enumerate()
+        .map(|(i, &close)| MarketEvent::Kline(create_test_kline(close, 1640995200000 + i as i64 * 60000)))
+        .collect();
+    let mut strategy = TrendFollowingStrategy::new(3, 8).with_adx_period(5);
+    let signals = run_events(&mut strategy, &events);
+    let buy_at = signals.iter().position(|s| *s == Some(Signal::Buy)).unwrap();
+
+    let mut strategy = TrendFollowingStrategy::new(3, 8).with_adx_period(5);
+    run_events(&mut strategy, &events[..=buy_at]);
+    assert!(strategy.is_holding());
+
+    strategy.on_order_filled(&aurora_core::Trade::new_sell(120.0, 1.0, 1640995200000 + buy_at as i64 * 60000));
+    assert!(!strategy.is_holding());
+
+    // 只回到空仓，交叉检测器的状态不受影响：回看期内的交叉仍然有效，
+    // 之后的信号与买入被拒绝、从未持仓的策略完全相同
+    let mut rejected = TrendFollowingStrategy::new(3, 8).with_adx_period(5);
+    run_events(&mut rejected, &events[..=buy_at]);
+    rejected.on_order_rejected(&aurora_core::OrderRejection {
+        signal: Signal::Buy,
+        price: 120.0,
+        timestamp: 1640995200000 + buy_at as i64 * 60000,
+        reason: "资金不足".to_string(),
+    });
+    assert!(!rejected.is_holding());
+
+    let after_sell = run_events(&mut strategy, &events[buy_at + 1..]);
+    assert_eq!(after_sell, run_events(&mut rejected, &events[buy_at + 1..]));
+    assert_eq!(after_sell.first(), Some(&Some(Signal::Buy)));
+}
+
+/// 测试ADX过滤在震荡行情中阻止均线交叉入场
+#[test]
+fn test_trend_following_adx_filter_blocks_choppy_market() {
+    let closes = choppy_closes();
+
+    let mut filtered = TrendFollowingStrategy::new(3, 8).with_adx_period(5);
+    assert!(run_closes(&mut filtered, &closes).iter().all(Option::is_none));
+
+    let mut unfiltered = TrendFollowingStrategy::new(3, 8).without_adx_filter();
+    assert!(!unfiltered.has_adx_filter());
+    assert!(run_closes(&mut unfiltered, &closes).contains(&Some(Signal::Buy)));
+}
+
+/// 测试MACD交叉入场
+#[test]
+fn test_trend_following_macd_entry() {
+    let closes = trend_closes();
+    let mut strategy = TrendFollowingStrategy::with_macd_entry(3, 8, 3)
+        .with_cross_lookback(3)
+        .with_adx_period(5);
+    let signals = run_closes(&mut strategy, &closes);
+    assert!(signals.contains(&Some(Signal::Buy)));
+    assert!(signals.contains(&Some(Signal::Sell)));
+
+    // 先创建EMA策略再切换为MACD入场，结果相同
+    let mut switched = TrendFollowingStrategy::new(3, 8)
+        .with_macd(3, 8, 3)
+        .with_cross_lookback(3)
+        .with_adx_period(5);
+    assert_eq!(run_closes(&mut switched, &closes), signals);
+}
+
+/// 测试MACD周期为0时panic
+#[test]
+#[should_panic(expected = "MACD周期必须大于0")]
+fn test_trend_following_zero_macd_period() {
+    TrendFollowingStrategy::new(10, 30).with_macd(0, 26, 9);
+}
+
+/// 测试PSAR加速因子非法时panic
+#[test]
+#[should_panic(expected = "PSAR加速因子")]
+fn test_trend_following_invalid_psar() {
+    TrendFollowingStrategy::new(10, 30).with_psar(0.3, 0.2);
+}
+
+/// 测试趋势跟踪策略的快照恢复
+#[test]
+fn test_trend_following_snapshot_restore() {
+    let closes = trend_closes();
+    let mut original = TrendFollowingStrategy::new(3, 8).with_adx_period(5);
+    let signals = run_closes(&mut original, &closes[..35]);
+    assert!(signals.contains(&Some(Signal::Buy)));
+    assert!(original.is_holding());
+
+    let snapshot = original.snapshot().unwrap();
+    let mut restored = TrendFollowingStrategy::new(3, 8).with_adx_period(5);
+    restored.restore(&snapshot).unwrap();
+    assert!(restored.is_holding());
+    assert_eq!(run_closes(&mut restored, &closes[35..]), run_closes(&mut original, &closes[35..]));
+
+    let mut mismatched = TrendFollowingStrategy::new(3, 8).without_adx_filter();
+    assert!(matches!(mismatched.restore(&snapshot), Err(SnapshotError::Incompatible(_))));
+}
+
+/// 测试通过注册表按配置创建趋势跟踪策略
+#[test]
+fn test_registry_creates_trend_following() {
+    let registry = StrategyRegistry::new();
+    let closes = trend_closes();
+
+    let config = strategy_config(
+        "trend-following",
+        &[
+            ("ema_fast", StrategyParameter::Integer(3)),
+            ("ema_slow", StrategyParameter::Integer(8)),
+            ("adx_period", StrategyParameter::Integer(5)),
+            ("psar_acceleration", StrategyParameter::Float(0.02)),
+        ],
+    );
+    let mut created = registry.from_config(&config).unwrap();
+    let mut direct = TrendFollowingStrategy::new(3, 8).with_adx_period(5);
+    assert_eq!(run_closes(&mut created, &closes), run_closes(&mut direct, &closes));
+
+    let config = strategy_config(
+        "trend-following",
+        &[
+            ("entry", StrategyParameter::String("MACD".to_string())),
+            ("adx_filter", StrategyParameter::Bool(false)),
+        ],
+    );
+    assert!(registry.from_config(&config).is_ok());
+
+    for parameters in [
+        vec![("ema_fast", StrategyParameter::Integer(30))],
+        vec![
+            ("entry", StrategyParameter::String("macd".to_string())),
+            ("macd_slow", StrategyParameter::Integer(5)),
+        ],
+        vec![("entry", StrategyParameter::String("sma".to_string()))],
+        vec![("cross_lookback", StrategyParameter::Integer(0))],
+        vec![("psar_max_acceleration", StrategyParameter::Float(0.01))],
+    ] {
+        let error = registry.from_config(&strategy_config("trend-following", &parameters)).err().unwrap();
+        assert!(matches!(error, StrategyError::InvalidParameter { .. }), "{}", error);
+    }
+}
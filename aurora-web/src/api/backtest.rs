@@ -304,27 +304,11 @@ async fn execute_backtest(
     // 执行回测
     info!("配置已加载，开始运行回测引擎");
     
-    // 从策略列表中提取第一个策略参数（如果存在）
-    let (short_period, long_period) = if !full_config.strategies.is_empty() {
-        let strategy = &full_config.strategies[0];
-        let short = strategy.parameters.get("short_period")
-            .and_then(|v| match v {
-                aurora_config::StrategyParameter::Integer(i) => Some(*i as usize),
-                aurora_config::StrategyParameter::Float(f) => Some(*f as usize),
-                _ => None,
-            })
-            .unwrap_or(5);
-        let long = strategy.parameters.get("long_period")
-            .and_then(|v| match v {
-                aurora_config::StrategyParameter::Integer(i) => Some(*i as usize),
-                aurora_config::StrategyParameter::Float(f) => Some(*f as usize),
-                _ => None,
-            })
-            .unwrap_or(20);
-        (short, long)
-    } else {
-        (5, 20) // 默认值
-    };
+    let strategy_config = resolve_strategy_config(&full_config.strategies);
+    info!(
+        "回测策略: {} ({}), 参数: {:?}",
+        strategy_config.name, strategy_config.strategy_type, strategy_config.parameters
+    );
 
     // 更新进度: 15% - 参数提取完成,准备运行回测
     {
@@ -356,9 +340,7 @@ async fn execute_backtest(
     info!("开始运行回测引擎...");
     let backtest_result = run_backtest_with_progress(
         data_full_path.to_str().unwrap(),
-        "ma-crossover",
-        short_period,
-        long_period,
+        &strategy_config,
         &full_config.portfolio,
         config.pricing_mode.as_ref(),
         Some(progress_callback),
@@ -424,3 +406,35 @@ async fn execute_backtest(
     info!("回测任务执行完成: {}", task_id);
     Ok(())
 }
+
+/// 选出回测使用的策略配置
+///
+/// 使用第一个启用的策略，没有启用的策略时使用 5:20 均线交叉。
+/// 均线交叉策略未给出周期时同样补上 5 和 20，与 Web 端一直以来的默认值一致，
+/// 而不是策略注册表的默认值。
+pub fn resolve_strategy_config(strategies: &[aurora_config::StrategyConfig]) -> aurora_config::StrategyConfig {
+    use aurora_config::StrategyParameter;
+
+    let mut config = strategies
+        .iter()
+        .find(|strategy| strategy.enabled)
+        .cloned()
+        .unwrap_or_else(|| aurora_config::StrategyConfig {
+            name: "ma-crossover".to_string(),
+            strategy_type: "ma-crossover".to_string(),
+            parameters: Default::default(),
+            enabled: true,
+        });
+
+    if config.strategy_type == "ma-crossover" {
+        for (names, default) in [(["short", "short_period"], 5), (["long", "long_period"], 20)] {
+            if !names.iter().any(|name| config.parameters.contains_key(*name)) {
+                config
+                    .parameters
+                    .insert(names[1].to_string(), StrategyParameter::Integer(default));
+            }
+        }
+    }
+
+    config
+}
@@ -19,6 +19,7 @@ use axum::{
     routing::{get, post},
     Json, Router,
 };
+use aurora_strategy::StrategyRegistry;
 use std::fs;
 use tracing::{debug, info};
 
@@ -196,12 +197,18 @@ async fn validate_config(
     let mut errors = Vec::new();
 
     match toml::from_str::<aurora_config::Config>(&req.content) {
-        Ok(_) => {
-            info!("配置验证成功");
-            Ok(Json(SuccessResponse::new(ConfigValidateResponse {
-                valid: true,
-                errors,
-            })))
+        Ok(config) => {
+            // 启用的策略必须能由注册表创建，提前暴露策略类型和参数错误
+            let registry = StrategyRegistry::new();
+            for strategy in config.strategies.iter().filter(|s| s.enabled) {
+                if let Err(e) = registry.from_config(strategy) {
+                    errors.push(format!("策略 {} 配置错误: {}", strategy.name, e));
+                }
+            }
+
+            let valid = errors.is_empty();
+            info!("配置验证{}: {:?}", if valid { "成功" } else { "失败" }, errors);
+            Ok(Json(SuccessResponse::new(ConfigValidateResponse { valid, errors })))
         }
         Err(e) => {
             errors.push(format!("TOML解析错误: {}", e));
@@ -0,0 +1,73 @@
+// Copyright 2025 blingbling21
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! 指标API
+//!
+//! 通过 [`IndicatorRegistry`] 列出可用指标，并按名称和参数验证指标配置。
+
+use aurora_indicators::IndicatorRegistry;
+use axum::{
+    routing::{get, post},
+    Json, Router,
+};
+use tracing::{debug, info};
+
+use crate::error::WebResult;
+use crate::models::{IndicatorValidateResponse, SuccessResponse, ValidateIndicatorRequest};
+use crate::state::AppState;
+
+/// 指标路由
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_indicators))
+        .route("/validate", post(validate_indicator))
+}
+
+/// 列出已注册的指标名称
+async fn list_indicators() -> WebResult<Json<SuccessResponse<Vec<String>>>> {
+    debug!("获取指标列表");
+
+    let names = IndicatorRegistry::new()
+        .names()
+        .into_iter()
+        .map(String::from)
+        .collect();
+    Ok(Json(SuccessResponse::new(names)))
+}
+
+/// 验证指标名称和参数
+///
+/// 由注册表实际构造一次指标，名称未注册或参数非法时返回错误信息，
+/// 成功时同时返回指标的预热长度。
+async fn validate_indicator(
+    Json(req): Json<ValidateIndicatorRequest>,
+) -> WebResult<Json<SuccessResponse<IndicatorValidateResponse>>> {
+    debug!("验证指标配置: {} {:?}", req.name, req.params);
+
+    let response = match IndicatorRegistry::new().create(&req.name, &req.params) {
+        Ok(indicator) => IndicatorValidateResponse {
+            valid: true,
+            warm_up_period: Some(indicator.warm_up_period()),
+            errors: Vec::new(),
+        },
+        Err(e) => IndicatorValidateResponse {
+            valid: false,
+            warm_up_period: None,
+            errors: vec![e.to_string()],
+        },
+    };
+
+    info!("指标 {} 验证{}", req.name, if response.valid { "成功" } else { "失败" });
+    Ok(Json(SuccessResponse::new(response)))
+}
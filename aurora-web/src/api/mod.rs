@@ -18,3 +18,4 @@ pub mod backtest;
 pub mod config;
 pub mod data;
 pub mod dashboard;
+pub mod indicators;
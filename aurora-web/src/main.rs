@@ -68,6 +68,7 @@ async fn main() -> std::io::Result<()> {
         .nest("/api/backtest", api::backtest::routes())
         .nest("/api/data", api::data::routes())
         .nest("/api/dashboard", api::dashboard::routes())
+        .nest("/api/indicators", api::indicators::routes())
         // WebSocket路由
         .nest("/ws", ws::routes())
         // 共享状态
@@ -43,6 +43,16 @@ pub struct UpdateConfigRequest {
     pub content: String,
 }
 
+/// 验证指标请求
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ValidateIndicatorRequest {
+    /// 指标名称(不区分大小写)
+    pub name: String,
+    /// 指标参数，未给出的参数取默认值
+    #[serde(default)]
+    pub params: aurora_indicators::IndicatorParams,
+}
+
 /// 获取历史数据请求
 #[derive(Debug, Deserialize, Serialize)]
 pub struct FetchDataRequest {
@@ -64,3 +64,12 @@ pub struct ConfigValidateResponse {
     pub valid: bool,
     pub errors: Vec<String>,
 }
+
+/// 指标验证响应
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndicatorValidateResponse {
+    pub valid: bool,
+    /// 指标产出有效值前需要的K线数
+    pub warm_up_period: Option<usize>,
+    pub errors: Vec<String>,
+}
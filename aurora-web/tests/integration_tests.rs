@@ -44,6 +44,7 @@ mod tests {
             .nest("/api/config", api::config::routes())
             .nest("/api/backtest", api::backtest::routes())
             .nest("/api/data", api::data::routes())
+            .nest("/api/indicators", api::indicators::routes())
             .with_state(app_state)
     }
 
@@ -149,6 +150,97 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn test_validate_config_checks_strategies() {
+        let app = create_test_app();
+
+        let invalid_strategy = serde_json::json!({
+            "content": "[[strategies]]\nname = \"双均线\"\nstrategy_type = \"ma-crossover\"\n\n[strategies.parameters]\nshort = 30\nlong = 10\n"
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/config/validate")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&invalid_strategy).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["data"]["valid"], false);
+        assert!(json["data"]["errors"][0].as_str().unwrap().contains("short"));
+    }
+
+    #[tokio::test]
+    async fn test_list_indicators() {
+        let app = create_test_app();
+
+        let response = app
+            .oneshot(Request::builder().uri("/api/indicators").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let names = json["data"].as_array().unwrap();
+        assert!(names.iter().any(|name| name == "macd"));
+        assert!(names.iter().any(|name| name == "rsi"));
+    }
+
+    #[tokio::test]
+    async fn test_validate_indicator() {
+        let app = create_test_app();
+        let validate = |body: serde_json::Value| {
+            Request::builder()
+                .method("POST")
+                .uri("/api/indicators/validate")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap()
+        };
+        let read_json = |response: axum::response::Response| async move {
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+        };
+
+        // 合法参数返回预热长度，名称不区分大小写
+        let response = app
+            .clone()
+            .oneshot(validate(serde_json::json!({ "name": "MA", "params": { "period": 14 } })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let json = read_json(response).await;
+        assert_eq!(json["data"]["valid"], true);
+        assert_eq!(json["data"]["warm_up_period"], 14);
+
+        // 参数非法
+        let response = app
+            .clone()
+            .oneshot(validate(serde_json::json!({ "name": "macd", "params": { "fast": 30, "slow": 10 } })))
+            .await
+            .unwrap();
+        let json = read_json(response).await;
+        assert_eq!(json["data"]["valid"], false);
+        assert!(json["data"]["errors"][0].as_str().unwrap().contains("fast"));
+
+        // 未注册的指标
+        let response = app
+            .oneshot(validate(serde_json::json!({ "name": "unknown" })))
+            .await
+            .unwrap();
+        let json = read_json(response).await;
+        assert_eq!(json["data"]["valid"], false);
+        assert!(json["data"]["errors"][0].as_str().unwrap().contains("unknown"));
+    }
+
     #[tokio::test]
     async fn test_get_nonexistent_config() {
         let app = create_test_app();
@@ -279,4 +371,38 @@ mod tests {
         // 清理测试文件
         std::fs::remove_file(&test_file_path).ok();
     }
+
+    #[test]
+    fn test_resolve_strategy_config_defaults() {
+        use aurora_config::{StrategyConfig, StrategyParameter};
+        use aurora_web::api::backtest::resolve_strategy_config;
+
+        // 没有启用的策略时使用 5:20 均线交叉
+        let config = resolve_strategy_config(&[]);
+        assert_eq!(config.strategy_type, "ma-crossover");
+        assert_eq!(config.parameters["short_period"].as_usize(), Some(5));
+        assert_eq!(config.parameters["long_period"].as_usize(), Some(20));
+
+        // 启用的均线交叉策略只给出长周期时，短周期补 5，已有参数保持不变
+        let configured = StrategyConfig {
+            name: "ma".to_string(),
+            strategy_type: "ma-crossover".to_string(),
+            parameters: [("long".to_string(), StrategyParameter::Integer(50))].into_iter().collect(),
+            enabled: true,
+        };
+        let config = resolve_strategy_config(&[configured]);
+        assert_eq!(config.parameters.len(), 2);
+        assert_eq!(config.parameters["short_period"].as_usize(), Some(5));
+        assert_eq!(config.parameters["long"].as_usize(), Some(50));
+        assert!(aurora_strategy::StrategyRegistry::new().from_config(&config).is_ok());
+
+        // 其他策略不补均线参数
+        let rsi = StrategyConfig {
+            name: "rsi".to_string(),
+            strategy_type: "rsi-reversion".to_string(),
+            parameters: Default::default(),
+            enabled: true,
+        };
+        assert!(resolve_strategy_config(&[rsi]).parameters.is_empty());
+    }
 }